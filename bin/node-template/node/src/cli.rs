@@ -7,6 +7,19 @@ pub struct Cli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	/// Seal blocks on demand instead of running Aura and GRANDPA, for test networks.
+	#[arg(long, value_enum, ignore_case = true)]
+	pub sealing: Option<Sealing>,
+}
+
+/// How blocks are sealed when `--sealing` is used.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum Sealing {
+	/// Seal blocks when requested over the `engine_*` RPC methods.
+	Manual,
+	/// Seal a block for every transaction imported into the pool.
+	Instant,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -27,15 +40,25 @@ pub enum Subcommand {
 	/// Export the state of a given block into a chain spec.
 	ExportState(sc_cli::ExportStateCmd),
 
+	/// Export the state of a given block into a state snapshot.
+	ExportSnapshot(sc_cli::ExportSnapshotCmd),
+
 	/// Import blocks.
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
+	/// Bootstrap an empty database from a state snapshot.
+	ImportSnapshot(sc_cli::ImportSnapshotCmd),
+
 	/// Remove the whole chain.
 	PurgeChain(sc_cli::PurgeChainCmd),
 
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
 
+	/// Database management cli utilities.
+	#[command(subcommand)]
+	Db(sc_cli::DbSubcommand),
+
 	/// Sub-commands concerned with benchmarking.
 	#[command(subcommand)]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
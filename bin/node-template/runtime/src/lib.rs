@@ -6,6 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use frame_support::genesis_builder_helper::{build_config, create_default_config};
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
@@ -391,6 +392,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(4)]
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
 		fn validate_transaction(
 			source: TransactionSource,
@@ -399,6 +401,30 @@ impl_runtime_apis! {
 		) -> TransactionValidity {
 			Executive::validate_transaction(source, tx, block_hash)
 		}
+
+		fn validate_transaction_with_info(
+			source: TransactionSource,
+			tx: <Block as BlockT>::Extrinsic,
+			block_hash: <Block as BlockT>::Hash,
+		) -> (TransactionValidity, Option<sp_transaction_pool::runtime_api::TransactionInfo>) {
+			use codec::Encode;
+
+			let info = tx.signature.as_ref().map(|(address, _, extra)| {
+				let sender = match address {
+					sp_runtime::MultiAddress::Id(who) => who.encode(),
+					other => other.encode(),
+				};
+				let tip = extra.7.tip();
+				let len = tx.encoded_size() as u32;
+				let info = TransactionPayment::query_info(tx.clone(), len);
+				sp_transaction_pool::runtime_api::TransactionInfo {
+					sender,
+					fee: info.partial_fee.saturating_add(tip),
+					weight: info.weight.ref_time(),
+				}
+			});
+			(Executive::validate_transaction(source, tx, block_hash), info)
+		}
 	}
 
 	impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
@@ -429,6 +455,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
+		fn create_default_config() -> Vec<u8> {
+			create_default_config::<GenesisConfig>()
+		}
+
+		fn build_config(config: Vec<u8>) -> sp_genesis_builder::Result {
+			build_config::<GenesisConfig>(config)
+		}
+	}
+
 	impl fg_primitives::GrandpaApi<Block> for Runtime {
 		fn grandpa_authorities() -> GrandpaAuthorityList {
 			Grandpa::grandpa_authorities()
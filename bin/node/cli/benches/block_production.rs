@@ -100,7 +100,12 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		prometheus_config: None,
 		telemetry_endpoints: None,
 		default_heap_pages: None,
-		offchain_worker: OffchainWorkerConfig { enabled: true, indexing_enabled: false },
+		offchain_worker: OffchainWorkerConfig {
+			enabled: true,
+			indexing_enabled: false,
+			websockets_enabled: false,
+			http_recording: None,
+		},
 		force_authoring: false,
 		disable_grandpa: false,
 		dev_key_seed: Some(Sr25519Keyring::Alice.to_seed()),
@@ -112,6 +117,8 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		base_path: Some(base_path),
 		informant_output_format: Default::default(),
 		wasm_runtime_overrides: None,
+		wasm_runtime_artifacts: None,
+		wasm_max_memory_pages: None,
 	};
 
 	node_cli::service::new_full_base(config, false, |_, _| ())
@@ -504,4 +504,28 @@ pub(crate) mod tests {
 	fn test_staging_test_net_chain_spec() {
 		staging_testnet_config().build_storage().unwrap();
 	}
+
+	#[test]
+	fn runtime_genesis_builder_matches_native_genesis() {
+		let genesis = local_testnet_genesis();
+		let expected = genesis.build_storage().unwrap();
+
+		let chain_spec = ChainSpec::from_runtime_genesis_patch(
+			"Local Testnet",
+			"local_testnet",
+			ChainType::Local,
+			wasm_binary_unwrap(),
+			serde_json::to_value(&genesis).unwrap(),
+			vec![],
+			None,
+			None,
+			None,
+			None,
+			Default::default(),
+		);
+		let storage = chain_spec.build_storage().unwrap();
+
+		assert_eq!(storage.top, expected.top);
+		assert_eq!(storage.children_default, expected.children_default);
+	}
 }
@@ -138,6 +138,13 @@ pub fn run() -> Result<()> {
 
 						cmd.run(config, partial.client, db, storage)
 					},
+					BenchmarkCmd::StorageProfile(cmd) => {
+						// ensure that we keep the task manager alive
+						let partial = new_partial(&config)?;
+						let profiler = partial.backend.storage_profiler().clone();
+
+						cmd.run(partial.client, profiler)
+					},
 					BenchmarkCmd::Overhead(cmd) => {
 						// ensure that we keep the task manager alive
 						let partial = new_partial(&config)?;
@@ -206,6 +213,20 @@ pub fn run() -> Result<()> {
 				Ok((cmd.run(client, config.chain_spec), task_manager))
 			})
 		},
+		Some(Subcommand::PrecompileRuntime(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, task_manager, .. } = new_partial(&config)?;
+				Ok((cmd.run(config, client), task_manager))
+			})
+		},
+		Some(Subcommand::TraceBlock(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, task_manager, .. } = new_partial(&config)?;
+				Ok((cmd.run(client), task_manager))
+			})
+		},
 		Some(Subcommand::ImportBlocks(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
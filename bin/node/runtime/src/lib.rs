@@ -29,13 +29,14 @@ use frame_election_provider_support::{
 use frame_support::{
 	construct_runtime,
 	dispatch::DispatchClass,
+	genesis_builder_helper::{build_config, create_default_config},
 	pallet_prelude::Get,
 	parameter_types,
 	traits::{
 		fungible::ItemOf, tokens::nonfungibles_v2::Inspect, AsEnsureOriginWithArg, ConstBool,
-		ConstU128, ConstU16, ConstU32, Currency, EitherOfDiverse, EqualPrivilegeOnly, Everything,
-		Imbalance, InstanceFilter, KeyOwnerProofSystem, LockIdentifier, Nothing, OnUnbalanced,
-		U128CurrencyToVote, WithdrawReasons,
+		ConstU128, ConstU16, ConstU32, Contains, Currency, EitherOfDiverse, EqualPrivilegeOnly,
+		Everything, Imbalance, InstanceFilter, KeyOwnerProofSystem, LockIdentifier, Nothing,
+		OnUnbalanced, U128CurrencyToVote, WithdrawReasons,
 	},
 	weights::{
 		constants::{
@@ -447,6 +448,7 @@ impl pallet_balances::Config for Runtime {
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = frame_system::Pallet<Runtime>;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
+	type IsTransferable = ConstBool<true>;
 }
 
 parameter_types! {
@@ -1196,6 +1198,7 @@ parameter_types! {
 		.max_total
 		.unwrap_or(RuntimeBlockWeights::get().max_block);
 	pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
+	pub CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
 }
 
 impl pallet_contracts::Config for Runtime {
@@ -1216,7 +1219,8 @@ impl pallet_contracts::Config for Runtime {
 	type CallStack = [pallet_contracts::Frame<Self>; 5];
 	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-	type ChainExtension = ();
+	type ChainExtension =
+		pallet_contracts::chain_extension::assets::AssetsExtension<ContractAssets>;
 	type DeletionQueueDepth = DeletionQueueDepth;
 	type DeletionWeightLimit = DeletionWeightLimit;
 	type Schedule = Schedule;
@@ -1225,6 +1229,59 @@ impl pallet_contracts::Config for Runtime {
 	type MaxStorageKeyLen = ConstU32<128>;
 	type UnsafeUnstableInterface = ConstBool<false>;
 	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+	type MaxCallTraceLen = ConstU32<1024>;
+	type MaxDelegateDependencies = ConstU32<32>;
+	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type Migrations = (pallet_contracts::migration::v10::Migration<Runtime>,);
+	type IsTransferable = ConstBool<true>;
+}
+
+/// Gives contracts access to `Assets` and `Nfts`.
+pub struct ContractAssets;
+
+impl pallet_contracts::chain_extension::assets::AssetsConfig<Runtime> for ContractAssets {
+	type Assets = Assets;
+	type TransferableAssets = TransferableAssets;
+	type Nfts = Nfts;
+	type WeightInfo = Self;
+}
+
+/// The functions reading a single value are weighed as one database read. The others are weighed
+/// as the dispatchables of `Assets` and `Nfts` doing the same.
+impl pallet_contracts::chain_extension::assets::WeightInfo for ContractAssets {
+	fn balance() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+	}
+	fn total_supply() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+	}
+	fn allowance() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+	}
+	fn transfer() -> Weight {
+		<<Runtime as pallet_assets::Config>::WeightInfo as pallet_assets::WeightInfo>::transfer_keep_alive()
+	}
+	fn approve() -> Weight {
+		<<Runtime as pallet_assets::Config>::WeightInfo as pallet_assets::WeightInfo>::approve_transfer()
+	}
+	fn transfer_from() -> Weight {
+		<<Runtime as pallet_assets::Config>::WeightInfo as pallet_assets::WeightInfo>::transfer_approved()
+	}
+	fn nft_owner() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+	}
+	fn nft_transfer() -> Weight {
+		<<Runtime as pallet_nfts::Config>::WeightInfo as pallet_nfts::WeightInfo>::transfer()
+	}
+}
+
+/// The assets of `Assets` which can be transferred.
+pub struct TransferableAssets;
+
+impl Contains<u32> for TransferableAssets {
+	fn contains(asset: &u32) -> bool {
+		Assets::is_transferable(*asset)
+	}
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -1573,6 +1630,11 @@ impl pallet_uniques::Config for Runtime {
 parameter_types! {
 	pub Features: PalletFeatures = PalletFeatures::all_enabled();
 	pub const MaxAttributesPerCall: u32 = 10;
+	pub const MaxRoyaltyRecipients: u32 = 5;
+	pub const MaxRoyalty: Permill = Permill::from_percent(25);
+	pub const MaxAuctionEndingsPerBlock: u32 = 10;
+	pub const MaxNestingDepth: u32 = 3;
+	pub const MaxNestedChildren: u32 = 10;
 }
 
 impl pallet_nfts::Config for Runtime {
@@ -1580,6 +1642,7 @@ impl pallet_nfts::Config for Runtime {
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
+	type Assets = Assets;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type CollectionDeposit = CollectionDeposit;
 	type ItemDeposit = ItemDeposit;
@@ -1594,6 +1657,11 @@ impl pallet_nfts::Config for Runtime {
 	type MaxTips = MaxTips;
 	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxAuctionEndingsPerBlock = MaxAuctionEndingsPerBlock;
+	type MaxNestingDepth = MaxNestingDepth;
+	type MaxNestedChildren = MaxNestedChildren;
 	type Features = Features;
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as traits::Verify>::Signer;
@@ -1834,6 +1902,7 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_nfts::migration::v1::MigrateToV1<Runtime>,
 );
 
 /// MMR helper types.
@@ -1941,6 +2010,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(4)]
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
 		fn validate_transaction(
 			source: TransactionSource,
@@ -1949,6 +2019,35 @@ impl_runtime_apis! {
 		) -> TransactionValidity {
 			Executive::validate_transaction(source, tx, block_hash)
 		}
+
+		fn validate_transaction_with_info(
+			source: TransactionSource,
+			tx: <Block as BlockT>::Extrinsic,
+			block_hash: <Block as BlockT>::Hash,
+		) -> (TransactionValidity, Option<sp_transaction_pool::runtime_api::TransactionInfo>) {
+			let info = tx.signature.as_ref().and_then(|(address, _, extra)| {
+				// Key the sender by its account, however the transaction addresses it.
+				let sender = <Runtime as frame_system::Config>::Lookup::lookup(address.clone()).ok()?;
+				let (
+					_non_zero_sender,
+					_spec_version,
+					_tx_version,
+					_genesis,
+					_era,
+					_nonce,
+					_weight,
+					payment,
+				) = extra;
+				let len = tx.encoded_size() as u32;
+				let info = TransactionPayment::query_info(tx.clone(), len);
+				Some(sp_transaction_pool::runtime_api::TransactionInfo {
+					sender: sender.encode(),
+					fee: info.partial_fee.saturating_add(payment.tip()),
+					weight: info.weight.ref_time(),
+				})
+			});
+			(Executive::validate_transaction(source, tx, block_hash), info)
+		}
 	}
 
 	impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
@@ -2120,6 +2219,55 @@ impl_runtime_apis! {
 			)
 		}
 
+		fn trace_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts_primitives::TracedResult<
+			pallet_contracts_primitives::ContractExecResult<Balance>,
+			AccountId,
+			Balance,
+		> {
+			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+			Contracts::bare_call_traced(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				pallet_contracts::Determinism::Deterministic,
+			)
+		}
+
+		fn trace_instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			code: pallet_contracts_primitives::Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> pallet_contracts_primitives::TracedResult<
+			pallet_contracts_primitives::ContractInstantiateResult<AccountId, Balance>,
+			AccountId,
+			Balance,
+		> {
+			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+			Contracts::bare_instantiate_traced(
+				origin,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				code,
+				data,
+				salt,
+			)
+		}
+
 		fn upload_code(
 			origin: AccountId,
 			code: Vec<u8>,
@@ -2287,6 +2435,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
+		fn create_default_config() -> Vec<u8> {
+			create_default_config::<GenesisConfig>()
+		}
+
+		fn build_config(config: Vec<u8>) -> sp_genesis_builder::Result {
+			build_config::<GenesisConfig>(config)
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
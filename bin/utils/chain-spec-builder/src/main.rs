@@ -23,7 +23,7 @@ use std::{
 };
 
 use ansi_term::Style;
-use clap::Parser;
+use clap::{Args, Parser};
 use rand::{distributions::Alphanumeric, rngs::OsRng, Rng};
 
 use node_cli::chain_spec::{self, AccountId};
@@ -34,66 +34,81 @@ use sp_core::{
 };
 use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
 
+mod spec;
+mod verify;
+
 /// A utility to easily create a testnet chain spec definition with a given set
 /// of authorities and endowed accounts and/or generate random accounts.
+///
+/// Chain specs that are not tied to the node runtime can be created, updated, converted to raw
+/// format, verified and compared as well.
 #[derive(Parser)]
 #[command(rename_all = "kebab-case")]
 enum ChainSpecBuilder {
 	/// Create a new chain spec with the given authorities, endowed and sudo
 	/// accounts.
-	New {
-		/// Authority key seed.
-		#[arg(long, short, required = true)]
-		authority_seeds: Vec<String>,
-		/// Active nominators (SS58 format), each backing a random subset of the aforementioned
-		/// authorities.
-		#[arg(long, short, default_value = "0")]
-		nominator_accounts: Vec<String>,
-		/// Endowed account address (SS58 format).
-		#[arg(long, short)]
-		endowed_accounts: Vec<String>,
-		/// Sudo account address (SS58 format).
-		#[arg(long, short)]
-		sudo_account: String,
-		/// The path where the chain spec should be saved.
-		#[arg(long, short, default_value = "./chain_spec.json")]
-		chain_spec_path: PathBuf,
-	},
+	New(NewCmd),
 	/// Create a new chain spec with the given number of authorities and endowed
 	/// accounts. Random keys will be generated as required.
-	Generate {
-		/// The number of authorities.
-		#[arg(long, short)]
-		authorities: usize,
-		/// The number of nominators backing the aforementioned authorities.
-		///
-		/// Will nominate a random subset of `authorities`.
-		#[arg(long, short, default_value_t = 0)]
-		nominators: usize,
-		/// The number of endowed accounts.
-		#[arg(long, short, default_value_t = 0)]
-		endowed: usize,
-		/// The path where the chain spec should be saved.
-		#[arg(long, short, default_value = "./chain_spec.json")]
-		chain_spec_path: PathBuf,
-		/// Path to use when saving generated keystores for each authority.
-		///
-		/// At this path, a new folder will be created for each authority's
-		/// keystore named `auth-$i` where `i` is the authority index, i.e.
-		/// `auth-0`, `auth-1`, etc.
-		#[arg(long, short)]
-		keystore_path: Option<PathBuf>,
-	},
+	Generate(GenerateCmd),
+	/// Create a new chain spec from a runtime wasm blob and a genesis config preset.
+	Create(spec::CreateCmd),
+	/// Replace the runtime code in the genesis of a chain spec.
+	UpdateCode(spec::UpdateCodeCmd),
+	/// Add a code substitute to a chain spec.
+	AddCodeSubstitute(spec::AddCodeSubstituteCmd),
+	/// Convert a chain spec to raw format, building its genesis state.
+	ConvertToRaw(spec::ConvertToRawCmd),
+	/// Verify the genesis state of a chain spec against the runtime metadata and print its state
+	/// root.
+	Verify(verify::VerifyCmd),
+	/// Print the differences between two chain specs.
+	Diff(spec::DiffCmd),
 }
 
-impl ChainSpecBuilder {
-	/// Returns the path where the chain spec should be saved.
-	fn chain_spec_path(&self) -> &Path {
-		match self {
-			ChainSpecBuilder::New { chain_spec_path, .. } => chain_spec_path.as_path(),
-			ChainSpecBuilder::Generate { chain_spec_path, .. } => chain_spec_path.as_path(),
-		}
-	}
+#[derive(Debug, Clone, Args)]
+struct NewCmd {
+	/// Authority key seed.
+	#[arg(long, short, required = true)]
+	authority_seeds: Vec<String>,
+	/// Active nominators (SS58 format), each backing a random subset of the aforementioned
+	/// authorities.
+	#[arg(long, short, default_value = "0")]
+	nominator_accounts: Vec<String>,
+	/// Endowed account address (SS58 format).
+	#[arg(long, short)]
+	endowed_accounts: Vec<String>,
+	/// Sudo account address (SS58 format).
+	#[arg(long, short)]
+	sudo_account: String,
+	/// The path where the chain spec should be saved.
+	#[arg(long, short, default_value = "./chain_spec.json")]
+	chain_spec_path: PathBuf,
+}
+
+#[derive(Debug, Clone, Args)]
+struct GenerateCmd {
+	/// The number of authorities.
+	#[arg(long, short)]
+	authorities: usize,
+	/// The number of nominators backing the aforementioned authorities.
+	///
+	/// Will nominate a random subset of `authorities`.
+	#[arg(long, short, default_value_t = 0)]
+	nominators: usize,
+	/// The number of endowed accounts.
+	#[arg(long, short, default_value_t = 0)]
+	endowed: usize,
+	/// The path where the chain spec should be saved.
+	#[arg(long, short, default_value = "./chain_spec.json")]
+	chain_spec_path: PathBuf,
+	/// Path to use when saving generated keystores for each authority.
+	///
+	/// At this path, a new folder will be created for each authority's
+	/// keystore named `auth-$i` where `i` is the authority index, i.e.
+	/// `auth-0`, `auth-1`, etc.
+	#[arg(long, short)]
+	keystore_path: Option<PathBuf>,
 }
 
 fn genesis_constructor(
@@ -229,64 +244,98 @@ fn print_seeds(
 }
 
 fn main() -> Result<(), String> {
+	let (chain_spec_path, authority_seeds, nominator_accounts, endowed_accounts, sudo_account) =
+		match ChainSpecBuilder::parse() {
+			ChainSpecBuilder::Generate(GenerateCmd {
+				authorities,
+				nominators,
+				endowed,
+				chain_spec_path,
+				keystore_path,
+			}) => {
+				print_debug_build_warning();
+
+				let authorities = authorities.max(1);
+				let rand_str = || -> String {
+					OsRng.sample_iter(&Alphanumeric).take(32).map(char::from).collect()
+				};
+
+				let authority_seeds = (0..authorities).map(|_| rand_str()).collect::<Vec<_>>();
+				let nominator_seeds = (0..nominators).map(|_| rand_str()).collect::<Vec<_>>();
+				let endowed_seeds = (0..endowed).map(|_| rand_str()).collect::<Vec<_>>();
+				let sudo_seed = rand_str();
+
+				print_seeds(&authority_seeds, &nominator_seeds, &endowed_seeds, &sudo_seed);
+
+				if let Some(keystore_path) = keystore_path {
+					generate_authority_keys_and_store(&authority_seeds, &keystore_path)?;
+				}
+
+				let nominator_accounts = nominator_seeds
+					.into_iter()
+					.map(|seed| {
+						chain_spec::get_account_id_from_seed::<sr25519::Public>(&seed)
+							.to_ss58check()
+					})
+					.collect();
+
+				let endowed_accounts = endowed_seeds
+					.into_iter()
+					.map(|seed| {
+						chain_spec::get_account_id_from_seed::<sr25519::Public>(&seed)
+							.to_ss58check()
+					})
+					.collect();
+
+				let sudo_account =
+					chain_spec::get_account_id_from_seed::<sr25519::Public>(&sudo_seed)
+						.to_ss58check();
+
+				(
+					chain_spec_path,
+					authority_seeds,
+					nominator_accounts,
+					endowed_accounts,
+					sudo_account,
+				)
+			},
+			ChainSpecBuilder::New(NewCmd {
+				authority_seeds,
+				nominator_accounts,
+				endowed_accounts,
+				sudo_account,
+				chain_spec_path,
+			}) => {
+				print_debug_build_warning();
+
+				(
+					chain_spec_path,
+					authority_seeds,
+					nominator_accounts,
+					endowed_accounts,
+					sudo_account,
+				)
+			},
+			ChainSpecBuilder::Create(cmd) => return cmd.run(),
+			ChainSpecBuilder::UpdateCode(cmd) => return cmd.run(),
+			ChainSpecBuilder::AddCodeSubstitute(cmd) => return cmd.run(),
+			ChainSpecBuilder::ConvertToRaw(cmd) => return cmd.run(),
+			ChainSpecBuilder::Verify(cmd) => return cmd.run(),
+			ChainSpecBuilder::Diff(cmd) => return cmd.run(),
+		};
+
+	let json =
+		generate_chain_spec(authority_seeds, nominator_accounts, endowed_accounts, sudo_account)?;
+
+	fs::write(chain_spec_path, json).map_err(|err| err.to_string())
+}
+
+/// The testnet chain specs embed the node runtime, which should be built in release mode.
+fn print_debug_build_warning() {
 	#[cfg(build_type = "debug")]
 	println!(
 		"The chain spec builder builds a chain specification that includes a Substrate runtime \
 		 compiled as WASM. To ensure proper functioning of the included runtime compile (or run) \
 		 the chain spec builder binary in `--release` mode.\n",
 	);
-
-	let builder = ChainSpecBuilder::parse();
-	let chain_spec_path = builder.chain_spec_path().to_path_buf();
-
-	let (authority_seeds, nominator_accounts, endowed_accounts, sudo_account) = match builder {
-		ChainSpecBuilder::Generate { authorities, nominators, endowed, keystore_path, .. } => {
-			let authorities = authorities.max(1);
-			let rand_str = || -> String {
-				OsRng.sample_iter(&Alphanumeric).take(32).map(char::from).collect()
-			};
-
-			let authority_seeds = (0..authorities).map(|_| rand_str()).collect::<Vec<_>>();
-			let nominator_seeds = (0..nominators).map(|_| rand_str()).collect::<Vec<_>>();
-			let endowed_seeds = (0..endowed).map(|_| rand_str()).collect::<Vec<_>>();
-			let sudo_seed = rand_str();
-
-			print_seeds(&authority_seeds, &nominator_seeds, &endowed_seeds, &sudo_seed);
-
-			if let Some(keystore_path) = keystore_path {
-				generate_authority_keys_and_store(&authority_seeds, &keystore_path)?;
-			}
-
-			let nominator_accounts = nominator_seeds
-				.into_iter()
-				.map(|seed| {
-					chain_spec::get_account_id_from_seed::<sr25519::Public>(&seed).to_ss58check()
-				})
-				.collect();
-
-			let endowed_accounts = endowed_seeds
-				.into_iter()
-				.map(|seed| {
-					chain_spec::get_account_id_from_seed::<sr25519::Public>(&seed).to_ss58check()
-				})
-				.collect();
-
-			let sudo_account =
-				chain_spec::get_account_id_from_seed::<sr25519::Public>(&sudo_seed).to_ss58check();
-
-			(authority_seeds, nominator_accounts, endowed_accounts, sudo_account)
-		},
-		ChainSpecBuilder::New {
-			authority_seeds,
-			nominator_accounts,
-			endowed_accounts,
-			sudo_account,
-			..
-		} => (authority_seeds, nominator_accounts, endowed_accounts, sudo_account),
-	};
-
-	let json =
-		generate_chain_spec(authority_seeds, nominator_accounts, endowed_accounts, sudo_account)?;
-
-	fs::write(chain_spec_path, json).map_err(|err| err.to_string())
 }
@@ -0,0 +1,447 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Subcommands creating and editing chain specs that are not tied to a native runtime.
+
+use std::{
+	collections::BTreeSet,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use ansi_term::Style;
+use clap::Args;
+use serde_json::{json, Value};
+
+use sc_chain_spec::{ChainType, GenericChainSpec, NoGenesisConfig};
+use sp_core::{bytes::to_hex, storage::well_known_keys};
+use sp_runtime::{BuildStorage, Storage};
+
+/// Chain spec whose genesis is built by the runtime it contains, or given as raw storage.
+///
+/// Any extensions of the spec are kept as they are. Use [`load`] to read chain specs with a
+/// native runtime genesis as well.
+pub type ChainSpec = GenericChainSpec<NoGenesisConfig, serde_json::Map<String, Value>>;
+
+/// Create a new chain spec from a runtime wasm blob and a genesis config preset.
+#[derive(Debug, Clone, Args)]
+pub struct CreateCmd {
+	/// The name of the chain.
+	#[arg(long, short = 'n', default_value = "Custom")]
+	chain_name: String,
+	/// The id of the chain.
+	#[arg(long, short = 'i', default_value = "custom")]
+	chain_id: String,
+	/// The path to the runtime wasm blob.
+	#[arg(long, short)]
+	runtime_wasm_path: PathBuf,
+	/// The path to a JSON patch applied to the default genesis config of the runtime.
+	///
+	/// The default genesis config is used if no preset is given.
+	#[arg(long, short)]
+	preset_path: Option<PathBuf>,
+	/// Store the genesis state in raw format.
+	#[arg(long)]
+	raw: bool,
+	/// The path where the chain spec should be saved.
+	#[arg(long, short, default_value = "./chain_spec.json")]
+	chain_spec_path: PathBuf,
+}
+
+impl CreateCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), String> {
+		let code = read_code(&self.runtime_wasm_path)?;
+		let patch = match &self.preset_path {
+			Some(path) => read_json(path)?,
+			None => json!({}),
+		};
+
+		let chain_spec = ChainSpec::from_runtime_genesis_patch(
+			&self.chain_name,
+			&self.chain_id,
+			ChainType::Live,
+			&code,
+			patch,
+			vec![],
+			None,
+			None,
+			None,
+			None,
+			Default::default(),
+		);
+
+		write(&self.chain_spec_path, chain_spec.as_json(self.raw)?)
+	}
+}
+
+/// Replace the runtime code in the genesis of a chain spec.
+#[derive(Debug, Clone, Args)]
+pub struct UpdateCodeCmd {
+	/// The chain spec to update.
+	input_chain_spec: PathBuf,
+	/// The path to the new runtime wasm blob.
+	runtime_wasm_path: PathBuf,
+	/// The path where the updated chain spec should be saved.
+	#[arg(long, short, default_value = "./chain_spec.json")]
+	chain_spec_path: PathBuf,
+}
+
+impl UpdateCodeCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), String> {
+		let mut chain_spec = read_json(&self.input_chain_spec)?;
+		update_code(&mut chain_spec, &read_code(&self.runtime_wasm_path)?)?;
+		write(&self.chain_spec_path, to_string(&chain_spec)?)
+	}
+}
+
+/// Add a code substitute to a chain spec.
+///
+/// The substitute is used instead of the on-chain runtime starting at the given block, until the
+/// `spec_version` on chain changes.
+#[derive(Debug, Clone, Args)]
+pub struct AddCodeSubstituteCmd {
+	/// The chain spec to update.
+	input_chain_spec: PathBuf,
+	/// The path to the substitute runtime wasm blob.
+	runtime_wasm_path: PathBuf,
+	/// The block height from which the substitute is used.
+	block_height: u64,
+	/// The path where the updated chain spec should be saved.
+	#[arg(long, short, default_value = "./chain_spec.json")]
+	chain_spec_path: PathBuf,
+}
+
+impl AddCodeSubstituteCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), String> {
+		let mut chain_spec = read_json(&self.input_chain_spec)?;
+		add_code_substitute(
+			&mut chain_spec,
+			&read_code(&self.runtime_wasm_path)?,
+			self.block_height,
+		)?;
+		write(&self.chain_spec_path, to_string(&chain_spec)?)
+	}
+}
+
+/// Convert a chain spec to raw format, building its genesis state.
+#[derive(Debug, Clone, Args)]
+pub struct ConvertToRawCmd {
+	/// The chain spec to convert.
+	input_chain_spec: PathBuf,
+	/// The path where the raw chain spec should be saved.
+	#[arg(long, short, default_value = "./chain_spec.json")]
+	chain_spec_path: PathBuf,
+}
+
+impl ConvertToRawCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), String> {
+		let chain_spec = load(&self.input_chain_spec)?;
+		write(&self.chain_spec_path, chain_spec.as_json(true)?)
+	}
+}
+
+/// Print the differences between two chain specs.
+///
+/// The genesis states are compared key by key, after building them if required.
+#[derive(Debug, Clone, Args)]
+pub struct DiffCmd {
+	/// The chain spec to compare against.
+	left: PathBuf,
+	/// The chain spec to compare.
+	right: PathBuf,
+}
+
+impl DiffCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), String> {
+		let header = Style::new().bold().underline();
+
+		println!("{}", header.paint("Chain spec fields"));
+		let fields = diff_fields(&read_json(&self.left)?, &read_json(&self.right)?);
+		print_changes(&fields);
+
+		println!("{}", header.paint("Genesis storage"));
+		let storage = diff_storage(&build_storage(&self.left)?, &build_storage(&self.right)?);
+		print_changes(&storage);
+
+		Ok(())
+	}
+}
+
+/// A difference between two chain specs.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change {
+	/// The entry only exists in the right chain spec.
+	Added(String),
+	/// The entry only exists in the left chain spec.
+	Removed(String),
+	/// The entry exists in both chain specs, with different values.
+	Changed(String),
+}
+
+/// Replace the runtime code in the genesis of `chain_spec`.
+fn update_code(chain_spec: &mut Value, code: &[u8]) -> Result<(), String> {
+	let code = Value::String(to_hex(code, false));
+	let genesis = chain_spec
+		.get_mut("genesis")
+		.and_then(Value::as_object_mut)
+		.ok_or("Chain spec has no genesis")?;
+
+	if let Some(runtime_genesis) = genesis.get_mut("runtimeGenesis") {
+		runtime_genesis["code"] = code;
+	} else if let Some(raw) = genesis.get_mut("raw") {
+		raw["top"][to_hex(well_known_keys::CODE, false)] = code;
+	} else if let Some(runtime) = genesis.get_mut("runtime") {
+		runtime["system"]["code"] = code;
+	} else {
+		return Err("Chain spec genesis does not contain any runtime code".into())
+	}
+
+	Ok(())
+}
+
+/// Add `code` as code substitute of `chain_spec`, starting at `block_height`.
+fn add_code_substitute(
+	chain_spec: &mut Value,
+	code: &[u8],
+	block_height: u64,
+) -> Result<(), String> {
+	let spec = chain_spec.as_object_mut().ok_or("Chain spec is not a JSON object")?;
+	spec.entry("codeSubstitutes")
+		.or_insert_with(|| json!({}))
+		.as_object_mut()
+		.ok_or("`codeSubstitutes` is not a JSON object")?
+		.insert(block_height.to_string(), Value::String(to_hex(code, false)));
+
+	Ok(())
+}
+
+/// Compare the top-level fields of two chain specs, except for the genesis.
+fn diff_fields(left: &Value, right: &Value) -> Vec<Change> {
+	let empty = serde_json::Map::new();
+	let left = left.as_object().unwrap_or(&empty);
+	let right = right.as_object().unwrap_or(&empty);
+
+	left.keys()
+		.chain(right.keys())
+		.filter(|key| *key != "genesis")
+		.collect::<BTreeSet<_>>()
+		.into_iter()
+		.filter_map(|key| match (left.get(key), right.get(key)) {
+			(None, Some(_)) => Some(Change::Added(key.clone())),
+			(Some(_), None) => Some(Change::Removed(key.clone())),
+			(Some(l), Some(r)) if l != r => Some(Change::Changed(key.clone())),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Compare two genesis states key by key.
+///
+/// Keys of child tries are prefixed with the storage key of their child trie.
+fn diff_storage(left: &Storage, right: &Storage) -> Vec<Change> {
+	let flatten = |storage: &Storage| {
+		storage
+			.top
+			.iter()
+			.map(|(k, v)| (to_hex(k, false), v.clone()))
+			.chain(storage.children_default.iter().flat_map(|(child, data)| {
+				data.data.iter().map(move |(k, v)| {
+					(format!("{}/{}", to_hex(child, false), to_hex(k, false)), v.clone())
+				})
+			}))
+			.collect::<std::collections::BTreeMap<_, _>>()
+	};
+	let (left, right) = (flatten(left), flatten(right));
+
+	left.keys()
+		.chain(right.keys())
+		.collect::<BTreeSet<_>>()
+		.into_iter()
+		.filter_map(|key| match (left.get(key), right.get(key)) {
+			(None, Some(_)) => Some(Change::Added(key.clone())),
+			(Some(_), None) => Some(Change::Removed(key.clone())),
+			(Some(l), Some(r)) if l != r => Some(Change::Changed(key.clone())),
+			_ => None,
+		})
+		.collect()
+}
+
+fn print_changes(changes: &[Change]) {
+	if changes.is_empty() {
+		println!("No differences");
+	}
+	for change in changes {
+		match change {
+			Change::Added(key) => println!("+ {}", key),
+			Change::Removed(key) => println!("- {}", key),
+			Change::Changed(key) => println!("~ {}", key),
+		}
+	}
+	println!();
+}
+
+/// Load the chain spec at `path`.
+///
+/// The native `GenesisConfig` type of the runtime is not known here, so a `runtime` genesis is
+/// loaded as the equivalent `runtimeGenesis` config, which is built through the `GenesisBuilder`
+/// API of the runtime code it contains. Raw genesis storage is loaded as it is.
+pub fn load(path: &Path) -> Result<ChainSpec, String> {
+	let mut chain_spec = read_json(path)?;
+	native_to_runtime_genesis(&mut chain_spec)?;
+	ChainSpec::from_json_bytes(to_string(&chain_spec)?.into_bytes())
+}
+
+/// Build the genesis state of the chain spec at `path`.
+pub fn build_storage(path: &Path) -> Result<Storage, String> {
+	load(path)?.build_storage()
+}
+
+/// Replace a native `runtime` genesis of `chain_spec` with the same config given to the
+/// `GenesisBuilder` API of the runtime code in `system.code`.
+fn native_to_runtime_genesis(chain_spec: &mut Value) -> Result<(), String> {
+	let Some(genesis) = chain_spec.get_mut("genesis").and_then(Value::as_object_mut) else {
+		return Ok(())
+	};
+	let Some(config) = genesis.remove("runtime") else { return Ok(()) };
+
+	let code = config
+		.get("system")
+		.and_then(|system| system.get("code"))
+		.cloned()
+		.ok_or("Native runtime genesis does not contain any runtime code")?;
+	genesis.insert("runtimeGenesis".into(), json!({ "code": code, "config": config }));
+
+	Ok(())
+}
+
+fn read_code(path: &Path) -> Result<Vec<u8>, String> {
+	fs::read(path).map_err(|e| format!("Error reading runtime wasm `{}`: {}", path.display(), e))
+}
+
+fn read_json(path: &Path) -> Result<Value, String> {
+	let bytes = fs::read(path)
+		.map_err(|e| format!("Error reading JSON file `{}`: {}", path.display(), e))?;
+	serde_json::from_slice(&bytes)
+		.map_err(|e| format!("Error parsing JSON file `{}`: {}", path.display(), e))
+}
+
+fn to_string(chain_spec: &Value) -> Result<String, String> {
+	serde_json::to_string_pretty(chain_spec)
+		.map_err(|e| format!("Error generating spec json: {}", e))
+}
+
+fn write(path: &Path, json: String) -> Result<(), String> {
+	fs::write(path, json)
+		.map_err(|e| format!("Error writing chain spec `{}`: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn update_code_replaces_code_of_each_genesis_format() {
+		let mut runtime_genesis =
+			json!({ "genesis": { "runtimeGenesis": { "code": "0x00", "patch": {} } } });
+		let mut raw = json!({ "genesis": { "raw": { "top": { "0x3a636f6465": "0x00" }, "childrenDefault": {} } } });
+		let mut runtime = json!({ "genesis": { "runtime": { "system": { "code": "0x00" } } } });
+
+		for spec in [&mut runtime_genesis, &mut raw, &mut runtime] {
+			update_code(spec, &[1, 2]).unwrap();
+		}
+
+		assert_eq!(runtime_genesis["genesis"]["runtimeGenesis"]["code"], "0x0102");
+		assert_eq!(raw["genesis"]["raw"]["top"]["0x3a636f6465"], "0x0102");
+		assert_eq!(runtime["genesis"]["runtime"]["system"]["code"], "0x0102");
+		assert!(update_code(&mut json!({ "genesis": { "stateRootHash": "0x00" } }), &[1]).is_err());
+	}
+
+	#[test]
+	fn native_genesis_is_built_by_the_runtime() {
+		let native = node_cli::chain_spec::development_config();
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("chain_spec.json");
+		fs::write(&path, native.as_json(false).unwrap()).unwrap();
+
+		let expected = native.build_storage().unwrap();
+		let storage = build_storage(&path).unwrap();
+
+		assert_eq!(storage.top, expected.top);
+		assert_eq!(storage.children_default, expected.children_default);
+	}
+
+	#[test]
+	fn native_to_runtime_genesis_keeps_other_genesis_formats() {
+		let mut native = json!({ "genesis": { "runtime": { "system": { "code": "0x00" } } } });
+		let mut raw = json!({ "genesis": { "raw": { "top": {}, "childrenDefault": {} } } });
+
+		native_to_runtime_genesis(&mut native).unwrap();
+		native_to_runtime_genesis(&mut raw).unwrap();
+
+		assert_eq!(
+			native,
+			json!({ "genesis": { "runtimeGenesis": {
+				"code": "0x00",
+				"config": { "system": { "code": "0x00" } },
+			} } }),
+		);
+		assert_eq!(raw, json!({ "genesis": { "raw": { "top": {}, "childrenDefault": {} } } }));
+		assert!(native_to_runtime_genesis(&mut json!({ "genesis": { "runtime": {} } })).is_err());
+	}
+
+	#[test]
+	fn add_code_substitute_keeps_existing_substitutes() {
+		let mut spec = json!({ "name": "Test" });
+
+		add_code_substitute(&mut spec, &[1], 10).unwrap();
+		add_code_substitute(&mut spec, &[2], 20).unwrap();
+
+		assert_eq!(spec["codeSubstitutes"], json!({ "10": "0x01", "20": "0x02" }));
+	}
+
+	#[test]
+	fn diff_reports_added_removed_and_changed_entries() {
+		let mut left = Storage::default();
+		left.top.insert(vec![1], vec![1]);
+		left.top.insert(vec![2], vec![2]);
+		let mut right = Storage::default();
+		right.top.insert(vec![2], vec![3]);
+		right.top.insert(vec![4], vec![4]);
+
+		assert_eq!(
+			diff_storage(&left, &right),
+			vec![
+				Change::Removed("0x01".into()),
+				Change::Changed("0x02".into()),
+				Change::Added("0x04".into()),
+			],
+		);
+		assert_eq!(
+			diff_fields(
+				&json!({ "name": "A", "id": "a", "genesis": 1 }),
+				&json!({ "name": "B", "id": "a", "genesis": 2, "forkId": "b" }),
+			),
+			vec![Change::Added("forkId".into()), Change::Changed("name".into())],
+		);
+	}
+}
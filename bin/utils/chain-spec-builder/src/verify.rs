@@ -0,0 +1,455 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Verification of the genesis state of a chain spec against the metadata of its runtime.
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use ansi_term::Style;
+use clap::Args;
+use codec::{Compact, Decode};
+use frame_metadata::{
+	v14::{StorageEntryType, StorageHasher},
+	RuntimeMetadata, RuntimeMetadataPrefixed,
+};
+use scale_info::{PortableRegistry, TypeDef, TypeDefPrimitive};
+
+use sc_executor::{WasmExecutionMethod, WasmExecutor};
+use sp_core::{
+	bytes::to_hex,
+	storage::well_known_keys,
+	traits::{CallContext, CodeExecutor, RuntimeVersionOf, WrappedRuntimeCode},
+	twox_128,
+};
+use sp_runtime::{StateVersion, Storage};
+use sp_state_machine::BasicExternalities;
+
+/// Verify the genesis state of a chain spec and print its state root.
+///
+/// Every storage key and value is decoded against the metadata of the runtime in the genesis
+/// state.
+#[derive(Debug, Clone, Args)]
+pub struct VerifyCmd {
+	/// The chain spec to verify.
+	input_chain_spec: PathBuf,
+}
+
+impl VerifyCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), String> {
+		let header = Style::new().bold().underline();
+		let entry = Style::new().bold();
+
+		let storage = crate::spec::build_storage(&self.input_chain_spec)?;
+		let code = storage
+			.top
+			.get(well_known_keys::CODE)
+			.ok_or("Genesis state does not contain any runtime code")?;
+		let runtime = Runtime::new(code);
+		let state_version = runtime.state_version()?;
+		let metadata = runtime.metadata()?;
+		let (registry, entries) = storage_entries(&metadata)?;
+
+		let mut decoded = BTreeMap::<&str, usize>::new();
+		let mut undecodable = Vec::new();
+		for (key, value) in &storage.top {
+			match decode_entry(registry, &entries, key, value) {
+				Ok(pallet) => *decoded.entry(pallet).or_default() += 1,
+				Err(e) => undecodable.push((key, e)),
+			}
+		}
+
+		println!("{}", header.paint("Storage keys"));
+		for (pallet, count) in &decoded {
+			println!("{} {}", entry.paint(format!("{}:", pallet)), count);
+		}
+		println!("{} {}", entry.paint("Child tries:"), storage.children_default.len());
+		println!();
+
+		println!("{}", header.paint("Genesis state root"));
+		println!("{}", to_hex(&state_root(storage.clone(), state_version), false));
+		println!();
+
+		if undecodable.is_empty() {
+			return Ok(())
+		}
+
+		println!("{}", header.paint("Undecodable storage entries"));
+		for (key, error) in &undecodable {
+			println!("{} {}", entry.paint(to_hex(key, false)), error);
+		}
+
+		Err(format!("{} storage entries could not be decoded", undecodable.len()))
+	}
+}
+
+/// Name of the "pseudo" storage entry holding the storage version of a pallet.
+const STORAGE_VERSION_ENTRY: &[u8] = b":__STORAGE_VERSION__:";
+
+/// A storage entry declared in the runtime metadata.
+#[derive(Debug)]
+struct Entry {
+	/// Name of the pallet declaring the entry.
+	pallet: String,
+	/// Name of the entry.
+	name: String,
+	/// Hashers of the keys of the entry, empty for plain values.
+	hashers: Vec<StorageHasher>,
+	/// Types of the keys of the entry, one for each hasher.
+	keys: Vec<u32>,
+	/// Type of the value of the entry, `None` for the storage version of the pallet.
+	value: Option<u32>,
+}
+
+/// Collect the storage entries of `metadata`, indexed by their 32 byte key prefix, along with the
+/// registry of their types.
+fn storage_entries(
+	metadata: &RuntimeMetadataPrefixed,
+) -> Result<(&PortableRegistry, BTreeMap<Vec<u8>, Entry>), String> {
+	let metadata = match &metadata.1 {
+		RuntimeMetadata::V14(metadata) => metadata,
+		_ => return Err("Unsupported runtime metadata version, only V14 is supported".into()),
+	};
+	let registry = &metadata.types;
+
+	let mut entries = BTreeMap::new();
+	for storage in metadata.pallets.iter().filter_map(|pallet| pallet.storage.as_ref()) {
+		let pallet_prefix = twox_128(storage.prefix.as_bytes());
+		let prefixed = |name: &[u8]| [&pallet_prefix[..], &twox_128(name)[..]].concat();
+
+		entries.insert(
+			prefixed(STORAGE_VERSION_ENTRY),
+			Entry {
+				pallet: storage.prefix.clone(),
+				name: String::from_utf8_lossy(STORAGE_VERSION_ENTRY).into(),
+				hashers: Vec::new(),
+				keys: Vec::new(),
+				value: None,
+			},
+		);
+		for entry in &storage.entries {
+			let (hashers, keys, value) = match &entry.ty {
+				StorageEntryType::Plain(value) => (Vec::new(), Vec::new(), value.id()),
+				StorageEntryType::Map { hashers, key, value } => {
+					// Maps with several hashers are keyed by a tuple, with one field per hasher.
+					let keys = match registry.resolve(key.id()).map(|ty| ty.type_def()) {
+						Some(TypeDef::Tuple(tuple)) if hashers.len() > 1 =>
+							tuple.fields().iter().map(|field| field.id()).collect(),
+						_ => vec![key.id()],
+					};
+					(hashers.clone(), keys, value.id())
+				},
+			};
+			if keys.len() != hashers.len() && !hashers.is_empty() {
+				return Err(format!(
+					"`{}::{}` has {} hashers for {} keys",
+					storage.prefix,
+					entry.name,
+					hashers.len(),
+					keys.len(),
+				))
+			}
+			entries.insert(
+				prefixed(entry.name.as_bytes()),
+				Entry {
+					pallet: storage.prefix.clone(),
+					name: entry.name.clone(),
+					hashers,
+					keys,
+					value: Some(value),
+				},
+			);
+		}
+	}
+
+	Ok((registry, entries))
+}
+
+/// Decode `key` and `value` against the storage `entries`, returning the pallet owning the key.
+fn decode_entry<'a>(
+	registry: &PortableRegistry,
+	entries: &'a BTreeMap<Vec<u8>, Entry>,
+	key: &[u8],
+	value: &[u8],
+) -> Result<&'a str, String> {
+	if key == well_known_keys::CODE {
+		return Ok("Well known keys")
+	}
+	if key == well_known_keys::HEAP_PAGES {
+		return decode_all::<u64>(value).map(|_| "Well known keys")
+	}
+	if key == well_known_keys::EXTRINSIC_INDEX {
+		return decode_all::<u32>(value).map(|_| "Well known keys")
+	}
+	if well_known_keys::is_child_storage_key(key) {
+		return Err("child trie root in top trie".into())
+	}
+
+	let entry = entries
+		.get(key.get(..32).unwrap_or(key))
+		.ok_or("does not belong to any storage entry in the metadata")?;
+	let in_entry = |e: String| format!("{} in `{}::{}`", e, entry.pallet, entry.name);
+
+	// Every hash is followed by the encoded key if the hasher is concatenating.
+	let mut input = &key[32..];
+	for (hasher, ty) in entry.hashers.iter().zip(&entry.keys) {
+		input = input.get(hash_len(hasher)..).ok_or_else(|| in_entry("key too short".into()))?;
+		if matches!(
+			hasher,
+			StorageHasher::Blake2_128Concat | StorageHasher::Twox64Concat | StorageHasher::Identity
+		) {
+			skip_value(registry, *ty, &mut input).map_err(|e| in_entry(format!("key: {}", e)))?;
+		}
+	}
+	if !input.is_empty() {
+		return Err(in_entry(format!("{} trailing key bytes", input.len())))
+	}
+
+	match entry.value {
+		Some(ty) => {
+			let mut input = value;
+			skip_value(registry, ty, &mut input).map_err(|e| in_entry(format!("value: {}", e)))?;
+			if !input.is_empty() {
+				return Err(in_entry(format!("{} trailing value bytes", input.len())))
+			}
+		},
+		None => decode_all::<u16>(value).map_err(in_entry)?,
+	}
+
+	Ok(&entry.pallet)
+}
+
+/// Decode `value` as `T`, requiring all bytes to be used.
+fn decode_all<T: Decode>(mut value: &[u8]) -> Result<(), String> {
+	T::decode(&mut value).map_err(|e| e.to_string())?;
+	if value.is_empty() {
+		Ok(())
+	} else {
+		Err(format!("{} trailing value bytes", value.len()))
+	}
+}
+
+/// Skip over a SCALE encoded value of the type `ty` of the `registry` in `input`.
+fn skip_value(registry: &PortableRegistry, ty: u32, input: &mut &[u8]) -> Result<(), String> {
+	let decode_len = |input: &mut &[u8]| {
+		Compact::<u32>::decode(input)
+			.map(|len| len.0 as usize)
+			.map_err(|e| e.to_string())
+	};
+	let skip_bytes = |input: &mut &[u8], len: usize| {
+		*input = input.get(len..).ok_or("unexpected end of input")?;
+		Ok::<_, String>(())
+	};
+
+	match registry.resolve(ty).ok_or_else(|| format!("unknown type {}", ty))?.type_def() {
+		TypeDef::Composite(composite) =>
+			for field in composite.fields() {
+				skip_value(registry, field.ty().id(), input)?;
+			},
+		TypeDef::Variant(variant) => {
+			let index = u8::decode(input).map_err(|e| e.to_string())?;
+			let variant = variant
+				.variants()
+				.iter()
+				.find(|variant| variant.index() == index)
+				.ok_or_else(|| format!("unknown variant index {}", index))?;
+			for field in variant.fields() {
+				skip_value(registry, field.ty().id(), input)?;
+			}
+		},
+		TypeDef::Sequence(sequence) =>
+			for _ in 0..decode_len(input)? {
+				skip_value(registry, sequence.type_param().id(), input)?;
+			},
+		TypeDef::Array(array) =>
+			for _ in 0..array.len() {
+				skip_value(registry, array.type_param().id(), input)?;
+			},
+		TypeDef::Tuple(tuple) =>
+			for field in tuple.fields() {
+				skip_value(registry, field.id(), input)?;
+			},
+		TypeDef::Primitive(primitive) => {
+			let len = match primitive {
+				TypeDefPrimitive::Bool | TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => 1,
+				TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => 2,
+				TypeDefPrimitive::Char | TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => 4,
+				TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => 8,
+				TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => 16,
+				TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => 32,
+				TypeDefPrimitive::Str => decode_len(input)?,
+			};
+			skip_bytes(input, len)?;
+		},
+		TypeDef::Compact(_) => {
+			Compact::<u128>::decode(input).map_err(|e| e.to_string())?;
+		},
+		TypeDef::BitSequence(bits) => {
+			let store = match registry.resolve(bits.bit_store_type().id()).map(|ty| ty.type_def()) {
+				Some(TypeDef::Primitive(TypeDefPrimitive::U8)) => 1,
+				Some(TypeDef::Primitive(TypeDefPrimitive::U16)) => 2,
+				Some(TypeDef::Primitive(TypeDefPrimitive::U32)) => 4,
+				Some(TypeDef::Primitive(TypeDefPrimitive::U64)) => 8,
+				_ => return Err("unsupported bit sequence store type".into()),
+			};
+			let bits = decode_len(input)?;
+			skip_bytes(input, (bits + store * 8 - 1) / (store * 8) * store)?;
+		},
+	}
+
+	Ok(())
+}
+
+/// Length of the hash produced by `hasher`, without the concatenated key.
+fn hash_len(hasher: &StorageHasher) -> usize {
+	match hasher {
+		StorageHasher::Blake2_128 | StorageHasher::Twox128 | StorageHasher::Blake2_128Concat => 16,
+		StorageHasher::Blake2_256 | StorageHasher::Twox256 => 32,
+		StorageHasher::Twox64Concat => 8,
+		StorageHasher::Identity => 0,
+	}
+}
+
+/// Compute the state root of `storage`, including its child tries.
+fn state_root(storage: Storage, state_version: StateVersion) -> Vec<u8> {
+	sp_io::TestExternalities::new_with_state_version(storage, state_version)
+		.execute_with(|| sp_io::storage::root(state_version))
+}
+
+/// Runtime code of the genesis state.
+struct Runtime<'a> {
+	code: WrappedRuntimeCode<'a>,
+	executor: WasmExecutor<sp_io::SubstrateHostFunctions>,
+}
+
+impl<'a> Runtime<'a> {
+	fn new(code: &'a [u8]) -> Self {
+		let mut executor = WasmExecutor::new(WasmExecutionMethod::Interpreted, None, 1, None, 1);
+		executor.allow_missing_host_functions(true);
+
+		Self { code: WrappedRuntimeCode(code.into()), executor }
+	}
+
+	fn runtime_code(&self) -> sp_core::traits::RuntimeCode {
+		sp_core::traits::RuntimeCode {
+			code_fetcher: &self.code,
+			heap_pages: None,
+			hash: sp_core::blake2_256(&self.code.0).to_vec(),
+		}
+	}
+
+	/// State version of the runtime.
+	fn state_version(&self) -> Result<StateVersion, String> {
+		let mut ext = BasicExternalities::new_empty();
+		self.executor
+			.runtime_version(&mut ext, &self.runtime_code())
+			.map(|version| version.state_version())
+			.map_err(|e| format!("Error reading runtime version: {}", e))
+	}
+
+	/// Metadata of the runtime.
+	fn metadata(&self) -> Result<RuntimeMetadataPrefixed, String> {
+		let mut ext = BasicExternalities::new_empty();
+		let encoded = self
+			.executor
+			.call(
+				&mut ext,
+				&self.runtime_code(),
+				"Metadata_metadata",
+				&[],
+				false,
+				CallContext::Offchain,
+			)
+			.0
+			.map_err(|e| format!("Error calling `Metadata_metadata`: {}", e))?;
+
+		// The metadata is returned as `OpaqueMetadata`, i.e. SCALE encoded bytes.
+		let bytes = Vec::<u8>::decode(&mut &encoded[..])
+			.map_err(|e| format!("Error decoding runtime metadata: {}", e))?;
+		RuntimeMetadataPrefixed::decode(&mut &bytes[..])
+			.map_err(|e| format!("Error decoding runtime metadata: {}", e))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use scale_info::{meta_type, Registry};
+
+	fn entries() -> (PortableRegistry, BTreeMap<Vec<u8>, Entry>) {
+		let mut registry = Registry::new();
+		let u32_ty = registry.register_type(&meta_type::<u32>()).id();
+		let bytes_ty = registry.register_type(&meta_type::<Vec<u8>>()).id();
+		let option_ty = registry.register_type(&meta_type::<Option<u64>>()).id();
+
+		let prefixed = |name: &[u8]| [&twox_128(b"System")[..], &twox_128(name)[..]].concat();
+		let entries = [
+			(prefixed(b"Number"), Vec::new(), Vec::new(), Some(u32_ty)),
+			(
+				prefixed(b"Account"),
+				vec![StorageHasher::Blake2_128Concat],
+				vec![u32_ty],
+				Some(bytes_ty),
+			),
+			(prefixed(b"BlockHash"), vec![StorageHasher::Twox128], vec![u32_ty], Some(option_ty)),
+			(prefixed(STORAGE_VERSION_ENTRY), Vec::new(), Vec::new(), None),
+		]
+		.into_iter()
+		.map(|(prefix, hashers, keys, value)| {
+			(prefix, Entry { pallet: "System".into(), name: String::new(), hashers, keys, value })
+		})
+		.collect();
+
+		(registry.into(), entries)
+	}
+
+	#[test]
+	fn decode_entry_checks_keys_and_values() {
+		let (registry, entries) = entries();
+		let key = |name: &[u8], suffix: &[u8]| {
+			[&twox_128(b"System")[..], &twox_128(name)[..], suffix].concat()
+		};
+		let decode = |key: &[u8], value: &[u8]| decode_entry(&registry, &entries, key, value);
+		let account = |id: &[u8]| key(b"Account", &[&[0; 16][..], id].concat());
+
+		assert_eq!(decode(well_known_keys::CODE, &[1, 2, 3]), Ok("Well known keys"));
+		assert_eq!(decode(well_known_keys::HEAP_PAGES, &[0; 8]), Ok("Well known keys"));
+		assert_eq!(decode(&key(b"Number", &[]), &[0; 4]), Ok("System"));
+		assert_eq!(decode(&account(&[0; 4]), &[8, 1, 2]), Ok("System"));
+		assert_eq!(
+			decode(&key(b"BlockHash", &[0; 16]), &[1, 0, 0, 0, 0, 0, 0, 0, 0]),
+			Ok("System")
+		);
+		assert_eq!(decode(&key(STORAGE_VERSION_ENTRY, &[]), &[1, 0]), Ok("System"));
+
+		// Wrong key lengths.
+		assert!(decode(&key(b"Number", &[0]), &[0; 4]).is_err());
+		assert!(decode(&account(&[0; 3]), &[0]).is_err());
+		assert!(decode(&account(&[0; 5]), &[0]).is_err());
+		assert!(decode(&key(b"BlockHash", &[0; 17]), &[0]).is_err());
+		assert!(decode(&key(b"Unknown", &[]), &[]).is_err());
+		assert!(decode(b"short", &[]).is_err());
+
+		// Values not matching their type.
+		assert!(decode(well_known_keys::HEAP_PAGES, &[0; 4]).is_err());
+		assert!(decode(&key(b"Number", &[]), &[0; 5]).is_err());
+		assert!(decode(&account(&[0; 4]), &[8, 1]).is_err());
+		assert!(decode(&key(b"BlockHash", &[0; 16]), &[2]).is_err());
+		assert!(decode(&key(STORAGE_VERSION_ENTRY, &[]), &[1]).is_err());
+	}
+}
@@ -0,0 +1,72 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Reference signer daemon for the remote keystore.
+//!
+//! Serves the keys of a local keystore on a Unix socket:
+//!
+//! ```sh
+//! remote-signer <KEYSTORE_PATH> <SOCKET_PATH>
+//! ```
+//!
+//! The password of the keystore is read from the `REMOTE_SIGNER_PASSWORD` environment variable.
+//! A node uses the signer when started with `--keystore-uri unix://<SOCKET_PATH>`.
+
+#[cfg(unix)]
+fn main() {
+	use sc_keystore::{LocalKeystore, RemoteKeystoreServer};
+	use sp_core::crypto::SecretString;
+	use std::{os::unix::net::UnixListener, path::PathBuf, sync::Arc};
+
+	let args = std::env::args().skip(1).collect::<Vec<_>>();
+	let [keystore_path, socket_path] = &args[..] else {
+		eprintln!("Usage: remote-signer <KEYSTORE_PATH> <SOCKET_PATH>");
+		std::process::exit(1)
+	};
+
+	let password = std::env::var("REMOTE_SIGNER_PASSWORD").ok().map(SecretString::new);
+	let keystore = match LocalKeystore::open(PathBuf::from(keystore_path), password) {
+		Ok(keystore) => Arc::new(keystore),
+		Err(e) => {
+			eprintln!("Failed to open the keystore at {}: {}", keystore_path, e);
+			std::process::exit(1)
+		},
+	};
+
+	// A socket left behind by a previous run would make binding fail.
+	let _ = std::fs::remove_file(socket_path);
+	let listener = match UnixListener::bind(socket_path) {
+		Ok(listener) => listener,
+		Err(e) => {
+			eprintln!("Failed to listen on {}: {}", socket_path, e);
+			std::process::exit(1)
+		},
+	};
+
+	eprintln!("Serving {} on {}", keystore_path, socket_path);
+	if let Err(e) = RemoteKeystoreServer::new(keystore).serve(listener) {
+		eprintln!("Failed to accept connections: {}", e);
+		std::process::exit(1)
+	}
+}
+
+#[cfg(not(unix))]
+fn main() {
+	eprintln!("The remote signer is only supported on Unix");
+	std::process::exit(1)
+}
@@ -31,3 +31,10 @@ pub enum Error {
 	#[error("Other: {0}")]
 	Other(&'static str),
 }
+
+impl Error {
+	/// Whether the allocation failed because the linear memory can't satisfy it.
+	pub fn is_memory_exhausted(&self) -> bool {
+		matches!(self, Error::RequestedAllocationTooLarge | Error::AllocatorOutOfSpace)
+	}
+}
@@ -57,6 +57,16 @@ pub const DEFAULT_BLOCK_SIZE_LIMIT: usize = 4 * 1024 * 1024 + 512;
 
 const DEFAULT_SOFT_DEADLINE_PERCENT: Percent = Percent::from_percent(50);
 
+/// Whether the execution failed because the runtime exhausted its stack or memory.
+///
+/// This happens deterministically, so the transaction will fail the same way every time and on
+/// every node.
+fn is_resource_exhausted(error: &sp_blockchain::Error) -> bool {
+	error
+		.execution_error::<sc_executor_common::error::Error>()
+		.map_or(false, |e| e.is_resource_exhausted())
+}
+
 /// [`Proposer`] factory.
 pub struct ProposerFactory<A, B, C, PR> {
 	spawn_handle: Box<dyn SpawnNamed>,
@@ -474,6 +484,14 @@ where
 						break EndProposingReason::HitBlockWeightLimit
 					}
 				},
+				Err(e) if is_resource_exhausted(&e) => {
+					pending_iterator.report_invalid(&pending_tx);
+					debug!(
+						"[{:?}] Transaction exhausted the stack or memory of the runtime: {}",
+						pending_tx_hash, e
+					);
+					unqueue_invalid.push(pending_tx_hash);
+				},
 				Err(e) if skipped > 0 => {
 					pending_iterator.report_invalid(&pending_tx);
 					trace!(
@@ -19,13 +19,16 @@
 //! Substrate chain configurations.
 #![warn(missing_docs)]
 
-use crate::{extension::GetExtension, ChainType, Properties, RuntimeGenesis};
+use crate::{
+	extension::GetExtension, genesis_config_builder::GenesisConfigBuilderRuntimeCaller, ChainType,
+	Properties, RuntimeGenesis,
+};
 use sc_network_common::config::MultiaddrWithPeerId;
 use sc_telemetry::TelemetryEndpoints;
 use serde::{Deserialize, Serialize};
 use serde_json as json;
 use sp_core::{
-	storage::{ChildInfo, Storage, StorageChild, StorageData, StorageKey},
+	storage::{well_known_keys, ChildInfo, Storage, StorageChild, StorageData, StorageKey},
 	Bytes,
 };
 use sp_runtime::BuildStorage;
@@ -36,6 +39,7 @@ enum GenesisSource<G> {
 	Binary(Cow<'static, [u8]>),
 	Factory(Arc<dyn Fn() -> G + Send + Sync>),
 	Storage(Storage),
+	GenesisBuilderApi(RuntimeGenesisInner),
 }
 
 impl<G> Clone for GenesisSource<G> {
@@ -45,6 +49,7 @@ impl<G> Clone for GenesisSource<G> {
 			Self::Binary(ref d) => Self::Binary(d.clone()),
 			Self::Factory(ref f) => Self::Factory(f.clone()),
 			Self::Storage(ref s) => Self::Storage(s.clone()),
+			Self::GenesisBuilderApi(ref inner) => Self::GenesisBuilderApi(inner.clone()),
 		}
 	}
 }
@@ -80,30 +85,8 @@ impl<G: RuntimeGenesis> GenesisSource<G> {
 				Ok(genesis.genesis)
 			},
 			Self::Factory(f) => Ok(Genesis::Runtime(f())),
-			Self::Storage(storage) => {
-				let top = storage
-					.top
-					.iter()
-					.map(|(k, v)| (StorageKey(k.clone()), StorageData(v.clone())))
-					.collect();
-
-				let children_default = storage
-					.children_default
-					.iter()
-					.map(|(k, child)| {
-						(
-							StorageKey(k.clone()),
-							child
-								.data
-								.iter()
-								.map(|(k, v)| (StorageKey(k.clone()), StorageData(v.clone())))
-								.collect(),
-						)
-					})
-					.collect();
-
-				Ok(Genesis::Raw(RawGenesis { top, children_default }))
-			},
+			Self::Storage(storage) => Ok(Genesis::Raw(RawGenesis::from(storage.clone()))),
+			Self::GenesisBuilderApi(inner) => Ok(Genesis::RuntimeGenesis(inner.clone())),
 		}
 	}
 }
@@ -112,6 +95,7 @@ impl<G: RuntimeGenesis, E> BuildStorage for ChainSpec<G, E> {
 	fn assimilate_storage(&self, storage: &mut Storage) -> Result<(), String> {
 		match self.genesis.resolve()? {
 			Genesis::Runtime(gc) => gc.assimilate_storage(storage),
+			Genesis::RuntimeGenesis(inner) => inner.assimilate_storage(storage),
 			Genesis::Raw(RawGenesis { top: map, children_default: children_map }) => {
 				storage.top.extend(map.into_iter().map(|(k, v)| (k.0, v.0)));
 				children_map.into_iter().for_each(|(k, v)| {
@@ -144,6 +128,76 @@ pub struct RawGenesis {
 	pub children_default: BTreeMap<StorageKey, GenesisStorage>,
 }
 
+impl From<Storage> for RawGenesis {
+	fn from(storage: Storage) -> Self {
+		let top = storage.top.into_iter().map(|(k, v)| (StorageKey(k), StorageData(v))).collect();
+
+		let children_default = storage
+			.children_default
+			.into_iter()
+			.map(|(sk, child)| {
+				(
+					StorageKey(sk),
+					child.data.into_iter().map(|(k, v)| (StorageKey(k), StorageData(v))).collect(),
+				)
+			})
+			.collect();
+
+		Self { top, children_default }
+	}
+}
+
+/// Runtime code along with the `GenesisConfig` to build the genesis storage from.
+///
+/// The storage is built by the runtime itself, through its `GenesisBuilder` runtime API.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeGenesisInner {
+	/// Runtime wasm code, stored at `:code` in the genesis storage.
+	code: Bytes,
+	/// The `GenesisConfig` in JSON format.
+	#[serde(flatten)]
+	json_blob: RuntimeGenesisConfigJson,
+}
+
+/// Represents the different ways the runtime `GenesisConfig` can be given.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+enum RuntimeGenesisConfigJson {
+	/// The full `GenesisConfig`, passed as is to `GenesisBuilder::build_config`.
+	Config(json::Value),
+	/// A patch merged into the default `GenesisConfig` returned by
+	/// `GenesisBuilder::create_default_config`.
+	Patch(json::Value),
+}
+
+impl BuildStorage for RuntimeGenesisInner {
+	fn assimilate_storage(&self, storage: &mut Storage) -> Result<(), String> {
+		let caller = GenesisConfigBuilderRuntimeCaller::new(&self.code[..]);
+		let genesis_storage = match &self.json_blob {
+			RuntimeGenesisConfigJson::Config(config) =>
+				caller.get_storage_for_config(config.clone())?,
+			RuntimeGenesisConfigJson::Patch(patch) =>
+				caller.get_storage_for_patch(patch.clone())?,
+		};
+
+		storage.top.extend(genesis_storage.top);
+		storage.top.insert(well_known_keys::CODE.to_vec(), self.code.to_vec());
+		for (k, child) in genesis_storage.children_default {
+			storage
+				.children_default
+				.entry(k)
+				.or_insert_with(|| StorageChild {
+					data: Default::default(),
+					child_info: child.child_info,
+				})
+				.data
+				.extend(child.data);
+		}
+		Ok(())
+	}
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
@@ -152,6 +206,22 @@ enum Genesis<G> {
 	Raw(RawGenesis),
 	/// State root hash of the genesis storage.
 	StateRootHash(StorageData),
+	/// Runtime code and `GenesisConfig` built into storage by the runtime.
+	RuntimeGenesis(RuntimeGenesisInner),
+}
+
+/// A genesis type for chain specs that are not tied to a native `GenesisConfig`.
+///
+/// Such chain specs hold the runtime code and its config in JSON format, see
+/// [`ChainSpec::from_runtime_genesis_patch`]. Parsing a spec with a native `runtime` genesis
+/// into it fails.
+#[derive(Serialize, Deserialize)]
+pub enum NoGenesisConfig {}
+
+impl BuildStorage for NoGenesisConfig {
+	fn assimilate_storage(&self, _: &mut Storage) -> Result<(), String> {
+		match *self {}
+	}
 }
 
 /// A configuration of a client. Does not include runtime storage initialization.
@@ -271,6 +341,67 @@ impl<G, E> ChainSpec<G, E> {
 		fork_id: Option<&str>,
 		properties: Option<Properties>,
 		extensions: E,
+	) -> Self {
+		Self::new(
+			name,
+			id,
+			chain_type,
+			GenesisSource::Factory(Arc::new(constructor)),
+			boot_nodes,
+			telemetry_endpoints,
+			protocol_id,
+			fork_id,
+			properties,
+			extensions,
+		)
+	}
+
+	/// Create a spec whose genesis storage is built by the given runtime `code`.
+	///
+	/// The runtime's default `GenesisConfig` is amended with `patch` and built through the
+	/// `GenesisBuilder` runtime API, so the node does not need to know the runtime's native
+	/// `GenesisConfig` type.
+	pub fn from_runtime_genesis_patch(
+		name: &str,
+		id: &str,
+		chain_type: ChainType,
+		code: &[u8],
+		patch: json::Value,
+		boot_nodes: Vec<MultiaddrWithPeerId>,
+		telemetry_endpoints: Option<TelemetryEndpoints>,
+		protocol_id: Option<&str>,
+		fork_id: Option<&str>,
+		properties: Option<Properties>,
+		extensions: E,
+	) -> Self {
+		Self::new(
+			name,
+			id,
+			chain_type,
+			GenesisSource::GenesisBuilderApi(RuntimeGenesisInner {
+				code: code.to_vec().into(),
+				json_blob: RuntimeGenesisConfigJson::Patch(patch),
+			}),
+			boot_nodes,
+			telemetry_endpoints,
+			protocol_id,
+			fork_id,
+			properties,
+			extensions,
+		)
+	}
+
+	fn new(
+		name: &str,
+		id: &str,
+		chain_type: ChainType,
+		genesis: GenesisSource<G>,
+		boot_nodes: Vec<MultiaddrWithPeerId>,
+		telemetry_endpoints: Option<TelemetryEndpoints>,
+		protocol_id: Option<&str>,
+		fork_id: Option<&str>,
+		properties: Option<Properties>,
+		extensions: E,
 	) -> Self {
 		let client_spec = ClientSpec {
 			name: name.to_owned(),
@@ -287,7 +418,7 @@ impl<G, E> ChainSpec<G, E> {
 			code_substitutes: BTreeMap::new(),
 		};
 
-		ChainSpec { client_spec, genesis: GenesisSource::Factory(Arc::new(constructor)) }
+		ChainSpec { client_spec, genesis }
 	}
 
 	/// Type of the chain.
@@ -335,27 +466,9 @@ struct JsonContainer<G, E> {
 impl<G: RuntimeGenesis, E: serde::Serialize + Clone + 'static> ChainSpec<G, E> {
 	fn json_container(&self, raw: bool) -> Result<JsonContainer<G, E>, String> {
 		let genesis = match (raw, self.genesis.resolve()?) {
-			(true, Genesis::Runtime(g)) => {
-				let storage = g.build_storage()?;
-				let top =
-					storage.top.into_iter().map(|(k, v)| (StorageKey(k), StorageData(v))).collect();
-				let children_default = storage
-					.children_default
-					.into_iter()
-					.map(|(sk, child)| {
-						(
-							StorageKey(sk),
-							child
-								.data
-								.into_iter()
-								.map(|(k, v)| (StorageKey(k), StorageData(v)))
-								.collect(),
-						)
-					})
-					.collect();
-
-				Genesis::Raw(RawGenesis { top, children_default })
-			},
+			(true, Genesis::Runtime(g)) => Genesis::Raw(RawGenesis::from(g.build_storage()?)),
+			(true, Genesis::RuntimeGenesis(inner)) =>
+				Genesis::Raw(RawGenesis::from(inner.build_storage()?)),
 			(_, genesis) => genesis,
 		};
 		Ok(JsonContainer { client_spec: self.client_spec.clone(), genesis })
@@ -536,4 +649,30 @@ mod tests {
 			);
 		}
 	}
+
+	#[test]
+	fn runtime_genesis_spec_should_roundtrip() {
+		let patch = json::json!({ "balances": { "balances": [] } });
+		let spec = ChainSpec::<NoGenesisConfig>::from_runtime_genesis_patch(
+			"Test",
+			"test",
+			ChainType::Local,
+			&[0x00, 0x61, 0x73, 0x6d],
+			patch.clone(),
+			Vec::new(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		);
+
+		let json = spec.as_json(false).unwrap();
+		let value: json::Value = json::from_str(&json).unwrap();
+		assert_eq!(value["genesis"]["runtimeGenesis"]["code"], "0x0061736d");
+		assert_eq!(value["genesis"]["runtimeGenesis"]["patch"], patch);
+
+		let spec = ChainSpec::<NoGenesisConfig>::from_json_bytes(json.as_bytes().to_vec()).unwrap();
+		assert_eq!(spec.as_json(false).unwrap(), json);
+	}
 }
@@ -148,7 +148,8 @@
 //! "consensusEngine": null,
 //! // The genesis declaration of the chain.
 //! //
-//! // `runtime`, `raw`, `stateRootHash` denote the type of the genesis declaration.
+//! // `runtime`, `raw`, `stateRootHash`, `runtimeGenesis` denote the type of the genesis
+//! // declaration.
 //! //
 //! // These declarations are in the following formats:
 //! // - `runtime` is a `json` object that can be parsed by a compatible `GenesisConfig`. This
@@ -157,6 +158,10 @@
 //! //   fields is a map of `key => value`. These key/value pairs represent the genesis storage.
 //! // - `stateRootHash` is a single hex encoded hash that represents the genesis hash. The hash
 //! //   type depends on the hash used by the chain.
+//! // - `runtimeGenesis` is a `json` object with the hex encoded runtime `code` and either a full
+//! //   `config` or a `patch` of the runtime's default config. The genesis storage is built by
+//! //   calling the runtime's `GenesisBuilder` API, so the node does not need to know the
+//! //   runtime's `GenesisConfig` type.
 //! //
 //! "genesis": { "runtime": {} },
 //! /// Optional map of `block_number` to `wasm_code`.
@@ -177,11 +182,14 @@
 
 mod chain_spec;
 mod extension;
+mod genesis_config_builder;
+mod json_patch;
 
-pub use chain_spec::{ChainSpec as GenericChainSpec, NoExtension};
+pub use chain_spec::{ChainSpec as GenericChainSpec, NoExtension, NoGenesisConfig};
 pub use extension::{
 	get_extension, get_extension_mut, Extension, Fork, Forks, GetExtension, Group,
 };
+pub use genesis_config_builder::GenesisConfigBuilderRuntimeCaller;
 pub use sc_chain_spec_derive::{ChainSpecExtension, ChainSpecGroup};
 
 use sc_network_common::config::MultiaddrWithPeerId;
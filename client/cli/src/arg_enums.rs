@@ -114,6 +114,25 @@ impl Into<sc_tracing::TracingReceiver> for TracingReceiver {
 	}
 }
 
+/// The format of an exported block trace.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum TraceFormat {
+	/// Chrome trace event format, for `chrome://tracing`, Perfetto or Speedscope.
+	Chrome,
+	/// OpenTelemetry protocol JSON encoding.
+	Otlp,
+}
+
+impl Into<sc_tracing::block::TraceFormat> for TraceFormat {
+	fn into(self) -> sc_tracing::block::TraceFormat {
+		match self {
+			TraceFormat::Chrome => sc_tracing::block::TraceFormat::ChromeTrace,
+			TraceFormat::Otlp => sc_tracing::block::TraceFormat::OtlpJson,
+		}
+	}
+}
+
 /// The type of the node key.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
 #[value(rename_all = "kebab-case")]
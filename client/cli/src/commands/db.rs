@@ -0,0 +1,48 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Database related CLI utilities
+
+use super::migrate_db_cmd::MigrateDbCmd;
+use crate::Error;
+use sc_service::Configuration;
+use sp_runtime::traits::Block as BlockT;
+
+/// Database utilities for the cli.
+#[derive(Debug, clap::Subcommand)]
+pub enum DbSubcommand {
+	/// Copy the database of the node to another database backend.
+	Migrate(MigrateDbCmd),
+}
+
+impl DbSubcommand {
+	/// run the db subcommands
+	pub fn run<B: BlockT>(&self, config: &Configuration) -> Result<(), Error> {
+		match self {
+			DbSubcommand::Migrate(cmd) => cmd.run::<B>(config),
+		}
+	}
+}
+
+impl crate::CliConfiguration for DbSubcommand {
+	fn shared_params(&self) -> &crate::SharedParams {
+		match self {
+			DbSubcommand::Migrate(cmd) => cmd.shared_params(),
+		}
+	}
+}
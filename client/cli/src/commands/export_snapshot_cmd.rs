@@ -0,0 +1,115 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{BlockNumberOrHash, DatabaseParams, PruningParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use log::info;
+use sc_client_api::{BlockBackend, HeaderBackend, StorageProvider, UsageProvider};
+use sc_service::chain_ops::{export_state_snapshot, DEFAULT_CHUNK_SIZE};
+use sp_api::CallApiAt;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{fmt::Debug, fs, io::BufWriter, path::PathBuf, str::FromStr, sync::Arc};
+
+/// The `export-snapshot` command used to export the state of a given block into a state
+/// snapshot.
+#[derive(Debug, Clone, Parser)]
+pub struct ExportSnapshotCmd {
+	/// Output file.
+	#[arg()]
+	pub output: PathBuf,
+
+	/// Block hash or number, the last finalized block is used if unspecified.
+	///
+	/// The consensus data of the node is only available for the last finalized block.
+	#[arg(long, value_name = "HASH or NUMBER")]
+	pub at: Option<BlockNumberOrHash>,
+
+	/// Amount of uncompressed state data stored in one snapshot chunk.
+	#[arg(long, value_name = "BYTES", default_value_t = DEFAULT_CHUNK_SIZE)]
+	pub chunk_size: usize,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ExportSnapshotCmd {
+	/// Run the `export-snapshot` command
+	///
+	/// `consensus_aux` returns the aux data of the consensus engines that an importing node needs
+	/// to continue from the given block.
+	pub async fn run<B, BA, C, F>(&self, client: Arc<C>, consensus_aux: F) -> error::Result<()>
+	where
+		B: BlockT,
+		F: FnOnce(B::Hash) -> error::Result<Vec<(Vec<u8>, Vec<u8>)>>,
+		C: UsageProvider<B>
+			+ StorageProvider<B, BA>
+			+ HeaderBackend<B>
+			+ BlockBackend<B>
+			+ CallApiAt<B>,
+		BA: sc_client_api::backend::Backend<B>,
+		B::Hash: FromStr,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let block_id = self.at.as_ref().map(|b| b.parse()).transpose()?;
+		let hash = match block_id {
+			Some(id) => client.expect_block_hash_from_id(&id)?,
+			None => client.usage_info().chain.finalized_hash,
+		};
+
+		info!("Exporting state snapshot of block {:?}...", hash);
+		let aux = consensus_aux(hash)?;
+		let output = BufWriter::new(fs::File::create(&self.output)?);
+		let header = export_state_snapshot(client, hash, self.chunk_size, aux, output)?;
+		info!(
+			"Exported {} entries in {} chunks, state root {:?}",
+			header.entries(),
+			header.chunks.len(),
+			header.state_root,
+		);
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ExportSnapshotCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
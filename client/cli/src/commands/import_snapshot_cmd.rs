@@ -0,0 +1,91 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{ImportParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use log::info;
+use sc_client_api::{AuxStore, HeaderBackend};
+use sc_consensus::BlockImport;
+use sc_service::chain_ops::{import_state_snapshot, read_state_snapshot};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{fs, io::BufReader, path::PathBuf, sync::Arc};
+
+/// The `import-snapshot` command used to bootstrap an empty database from a state snapshot.
+#[derive(Debug, Parser)]
+pub struct ImportSnapshotCmd {
+	/// Input file.
+	#[arg()]
+	pub input: PathBuf,
+
+	/// Only check the snapshot, without importing it.
+	///
+	/// The hash of every chunk is checked and the state root is rebuilt from the entries, one
+	/// chunk at a time.
+	#[arg(long)]
+	pub verify_only: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+}
+
+impl ImportSnapshotCmd {
+	/// Run the `import-snapshot` command
+	pub async fn run<B, C, I>(&self, client: Arc<C>, block_import: I) -> error::Result<()>
+	where
+		B: BlockT,
+		C: HeaderBackend<B> + AuxStore,
+		I: BlockImport<B>,
+	{
+		let input = BufReader::new(fs::File::open(&self.input)?);
+		let header = if self.verify_only {
+			read_state_snapshot::<B, _, _>(input, |_| ())?
+		} else {
+			import_state_snapshot(client, block_import, input).await?
+		};
+
+		info!(
+			"{} state snapshot of block #{} ({:?}): {} entries, state root {:?}",
+			if self.verify_only { "Verified" } else { "Imported" },
+			header.header.number(),
+			header.block_hash,
+			header.entries(),
+			header.state_root,
+		);
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ImportSnapshotCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
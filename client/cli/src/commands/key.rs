@@ -19,7 +19,7 @@
 
 use super::{
 	generate::GenerateCmd, generate_node_key::GenerateNodeKeyCmd, insert_key::InsertKeyCmd,
-	inspect_key::InspectKeyCmd, inspect_node_key::InspectNodeKeyCmd,
+	inspect_key::InspectKeyCmd, inspect_node_key::InspectNodeKeyCmd, rekey::RekeyCmd,
 };
 use crate::{Error, SubstrateCli};
 
@@ -41,6 +41,9 @@ pub enum KeySubcommand {
 
 	/// Insert a key to the keystore of a node.
 	Insert(InsertKeyCmd),
+
+	/// Change the password of the keystore of a node, encrypting all its keys.
+	Rekey(RekeyCmd),
 }
 
 impl KeySubcommand {
@@ -51,6 +54,7 @@ impl KeySubcommand {
 			KeySubcommand::Generate(cmd) => cmd.run(),
 			KeySubcommand::Inspect(cmd) => cmd.run(),
 			KeySubcommand::Insert(cmd) => cmd.run(cli),
+			KeySubcommand::Rekey(cmd) => cmd.run(cli),
 			KeySubcommand::InspectNodeKey(cmd) => cmd.run(),
 		}
 	}
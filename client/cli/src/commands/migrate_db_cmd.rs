@@ -0,0 +1,110 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{arg_enums::Database, error, params::SharedParams, CliConfiguration};
+use clap::Parser;
+use sc_client_db::migration::{self, DEFAULT_BATCH_SIZE};
+use sc_service::Configuration;
+use sp_runtime::traits::Block as BlockT;
+use std::fmt::Debug;
+
+/// The `db migrate` command used to convert the database of a node to another backend.
+#[derive(Debug, Clone, Parser)]
+pub struct MigrateDbCmd {
+	/// Database backend to migrate from.
+	#[arg(long, value_name = "DB", ignore_case = true, value_enum)]
+	pub from: Database,
+
+	/// Database backend to migrate to.
+	#[arg(long, value_name = "DB", ignore_case = true, value_enum)]
+	pub to: Database,
+
+	/// Number of entries copied per database transaction.
+	///
+	/// Progress is saved after every transaction, so an interrupted migration continues from the
+	/// last committed transaction when the command is started again.
+	#[arg(long, value_name = "COUNT", default_value_t = DEFAULT_BATCH_SIZE)]
+	pub batch_size: usize,
+
+	/// Skip comparing the state of the best and finalized blocks after the migration.
+	#[arg(long)]
+	pub skip_verify: bool,
+
+	/// Limit the memory the database cache can use.
+	#[arg(long = "db-cache", value_name = "MiB", default_value_t = 1024)]
+	pub database_cache_size: usize,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+}
+
+impl MigrateDbCmd {
+	/// Run the `db migrate` command
+	pub fn run<B: BlockT>(&self, config: &Configuration) -> error::Result<()> {
+		if !self.is_supported() {
+			return Err(error::Error::Input(format!(
+				"Migrating from {:?} to {:?} is not supported",
+				self.from, self.to
+			)))
+		}
+		if self.batch_size == 0 {
+			return Err(error::Error::Input("Batch size must be greater than zero".into()))
+		}
+
+		let config_dir = config
+			.base_path
+			.as_ref()
+			.ok_or_else(|| error::Error::Input("Base path is required to locate databases".into()))?
+			.config_dir(config.chain_spec.id());
+		let from = self.database_config(&config_dir, self.database_cache_size, self.from)?;
+		let to = self.database_config(&config_dir, self.database_cache_size, self.to)?;
+
+		let summary = migration::migrate::<B>(&from, &to, self.batch_size, !self.skip_verify)?;
+		for column in summary.columns {
+			println!("column {}: {} entries, {} bytes", column.column, column.keys, column.bytes);
+		}
+		for state in summary.verified {
+			println!(
+				"verified state of #{} ({:?}): root {:?}, {} keys",
+				state.number, state.hash, state.state_root, state.keys
+			);
+		}
+		println!(
+			"{:?} migrated to {:?}.",
+			from.path().unwrap_or(&config_dir),
+			to.path().unwrap_or(&config_dir)
+		);
+
+		Ok(())
+	}
+
+	fn is_supported(&self) -> bool {
+		match (self.from, self.to) {
+			#[cfg(feature = "rocksdb")]
+			(Database::RocksDb, Database::ParityDb | Database::ParityDbDeprecated) => true,
+			_ => false,
+		}
+	}
+}
+
+impl CliConfiguration for MigrateDbCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
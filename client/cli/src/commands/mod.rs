@@ -18,29 +18,38 @@
 mod build_spec_cmd;
 mod chain_info_cmd;
 mod check_block_cmd;
+mod db;
 mod export_blocks_cmd;
+mod export_snapshot_cmd;
 mod export_state_cmd;
 mod generate;
 mod generate_node_key;
 mod import_blocks_cmd;
+mod import_snapshot_cmd;
 mod insert_key;
 mod inspect_key;
 mod inspect_node_key;
 mod key;
+mod migrate_db_cmd;
+mod precompile_runtime_cmd;
 mod purge_chain_cmd;
+mod rekey;
 mod revert_cmd;
 mod run_cmd;
 mod sign;
 mod test;
+mod trace_block_cmd;
 pub mod utils;
 mod vanity;
 mod verify;
 
 pub use self::{
 	build_spec_cmd::BuildSpecCmd, chain_info_cmd::ChainInfoCmd, check_block_cmd::CheckBlockCmd,
-	export_blocks_cmd::ExportBlocksCmd, export_state_cmd::ExportStateCmd, generate::GenerateCmd,
-	generate_node_key::GenerateNodeKeyCmd, import_blocks_cmd::ImportBlocksCmd,
+	db::DbSubcommand, export_blocks_cmd::ExportBlocksCmd, export_snapshot_cmd::ExportSnapshotCmd,
+	export_state_cmd::ExportStateCmd, generate::GenerateCmd, generate_node_key::GenerateNodeKeyCmd,
+	import_blocks_cmd::ImportBlocksCmd, import_snapshot_cmd::ImportSnapshotCmd,
 	insert_key::InsertKeyCmd, inspect_key::InspectKeyCmd, inspect_node_key::InspectNodeKeyCmd,
-	key::KeySubcommand, purge_chain_cmd::PurgeChainCmd, revert_cmd::RevertCmd, run_cmd::RunCmd,
-	sign::SignCmd, vanity::VanityCmd, verify::VerifyCmd,
+	key::KeySubcommand, migrate_db_cmd::MigrateDbCmd, precompile_runtime_cmd::PrecompileRuntimeCmd,
+	purge_chain_cmd::PurgeChainCmd, rekey::RekeyCmd, revert_cmd::RevertCmd, run_cmd::RunCmd,
+	sign::SignCmd, trace_block_cmd::TraceBlockCmd, vanity::VanityCmd, verify::VerifyCmd,
 };
@@ -0,0 +1,154 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{BlockNumberOrHash, DatabaseParams, ImportParams, PruningParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use log::info;
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::{HeaderBackend, StorageProvider, UsageProvider};
+use sc_service::{
+	config::{WasmExecutionMethod, WasmtimeInstantiationStrategy},
+	Configuration, PrecompileRuntime,
+};
+use sp_core::{
+	storage::{well_known_keys, StorageKey},
+	traits::{RuntimeCode, WrappedRuntimeCode},
+};
+use sp_runtime::traits::{Block as BlockT, Hash, HashFor, Header as HeaderT};
+use std::{fmt::Debug, path::PathBuf, str::FromStr, sync::Arc};
+
+/// The `precompile-runtime` command used to compile a runtime ahead of time.
+///
+/// The compiled artifact is stored in the runtime artifacts directory of the node, which is used
+/// when the node is started with `--wasm-runtime-artifacts`.
+#[derive(Debug, Clone, Parser)]
+pub struct PrecompileRuntimeCmd {
+	/// Block hash or number of the block whose runtime to compile.
+	///
+	/// Defaults to the best block.
+	#[arg(value_name = "HASH or NUMBER", conflicts_with = "runtime")]
+	pub input: Option<BlockNumberOrHash>,
+
+	/// Compile the given runtime Wasm blob instead of an on-chain runtime.
+	#[arg(long, value_name = "PATH")]
+	pub runtime: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl PrecompileRuntimeCmd {
+	/// Run the `precompile-runtime` command
+	pub async fn run<B, BA, C>(&self, config: Configuration, client: Arc<C>) -> error::Result<()>
+	where
+		B: BlockT,
+		C: UsageProvider<B> + StorageProvider<B, BA> + HeaderBackend<B>,
+		BA: sc_client_api::backend::Backend<B>,
+		B::Hash: FromStr,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		match config.wasm_method {
+			WasmExecutionMethod::Compiled {
+				instantiation_strategy: WasmtimeInstantiationStrategy::LegacyInstanceReuse,
+			} => return Err("The legacy instance reuse doesn't support precompiled runtimes".into()),
+			WasmExecutionMethod::Interpreted =>
+				return Err("Only `--wasm-execution=compiled` supports precompiled runtimes".into()),
+			WasmExecutionMethod::Compiled { .. } => {},
+		}
+		let artifacts = config
+			.wasm_runtime_artifacts
+			.clone()
+			.ok_or("The runtime artifacts directory is not configured")?;
+
+		let (code, heap_pages) = match self.runtime {
+			Some(ref path) => (std::fs::read(path)?, None),
+			None => {
+				let block_id = self.input.as_ref().map(|b| b.parse()).transpose()?;
+				let hash = match block_id {
+					Some(id) => client.expect_block_hash_from_id(&id)?,
+					None => client.usage_info().chain.best_hash,
+				};
+
+				let code = client
+					.storage(hash, &StorageKey(well_known_keys::CODE.to_vec()))?
+					.ok_or("The block has no runtime code")?;
+				let heap_pages = client
+					.storage(hash, &StorageKey(well_known_keys::HEAP_PAGES.to_vec()))?
+					.map(|data| u64::decode(&mut &data.0[..]))
+					.transpose()?;
+
+				(code.0, heap_pages)
+			},
+		};
+
+		let hash = HashFor::<B>::hash(&code).encode();
+		info!("Compiling runtime with code hash 0x{}...", array_bytes::bytes2hex("", &hash));
+
+		let runtime_code =
+			RuntimeCode { code_fetcher: &WrappedRuntimeCode(code.into()), heap_pages, hash };
+
+		// Host functions are only resolved when the runtime is instantiated, compiling it doesn't
+		// need any.
+		sc_service::new_wasm_executor::<()>(&config)
+			.precompile_runtime(&runtime_code)
+			.map_err(|e| error::Error::Application(Box::new(e)))?;
+
+		info!("Runtime artifact written to {}", artifacts.display());
+		Ok(())
+	}
+}
+
+impl CliConfiguration for PrecompileRuntimeCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+
+	fn wasm_runtime_artifacts(&self) -> bool {
+		true
+	}
+}
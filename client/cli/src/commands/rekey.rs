@@ -0,0 +1,105 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementation of the `rekey` subcommand
+
+use crate::{params::secret_string_from_str, Error, KeystoreParams, SharedParams, SubstrateCli};
+use clap::Parser;
+use sc_keystore::LocalKeystore;
+use sc_service::config::{BasePath, KeystoreConfig};
+use sp_core::crypto::SecretString;
+use std::{fs, path::PathBuf};
+
+/// The `rekey` command
+#[derive(Debug, Clone, Parser)]
+#[command(
+	name = "rekey",
+	about = "Change the password of the keystore of a node. Keys that are not encrypted yet are \
+		encrypted with the new password."
+)]
+pub struct RekeyCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub keystore_params: KeystoreParams,
+
+	/// Use interactive shell for entering the new password of the keystore.
+	#[arg(long, conflicts_with_all = &["new_password", "new_password_filename"])]
+	pub new_password_interactive: bool,
+
+	/// The new password of the keystore.
+	#[arg(
+		long,
+		value_parser = secret_string_from_str,
+		conflicts_with_all = &["new_password_interactive", "new_password_filename"]
+	)]
+	pub new_password: Option<SecretString>,
+
+	/// File that contains the new password of the keystore.
+	#[arg(
+		long,
+		value_name = "PATH",
+		conflicts_with_all = &["new_password_interactive", "new_password"]
+	)]
+	pub new_password_filename: Option<PathBuf>,
+}
+
+impl RekeyCmd {
+	/// Run the command
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<(), Error> {
+		let base_path = self
+			.shared_params
+			.base_path()?
+			.unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
+		let chain_id = self.shared_params.chain_id(self.shared_params.is_dev());
+		let chain_spec = cli.load_spec(&chain_id)?;
+		let config_dir = base_path.config_dir(chain_spec.id());
+
+		let new_password = self.new_password()?;
+		let keystore = match self.keystore_params.keystore_config(&config_dir)? {
+			(_, KeystoreConfig::Path { path, password }) => LocalKeystore::open(path, password)?,
+			_ => unreachable!("keystore_config always returns path and password; qed"),
+		};
+
+		let count = keystore.rekey(new_password)?;
+		println!("Encrypted {} keys with the new password", count);
+
+		Ok(())
+	}
+
+	fn new_password(&self) -> Result<SecretString, Error> {
+		let password = if self.new_password_interactive {
+			let password = rpassword::prompt_password("New keystore password: ")?;
+			if rpassword::prompt_password("Repeat the new keystore password: ")? != password {
+				return Err(Error::Input("The passwords don't match".into()))
+			}
+			SecretString::new(password)
+		} else if let Some(ref file) = self.new_password_filename {
+			SecretString::new(fs::read_to_string(file)?)
+		} else {
+			self.new_password
+				.clone()
+				.ok_or_else(|| Error::Input("A new password is required".into()))?
+		};
+
+		Ok(password)
+	}
+}
@@ -0,0 +1,143 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	arg_enums::TraceFormat,
+	error,
+	params::{BlockNumberOrHash, DatabaseParams, ImportParams, PruningParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use log::info;
+use sc_client_api::{BlockBackend, HeaderBackend};
+use sc_tracing::block::BlockExecutor;
+use sp_api::{Metadata, ProvideRuntimeApi};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{fmt::Debug, fs, io::BufWriter, path::PathBuf, str::FromStr, sync::Arc};
+
+/// The `trace-block` command used to export the execution trace of a block.
+///
+/// The block is re-executed on top of its parent and the recorded spans and events are written
+/// in a format understood by standard trace viewers, along with the host function calls made by
+/// the runtime. Runtime spans are only recorded if the runtime was built with the `with-tracing`
+/// feature.
+#[derive(Debug, Clone, Parser)]
+pub struct TraceBlockCmd {
+	/// Block hash or number of the block to trace.
+	#[arg(long, value_name = "HASH or NUMBER")]
+	pub block: BlockNumberOrHash,
+
+	/// The file to write the trace to.
+	#[arg(long, value_name = "PATH")]
+	pub out: PathBuf,
+
+	/// The format of the trace.
+	#[arg(
+		long,
+		value_name = "FORMAT",
+		value_enum,
+		ignore_case = true,
+		default_value_t = TraceFormat::Chrome
+	)]
+	pub format: TraceFormat,
+
+	/// Comma separated tracing targets to record, e.g. `pallet,frame,state`.
+	///
+	/// Defaults to `pallet,frame,state`.
+	#[arg(long, value_name = "TARGETS")]
+	pub targets: Option<String>,
+
+	/// Comma separated hex encoded storage key prefixes, only storage events for these keys are
+	/// recorded.
+	#[arg(long, value_name = "KEYS")]
+	pub storage_keys: Option<String>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl TraceBlockCmd {
+	/// Run the `trace-block` command
+	pub async fn run<B, C>(&self, client: Arc<C>) -> error::Result<()>
+	where
+		B: BlockT + 'static,
+		C: HeaderBackend<B> + BlockBackend<B> + ProvideRuntimeApi<B> + Send + Sync + 'static,
+		C::Api: Metadata<B>,
+		B::Hash: FromStr,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let block_id = self.block.parse::<B>()?;
+		let hash = client.expect_block_hash_from_id(&block_id)?;
+
+		let timeline = BlockExecutor::new(
+			client,
+			hash,
+			self.targets.clone(),
+			self.storage_keys.clone(),
+			None,
+			None,
+		)
+		.trace_block_timeline()
+		.map_err(|e| error::Error::Application(Box::new(e)))?;
+		info!(
+			"Recorded {} spans and {} events in {:?}",
+			timeline.spans.len(),
+			timeline.events.len(),
+			timeline.duration,
+		);
+
+		let file = BufWriter::new(fs::File::create(&self.out)?);
+		serde_json::to_writer(file, &timeline.export(self.format.into()))
+			.map_err(|e| error::Error::Application(Box::new(e)))?;
+		info!("Trace written to {}", self.out.display());
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for TraceBlockCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
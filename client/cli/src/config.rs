@@ -42,6 +42,9 @@ pub(crate) const NODE_NAME_MAX_LENGTH: usize = 64;
 /// Default sub directory to store network config.
 pub(crate) const DEFAULT_NETWORK_CONFIG_PATH: &str = "network";
 
+/// Default sub directory to store precompiled runtime artifacts.
+pub(crate) const DEFAULT_WASM_RUNTIME_ARTIFACTS_PATH: &str = "runtime-artifacts";
+
 /// The recommended open file descriptor limit to be configured for the process.
 const RECOMMENDED_OPEN_FILE_DESCRIPTOR_LIMIT: u64 = 10_000;
 
@@ -287,6 +290,20 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		self.import_params().map(|x| x.wasm_runtime_overrides()).unwrap_or_default()
 	}
 
+	/// Whether to use precompiled runtime artifacts from the config directory.
+	///
+	/// By default this is retrieved from `ImportParams` if it is available. Otherwise its `false`.
+	fn wasm_runtime_artifacts(&self) -> bool {
+		self.import_params().map(|x| x.wasm_runtime_artifacts()).unwrap_or_default()
+	}
+
+	/// Get the maximum number of pages the linear memory of the runtime may have.
+	///
+	/// By default this is retrieved from `ImportParams` if it is available. Otherwise its `None`.
+	fn wasm_max_memory_pages(&self) -> Option<u32> {
+		self.import_params().and_then(|x| x.wasm_max_memory_pages())
+	}
+
 	/// Get the execution strategies.
 	///
 	/// By default this is retrieved from `ImportParams` if it is available. Otherwise its
@@ -502,6 +519,10 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			},
 		);
 		let node_key = self.node_key(&net_config_dir)?;
+		let mut transaction_pool = self.transaction_pool(is_dev)?;
+		if let Some(journal) = transaction_pool.journal.as_mut().filter(|path| path.is_relative()) {
+			*journal = config_dir.join(&*journal);
+		}
 		let role = self.role(is_dev)?;
 		let max_runtime_instances = self.max_runtime_instances()?.unwrap_or(8);
 		let is_validator = role.is_authority();
@@ -513,7 +534,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			impl_name: C::impl_name(),
 			impl_version: C::impl_version(),
 			tokio_handle,
-			transaction_pool: self.transaction_pool(is_dev)?,
+			transaction_pool,
 			network: self.network_config(
 				&chain_spec,
 				is_dev,
@@ -532,6 +553,10 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			blocks_pruning: self.blocks_pruning()?,
 			wasm_method: self.wasm_method()?,
 			wasm_runtime_overrides: self.wasm_runtime_overrides(),
+			wasm_runtime_artifacts: self
+				.wasm_runtime_artifacts()
+				.then(|| config_dir.join(DEFAULT_WASM_RUNTIME_ARTIFACTS_PATH)),
+			wasm_max_memory_pages: self.wasm_max_memory_pages(),
 			execution_strategies: self.execution_strategies(is_dev, is_validator)?,
 			rpc_http: self.rpc_http(DCV::rpc_http_listen_port())?,
 			rpc_ws: self.rpc_ws(DCV::rpc_ws_listen_port())?,
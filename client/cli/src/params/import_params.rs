@@ -78,6 +78,22 @@ pub struct ImportParams {
 	#[arg(long, value_name = "PATH")]
 	pub wasm_runtime_overrides: Option<PathBuf>,
 
+	/// Use ahead-of-time compiled runtimes from the config directory.
+	///
+	/// Runtimes are loaded from precompiled artifacts instead of being compiled on startup.
+	/// Runtimes without an artifact, including runtime upgrades, are compiled into the artifacts
+	/// directory. Artifacts can also be created up front with the `precompile-runtime`
+	/// subcommand. Only has an effect with `--wasm-execution=compiled`.
+	#[arg(long)]
+	pub wasm_runtime_artifacts: bool,
+
+	/// Limit the linear memory of the runtime to the given number of 64KiB pages.
+	///
+	/// The runtime fails deterministically when it tries to use more memory. Only has an effect
+	/// with `--wasm-execution=compiled`.
+	#[arg(long, value_name = "COUNT")]
+	pub wasm_max_memory_pages: Option<u32>,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub execution_strategies: ExecutionStrategiesParams,
@@ -120,6 +136,16 @@ impl ImportParams {
 		self.wasm_runtime_overrides.clone()
 	}
 
+	/// Whether runtimes should be loaded from and compiled into precompiled artifacts.
+	pub fn wasm_runtime_artifacts(&self) -> bool {
+		self.wasm_runtime_artifacts
+	}
+
+	/// The maximum number of pages the linear memory of the runtime may have.
+	pub fn wasm_max_memory_pages(&self) -> Option<u32> {
+		self.wasm_max_memory_pages
+	}
+
 	/// Get execution strategies for the parameters
 	pub fn execution_strategies(&self, is_dev: bool, is_validator: bool) -> ExecutionStrategies {
 		let exec = &self.execution_strategies;
@@ -32,6 +32,8 @@ const DEFAULT_KEYSTORE_CONFIG_PATH: &str = "keystore";
 #[derive(Debug, Clone, Args)]
 pub struct KeystoreParams {
 	/// Specify custom URIs to connect to for keystore-services
+	///
+	/// A remote signer listening on a Unix socket is used with `unix://<PATH>`.
 	#[arg(long)]
 	pub keystore_uri: Option<String>,
 
@@ -44,7 +46,7 @@ pub struct KeystoreParams {
 	pub password_interactive: bool,
 
 	/// Password used by the keystore. This allows appending an extra user-defined secret to the
-	/// seed.
+	/// seed. Key files are stored encrypted with the password.
 	#[arg(
 		long,
 		value_parser = secret_string_from_str,
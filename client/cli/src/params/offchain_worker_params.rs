@@ -23,6 +23,8 @@
 //! targeted at handling input parameter parsing providing
 //! a reasonable abstraction.
 
+use std::path::PathBuf;
+
 use clap::{ArgAction, Args};
 use sc_network::config::Role;
 use sc_service::config::OffchainWorkerConfig;
@@ -50,6 +52,19 @@ pub struct OffchainWorkerParams {
 	/// DB during block import.
 	#[arg(long = "enable-offchain-indexing", value_name = "ENABLE_OFFCHAIN_INDEXING", default_value_t = false, action = ArgAction::Set)]
 	pub indexing_enabled: bool,
+
+	/// Allow offchain workers to open WebSocket connections.
+	///
+	/// Runtimes that use the offchain WebSocket host functions panic if this is disabled.
+	#[arg(long = "enable-offchain-websockets")]
+	pub websockets_enabled: bool,
+
+	/// Record the HTTP requests of offchain workers into the given file.
+	///
+	/// The recorded requests are saved as a JSON fixture when the node shuts down. The fixture
+	/// can be replayed in offchain worker tests.
+	#[arg(long = "offchain-http-recording", value_name = "PATH")]
+	pub http_recording: Option<PathBuf>,
 }
 
 impl OffchainWorkerParams {
@@ -63,6 +78,8 @@ impl OffchainWorkerParams {
 		};
 
 		let indexing_enabled = self.indexing_enabled;
-		Ok(OffchainWorkerConfig { enabled, indexing_enabled })
+		let websockets_enabled = self.websockets_enabled;
+		let http_recording = self.http_recording.clone();
+		Ok(OffchainWorkerConfig { enabled, indexing_enabled, websockets_enabled, http_recording })
 	}
 }
@@ -18,6 +18,10 @@
 
 use clap::Args;
 use sc_service::config::TransactionPoolOptions;
+use std::path::PathBuf;
+
+/// Default path of the transaction pool journal, relative to the config directory.
+const DEFAULT_POOL_JOURNAL_PATH: &str = "txpool/journal";
 
 /// Parameters used to create the pool configuration.
 #[derive(Debug, Clone, Args)]
@@ -33,6 +37,32 @@ pub struct TransactionPoolParams {
 	/// How long a transaction is banned for, if it is considered invalid. Defaults to 1800s.
 	#[arg(long, value_name = "SECONDS")]
 	pub tx_ban_seconds: Option<u64>,
+
+	/// Maximum number of ready transactions of a single sender.
+	///
+	/// Only applies if the runtime reports the sender of transactions.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_sender_limit: Option<usize>,
+
+	/// Maximum number of kilobytes of ready transactions of a single sender.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_sender_kbytes: Option<usize>,
+
+	/// Maximum number of future transactions of a single sender.
+	///
+	/// Future transactions wait for other transactions, e.g. because of a nonce gap.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_sender_future_limit: Option<usize>,
+
+	/// Maximum number of kilobytes of future transactions of a single sender.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_sender_future_kbytes: Option<usize>,
+
+	/// Persist the transaction pool to a journal in the config directory.
+	///
+	/// Journaled transactions are revalidated and resubmitted when the node restarts.
+	#[arg(long)]
+	pub pool_journal: bool,
 }
 
 impl TransactionPoolParams {
@@ -49,6 +79,32 @@ impl TransactionPoolParams {
 		opts.future.count = self.pool_limit / factor;
 		opts.future.total_bytes = self.pool_kbytes * 1024 / factor;
 
+		// per-sender limits, unset values fall back to the limits of the whole queue
+		if self.pool_sender_limit.is_some() || self.pool_sender_kbytes.is_some() {
+			let mut limit = opts.ready.clone();
+			if let Some(count) = self.pool_sender_limit {
+				limit.count = count;
+			}
+			if let Some(kbytes) = self.pool_sender_kbytes {
+				limit.total_bytes = kbytes * 1024;
+			}
+			opts.ready_per_sender = Some(limit);
+		}
+		if self.pool_sender_future_limit.is_some() || self.pool_sender_future_kbytes.is_some() {
+			let mut limit = opts.future.clone();
+			if let Some(count) = self.pool_sender_future_limit {
+				limit.count = count;
+			}
+			if let Some(kbytes) = self.pool_sender_future_kbytes {
+				limit.total_bytes = kbytes * 1024;
+			}
+			opts.future_per_sender = Some(limit);
+		}
+
+		if self.pool_journal {
+			opts.journal = Some(PathBuf::from(DEFAULT_POOL_JOURNAL_PATH));
+		}
+
 		opts.ban_time = if let Some(ban_seconds) = self.tx_ban_seconds {
 			std::time::Duration::from_secs(ban_seconds)
 		} else if is_dev {
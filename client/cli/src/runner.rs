@@ -340,6 +340,8 @@ mod tests {
 				)),
 				wasm_method: Default::default(),
 				wasm_runtime_overrides: None,
+				wasm_runtime_artifacts: None,
+				wasm_max_memory_pages: None,
 				execution_strategies: Default::default(),
 				rpc_http: None,
 				rpc_ws: None,
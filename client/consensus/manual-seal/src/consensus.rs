@@ -20,6 +20,7 @@
 use super::Error;
 
 use sc_consensus::BlockImportParams;
+use sp_consensus_slots::SlotDuration;
 use sp_inherents::InherentData;
 use sp_runtime::{traits::Block as BlockT, Digest};
 
@@ -47,4 +48,11 @@ pub trait ConsensusDataProvider<B: BlockT>: Send + Sync {
 		inherents: &InherentData,
 		proof: Self::Proof,
 	) -> Result<(), Error>;
+
+	/// The slot duration of the produced blocks, if their slot is derived from the timestamp.
+	///
+	/// This is required to seal blocks in a specific slot.
+	fn slot_duration(&self) -> Option<SlotDuration> {
+		None
+	}
 }
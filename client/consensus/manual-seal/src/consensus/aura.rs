@@ -98,4 +98,8 @@ where
 	) -> Result<(), Error> {
 		Ok(())
 	}
+
+	fn slot_duration(&self) -> Option<SlotDuration> {
+		Some(self.slot_duration)
+	}
 }
@@ -34,6 +34,7 @@ use std::{marker::PhantomData, sync::Arc};
 
 mod error;
 mod finalize_block;
+mod revert_block;
 mod seal_block;
 
 pub mod consensus;
@@ -43,8 +44,9 @@ pub use self::{
 	consensus::ConsensusDataProvider,
 	error::Error,
 	finalize_block::{finalize_block, FinalizeBlockParams},
-	rpc::{CreatedBlock, EngineCommand},
-	seal_block::{seal_block, SealBlockParams, MAX_PROPOSAL_DURATION},
+	revert_block::{revert_block, RevertBlockParams},
+	rpc::{CreatedBlock, EngineCommand, RevertedBlocks},
+	seal_block::{seal_block, InherentOverrides, SealBlockParams, MAX_PROPOSAL_DURATION},
 };
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::{ProvideRuntimeApi, TransactionFor};
@@ -83,10 +85,13 @@ where
 }
 
 /// Params required to start the instant sealing authorship task.
-pub struct ManualSealParams<B: BlockT, BI, E, C: ProvideRuntimeApi<B>, TP, SC, CS, CIDP, P> {
+pub struct ManualSealParams<B: BlockT, BI, CB, E, C: ProvideRuntimeApi<B>, TP, SC, CS, CIDP, P> {
 	/// Block import instance for well. importing blocks.
 	pub block_import: BI,
 
+	/// Backend used to revert blocks, reverting is not supported if `None`.
+	pub backend: Option<Arc<CB>>,
+
 	/// The environment we are producing blocks for.
 	pub env: E,
 
@@ -140,6 +145,7 @@ pub struct InstantSealParams<B: BlockT, BI, E, C: ProvideRuntimeApi<B>, TP, SC,
 pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 	ManualSealParams {
 		mut block_import,
+		backend,
 		mut env,
 		client,
 		pool,
@@ -147,7 +153,7 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 		select_chain,
 		consensus_data_provider,
 		create_inherent_data_providers,
-	}: ManualSealParams<B, BI, E, C, TP, SC, CS, CIDP, P>,
+	}: ManualSealParams<B, BI, CB, E, C, TP, SC, CS, CIDP, P>,
 ) where
 	B: BlockT + 'static,
 	BI: BlockImport<B, Error = sp_consensus::Error, Transaction = sp_api::TransactionFor<C, B>>
@@ -165,12 +171,16 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 	CIDP: CreateInherentDataProviders<B, ()>,
 	P: Send + Sync + 'static,
 {
+	// overrides applied to the next sealed block only.
+	let mut overrides = InherentOverrides::default();
+
 	while let Some(command) = commands_stream.next().await {
 		match command {
 			EngineCommand::SealNewBlock { create_empty, finalize, parent_hash, sender } => {
 				seal_block(SealBlockParams {
 					sender,
 					parent_hash,
+					overrides: &mut overrides,
 					finalize,
 					create_empty,
 					env: &mut env,
@@ -194,6 +204,23 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 				})
 				.await
 			},
+			EngineCommand::JumpTimestamp { millis, mut sender } => {
+				let jump = overrides.timestamp_jump.unwrap_or_default().saturating_add(millis);
+				overrides.timestamp_jump = Some(jump);
+				rpc::send_result(&mut sender, Ok(()))
+			},
+			EngineCommand::SetSlot { slot, mut sender } => {
+				overrides.slot = Some(slot.into());
+				rpc::send_result(&mut sender, Ok(()))
+			},
+			EngineCommand::RevertTo { hash, sender } =>
+				revert_block(RevertBlockParams {
+					hash,
+					sender,
+					client: client.clone(),
+					backend: backend.clone(),
+				})
+				.await,
 		}
 	}
 }
@@ -238,6 +265,7 @@ pub async fn run_instant_seal<B, BI, CB, E, C, TP, SC, CIDP, P>(
 
 	run_manual_seal(ManualSealParams {
 		block_import,
+		backend: None,
 		env,
 		client,
 		pool,
@@ -292,6 +320,7 @@ pub async fn run_instant_seal_and_finalize<B, BI, CB, E, C, TP, SC, CIDP, P>(
 
 	run_manual_seal(ManualSealParams {
 		block_import,
+		backend: None,
 		env,
 		client,
 		pool,
@@ -357,6 +386,7 @@ mod tests {
 	#[tokio::test]
 	async fn instant_seal() {
 		let builder = TestClientBuilder::new();
+		let backend = builder.backend();
 		let (client, select_chain) = builder.build_with_longest_chain();
 		let client = Arc::new(client);
 		let spawner = sp_core::testing::TaskExecutor::new();
@@ -391,6 +421,7 @@ mod tests {
 			});
 		let future = run_manual_seal(ManualSealParams {
 			block_import: client.clone(),
+			backend: Some(backend),
 			env,
 			client: client.clone(),
 			pool: pool.clone(),
@@ -431,6 +462,7 @@ mod tests {
 	#[tokio::test]
 	async fn manual_seal_and_finalization() {
 		let builder = TestClientBuilder::new();
+		let backend = builder.backend();
 		let (client, select_chain) = builder.build_with_longest_chain();
 		let client = Arc::new(client);
 		let spawner = sp_core::testing::TaskExecutor::new();
@@ -452,6 +484,7 @@ mod tests {
 		let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
 		let future = run_manual_seal(ManualSealParams {
 			block_import: client.clone(),
+			backend: Some(backend),
 			env,
 			client: client.clone(),
 			pool: pool.clone(),
@@ -511,6 +544,7 @@ mod tests {
 	#[tokio::test]
 	async fn manual_seal_fork_blocks() {
 		let builder = TestClientBuilder::new();
+		let backend = builder.backend();
 		let (client, select_chain) = builder.build_with_longest_chain();
 		let client = Arc::new(client);
 		let pool_api = Arc::new(FullChainApi::new(
@@ -537,6 +571,7 @@ mod tests {
 		let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
 		let future = run_manual_seal(ManualSealParams {
 			block_import: client.clone(),
+			backend: Some(backend),
 			env,
 			client: client.clone(),
 			pool: pool.clone(),
@@ -622,6 +657,7 @@ mod tests {
 	#[tokio::test]
 	async fn manual_seal_post_hash() {
 		let builder = TestClientBuilder::new();
+		let backend = builder.backend();
 		let (client, select_chain) = builder.build_with_longest_chain();
 		let client = Arc::new(client);
 		let spawner = sp_core::testing::TaskExecutor::new();
@@ -642,6 +678,7 @@ mod tests {
 		let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
 		let future = run_manual_seal(ManualSealParams {
 			block_import: client.clone(),
+			backend: Some(backend),
 			env,
 			client: client.clone(),
 			pool: pool.clone(),
@@ -670,4 +707,162 @@ mod tests {
 		let header = client.header(created_block.hash).unwrap().unwrap();
 		assert_eq!(header.number, 1);
 	}
+
+	#[tokio::test]
+	async fn manual_seal_revert_to() {
+		let builder = TestClientBuilder::new();
+		let backend = builder.backend();
+		let (client, select_chain) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let genesis_hash = client.info().genesis_hash;
+		let pool = Arc::new(BasicPool::with_revalidation_type(
+			Options::default(),
+			true.into(),
+			api(),
+			None,
+			RevalidationType::Full,
+			spawner.clone(),
+			0,
+			genesis_hash,
+			genesis_hash,
+		));
+		let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None, None);
+
+		let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
+		let future = run_manual_seal(ManualSealParams {
+			block_import: client.clone(),
+			backend: Some(backend),
+			env,
+			client: client.clone(),
+			pool: pool.clone(),
+			commands_stream,
+			select_chain,
+			consensus_data_provider: None,
+			create_inherent_data_providers: |_, _| async { Ok(()) },
+		});
+		std::thread::spawn(|| {
+			let rt = tokio::runtime::Runtime::new().unwrap();
+			rt.block_on(future);
+		});
+
+		let mut hashes = Vec::new();
+		for _ in 0..3 {
+			let (tx, rx) = futures::channel::oneshot::channel();
+			sink.send(EngineCommand::SealNewBlock {
+				parent_hash: None,
+				sender: Some(tx),
+				create_empty: true,
+				finalize: true,
+			})
+			.await
+			.unwrap();
+			hashes.push(rx.await.unwrap().unwrap().hash);
+		}
+		assert_eq!(client.info().best_number, 3);
+
+		let (tx, rx) = futures::channel::oneshot::channel();
+		sink.send(EngineCommand::RevertTo { hash: hashes[0], sender: Some(tx) })
+			.await
+			.unwrap();
+		let reverted = rx.await.unwrap().unwrap();
+		assert_eq!(reverted, RevertedBlocks { hash: hashes[0], reverted: 2 });
+
+		let info = client.info();
+		assert_eq!(info.best_hash, hashes[0]);
+		assert_eq!(info.finalized_hash, hashes[0]);
+
+		// reverted blocks can't be reverted to anymore.
+		let (tx, rx) = futures::channel::oneshot::channel();
+		sink.send(EngineCommand::RevertTo { hash: hashes[2], sender: Some(tx) })
+			.await
+			.unwrap();
+		assert_matches::assert_matches!(rx.await.unwrap(), Err(Error::BlockNotFound(_)));
+	}
+
+	#[tokio::test]
+	async fn failed_seal_keeps_inherent_overrides() {
+		let builder = TestClientBuilder::new();
+		let (client, select_chain) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let genesis_hash = client.info().genesis_hash;
+		let pool = Arc::new(BasicPool::with_revalidation_type(
+			Options::default(),
+			true.into(),
+			api(),
+			None,
+			RevalidationType::Full,
+			spawner.clone(),
+			0,
+			genesis_hash,
+			genesis_hash,
+		));
+		let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None, None);
+
+		let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
+		let future = run_manual_seal(ManualSealParams {
+			block_import: client.clone(),
+			backend: None,
+			env,
+			client: client.clone(),
+			pool: pool.clone(),
+			commands_stream,
+			select_chain,
+			consensus_data_provider: None,
+			// no timestamp inherent data to jump from, sealing fails while a jump is pending.
+			create_inherent_data_providers: |_, _| async { Ok(()) },
+		});
+		std::thread::spawn(|| {
+			let rt = tokio::runtime::Runtime::new().unwrap();
+			rt.block_on(future);
+		});
+
+		let (tx, rx) = futures::channel::oneshot::channel();
+		sink.send(EngineCommand::JumpTimestamp { millis: 6000, sender: Some(tx) })
+			.await
+			.unwrap();
+		rx.await.unwrap().unwrap();
+
+		for _ in 0..2 {
+			let (tx, rx) = futures::channel::oneshot::channel();
+			sink.send(EngineCommand::SealNewBlock {
+				parent_hash: None,
+				sender: Some(tx),
+				create_empty: true,
+				finalize: false,
+			})
+			.await
+			.unwrap();
+			assert_matches::assert_matches!(rx.await.unwrap(), Err(Error::StringError(_)));
+		}
+		assert_eq!(client.info().best_number, 0);
+	}
+
+	#[test]
+	fn inherent_overrides_keep_slot_and_timestamp_in_sync() {
+		use sp_consensus_babe::inherents::BabeInherentData;
+		use sp_consensus_slots::{Slot, SlotDuration};
+		use sp_timestamp::TimestampInherentData;
+
+		let slot_duration = SlotDuration::from_millis(6000);
+		let mut inherent_data = InherentData::new();
+		inherent_data.put_data(sp_timestamp::INHERENT_IDENTIFIER, &12_000u64).unwrap();
+		inherent_data
+			.put_data(sp_consensus_babe::inherents::INHERENT_IDENTIFIER, &Slot::from(2))
+			.unwrap();
+
+		let overrides = InherentOverrides { timestamp_jump: Some(60_000), slot: None };
+		overrides.apply(&mut inherent_data, Some(slot_duration)).unwrap();
+		assert_eq!(inherent_data.timestamp_inherent_data().unwrap(), Some(72_000.into()));
+		assert_eq!(inherent_data.babe_inherent_data().unwrap(), Some(Slot::from(12)));
+
+		let overrides = InherentOverrides { timestamp_jump: Some(60_000), slot: Some(20.into()) };
+		overrides.apply(&mut inherent_data, Some(slot_duration)).unwrap();
+		assert_eq!(inherent_data.timestamp_inherent_data().unwrap(), Some(120_000.into()));
+		assert_eq!(inherent_data.babe_inherent_data().unwrap(), Some(Slot::from(20)));
+
+		// slots can't be overridden without knowing the slot duration.
+		assert!(overrides.apply(&mut inherent_data, None).is_err());
+	}
 }
@@ -0,0 +1,84 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Block reverting utilities
+
+use crate::{rpc, Error, RevertedBlocks};
+use sc_client_api::backend::Backend as ClientBackend;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
+use std::sync::Arc;
+
+/// params for reverting the chain to a block.
+pub struct RevertBlockParams<B: BlockT, C, CB> {
+	/// hash of the block that becomes the best block
+	pub hash: <B as BlockT>::Hash,
+	/// sender to report errors/success to the rpc.
+	pub sender: rpc::Sender<RevertedBlocks<<B as BlockT>::Hash>>,
+	/// header backend
+	pub client: Arc<C>,
+	/// backend to revert the blocks in, reverting is not supported without it.
+	pub backend: Option<Arc<CB>>,
+}
+
+/// reverts the best chain, including finalized blocks, to the block with the given params.
+pub async fn revert_block<B, C, CB>(params: RevertBlockParams<B, C, CB>)
+where
+	B: BlockT,
+	C: HeaderBackend<B>,
+	CB: ClientBackend<B>,
+{
+	let RevertBlockParams { hash, mut sender, client, backend } = params;
+
+	let result = revert_to(hash, &*client, backend.as_deref());
+	match &result {
+		Err(e) => log::warn!("Failed to revert to block {}: {}", hash, e),
+		Ok(reverted) =>
+			log::info!("⏪ Reverted {} blocks, best block: {}", reverted.reverted, hash),
+	}
+	rpc::send_result(&mut sender, result)
+}
+
+fn revert_to<B, C, CB>(
+	hash: <B as BlockT>::Hash,
+	client: &C,
+	backend: Option<&CB>,
+) -> Result<RevertedBlocks<<B as BlockT>::Hash>, Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B>,
+	CB: ClientBackend<B>,
+{
+	let backend = backend
+		.ok_or_else(|| Error::StringError("Manual seal was started without a backend".into()))?;
+	let header = client.header(hash)?.ok_or_else(|| Error::BlockNotFound(format!("{}", hash)))?;
+	if client.hash(*header.number())? != Some(hash) {
+		return Err(Error::StringError(format!("Block {} is not part of the best chain", hash)))
+	}
+
+	let blocks = client.info().best_number - *header.number();
+	let (reverted, _) = backend.revert(blocks, true)?;
+	if reverted != blocks {
+		return Err(Error::StringError(format!(
+			"Only {} of {} blocks could be reverted",
+			reverted, blocks
+		)))
+	}
+
+	Ok(RevertedBlocks { hash, reverted: reverted.unique_saturated_into() })
+}
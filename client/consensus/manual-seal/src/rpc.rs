@@ -28,9 +28,13 @@ use jsonrpsee::{
 	proc_macros::rpc,
 };
 use sc_consensus::ImportedAux;
+use sc_rpc_api::DenyUnsafe;
 use serde::{Deserialize, Serialize};
 use sp_runtime::EncodedJustification;
 
+/// Maximum number of blocks `engine_createBlocks` creates in one call.
+pub const MAX_CREATE_BLOCKS: u32 = 1024;
+
 /// Sender passed to the authorship task to report errors or successes.
 pub type Sender<T> = Option<oneshot::Sender<std::result::Result<T, Error>>>;
 
@@ -62,6 +66,27 @@ pub enum EngineCommand<Hash> {
 		/// finalization justification
 		justification: Option<EncodedJustification>,
 	},
+	/// Tells the engine to move the timestamp of the next sealed block forward
+	JumpTimestamp {
+		/// milliseconds added to the timestamp of the next block
+		millis: u64,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<()>,
+	},
+	/// Tells the engine to seal the next block in the given slot
+	SetSlot {
+		/// slot of the next block
+		slot: u64,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<()>,
+	},
+	/// Tells the engine to revert the chain to the block with the supplied hash
+	RevertTo {
+		/// hash of the block that becomes the best block
+		hash: Hash,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<RevertedBlocks<Hash>>,
+	},
 }
 
 /// RPC trait that provides methods for interacting with the manual-seal authorship task over rpc.
@@ -83,11 +108,34 @@ pub trait ManualSealApi<Hash> {
 		hash: Hash,
 		justification: Option<EncodedJustification>,
 	) -> RpcResult<bool>;
+
+	/// Instructs the manual-seal authorship task to create `count` empty blocks on top of the
+	/// best block, `count` is at most [`MAX_CREATE_BLOCKS`]
+	#[method(name = "engine_createBlocks")]
+	async fn create_blocks(&self, count: u32, finalize: bool)
+		-> RpcResult<Vec<CreatedBlock<Hash>>>;
+
+	/// Instructs the manual-seal authorship task to move the timestamp of the next block forward
+	/// by `millis` milliseconds
+	#[method(name = "engine_jumpTimestamp")]
+	async fn jump_timestamp(&self, millis: u64) -> RpcResult<bool>;
+
+	/// Instructs the manual-seal authorship task to seal the next block in `slot`
+	#[method(name = "engine_setSlot")]
+	async fn set_slot(&self, slot: u64) -> RpcResult<bool>;
+
+	/// Instructs the manual-seal authorship task to revert the chain to a block, finalized
+	/// blocks are reverted as well
+	///
+	/// This is an unsafe RPC.
+	#[method(name = "engine_revertTo")]
+	async fn revert_to(&self, hash: Hash) -> RpcResult<RevertedBlocks<Hash>>;
 }
 
 /// A struct that implements the [`ManualSealApiServer`].
 pub struct ManualSeal<Hash> {
 	import_block_channel: mpsc::Sender<EngineCommand<Hash>>,
+	deny_unsafe: DenyUnsafe,
 }
 
 /// return type of `engine_createBlock`
@@ -99,10 +147,22 @@ pub struct CreatedBlock<Hash> {
 	pub aux: ImportedAux,
 }
 
+/// return type of `engine_revertTo`
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RevertedBlocks<Hash> {
+	/// hash of the new best block.
+	pub hash: Hash,
+	/// number of blocks that were reverted.
+	pub reverted: u64,
+}
+
 impl<Hash> ManualSeal<Hash> {
 	/// Create new `ManualSeal` with the given reference to the client.
-	pub fn new(import_block_channel: mpsc::Sender<EngineCommand<Hash>>) -> Self {
-		Self { import_block_channel }
+	pub fn new(
+		import_block_channel: mpsc::Sender<EngineCommand<Hash>>,
+		deny_unsafe: DenyUnsafe,
+	) -> Self {
+		Self { import_block_channel, deny_unsafe }
 	}
 }
 
@@ -144,6 +204,62 @@ impl<Hash: Send + 'static> ManualSealApiServer<Hash> for ManualSeal<Hash> {
 		sink.send(command).await?;
 		receiver.await.map(|_| true).map_err(|e| JsonRpseeError::to_call_error(e))
 	}
+
+	async fn create_blocks(
+		&self,
+		count: u32,
+		finalize: bool,
+	) -> RpcResult<Vec<CreatedBlock<Hash>>> {
+		if count > MAX_CREATE_BLOCKS {
+			return Err(Error::StringError(format!(
+				"Can not create more than {} blocks at once",
+				MAX_CREATE_BLOCKS
+			))
+			.into())
+		}
+
+		let mut blocks = Vec::with_capacity(count as usize);
+		for _ in 0..count {
+			blocks.push(self.create_block(true, finalize, None).await?);
+		}
+		Ok(blocks)
+	}
+
+	async fn jump_timestamp(&self, millis: u64) -> RpcResult<bool> {
+		self.send_command(|sender| EngineCommand::JumpTimestamp { millis, sender })
+			.await
+			.map(|_| true)
+	}
+
+	async fn set_slot(&self, slot: u64) -> RpcResult<bool> {
+		self.send_command(|sender| EngineCommand::SetSlot { slot, sender })
+			.await
+			.map(|_| true)
+	}
+
+	async fn revert_to(&self, hash: Hash) -> RpcResult<RevertedBlocks<Hash>> {
+		self.deny_unsafe.check_if_safe()?;
+		self.send_command(|sender| EngineCommand::RevertTo { hash, sender }).await
+	}
+}
+
+impl<Hash: Send + 'static> ManualSeal<Hash> {
+	/// Send the command built by `command` and wait for the result reported by the authorship
+	/// task.
+	async fn send_command<T>(
+		&self,
+		command: impl FnOnce(Sender<T>) -> EngineCommand<Hash>,
+	) -> RpcResult<T> {
+		let mut sink = self.import_block_channel.clone();
+		let (sender, receiver) = oneshot::channel();
+		sink.send(command(Some(sender))).await?;
+
+		match receiver.await {
+			Ok(Ok(rx)) => Ok(rx),
+			Ok(Err(e)) => Err(e.into()),
+			Err(e) => Err(JsonRpseeError::to_call_error(e)),
+		}
+	}
 }
 
 /// report any errors or successes encountered by the authorship task back
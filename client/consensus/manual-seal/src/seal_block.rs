@@ -25,13 +25,68 @@ use sc_transaction_pool_api::TransactionPool;
 use sp_api::{ProvideRuntimeApi, TransactionFor};
 use sp_blockchain::HeaderBackend;
 use sp_consensus::{self, BlockOrigin, Environment, Proposer, SelectChain};
-use sp_inherents::{CreateInherentDataProviders, InherentDataProvider};
+use sp_consensus_babe::inherents::BabeInherentData;
+use sp_consensus_slots::{Slot, SlotDuration};
+use sp_inherents::{CreateInherentDataProviders, InherentData, InherentDataProvider};
 use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use sp_timestamp::TimestampInherentData;
 use std::{collections::HashMap, sync::Arc, time::Duration};
 
 /// max duration for creating a proposal in secs
 pub const MAX_PROPOSAL_DURATION: u64 = 10;
 
+/// Overrides of the inherent data used to seal a block.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InherentOverrides {
+	/// milliseconds added to the timestamp provided by the inherent data providers.
+	pub timestamp_jump: Option<u64>,
+	/// seal the block in this slot, the timestamp is moved to the start of the slot.
+	pub slot: Option<Slot>,
+}
+
+impl InherentOverrides {
+	/// Apply the overrides to `inherent_data`.
+	///
+	/// The timestamp and the BABE slot inherents are kept in sync using `slot_duration`, which is
+	/// required to override the slot.
+	pub fn apply(
+		&self,
+		inherent_data: &mut InherentData,
+		slot_duration: Option<SlotDuration>,
+	) -> Result<(), Error> {
+		let mut timestamp = match self.timestamp_jump {
+			Some(jump) => {
+				let timestamp = inherent_data.timestamp_inherent_data()?.ok_or_else(|| {
+					Error::StringError("No timestamp inherent data to jump from".into())
+				})?;
+				Some(timestamp + jump)
+			},
+			None => None,
+		};
+
+		if let Some(slot) = self.slot {
+			let slot_duration = slot_duration.ok_or_else(|| {
+				Error::StringError("Consensus data provider does not support slots".into())
+			})?;
+			timestamp = Some((*slot * slot_duration.as_millis()).into());
+		}
+
+		if let Some(timestamp) = timestamp {
+			inherent_data.replace_data(sp_timestamp::INHERENT_IDENTIFIER, &timestamp);
+			if let Some(slot_duration) = slot_duration {
+				if inherent_data.babe_inherent_data()?.is_some() {
+					inherent_data.replace_data(
+						sp_consensus_babe::inherents::INHERENT_IDENTIFIER,
+						&Slot::from_timestamp(timestamp, slot_duration),
+					);
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
 /// params for sealing a new block
 pub struct SealBlockParams<'a, B: BlockT, BI, SC, C: ProvideRuntimeApi<B>, E, TP, CIDP, P> {
 	/// if true, empty blocks(without extrinsics) will be created.
@@ -41,6 +96,8 @@ pub struct SealBlockParams<'a, B: BlockT, BI, SC, C: ProvideRuntimeApi<B>, E, TP
 	pub finalize: bool,
 	/// specify the parent hash of the about-to-created block
 	pub parent_hash: Option<<B as BlockT>::Hash>,
+	/// overrides of the inherent data of this block, cleared once the block is imported
+	pub overrides: &'a mut InherentOverrides,
 	/// sender to report errors/success to the rpc.
 	pub sender: rpc::Sender<CreatedBlock<<B as BlockT>::Hash>>,
 	/// transaction pool
@@ -67,6 +124,7 @@ pub async fn seal_block<B, BI, SC, C, E, TP, CIDP, P>(
 		finalize,
 		pool,
 		parent_hash,
+		overrides,
 		client,
 		select_chain,
 		block_import,
@@ -109,7 +167,11 @@ pub async fn seal_block<B, BI, SC, C, E, TP, CIDP, P>(
 			.await
 			.map_err(|e| Error::Other(e))?;
 
-		let inherent_data = inherent_data_providers.create_inherent_data().await?;
+		let mut inherent_data = inherent_data_providers.create_inherent_data().await?;
+		overrides.apply(
+			&mut inherent_data,
+			digest_provider.and_then(|provider| provider.slot_duration()),
+		)?;
 
 		let proposer = env.init(&parent).map_err(|err| Error::StringError(err.to_string())).await?;
 		let inherents_len = inherent_data.len();
@@ -160,5 +222,9 @@ pub async fn seal_block<B, BI, SC, C, E, TP, CIDP, P>(
 		}
 	};
 
-	rpc::send_result(&mut sender, future.await)
+	let result = future.await;
+	if result.is_ok() {
+		*overrides = InherentOverrides::default();
+	}
+	rpc::send_result(&mut sender, result)
 }
@@ -31,12 +31,14 @@
 pub mod offchain;
 
 pub mod bench;
+pub mod migration;
 
 mod children;
 mod parity_db;
 mod pinned_blocks_cache;
 mod record_stats_state;
 mod stats;
+mod storage_profile;
 #[cfg(any(feature = "rocksdb", test))]
 mod upgrade;
 mod utils;
@@ -97,6 +99,7 @@ pub use sc_state_db::PruningMode;
 pub use sp_database::Database;
 
 pub use bench::BenchmarkingState;
+pub use storage_profile::{PrefixUsage, StorageProfile, StorageProfiler, PROFILE_PREFIX_LEN};
 
 const CACHE_HEADERS: usize = 8;
 
@@ -1107,6 +1110,7 @@ pub struct Backend<Block: BlockT> {
 	blocks_pruning: BlocksPruning,
 	io_stats: FrozenForDuration<(kvdb::IoStats, StateUsageInfo)>,
 	state_usage: Arc<StateUsageStats>,
+	storage_profiler: Arc<StorageProfiler>,
 	genesis_state: RwLock<Option<Arc<DbGenesisStorage<Block>>>>,
 	shared_trie_cache: Option<sp_trie::cache::SharedTrieCache<HashFor<Block>>>,
 }
@@ -1180,6 +1184,11 @@ impl<Block: BlockT> Backend<Block> {
 		self.storage.clone()
 	}
 
+	/// The profiler of the storage accesses of the states created by this backend.
+	pub fn storage_profiler(&self) -> &Arc<StorageProfiler> {
+		&self.storage_profiler
+	}
+
 	fn from_database(
 		db: Arc<dyn Database<DbHash>>,
 		canonicalization_delay: u64,
@@ -1220,6 +1229,7 @@ impl<Block: BlockT> Backend<Block> {
 			is_archive: is_archive_pruning,
 			io_stats: FrozenForDuration::new(std::time::Duration::from_secs(1)),
 			state_usage: Arc::new(StateUsageStats::new()),
+			storage_profiler: Default::default(),
 			blocks_pruning: config.blocks_pruning,
 			genesis_state: RwLock::new(None),
 			shared_trie_cache: config.trie_cache_maximum_size.map(|maximum_size| {
@@ -1915,7 +1925,7 @@ impl<Block: BlockT> Backend<Block> {
 			.with_optional_cache(self.shared_trie_cache.as_ref().map(|c| c.local_cache()))
 			.build();
 		let state = RefTrackingState::new(db_state, self.storage.clone(), None);
-		RecordStatsState::new(state, None, self.state_usage.clone())
+		RecordStatsState::new(state, None, self.state_usage.clone(), &self.storage_profiler)
 	}
 }
 
@@ -2408,7 +2418,12 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 					.build();
 
 				let state = RefTrackingState::new(db_state, self.storage.clone(), None);
-				return Ok(RecordStatsState::new(state, None, self.state_usage.clone()))
+				return Ok(RecordStatsState::new(
+					state,
+					None,
+					self.state_usage.clone(),
+					&self.storage_profiler,
+				))
 			}
 		}
 
@@ -2430,7 +2445,12 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 						)
 						.build();
 					let state = RefTrackingState::new(db_state, self.storage.clone(), Some(hash));
-					Ok(RecordStatsState::new(state, Some(hash), self.state_usage.clone()))
+					Ok(RecordStatsState::new(
+						state,
+						Some(hash),
+						self.state_usage.clone(),
+						&self.storage_profiler,
+					))
 				} else {
 					Err(sp_blockchain::Error::UnknownBlock(format!(
 						"State already discarded for {:?}",
@@ -0,0 +1,662 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Migration of a database between backends.
+//!
+//! Every column of the source database is copied into the target database in batches. Each
+//! batch is committed together with a progress marker in the target meta column, so an
+//! interrupted migration picks up where it stopped when started again. The meta column is copied
+//! last, which means the target only describes a usable chain once all other columns are in place.
+//!
+//! When the source database does its reference counting in the state-db (RocksDB) and the target
+//! counts references itself (ParityDB), trie node keys lose their path prefix, and refcounted
+//! entries of the transaction column are re-stored once per reference. Trie nodes are stored with
+//! as many references as the pruning window of the source will release, so that pruning the
+//! target removes every node at the same block as pruning the source would have.
+
+use std::{collections::HashMap, sync::Arc};
+
+use codec::{Decode, Encode};
+use log::{error, info};
+use parking_lot::Mutex;
+use sc_client_api::backend::Backend as _;
+use sp_blockchain::{Error as ClientError, HeaderBackend, Result as ClientResult};
+use sp_core::storage::{well_known_keys, ChildInfo};
+use sp_database::{ColumnId, Database, Transaction};
+use sp_runtime::traits::{Block as BlockT, Hash, HashFor, Header as HeaderT, NumberFor};
+use sp_state_machine::{backend::Backend as StateBackend, IterArgs};
+
+use crate::{
+	columns,
+	utils::{meta_keys, open_database, DatabaseType, COLUMN_META, NUM_COLUMNS},
+	Backend, BlocksPruning, DatabaseSettings, DatabaseSource, DbHash, DB_HASH_LEN,
+};
+
+/// Default number of source entries copied per committed batch.
+pub const DEFAULT_BATCH_SIZE: usize = 10_000;
+
+/// Number of entries copied from a single column.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnSummary {
+	/// The column id.
+	pub column: ColumnId,
+	/// Number of source entries copied.
+	pub keys: u64,
+	/// Total size of the copied keys and values in bytes.
+	pub bytes: u64,
+}
+
+/// State that was found to be identical in both databases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedState<Block: BlockT> {
+	/// Hash of the block the state belongs to.
+	pub hash: Block::Hash,
+	/// Number of the block the state belongs to.
+	pub number: NumberFor<Block>,
+	/// State root announced by the block header. Every trie node of the target state was checked
+	/// to hash to the key it is stored under, so the target state is the one committed to by
+	/// this root.
+	pub state_root: Block::Hash,
+	/// Number of key-value pairs compared, child tries included.
+	pub keys: u64,
+}
+
+/// Outcome of [`migrate`].
+#[derive(Debug, Clone)]
+pub struct MigrationSummary<Block: BlockT> {
+	/// Entries copied per column during this run. Columns that were completed by an earlier,
+	/// interrupted run are not listed.
+	pub columns: Vec<ColumnSummary>,
+	/// States compared after the copy, empty if verification was not requested.
+	pub verified: Vec<VerifiedState<Block>>,
+}
+
+/// Position of an interrupted migration: the column being copied and the last source key of that
+/// column that has been committed to the target, if any.
+#[derive(Debug, Encode, Decode)]
+struct Progress {
+	column: ColumnId,
+	last_key: Option<Vec<u8>>,
+}
+
+/// The order in which columns are copied. The meta column goes last, see module documentation.
+fn column_order() -> impl Iterator<Item = ColumnId> {
+	(0..NUM_COLUMNS)
+		.filter(|col| *col != COLUMN_META)
+		.chain(std::iter::once(COLUMN_META))
+}
+
+/// Copy the database at `from` into `to`, and compare the state of the best and finalized blocks
+/// of both databases afterwards if `verify` is set.
+///
+/// The target is created if it does not exist. A target that already holds a chain is only
+/// accepted if it is the leftover of an interrupted migration.
+pub fn migrate<Block: BlockT>(
+	from: &DatabaseSource,
+	to: &DatabaseSource,
+	batch_size: usize,
+	verify: bool,
+) -> ClientResult<MigrationSummary<Block>> {
+	let source = open_database::<Block>(from, DatabaseType::Full, false)?;
+	let target = open_database::<Block>(to, DatabaseType::Full, true)?;
+
+	let columns = migrate_columns::<Block>(&*source, &*target, batch_size)?;
+	let verified = if verify { verify_migration::<Block>(source, target)? } else { Vec::new() };
+
+	Ok(MigrationSummary { columns, verified })
+}
+
+/// Copy all columns of `source` into `target`, resuming an earlier interrupted run if the target
+/// carries a progress marker.
+pub fn migrate_columns<Block: BlockT>(
+	source: &dyn Database<DbHash>,
+	target: &dyn Database<DbHash>,
+	batch_size: usize,
+) -> ClientResult<Vec<ColumnSummary>> {
+	let convert = match (source.supports_ref_counting(), target.supports_ref_counting()) {
+		(false, true) => true,
+		(false, false) => false,
+		(true, _) =>
+			return Err(ClientError::Backend(
+				"Migrating from a database with internal reference counting is not supported"
+					.into(),
+			)),
+	};
+
+	let progress = match target.get(COLUMN_META, meta_keys::MIGRATION_PROGRESS) {
+		Some(progress) => Some(Progress::decode(&mut &progress[..]).map_err(|e| {
+			ClientError::Backend(format!("Error decoding migration progress: {}", e))
+		})?),
+		None if target.get(COLUMN_META, meta_keys::GENESIS_HASH).is_some() =>
+			return Err(ClientError::Backend(
+				"Target database already contains a chain and no migration is in progress".into(),
+			)),
+		None => None,
+	};
+
+	// Only the pruning window of the source knows how often the target will release each node.
+	let references = if convert { state_references::<Block>(source)? } else { HashMap::new() };
+
+	let mut summaries = Vec::new();
+	let mut columns = column_order().peekable();
+	if let Some(progress) = &progress {
+		while columns.next_if(|col| *col != progress.column).is_some() {}
+		if columns.peek().is_none() {
+			return Err(ClientError::Backend(format!(
+				"Invalid migration progress: unknown column {}",
+				progress.column
+			)))
+		}
+		info!(target: "db", "Resuming database migration at column {}", progress.column);
+	}
+
+	for column in columns {
+		let resume_after = progress
+			.as_ref()
+			.filter(|progress| progress.column == column)
+			.and_then(|progress| progress.last_key.as_deref());
+		let summary = copy_column::<Block>(
+			source,
+			target,
+			column,
+			resume_after,
+			convert.then_some(&references),
+			batch_size,
+		)?;
+		info!(
+			target: "db",
+			"Migrated column {}: {} entries, {} bytes",
+			summary.column,
+			summary.keys,
+			summary.bytes,
+		);
+		summaries.push(summary);
+	}
+
+	Ok(summaries)
+}
+
+/// Reference counts of the trie nodes released by the pruning window of `source`, keyed by their
+/// source key.
+fn state_references<Block: BlockT>(
+	source: &dyn Database<DbHash>,
+) -> ClientResult<HashMap<Vec<u8>, u32>> {
+	let mut meta = Vec::new();
+	source
+		.iter_column_while(columns::STATE_META, &mut |key, value| {
+			meta.push((key.to_vec(), value.to_vec()));
+			true
+		})
+		.map_err(ClientError::from)?;
+	sc_state_db::pruning_window_references::<Block::Hash, Vec<u8>>(meta)
+		.map_err(|e| ClientError::Backend(format!("Error decoding state-db journal: {}", e)))
+}
+
+/// Copy `column` of `source` into `target`. `references` holds the reference counts of trie
+/// nodes if the entries are converted for a reference counting target.
+fn copy_column<Block: BlockT>(
+	source: &dyn Database<DbHash>,
+	target: &dyn Database<DbHash>,
+	column: ColumnId,
+	resume_after: Option<&[u8]>,
+	references: Option<&HashMap<Vec<u8>, u32>>,
+	batch_size: usize,
+) -> ClientResult<ColumnSummary> {
+	let mut summary = ColumnSummary { column, ..Default::default() };
+	let mut transaction = Transaction::new();
+	let mut pending = 0;
+	let mut last_key = Vec::new();
+	let mut error = None;
+
+	let commit = |transaction: &mut Transaction<DbHash>, last_key: &[u8], done: bool| {
+		let mut transaction = std::mem::take(transaction);
+		if done && column == COLUMN_META {
+			transaction.remove(COLUMN_META, meta_keys::MIGRATION_PROGRESS);
+		} else {
+			let progress = match (done, column_order().skip_while(|col| *col != column).nth(1)) {
+				(true, Some(next)) => Progress { column: next, last_key: None },
+				_ => Progress { column, last_key: Some(last_key.to_vec()) },
+			};
+			transaction.set_from_vec(COLUMN_META, meta_keys::MIGRATION_PROGRESS, progress.encode());
+		}
+		target.commit(transaction).map_err(ClientError::from)
+	};
+
+	source
+		.iter_column_while(column, &mut |key, value| {
+			if resume_after.map_or(false, |resume_after| key <= resume_after) {
+				return true
+			}
+			if let Err(e) =
+				copy_entry::<Block>(source, &mut transaction, column, key, value, references)
+			{
+				error = Some(e);
+				return false
+			}
+			summary.keys += 1;
+			summary.bytes += (key.len() + value.len()) as u64;
+			pending += 1;
+			last_key = key.to_vec();
+
+			if pending >= batch_size {
+				pending = 0;
+				if let Err(e) = commit(&mut transaction, &last_key, false) {
+					error = Some(e);
+					return false
+				}
+			}
+			true
+		})
+		.map_err(ClientError::from)?;
+
+	if let Some(e) = error {
+		return Err(e)
+	}
+	commit(&mut transaction, &last_key, true)?;
+
+	Ok(summary)
+}
+
+fn copy_entry<Block: BlockT>(
+	source: &dyn Database<DbHash>,
+	transaction: &mut Transaction<DbHash>,
+	column: ColumnId,
+	key: &[u8],
+	value: &[u8],
+	references: Option<&HashMap<Vec<u8>, u32>>,
+) -> ClientResult<()> {
+	let Some(references) = references else {
+		transaction.set(column, key, value);
+		return Ok(())
+	};
+
+	match column {
+		columns::STATE => {
+			if key.len() < DB_HASH_LEN {
+				return Err(ClientError::Backend(format!("Invalid state key: {:?}", key)))
+			}
+			// Nodes with the same hash under different paths share a single reference counter.
+			for _ in 0..references.get(key).copied().unwrap_or(1) {
+				transaction.store(columns::STATE, hash_suffix(key), value.to_vec());
+			}
+		},
+		columns::STATE_META => {
+			let mapped = sc_state_db::map_journal_keys::<Block::Hash, Vec<u8>>(key, value, |k| {
+				if k.len() > DB_HASH_LEN {
+					k.drain(..k.len() - DB_HASH_LEN);
+				}
+			})
+			.map_err(|e| ClientError::Backend(format!("Error decoding state-db journal: {}", e)))?;
+			transaction.set_from_vec(
+				columns::STATE_META,
+				key,
+				mapped.unwrap_or_else(|| value.to_vec()),
+			);
+		},
+		columns::TRANSACTION => {
+			// The kvdb adapter keeps the reference counter of `hash` under `hash ++ [0]`.
+			if key.len() != DB_HASH_LEN {
+				return Ok(())
+			}
+			let mut counter_key = key.to_vec();
+			counter_key.push(0);
+			let references = match source.get(columns::TRANSACTION, &counter_key) {
+				Some(counter) => u32::decode(&mut &counter[..]).map_err(|e| {
+					ClientError::Backend(format!("Error decoding reference counter: {}", e))
+				})?,
+				None => 1,
+			};
+			for _ in 0..references {
+				transaction.store(columns::TRANSACTION, hash_suffix(key), value.to_vec());
+			}
+		},
+		_ => transaction.set(column, key, value),
+	}
+
+	Ok(())
+}
+
+fn hash_suffix(key: &[u8]) -> DbHash {
+	DbHash::from_slice(&key[key.len() - DB_HASH_LEN..])
+}
+
+/// A database that only returns trie nodes which hash to the key they are stored under.
+///
+/// Walking a trie through this database proves that the walked state is the one committed to by
+/// the state root the walk started at.
+struct RootCheckingDb<Block> {
+	inner: Arc<dyn Database<DbHash>>,
+	/// The key of the first trie node that did not match its hash.
+	mismatch: Mutex<Option<Vec<u8>>>,
+	_phantom: std::marker::PhantomData<fn() -> Block>,
+}
+
+impl<Block: BlockT> Database<DbHash> for RootCheckingDb<Block> {
+	fn commit(&self, transaction: Transaction<DbHash>) -> sp_database::error::Result<()> {
+		self.inner.commit(transaction)
+	}
+
+	fn get(&self, col: ColumnId, key: &[u8]) -> Option<Vec<u8>> {
+		let value = self.inner.get(col, key)?;
+		if col == columns::STATE &&
+			key.len() >= DB_HASH_LEN &&
+			HashFor::<Block>::hash(&value).as_ref() != &key[key.len() - DB_HASH_LEN..]
+		{
+			error!(target: "db", "Trie node {:?} does not match its hash", key);
+			self.mismatch.lock().get_or_insert_with(|| key.to_vec());
+			return None
+		}
+		Some(value)
+	}
+
+	fn supports_ref_counting(&self) -> bool {
+		self.inner.supports_ref_counting()
+	}
+
+	fn sanitize_key(&self, key: &mut Vec<u8>) {
+		self.inner.sanitize_key(key)
+	}
+}
+
+/// Compare the state of the best and finalized blocks between `source` and `target`.
+///
+/// Each state is opened through the state root of its block header in both databases and all
+/// key-value pairs, including the ones of default child tries, are compared. Every trie node read
+/// from the target is checked against its hash, so a state that matches is also known to match
+/// the state root of the header. A missing or corrupted trie node in the target is reported as an
+/// error.
+pub fn verify_migration<Block: BlockT>(
+	source: Arc<dyn Database<DbHash>>,
+	target: Arc<dyn Database<DbHash>>,
+) -> ClientResult<Vec<VerifiedState<Block>>> {
+	let checked_target = Arc::new(RootCheckingDb::<Block> {
+		inner: target,
+		mismatch: Mutex::new(None),
+		_phantom: Default::default(),
+	});
+	let open = |db: Arc<dyn Database<DbHash>>| {
+		let settings = DatabaseSettings {
+			trie_cache_maximum_size: None,
+			state_pruning: None,
+			source: DatabaseSource::Custom { db: db.clone(), require_create_flag: false },
+			blocks_pruning: BlocksPruning::KeepAll,
+		};
+		Backend::<Block>::from_database(db, 0, &settings, false)
+	};
+	let source = open(source)?;
+	let target = open(checked_target.clone())?;
+
+	let source_info = source.blockchain().info();
+	let target_info = target.blockchain().info();
+	if (source_info.best_hash, source_info.finalized_hash) !=
+		(target_info.best_hash, target_info.finalized_hash)
+	{
+		return Err(ClientError::Backend(format!(
+			"Chain head mismatch: source best {:?}, finalized {:?}; target best {:?}, finalized {:?}",
+			source_info.best_hash,
+			source_info.finalized_hash,
+			target_info.best_hash,
+			target_info.finalized_hash,
+		)))
+	}
+
+	let mut verified = Vec::new();
+	let mut blocks = vec![target_info.best_hash];
+	if target_info.finalized_hash != target_info.best_hash {
+		blocks.push(target_info.finalized_hash);
+	}
+	for hash in blocks {
+		let header = target.blockchain().header(hash)?.ok_or_else(|| {
+			ClientError::Backend(format!("Header {:?} missing from target database", hash))
+		})?;
+		let source_state = source.state_at(hash)?;
+		let target_state = target.state_at(hash)?;
+		let compared = compare_states::<Block, _>(&source_state, &target_state, hash);
+		if let Some(key) = checked_target.mismatch.lock().take() {
+			return Err(ClientError::Backend(format!(
+				"State of block {:?} does not match state root {:?}: trie node {:?} is corrupted",
+				hash,
+				header.state_root(),
+				key,
+			)))
+		}
+		let keys = compared?;
+
+		info!(
+			target: "db",
+			"Verified state of block #{} ({:?}) against root {:?}: {} keys",
+			header.number(),
+			hash,
+			header.state_root(),
+			keys,
+		);
+		verified.push(VerifiedState {
+			hash,
+			number: *header.number(),
+			state_root: *header.state_root(),
+			keys,
+		});
+	}
+
+	Ok(verified)
+}
+
+fn compare_states<Block: BlockT, S: StateBackend<sp_runtime::traits::HashFor<Block>>>(
+	source: &S,
+	target: &S,
+	hash: Block::Hash,
+) -> ClientResult<u64> {
+	let mut keys = compare_tries::<Block, _>(source, target, None, hash)?;
+
+	let mut args = IterArgs::default();
+	args.prefix = Some(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX);
+	for key in source.keys(args).map_err(|e| state_error(hash, e))? {
+		let key = key.map_err(|e| state_error(hash, e))?;
+		let child_info =
+			ChildInfo::new_default(&key[well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX.len()..]);
+		keys += compare_tries::<Block, _>(source, target, Some(child_info), hash)?;
+	}
+
+	Ok(keys)
+}
+
+fn compare_tries<Block: BlockT, S: StateBackend<sp_runtime::traits::HashFor<Block>>>(
+	source: &S,
+	target: &S,
+	child_info: Option<ChildInfo>,
+	hash: Block::Hash,
+) -> ClientResult<u64> {
+	let mut source_args = IterArgs::default();
+	source_args.child_info = child_info.clone();
+	let mut target_args = IterArgs::default();
+	target_args.child_info = child_info;
+
+	let mut source_pairs = source.pairs(source_args).map_err(|e| state_error(hash, e))?;
+	let mut target_pairs = target.pairs(target_args).map_err(|e| state_error(hash, e))?;
+	let mut keys = 0;
+	loop {
+		let source_pair = source_pairs.next().transpose().map_err(|e| state_error(hash, e))?;
+		let target_pair = target_pairs.next().transpose().map_err(|e| state_error(hash, e))?;
+		match (source_pair, target_pair) {
+			(None, None) => return Ok(keys),
+			(source_pair, target_pair) if source_pair == target_pair => keys += 1,
+			(source_pair, target_pair) =>
+				return Err(ClientError::Backend(format!(
+					"State mismatch at block {:?}: source has {:?}, target has {:?}",
+					hash,
+					source_pair.map(|(key, _)| key),
+					target_pair.map(|(key, _)| key),
+				))),
+		}
+	}
+}
+
+fn state_error<H: std::fmt::Debug, E: std::fmt::Display>(hash: H, error: E) -> ClientError {
+	ClientError::Backend(format!("Error reading state at block {:?}: {}", hash, error))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{insert_header, Block};
+	use sp_core::H256;
+
+	fn source_backend() -> Backend<Block> {
+		let backend = Backend::<Block>::new_test(4, 10);
+		let mut hash = insert_header(&backend, 0, Default::default(), None, Default::default());
+		for number in 1..12 {
+			hash = insert_header(&backend, number, hash, None, H256::from_low_u64_be(number));
+			if number <= 6 {
+				backend.finalize_block(hash, None).unwrap();
+			}
+		}
+		backend
+	}
+
+	fn target_db(path: &std::path::Path) -> Arc<dyn Database<DbHash>> {
+		crate::parity_db::open(path, DatabaseType::Full, true, false).unwrap()
+	}
+
+	/// A database that fails every commit after the first `commits`.
+	struct InterruptedDb {
+		inner: Arc<dyn Database<DbHash>>,
+		commits: std::sync::atomic::AtomicUsize,
+	}
+
+	impl Database<DbHash> for InterruptedDb {
+		fn commit(&self, transaction: Transaction<DbHash>) -> sp_database::error::Result<()> {
+			use std::sync::atomic::Ordering;
+			if self
+				.commits
+				.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |c| c.checked_sub(1))
+				.is_err()
+			{
+				return Err(sp_database::error::DatabaseError("interrupted".into()))
+			}
+			self.inner.commit(transaction)
+		}
+
+		fn get(&self, col: ColumnId, key: &[u8]) -> Option<Vec<u8>> {
+			self.inner.get(col, key)
+		}
+
+		fn supports_ref_counting(&self) -> bool {
+			self.inner.supports_ref_counting()
+		}
+	}
+
+	/// A database that appends a byte to every trie node it returns.
+	struct CorruptedDb(Arc<dyn Database<DbHash>>);
+
+	impl Database<DbHash> for CorruptedDb {
+		fn commit(&self, transaction: Transaction<DbHash>) -> sp_database::error::Result<()> {
+			self.0.commit(transaction)
+		}
+
+		fn get(&self, col: ColumnId, key: &[u8]) -> Option<Vec<u8>> {
+			let mut value = self.0.get(col, key)?;
+			if col == columns::STATE {
+				value.push(0);
+			}
+			Some(value)
+		}
+
+		fn supports_ref_counting(&self) -> bool {
+			self.0.supports_ref_counting()
+		}
+
+		fn sanitize_key(&self, key: &mut Vec<u8>) {
+			self.0.sanitize_key(key)
+		}
+	}
+
+	#[test]
+	fn migrates_to_ref_counted_database() {
+		let source = source_backend();
+		let info = source.blockchain().info();
+		let dir = tempfile::tempdir().unwrap();
+		let target = target_db(dir.path());
+
+		let columns = migrate_columns::<Block>(&*source.storage.db, &*target, 16).unwrap();
+		assert_eq!(columns.len(), NUM_COLUMNS as usize);
+		assert_eq!(columns.last().unwrap().column, COLUMN_META);
+		assert!(target.get(COLUMN_META, meta_keys::MIGRATION_PROGRESS).is_none());
+
+		let verified =
+			verify_migration::<Block>(source.storage.db.clone(), target.clone()).unwrap();
+		assert_eq!(
+			verified.iter().map(|state| state.hash).collect::<Vec<_>>(),
+			vec![info.best_hash, info.finalized_hash],
+		);
+
+		// The migrated database is usable as a backend and keeps following the chain.
+		drop(source);
+		let settings = DatabaseSettings {
+			trie_cache_maximum_size: None,
+			state_pruning: None,
+			source: DatabaseSource::Custom { db: target.clone(), require_create_flag: false },
+			blocks_pruning: BlocksPruning::Some(4),
+		};
+		let backend = Backend::<Block>::from_database(target, 10, &settings, false).unwrap();
+		let hash = insert_header(&backend, 12, info.best_hash, None, Default::default());
+		for number in 7..=12 {
+			let hash = backend.blockchain().hash(number).unwrap().unwrap();
+			backend.finalize_block(hash, None).unwrap();
+		}
+		assert!(backend.have_state_at(hash, 12));
+
+		// Pruning released no trie node that the remaining states still use.
+		for number in 9..=12 {
+			let hash = backend.blockchain().hash(number).unwrap().unwrap();
+			let state = backend.state_at(hash).unwrap();
+			let pairs = state.pairs(Default::default()).unwrap().collect::<Result<Vec<_>, _>>();
+			assert_eq!(pairs.unwrap().len(), number as usize + 1);
+		}
+	}
+
+	#[test]
+	fn detects_corrupted_state() {
+		let source = source_backend();
+		let dir = tempfile::tempdir().unwrap();
+		let target = target_db(dir.path());
+		migrate_columns::<Block>(&*source.storage.db, &*target, 16).unwrap();
+
+		let corrupted = Arc::new(CorruptedDb(target));
+		let error = verify_migration::<Block>(source.storage.db.clone(), corrupted).unwrap_err();
+		assert!(error.to_string().contains("does not match state root"));
+	}
+
+	#[test]
+	fn resumes_interrupted_migration() {
+		let source = source_backend();
+		let dir = tempfile::tempdir().unwrap();
+		let target = target_db(dir.path());
+
+		let interrupted = InterruptedDb { inner: target.clone(), commits: 3.into() };
+		assert!(migrate_columns::<Block>(&*source.storage.db, &interrupted, 4).is_err());
+		let progress = target.get(COLUMN_META, meta_keys::MIGRATION_PROGRESS).unwrap();
+		assert_ne!(Progress::decode(&mut &progress[..]).unwrap().column, COLUMN_META);
+		assert!(target.get(COLUMN_META, meta_keys::GENESIS_HASH).is_none());
+
+		migrate_columns::<Block>(&*source.storage.db, &*target, 4).unwrap();
+		verify_migration::<Block>(source.storage.db.clone(), target.clone()).unwrap();
+
+		// Once completed, the target is not migrated into a second time.
+		assert!(migrate_columns::<Block>(&*source.storage.db, &*target, 4).is_err());
+	}
+}
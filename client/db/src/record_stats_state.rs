@@ -18,7 +18,11 @@
 
 //! Provides [`RecordStatsState`] for recording stats about state access.
 
-use crate::stats::StateUsageStats;
+use crate::{
+	stats::StateUsageStats,
+	storage_profile::{StateProfile, StorageProfiler},
+};
+use parking_lot::Mutex;
 use sp_core::storage::ChildInfo;
 use sp_runtime::{
 	traits::{Block as BlockT, HashFor},
@@ -26,7 +30,8 @@ use sp_runtime::{
 };
 use sp_state_machine::{
 	backend::{AsTrieBackend, Backend as StateBackend},
-	IterArgs, StorageIterator, StorageKey, StorageValue, TrieBackend,
+	prove_child_read_on_trie_backend, prove_read_on_trie_backend, IterArgs, StorageIterator,
+	StorageKey, StorageValue, TrieBackend,
 };
 use std::sync::Arc;
 
@@ -42,6 +47,10 @@ pub struct RecordStatsState<S, B: BlockT> {
 	block_hash: Option<B::Hash>,
 	/// The usage statistics of the backend. These will be updated on drop.
 	state_usage: Arc<StateUsageStats>,
+	/// Storage accesses, recorded if profiling was enabled when the state was created.
+	profile: Mutex<StateProfile>,
+	/// The storage profiler of the backend. It will be updated on drop.
+	profiler: Option<Arc<StorageProfiler>>,
 }
 
 impl<S, B: BlockT> std::fmt::Debug for RecordStatsState<S, B> {
@@ -53,6 +62,9 @@ impl<S, B: BlockT> std::fmt::Debug for RecordStatsState<S, B> {
 impl<S, B: BlockT> Drop for RecordStatsState<S, B> {
 	fn drop(&mut self) {
 		self.state_usage.merge_sm(self.usage.take());
+		if let Some(profiler) = &self.profiler {
+			profiler.merge(&self.profile.get_mut().take());
+		}
 	}
 }
 
@@ -62,6 +74,7 @@ impl<S: StateBackend<HashFor<B>>, B: BlockT> RecordStatsState<S, B> {
 		state: S,
 		block_hash: Option<B::Hash>,
 		state_usage: Arc<StateUsageStats>,
+		profiler: &Arc<StorageProfiler>,
 	) -> Self {
 		RecordStatsState {
 			usage: StateUsageStats::new(),
@@ -69,10 +82,61 @@ impl<S: StateBackend<HashFor<B>>, B: BlockT> RecordStatsState<S, B> {
 			state,
 			block_hash,
 			state_usage,
+			profile: Default::default(),
+			profiler: profiler.is_enabled().then(|| profiler.clone()),
+		}
+	}
+}
+
+impl<S: StateBackend<HashFor<B>> + AsTrieBackend<HashFor<B>>, B: BlockT> RecordStatsState<S, B> {
+	/// Record a read of `key` from the top trie, or from the given child trie.
+	fn profile_read(&self, child_info: Option<&ChildInfo>, key: &[u8], value: Option<&Vec<u8>>) {
+		if self.profiler.is_none() {
+			return
+		}
+
+		let bytes = key.len() as u64 + value.map(|v| v.len() as u64).unwrap_or(0);
+		let child = child_prefix(child_info);
+		// The trie depth of a repeat read was already recorded with the first read.
+		let depth = if self.profile.lock().was_read(child.as_deref(), key) {
+			None
+		} else {
+			// The proof contains exactly the trie nodes on the path to the key.
+			let trie_backend = self.state.as_trie_backend();
+			let proof = match child_info {
+				Some(child_info) =>
+					prove_child_read_on_trie_backend(trie_backend, child_info, [key]),
+				None => prove_read_on_trie_backend(trie_backend, [key]),
+			};
+			proof.ok().map(|proof| proof.iter_nodes().count() as u32)
+		};
+		self.profile.lock().tally_read(child.as_deref(), key, bytes, depth);
+	}
+
+	/// Record the writes of a storage root calculation.
+	fn profile_writes<'a>(
+		&self,
+		child_info: Option<&ChildInfo>,
+		delta: impl Iterator<Item = (&'a [u8], Option<&'a [u8]>)>,
+	) -> Vec<(&'a [u8], Option<&'a [u8]>)> {
+		let delta = delta.collect::<Vec<_>>();
+		if self.profiler.is_some() {
+			let child = child_prefix(child_info);
+			let mut profile = self.profile.lock();
+			for (key, value) in &delta {
+				let bytes = key.len() as u64 + value.map(|v| v.len() as u64).unwrap_or(0);
+				profile.tally_write(child.as_deref(), key, bytes);
+			}
 		}
+		delta
 	}
 }
 
+/// The prefixed storage key of the child trie, the accesses to it are profiled under.
+fn child_prefix(child_info: Option<&ChildInfo>) -> Option<Vec<u8>> {
+	child_info.map(|child_info| child_info.prefixed_storage_key().into_inner())
+}
+
 pub struct RawIter<S, B>
 where
 	S: StateBackend<HashFor<B>>,
@@ -105,15 +169,18 @@ where
 	}
 }
 
-impl<S: StateBackend<HashFor<B>>, B: BlockT> StateBackend<HashFor<B>> for RecordStatsState<S, B> {
+impl<S: StateBackend<HashFor<B>> + AsTrieBackend<HashFor<B>>, B: BlockT> StateBackend<HashFor<B>>
+	for RecordStatsState<S, B>
+{
 	type Error = S::Error;
 	type Transaction = S::Transaction;
-	type TrieBackendStorage = S::TrieBackendStorage;
+	type TrieBackendStorage = <S as StateBackend<HashFor<B>>>::TrieBackendStorage;
 	type RawIter = RawIter<S, B>;
 
 	fn storage(&self, key: &[u8]) -> Result<Option<Vec<u8>>, Self::Error> {
 		let value = self.state.storage(key)?;
 		self.usage.tally_key_read(key, value.as_ref(), false);
+		self.profile_read(None, key, value.as_ref());
 		Ok(value)
 	}
 
@@ -128,6 +195,7 @@ impl<S: StateBackend<HashFor<B>>, B: BlockT> StateBackend<HashFor<B>> for Record
 	) -> Result<Option<Vec<u8>>, Self::Error> {
 		let key = (child_info.storage_key().to_vec(), key.to_vec());
 		let value = self.state.child_storage(child_info, &key.1)?;
+		self.profile_read(Some(child_info), &key.1, value.as_ref());
 
 		// just pass it through the usage counter
 		let value = self.usage.tally_child_key_read(&key, value, false);
@@ -175,6 +243,10 @@ impl<S: StateBackend<HashFor<B>>, B: BlockT> StateBackend<HashFor<B>> for Record
 	where
 		B::Hash: Ord,
 	{
+		if self.profiler.is_some() {
+			let delta = self.profile_writes(None, delta);
+			return self.state.storage_root(delta.into_iter(), state_version)
+		}
 		self.state.storage_root(delta, state_version)
 	}
 
@@ -187,6 +259,10 @@ impl<S: StateBackend<HashFor<B>>, B: BlockT> StateBackend<HashFor<B>> for Record
 	where
 		B::Hash: Ord,
 	{
+		if self.profiler.is_some() {
+			let delta = self.profile_writes(Some(child_info), delta);
+			return self.state.child_storage_root(child_info, delta.into_iter(), state_version)
+		}
 		self.state.child_storage_root(child_info, delta, state_version)
 	}
 
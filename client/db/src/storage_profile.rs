@@ -0,0 +1,319 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage access profiling.
+//!
+//! While profiling is enabled, every state access is attributed to the prefix of the accessed
+//! key. FRAME storage keys start with `twox128(pallet) ++ twox128(item)`, so grouping the
+//! accesses by the first [`PROFILE_PREFIX_LEN`] bytes of the key groups them by storage item.
+//! Accesses to a child trie are grouped by the prefixed storage key of the child trie, e.g.
+//! `:child_storage:default:` followed by the storage key. Resolving the prefixes to names requires
+//! the runtime metadata and is left to the user of the profile.
+
+use parking_lot::Mutex;
+use std::{
+	collections::{BTreeMap, HashMap, HashSet},
+	sync::atomic::{AtomicBool, Ordering as AtomicOrdering},
+};
+
+/// The length of the key prefix accesses are grouped by.
+pub const PROFILE_PREFIX_LEN: usize = 32;
+
+/// Storage access statistics of a single key prefix.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrefixUsage {
+	/// The number of reads.
+	pub reads: u64,
+	/// The number of reads of keys that were already read from the same state.
+	///
+	/// Whether these are served from a cache depends on the trie cache configuration, so they
+	/// are not necessarily cache hits.
+	pub repeat_reads: u64,
+	/// The number of bytes read, including the keys.
+	pub bytes_read: u64,
+	/// The number of distinct keys written, including removals.
+	pub writes: u64,
+	/// The number of bytes written, including the keys.
+	pub bytes_written: u64,
+	/// The number of distinct keys accessed.
+	pub keys: u64,
+	/// The sum of the trie depths of all reads that were not repeat reads.
+	pub trie_depth: u64,
+	/// The maximum trie depth of a read.
+	pub max_trie_depth: u32,
+}
+
+impl PrefixUsage {
+	/// The average number of trie nodes on the path to a read key.
+	pub fn average_trie_depth(&self) -> f64 {
+		let reads = self.reads - self.repeat_reads;
+		if reads == 0 {
+			0.0
+		} else {
+			self.trie_depth as f64 / reads as f64
+		}
+	}
+
+	/// Add the statistics of `other` to `self`.
+	pub fn merge(&mut self, other: &Self) {
+		self.reads += other.reads;
+		self.repeat_reads += other.repeat_reads;
+		self.bytes_read += other.bytes_read;
+		self.writes += other.writes;
+		self.bytes_written += other.bytes_written;
+		self.keys += other.keys;
+		self.trie_depth += other.trie_depth;
+		self.max_trie_depth = self.max_trie_depth.max(other.max_trie_depth);
+	}
+}
+
+/// Storage access statistics grouped by key prefix.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageProfile {
+	/// The statistics of every accessed prefix.
+	pub prefixes: BTreeMap<Vec<u8>, PrefixUsage>,
+}
+
+impl StorageProfile {
+	/// The prefix an access to `key` is attributed to.
+	///
+	/// Keys shorter than [`PROFILE_PREFIX_LEN`], like the well known keys, are their own prefix.
+	pub fn prefix_of(key: &[u8]) -> &[u8] {
+		&key[..key.len().min(PROFILE_PREFIX_LEN)]
+	}
+
+	/// Add the statistics of `other` to `self`.
+	pub fn merge(&mut self, other: &Self) {
+		for (prefix, usage) in &other.prefixes {
+			self.prefixes.entry(prefix.clone()).or_default().merge(usage);
+		}
+	}
+
+	/// Returns `true` if no access was recorded.
+	pub fn is_empty(&self) -> bool {
+		self.prefixes.is_empty()
+	}
+
+	/// The usage of the prefix an access to `key` in the top trie, or in the child trie with the
+	/// given prefixed storage key, is attributed to.
+	fn usage(&mut self, child: Option<&[u8]>, key: &[u8]) -> &mut PrefixUsage {
+		let prefix = child.unwrap_or_else(|| Self::prefix_of(key));
+		if !self.prefixes.contains_key(prefix) {
+			self.prefixes.insert(prefix.to_vec(), Default::default());
+		}
+		self.prefixes.get_mut(prefix).expect("inserted above; qed")
+	}
+}
+
+/// The storage accesses of a single state instance.
+///
+/// Keys in child tries are identified by the prefixed storage key of the child trie followed by
+/// the key.
+#[derive(Default)]
+pub(crate) struct StateProfile {
+	profile: StorageProfile,
+	read: HashSet<Vec<u8>>,
+	accessed: HashSet<Vec<u8>>,
+	/// The number of bytes tallied for every written key.
+	written: HashMap<Vec<u8>, u64>,
+}
+
+impl StateProfile {
+	/// Returns `true` if `key` was already read from the top trie, or from the child trie with
+	/// the given prefixed storage key.
+	pub fn was_read(&self, child: Option<&[u8]>, key: &[u8]) -> bool {
+		self.read.contains(&access_key(child, key))
+	}
+
+	/// Tally one read of `key` from the top trie, or from the child trie with the given prefixed
+	/// storage key.
+	pub fn tally_read(&mut self, child: Option<&[u8]>, key: &[u8], bytes: u64, depth: Option<u32>) {
+		let access_key = access_key(child, key);
+		let first_access = self.accessed.insert(access_key.clone());
+		let repeat_read = !self.read.insert(access_key);
+		let usage = self.profile.usage(child, key);
+
+		usage.reads += 1;
+		usage.bytes_read += bytes;
+		usage.keys += first_access as u64;
+		if repeat_read {
+			usage.repeat_reads += 1;
+		}
+		if let Some(depth) = depth {
+			usage.trie_depth += depth as u64;
+			usage.max_trie_depth = usage.max_trie_depth.max(depth);
+		}
+	}
+
+	/// Tally a write of `key` to the top trie, or to the child trie with the given prefixed
+	/// storage key.
+	///
+	/// Every storage root calculation of a state includes all changes made so far, so a key that
+	/// is written again is only counted once, with the size of its last value.
+	pub fn tally_write(&mut self, child: Option<&[u8]>, key: &[u8], bytes: u64) {
+		let access_key = access_key(child, key);
+		let first_access = self.accessed.insert(access_key.clone());
+		let previous = self.written.insert(access_key, bytes);
+		let usage = self.profile.usage(child, key);
+
+		match previous {
+			Some(previous) => usage.bytes_written = usage.bytes_written - previous + bytes,
+			None => {
+				usage.writes += 1;
+				usage.bytes_written += bytes;
+			},
+		}
+		usage.keys += first_access as u64;
+	}
+
+	/// Take the recorded profile.
+	pub fn take(&mut self) -> StorageProfile {
+		self.read.clear();
+		self.accessed.clear();
+		self.written.clear();
+		std::mem::take(&mut self.profile)
+	}
+}
+
+/// The key an access is tracked under, see [`StateProfile`].
+fn access_key(child: Option<&[u8]>, key: &[u8]) -> Vec<u8> {
+	match child {
+		Some(child) => [child, key].concat(),
+		None => key.to_vec(),
+	}
+}
+
+/// Collects the storage profiles of all states of a backend.
+///
+/// Profiling is disabled by default. It slows down state access considerably, because the trie
+/// depth of every read key is determined by recording a read proof for it.
+#[derive(Default)]
+pub struct StorageProfiler {
+	enabled: AtomicBool,
+	profile: Mutex<StorageProfile>,
+}
+
+impl StorageProfiler {
+	/// Start profiling the states created from now on.
+	pub fn enable(&self) {
+		self.enabled.store(true, AtomicOrdering::Relaxed);
+	}
+
+	/// Stop profiling the states created from now on.
+	pub fn disable(&self) {
+		self.enabled.store(false, AtomicOrdering::Relaxed);
+	}
+
+	/// Returns `true` if profiling is enabled.
+	pub fn is_enabled(&self) -> bool {
+		self.enabled.load(AtomicOrdering::Relaxed)
+	}
+
+	/// Take the profile of all states dropped since the last call.
+	pub fn take(&self) -> StorageProfile {
+		std::mem::take(&mut *self.profile.lock())
+	}
+
+	/// Add the profile of a dropped state.
+	pub(crate) fn merge(&self, profile: &StorageProfile) {
+		self.profile.lock().merge(profile);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn accesses_are_grouped_by_prefix() {
+		let item = [1u8; PROFILE_PREFIX_LEN];
+		let key = |suffix: u8| {
+			let mut key = item.to_vec();
+			key.push(suffix);
+			key
+		};
+
+		let child = |name: &[u8]| [&b":child_storage:default:"[..], name].concat();
+
+		let mut state = StateProfile::default();
+		state.tally_read(None, &key(1), 10, Some(3));
+		assert!(state.was_read(None, &key(1)));
+		state.tally_read(None, &key(1), 10, None);
+		state.tally_read(None, &key(2), 20, Some(5));
+		state.tally_write(None, &key(2), 7);
+		state.tally_write(None, &key(3), 8);
+		state.tally_read(None, b":code", 100, Some(1));
+		state.tally_read(Some(&child(b"first")), b"child_key", 4, Some(6));
+		state.tally_read(Some(&child(b"second")), b"child_key", 4, Some(2));
+		assert!(!state.was_read(None, b"child_key"));
+
+		let profile = state.take();
+		assert_eq!(profile.prefixes.len(), 4);
+		assert_eq!(
+			profile.prefixes[&item[..]],
+			PrefixUsage {
+				reads: 3,
+				repeat_reads: 1,
+				bytes_read: 40,
+				writes: 2,
+				bytes_written: 15,
+				keys: 3,
+				trie_depth: 8,
+				max_trie_depth: 5,
+			}
+		);
+		assert_eq!(profile.prefixes[&item[..]].average_trie_depth(), 4.0);
+		assert_eq!(profile.prefixes[&b":code"[..]].reads, 1);
+		assert_eq!(profile.prefixes[&child(b"first")].max_trie_depth, 6);
+		assert_eq!(profile.prefixes[&child(b"second")].repeat_reads, 0);
+		assert!(state.take().is_empty());
+	}
+
+	#[test]
+	fn repeated_storage_root_writes_are_counted_once() {
+		let mut state = StateProfile::default();
+		state.tally_write(None, b":code", 10);
+		state.tally_write(None, b":code", 10);
+		state.tally_write(None, b":code", 15);
+
+		let profile = state.take();
+		let usage = &profile.prefixes[&b":code"[..]];
+		assert_eq!(usage.writes, 1);
+		assert_eq!(usage.bytes_written, 15);
+		assert_eq!(usage.keys, 1);
+	}
+
+	#[test]
+	fn profiler_merges_state_profiles() {
+		let profiler = StorageProfiler::default();
+		assert!(!profiler.is_enabled());
+		profiler.enable();
+		assert!(profiler.is_enabled());
+
+		let mut state = StateProfile::default();
+		state.tally_read(None, b":code", 100, Some(1));
+		let profile = state.take();
+		profiler.merge(&profile);
+		profiler.merge(&profile);
+
+		let merged = profiler.take();
+		assert_eq!(merged.prefixes[&b":code"[..]].reads, 2);
+		assert_eq!(merged.prefixes[&b":code"[..]].keys, 2);
+		assert!(profiler.take().is_empty());
+	}
+}
@@ -56,6 +56,8 @@ pub mod meta_keys {
 	pub const LEAF_PREFIX: &[u8; 4] = b"leaf";
 	/// Children prefix list key.
 	pub const CHILDREN_PREFIX: &[u8; 8] = b"children";
+	/// Progress of an unfinished database migration.
+	pub const MIGRATION_PROGRESS: &[u8; 9] = b"migration";
 }
 
 /// Database metadata.
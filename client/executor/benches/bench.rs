@@ -72,6 +72,7 @@ fn initialize(
 					deterministic_stack_limit: None,
 					canonicalize_nans: false,
 					parallel_compilation: true,
+					max_memory_pages: None,
 				},
 			};
 
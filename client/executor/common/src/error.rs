@@ -104,6 +104,27 @@ pub enum Error {
 
 	#[error("Execution aborted due to trap: {0}")]
 	AbortedDueToTrap(MessageWithBacktrace),
+
+	/// The execution exceeded the deterministic stack limit.
+	///
+	/// Overflowing the native stack of the host is reported as
+	/// [`AbortedDueToTrap`](Self::AbortedDueToTrap) instead.
+	#[error("Execution exhausted the stack")]
+	StackExhausted,
+
+	#[error("Execution exhausted the linear memory")]
+	MemoryExhausted,
+}
+
+impl Error {
+	/// Whether the execution aborted because it ran out of stack or linear memory.
+	///
+	/// With deterministic limits configured, these errors are reached at the same point by every
+	/// node executing the same code, so they must be treated like any other runtime trap: the
+	/// offending extrinsic or block is invalid.
+	pub fn is_resource_exhausted(&self) -> bool {
+		matches!(self, Error::StackExhausted | Error::MemoryExhausted)
+	}
 }
 
 impl wasmi::HostError for Error {}
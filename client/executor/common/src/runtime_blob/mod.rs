@@ -55,4 +55,4 @@ mod runtime_blob;
 
 pub use data_segments_snapshot::DataSegmentsSnapshot;
 pub use globals_snapshot::{ExposedMutableGlobalsSet, GlobalsSnapshot, InstanceGlobals};
-pub use runtime_blob::RuntimeBlob;
+pub use runtime_blob::{RuntimeBlob, STACK_HEIGHT_GLOBAL_EXPORT};
@@ -25,6 +25,10 @@ use wasm_instrument::{
 	},
 };
 
+/// The name under which the counter introduced by
+/// [`RuntimeBlob::inject_stack_depth_metering`] is exported.
+pub const STACK_HEIGHT_GLOBAL_EXPORT: &str = "__substrate_stack_height";
+
 /// A bunch of information collected from a WebAssembly module.
 #[derive(Clone)]
 pub struct RuntimeBlob {
@@ -85,13 +89,25 @@ impl RuntimeBlob {
 	///
 	/// The stack cost of a function is computed based on how much locals there are and the maximum
 	/// depth of the wasm operand stack.
+	///
+	/// The counter is exported as [`STACK_HEIGHT_GLOBAL_EXPORT`]. Since it isn't decreased when
+	/// the execution traps, a counter above `stack_depth_limit` after a trap tells that the trap
+	/// was caused by exhausting the stack.
 	pub fn inject_stack_depth_metering(self, stack_depth_limit: u32) -> Result<Self, WasmError> {
+		let globals_before = self.imported_globals_count() + self.declared_globals_count();
 		let injected_module =
 			wasm_instrument::inject_stack_limiter(self.raw_module, stack_depth_limit).map_err(
 				|e| WasmError::Other(format!("cannot inject the stack limiter: {:?}", e)),
 			)?;
+		let mut blob = Self { raw_module: injected_module };
+
+		// The stack limiter appends exactly one global, the stack height counter.
+		if blob.imported_globals_count() + blob.declared_globals_count() != globals_before + 1 {
+			return Err(WasmError::Other("the stack limiter didn't introduce a counter".into()))
+		}
+		blob.export(STACK_HEIGHT_GLOBAL_EXPORT, Internal::Global(globals_before));
 
-		Ok(Self { raw_module: injected_module })
+		Ok(blob)
 	}
 
 	/// Perform an instrumentation that makes sure that a specific function `entry_point` is
@@ -138,18 +154,7 @@ impl RuntimeBlob {
 				))
 				})?;
 
-			if self.raw_module.export_section_mut().is_none() {
-				// A module without an export section is somewhat unrealistic, but let's do this
-				// just in case to cover all of our bases.
-				self.raw_module
-					.insert_section(Section::Export(Default::default()))
-					.expect("an export section can be always inserted if it doesn't exist; qed");
-			}
-			self.raw_module
-				.export_section_mut()
-				.expect("export section already existed or we just added it above, so it always exists; qed")
-				.entries_mut()
-				.push(ExportEntry::new(memory_name, Internal::Memory(0)));
+			self.export(memory_name, Internal::Memory(0));
 
 			break
 		}
@@ -157,6 +162,47 @@ impl RuntimeBlob {
 		Ok(())
 	}
 
+	/// Add an export entry, creating the export section if necessary.
+	fn export(&mut self, name: impl Into<String>, internal: Internal) {
+		if self.raw_module.export_section_mut().is_none() {
+			// A module without an export section is somewhat unrealistic, but let's do this
+			// just in case to cover all of our bases.
+			self.raw_module
+				.insert_section(Section::Export(Default::default()))
+				.expect("an export section can be always inserted if it doesn't exist; qed");
+		}
+		self.raw_module
+			.export_section_mut()
+			.expect("export section already existed or we just added it above, so it always exists; qed")
+			.entries_mut()
+			.push(ExportEntry::new(name.into(), internal));
+	}
+
+	/// Caps the maximum size of the blob's linear memory to `max_pages`.
+	///
+	/// Should be called after [`Self::setup_memory_according_to_heap_alloc_strategy`]. Returns an
+	/// error if the memory initially requires more than `max_pages`.
+	pub fn limit_memory_pages(&mut self, max_pages: u32) -> Result<(), WasmError> {
+		let memory_section = self
+			.raw_module
+			.memory_section_mut()
+			.ok_or_else(|| WasmError::Other("no memory section found".into()))?;
+
+		for memory_ty in memory_section.entries_mut() {
+			let initial = memory_ty.limits().initial();
+			if initial > max_pages {
+				return Err(WasmError::Other(format!(
+					"the memory requires {} pages, but at most {} pages are allowed",
+					initial, max_pages,
+				)))
+			}
+
+			let maximum = memory_ty.limits().maximum().map_or(max_pages, |m| m.min(max_pages));
+			*memory_ty = MemoryType::new(initial, Some(maximum));
+		}
+		Ok(())
+	}
+
 	/// Modifies the blob's memory section according to the given `heap_alloc_strategy`.
 	///
 	/// Will return an error in case there is no memory section present,
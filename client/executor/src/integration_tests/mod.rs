@@ -465,17 +465,7 @@ fn should_trap_when_heap_exhausted(wasm_method: WasmExecutionMethod) {
 		.unwrap_err();
 
 	match err {
-		Error::AbortedDueToTrap(error)
-			if matches!(wasm_method, WasmExecutionMethod::Compiled { .. }) =>
-		{
-			assert_eq!(
-				error.message,
-				r#"host code panicked while being called by the runtime: Failed to allocate memory: "Allocator ran out of space""#
-			);
-		},
-		Error::RuntimePanicked(error) if wasm_method == WasmExecutionMethod::Interpreted => {
-			assert_eq!(error, r#"Failed to allocate memory: "Allocator ran out of space""#);
-		},
+		Error::MemoryExhausted => {},
 		error => panic!("unexpected error: {:?}", error),
 	}
 }
@@ -490,6 +480,7 @@ fn mk_test_runtime(
 	crate::wasm_runtime::create_wasm_runtime_with_code::<HostFunctions>(
 		wasm_method,
 		pages,
+		None,
 		blob,
 		true,
 		None,
@@ -737,6 +728,7 @@ fn memory_is_cleared_between_invocations(wasm_method: WasmExecutionMethod) {
 	let runtime = crate::wasm_runtime::create_wasm_runtime_with_code::<HostFunctions>(
 		wasm_method,
 		HeapAllocStrategy::Dynamic { maximum_pages: Some(1024) },
+		None,
 		RuntimeBlob::uncompress_if_needed(&binary[..]).unwrap(),
 		true,
 		None,
@@ -46,11 +46,13 @@ pub use sp_core::traits::Externalities;
 pub use sp_version::{NativeVersion, RuntimeVersion};
 #[doc(hidden)]
 pub use sp_wasm_interface;
-pub use wasm_runtime::{read_embedded_version, WasmExecutionMethod};
+pub use wasm_runtime::{precompile_runtime, read_embedded_version, WasmExecutionMethod};
 pub use wasmi;
 
-pub use sc_executor_common::error;
-pub use sc_executor_wasmtime::InstantiationStrategy as WasmtimeInstantiationStrategy;
+pub use sc_executor_common::{error, wasm_runtime::HeapAllocStrategy};
+pub use sc_executor_wasmtime::{
+	ArtifactCache as WasmtimeArtifactCache, InstantiationStrategy as WasmtimeInstantiationStrategy,
+};
 
 /// Extracts the runtime version of a given runtime code.
 pub trait RuntimeVersionOf {
@@ -62,6 +64,15 @@ pub trait RuntimeVersionOf {
 	) -> error::Result<RuntimeVersion>;
 }
 
+/// Compiles runtimes ahead of time.
+pub trait PrecompileRuntime {
+	/// Compile the given `runtime_code` for on-chain execution before it is called the first time.
+	///
+	/// This is a no-op unless the executor uses the compiled execution method and was configured
+	/// with an artifact path.
+	fn precompile_runtime(&self, runtime_code: &sp_core::traits::RuntimeCode) -> error::Result<()>;
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -19,7 +19,7 @@
 use crate::{
 	error::{Error, Result},
 	wasm_runtime::{RuntimeCache, WasmExecutionMethod},
-	RuntimeVersionOf,
+	PrecompileRuntime, RuntimeVersionOf,
 };
 
 use std::{
@@ -92,6 +92,8 @@ pub struct WasmExecutorBuilder<H> {
 	offchain_heap_alloc_strategy: Option<HeapAllocStrategy>,
 	max_runtime_instances: usize,
 	cache_path: Option<PathBuf>,
+	artifact_path: Option<PathBuf>,
+	max_memory_pages: Option<u32>,
 	allow_missing_host_functions: bool,
 	runtime_cache_size: u8,
 }
@@ -110,6 +112,8 @@ impl<H> WasmExecutorBuilder<H> {
 			runtime_cache_size: 4,
 			allow_missing_host_functions: false,
 			cache_path: None,
+			artifact_path: None,
+			max_memory_pages: None,
 		}
 	}
 
@@ -156,6 +160,30 @@ impl<H> WasmExecutorBuilder<H> {
 		self
 	}
 
+	/// Create the wasm executor with the given `artifact_path`.
+	///
+	/// The `artifact_path` is a directory of ahead-of-time compiled runtimes. When the compiled
+	/// execution method is used, runtimes are loaded from there instead of being compiled and
+	/// runtimes that are not found there yet are compiled into it. See [`PrecompileRuntime`].
+	///
+	/// By default there is no `artifact_path` given.
+	pub fn with_artifact_path(mut self, artifact_path: impl Into<PathBuf>) -> Self {
+		self.artifact_path = Some(artifact_path.into());
+		self
+	}
+
+	/// Create the wasm executor with the given `max_memory_pages`.
+	///
+	/// The linear memory of runtimes executed with the compiled execution method never grows
+	/// beyond `max_memory_pages`, independently of the heap allocation strategy. Allocations that
+	/// would need more memory fail with [`Error::MemoryExhausted`].
+	///
+	/// By default the memory is not limited.
+	pub fn with_max_memory_pages(mut self, max_memory_pages: u32) -> Self {
+		self.max_memory_pages = Some(max_memory_pages);
+		self
+	}
+
 	/// Create the wasm executor and allow/forbid missing host functions.
 	///
 	/// If missing host functions are forbidden, the instantiation of a wasm blob will fail
@@ -189,12 +217,19 @@ impl<H> WasmExecutorBuilder<H> {
 			default_onchain_heap_alloc_strategy: unwrap_heap_pages(
 				self.onchain_heap_alloc_strategy,
 			),
-			cache: Arc::new(RuntimeCache::new(
-				self.max_runtime_instances,
-				self.cache_path.clone(),
-				self.runtime_cache_size,
-			)),
+			cache: Arc::new(
+				RuntimeCache::new(
+					self.max_runtime_instances,
+					self.cache_path.clone(),
+					self.runtime_cache_size,
+				)
+				.with_artifact_cache(
+					self.artifact_path.map(sc_executor_wasmtime::ArtifactCache::new),
+				)
+				.with_max_memory_pages(self.max_memory_pages),
+			),
 			cache_path: self.cache_path,
+			max_memory_pages: self.max_memory_pages,
 			allow_missing_host_functions: self.allow_missing_host_functions,
 			phantom: PhantomData,
 		}
@@ -215,6 +250,8 @@ pub struct WasmExecutor<H> {
 	/// The path to a directory which the executor can leverage for a file cache, e.g. put there
 	/// compiled artifacts.
 	cache_path: Option<PathBuf>,
+	/// The maximum number of pages the linear memory of compiled runtimes may have.
+	max_memory_pages: Option<u32>,
 	/// Ignore missing function imports.
 	allow_missing_host_functions: bool,
 	phantom: PhantomData<H>,
@@ -228,6 +265,7 @@ impl<H> Clone for WasmExecutor<H> {
 			default_offchain_heap_alloc_strategy: self.default_offchain_heap_alloc_strategy,
 			cache: self.cache.clone(),
 			cache_path: self.cache_path.clone(),
+			max_memory_pages: self.max_memory_pages,
 			allow_missing_host_functions: self.allow_missing_host_functions,
 			phantom: self.phantom,
 		}
@@ -277,6 +315,7 @@ where
 				runtime_cache_size,
 			)),
 			cache_path,
+			max_memory_pages: None,
 			allow_missing_host_functions: false,
 			phantom: PhantomData,
 		}
@@ -398,6 +437,7 @@ where
 		let module = crate::wasm_runtime::create_wasm_runtime_with_code::<H>(
 			self.method,
 			self.default_onchain_heap_alloc_strategy,
+			self.max_memory_pages,
 			runtime_blob,
 			allow_missing_host_functions,
 			self.cache_path.as_deref(),
@@ -503,6 +543,22 @@ where
 	}
 }
 
+impl<H> PrecompileRuntime for WasmExecutor<H>
+where
+	H: HostFunctions,
+{
+	fn precompile_runtime(&self, runtime_code: &RuntimeCode) -> Result<()> {
+		let on_chain_heap_alloc_strategy = runtime_code
+			.heap_pages
+			.map(|h| HeapAllocStrategy::Static { extra_pages: h as _ })
+			.unwrap_or_else(|| self.default_onchain_heap_alloc_strategy);
+
+		self.cache
+			.precompile(runtime_code, self.method, on_chain_heap_alloc_strategy)
+			.map_err(Into::into)
+	}
+}
+
 impl<H> RuntimeVersionOf for WasmExecutor<H>
 where
 	H: HostFunctions,
@@ -595,6 +651,12 @@ impl<D: NativeExecutionDispatch> RuntimeVersionOf for NativeElseWasmExecutor<D>
 	}
 }
 
+impl<D: NativeExecutionDispatch> PrecompileRuntime for NativeElseWasmExecutor<D> {
+	fn precompile_runtime(&self, runtime_code: &RuntimeCode) -> Result<()> {
+		self.wasm.precompile_runtime(runtime_code)
+	}
+}
+
 impl<D: NativeExecutionDispatch> GetNativeVersion for NativeElseWasmExecutor<D> {
 	fn native_version(&self) -> &NativeVersion {
 		&self.native_version
@@ -38,6 +38,7 @@ use std::{
 	sync::Arc,
 };
 
+use sc_executor_wasmtime::ArtifactCache;
 use sp_wasm_interface::HostFunctions;
 
 /// Specification of different methods of executing the runtime Wasm code.
@@ -167,6 +168,10 @@ pub struct RuntimeCache {
 	/// The size of the instances cache for each runtime.
 	max_runtime_instances: usize,
 	cache_path: Option<PathBuf>,
+	/// Directory of ahead-of-time compiled runtimes used by the compiled execution method.
+	artifact_cache: Option<ArtifactCache>,
+	/// The maximum number of pages the linear memory of the compiled runtimes may have.
+	max_memory_pages: Option<u32>,
 }
 
 impl RuntimeCache {
@@ -187,7 +192,59 @@ impl RuntimeCache {
 	) -> RuntimeCache {
 		let cap =
 			NonZeroUsize::new(runtime_cache_size.max(1) as usize).expect("cache size is not zero");
-		RuntimeCache { runtimes: Mutex::new(LruCache::new(cap)), max_runtime_instances, cache_path }
+		RuntimeCache {
+			runtimes: Mutex::new(LruCache::new(cap)),
+			max_runtime_instances,
+			cache_path,
+			artifact_cache: None,
+			max_memory_pages: None,
+		}
+	}
+
+	/// Load and store the runtimes compiled with [`WasmExecutionMethod::Compiled`] as
+	/// precompiled artifacts in the given `artifact_cache`.
+	pub fn with_artifact_cache(mut self, artifact_cache: Option<ArtifactCache>) -> Self {
+		self.artifact_cache = artifact_cache;
+		self
+	}
+
+	/// Limit the linear memory of the runtimes compiled with [`WasmExecutionMethod::Compiled`] to
+	/// `max_memory_pages`.
+	pub fn with_max_memory_pages(mut self, max_memory_pages: Option<u32>) -> Self {
+		self.max_memory_pages = max_memory_pages;
+		self
+	}
+
+	/// Compile the given runtime ahead of time into the artifact cache.
+	///
+	/// Does nothing if there is no artifact cache or the runtime isn't executed by
+	/// [`WasmExecutionMethod::Compiled`] with a strategy that supports precompiled runtimes.
+	pub fn precompile(
+		&self,
+		runtime_code: &RuntimeCode,
+		wasm_method: WasmExecutionMethod,
+		heap_alloc_strategy: HeapAllocStrategy,
+	) -> Result<(), WasmError> {
+		let Some(artifact_cache) = self.artifact_cache.as_ref() else { return Ok(()) };
+		match wasm_method {
+			WasmExecutionMethod::Compiled {
+				instantiation_strategy:
+					sc_executor_wasmtime::InstantiationStrategy::LegacyInstanceReuse,
+			} |
+			WasmExecutionMethod::Interpreted => return Ok(()),
+			WasmExecutionMethod::Compiled { .. } => {},
+		}
+
+		let code = runtime_code.fetch_runtime_code().ok_or(WasmError::CodeNotFound)?;
+		precompile_runtime(
+			&code,
+			&runtime_code.hash,
+			wasm_method,
+			heap_alloc_strategy,
+			self.max_memory_pages,
+			artifact_cache,
+		)
+		.map(drop)
 	}
 
 	/// Prepares a WASM module instance and executes given function for it.
@@ -256,6 +313,9 @@ impl RuntimeCache {
 				allow_missing_func_imports,
 				self.max_runtime_instances,
 				self.cache_path.as_deref(),
+				code_hash,
+				self.artifact_cache.as_ref(),
+				self.max_memory_pages,
 			);
 
 			match result {
@@ -288,9 +348,12 @@ impl RuntimeCache {
 }
 
 /// Create a wasm runtime with the given `code`.
+///
+/// `max_memory_pages` is only enforced by [`WasmExecutionMethod::Compiled`].
 pub fn create_wasm_runtime_with_code<H>(
 	wasm_method: WasmExecutionMethod,
 	heap_alloc_strategy: HeapAllocStrategy,
+	max_memory_pages: Option<u32>,
 	blob: RuntimeBlob,
 	allow_missing_func_imports: bool,
 	cache_path: Option<&Path>,
@@ -320,19 +383,65 @@ where
 				sc_executor_wasmtime::Config {
 					allow_missing_func_imports,
 					cache_path: cache_path.map(ToOwned::to_owned),
-					semantics: sc_executor_wasmtime::Semantics {
-						heap_alloc_strategy,
+					semantics: wasmtime_semantics(
 						instantiation_strategy,
-						deterministic_stack_limit: None,
-						canonicalize_nans: false,
-						parallel_compilation: true,
-					},
+						heap_alloc_strategy,
+						max_memory_pages,
+					),
 				},
 			)
 			.map(|runtime| -> Arc<dyn WasmModule> { Arc::new(runtime) }),
 	}
 }
 
+/// Compile the given runtime `code` ahead of time and store it in the `artifact_cache`.
+///
+/// The artifact is keyed by `code_hash`, which is expected to be the storage hash of `:code` as
+/// found in [`RuntimeCode::hash`]. Returns the path of the artifact.
+pub fn precompile_runtime(
+	code: &[u8],
+	code_hash: &[u8],
+	wasm_method: WasmExecutionMethod,
+	heap_alloc_strategy: HeapAllocStrategy,
+	max_memory_pages: Option<u32>,
+	artifact_cache: &ArtifactCache,
+) -> Result<PathBuf, WasmError> {
+	let instantiation_strategy = match wasm_method {
+		WasmExecutionMethod::Compiled { instantiation_strategy }
+			if instantiation_strategy !=
+				sc_executor_wasmtime::InstantiationStrategy::LegacyInstanceReuse =>
+			instantiation_strategy,
+		_ =>
+			return Err(WasmError::Other(
+				"precompiled runtimes require the compiled execution method without legacy \
+				 instance reuse"
+					.into(),
+			)),
+	};
+
+	let blob = RuntimeBlob::uncompress_if_needed(code)?;
+	artifact_cache.precompile(
+		code_hash,
+		blob,
+		&wasmtime_semantics(instantiation_strategy, heap_alloc_strategy, max_memory_pages),
+	)
+}
+
+fn wasmtime_semantics(
+	instantiation_strategy: sc_executor_wasmtime::InstantiationStrategy,
+	heap_alloc_strategy: HeapAllocStrategy,
+	max_memory_pages: Option<u32>,
+) -> sc_executor_wasmtime::Semantics {
+	sc_executor_wasmtime::Semantics {
+		heap_alloc_strategy,
+		instantiation_strategy,
+		deterministic_stack_limit: None,
+		canonicalize_nans: false,
+		parallel_compilation: true,
+		max_memory_pages,
+	}
+}
+
 fn decode_version(mut version: &[u8]) -> Result<RuntimeVersion, WasmError> {
 	Decode::decode(&mut version).map_err(|_| {
 		WasmError::Instantiation(
@@ -397,6 +506,9 @@ fn create_versioned_wasm_runtime<H>(
 	allow_missing_func_imports: bool,
 	max_instances: usize,
 	cache_path: Option<&Path>,
+	code_hash: &[u8],
+	artifact_cache: Option<&ArtifactCache>,
+	max_memory_pages: Option<u32>,
 ) -> Result<VersionedRuntime, WasmError>
 where
 	H: HostFunctions,
@@ -410,13 +522,32 @@ where
 	// runtime.
 	let mut version = read_embedded_version(&blob)?;
 
-	let runtime = create_wasm_runtime_with_code::<H>(
-		wasm_method,
-		heap_alloc_strategy,
-		blob,
-		allow_missing_func_imports,
-		cache_path,
-	)?;
+	let runtime = match (wasm_method, artifact_cache) {
+		(WasmExecutionMethod::Compiled { instantiation_strategy }, Some(artifact_cache)) =>
+			sc_executor_wasmtime::create_runtime_with_artifact_cache::<H>(
+				artifact_cache,
+				code_hash,
+				blob,
+				sc_executor_wasmtime::Config {
+					allow_missing_func_imports,
+					cache_path: cache_path.map(ToOwned::to_owned),
+					semantics: wasmtime_semantics(
+						instantiation_strategy,
+						heap_alloc_strategy,
+						max_memory_pages,
+					),
+				},
+			)
+			.map(|runtime| -> Arc<dyn WasmModule> { Arc::new(runtime) })?,
+		_ => create_wasm_runtime_with_code::<H>(
+			wasm_method,
+			heap_alloc_strategy,
+			max_memory_pages,
+			blob,
+			allow_missing_func_imports,
+			cache_path,
+		)?,
+	};
 
 	// If the runtime blob doesn't embed the runtime version then use the legacy version query
 	// mechanism: call the runtime.
@@ -79,6 +79,8 @@ struct FunctionExecutor {
 	allow_missing_func_imports: bool,
 	missing_functions: Arc<Vec<String>>,
 	panic_message: Option<String>,
+	/// Set when an allocation failed because the linear memory is exhausted.
+	memory_exhausted: bool,
 }
 
 impl FunctionExecutor {
@@ -96,6 +98,7 @@ impl FunctionExecutor {
 			allow_missing_func_imports,
 			missing_functions,
 			panic_message: None,
+			memory_exhausted: false,
 		})
 	}
 }
@@ -113,7 +116,12 @@ impl FunctionContext for FunctionExecutor {
 		self.heap
 			.borrow_mut()
 			.allocate(&mut MemoryWrapper(&self.memory), size)
-			.map_err(|e| e.to_string())
+			.map_err(|e| {
+				if e.is_memory_exhausted() {
+					self.memory_exhausted = true;
+				}
+				e.to_string()
+			})
 	}
 
 	fn deallocate_memory(&mut self, ptr: Pointer<u8>) -> WResult<()> {
@@ -285,7 +293,11 @@ fn call_in_wasm_module(
 	function_executor.write_memory(offset, data)?;
 
 	fn convert_trap(executor: &mut FunctionExecutor, trap: wasmi::Trap) -> Error {
-		if let Some(message) = executor.panic_message.take() {
+		if executor.memory_exhausted {
+			Error::MemoryExhausted
+		} else if matches!(trap, wasmi::Trap::Code(wasmi::TrapCode::StackOverflow)) {
+			Error::StackExhausted
+		} else if let Some(message) = executor.panic_message.take() {
 			Error::AbortedDueToPanic(MessageWithBacktrace { message, backtrace: None })
 		} else {
 			Error::AbortedDueToTrap(MessageWithBacktrace {
@@ -0,0 +1,300 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Ahead-of-time compiled runtime artifacts.
+//!
+//! An [`ArtifactCache`] is a directory of runtimes compiled by [`prepare_runtime_artifact`]. Each
+//! artifact is keyed by the hash of the runtime code and a fingerprint of the [`Semantics`] it was
+//! compiled with, so an artifact is never loaded with a configuration it wasn't compiled for.
+//!
+//! Next to every artifact a checksum file holding its size and hash is written. The first load of
+//! an artifact by an [`ArtifactCache`] verifies the hash, later loads only compare the size.
+//! Artifacts that are truncated or were modified on disk are removed and compiled again.
+
+use crate::runtime::{
+	create_runtime, create_runtime_from_artifact, hash_artifact_engine, prepare_runtime_artifact,
+	Config, InstantiationStrategy, Semantics, WasmtimeRuntime,
+};
+
+use sc_executor_common::{error::WasmError, runtime_blob::RuntimeBlob};
+use sp_core_hashing::{blake2_256, blake2_64};
+use sp_wasm_interface::HostFunctions;
+use std::{
+	collections::HashSet,
+	fs,
+	hash::Hasher,
+	path::{Path, PathBuf},
+	sync::{Arc, Mutex},
+};
+
+/// The version of the artifact layout.
+///
+/// Must be bumped whenever the way artifacts are produced changes in a way that is not covered by
+/// the [`Semantics`] fingerprint.
+pub const ARTIFACT_VERSION: u32 = 2;
+
+const ARTIFACT_EXTENSION: &str = "cwasm";
+const CHECKSUM_EXTENSION: &str = "checksum";
+
+/// A directory containing precompiled runtime artifacts.
+#[derive(Clone, Debug)]
+pub struct ArtifactCache {
+	dir: PathBuf,
+	/// The artifacts whose hash was already verified.
+	verified: Arc<Mutex<HashSet<PathBuf>>>,
+}
+
+impl ArtifactCache {
+	/// Create a new artifact cache at the given directory.
+	///
+	/// The directory is created lazily when the first artifact is written.
+	pub fn new(dir: impl Into<PathBuf>) -> Self {
+		Self { dir: dir.into(), verified: Default::default() }
+	}
+
+	/// The directory the artifacts are stored in.
+	pub fn dir(&self) -> &Path {
+		&self.dir
+	}
+
+	/// The path of the artifact for the runtime with the given `code_hash` compiled with the
+	/// given `semantics`.
+	pub fn artifact_path(
+		&self,
+		code_hash: &[u8],
+		semantics: &Semantics,
+	) -> Result<PathBuf, WasmError> {
+		Ok(self.dir.join(format!(
+			"v{}-{}-{}.{}",
+			ARTIFACT_VERSION,
+			array_bytes::bytes2hex("", code_hash),
+			array_bytes::bytes2hex("", &semantics_fingerprint(semantics)?[..8]),
+			ARTIFACT_EXTENSION,
+		)))
+	}
+
+	/// Returns the path of the artifact for the given runtime if it exists and its checksum
+	/// matches.
+	///
+	/// The hash of the artifact is only verified the first time it is loaded, afterwards only
+	/// its size is compared. An artifact with a missing or mismatching checksum is removed.
+	pub fn load(&self, code_hash: &[u8], semantics: &Semantics) -> Option<PathBuf> {
+		let path = match self.artifact_path(code_hash, semantics) {
+			Ok(path) => path,
+			Err(e) => {
+				log::debug!(target: "wasm-runtime", "Cannot locate runtime artifact: {}", e);
+				return None
+			},
+		};
+		if !path.exists() {
+			return None
+		}
+
+		match self.verify(&path) {
+			true => Some(path),
+			false => {
+				log::warn!(
+					target: "wasm-runtime",
+					"Removing corrupted runtime artifact {}",
+					path.display(),
+				);
+				self.remove(&path);
+				None
+			},
+		}
+	}
+
+	/// Compile the given runtime and store the resulting artifact in the cache.
+	///
+	/// Returns the path of the artifact. An artifact that already exists and passes the checksum
+	/// verification is not compiled again.
+	pub fn precompile(
+		&self,
+		code_hash: &[u8],
+		blob: RuntimeBlob,
+		semantics: &Semantics,
+	) -> Result<PathBuf, WasmError> {
+		if let Some(path) = self.load(code_hash, semantics) {
+			return Ok(path)
+		}
+
+		let artifact = prepare_runtime_artifact(blob, semantics)?;
+		let path = self.artifact_path(code_hash, semantics)?;
+
+		fs::create_dir_all(&self.dir).map_err(|e| {
+			WasmError::Other(format!("cannot create {}: {}", self.dir.display(), e))
+		})?;
+
+		// Write into temporary files first and move them into place afterwards, so that a
+		// concurrently running executor never observes a partially written artifact.
+		let tmp_path = path.with_extension(format!("{}.tmp", ARTIFACT_EXTENSION));
+		let tmp_checksum_path = path.with_extension(format!("{}.tmp", CHECKSUM_EXTENSION));
+		let write = || -> std::io::Result<()> {
+			fs::write(&tmp_path, &artifact)?;
+			fs::write(
+				&tmp_checksum_path,
+				format!(
+					"{}:{}",
+					artifact.len(),
+					array_bytes::bytes2hex("", &blake2_256(&artifact))
+				),
+			)?;
+			fs::rename(&tmp_path, &path)?;
+			fs::rename(&tmp_checksum_path, path.with_extension(CHECKSUM_EXTENSION))
+		};
+
+		write().map_err(|e| {
+			let _ = fs::remove_file(&tmp_path);
+			let _ = fs::remove_file(&tmp_checksum_path);
+			WasmError::Other(format!("cannot write artifact {}: {}", path.display(), e))
+		})?;
+		self.verified.lock().expect("Lock is never poisoned; qed").insert(path.clone());
+
+		Ok(path)
+	}
+
+	/// Check the artifact at `path` against its checksum file.
+	fn verify(&self, path: &Path) -> bool {
+		let checksum = match fs::read_to_string(path.with_extension(CHECKSUM_EXTENSION)) {
+			Ok(checksum) => checksum,
+			Err(_) => return false,
+		};
+		let (size, hash) = match checksum.trim().split_once(':') {
+			Some((size, hash)) => (size.parse::<u64>().ok(), hash),
+			None => return false,
+		};
+		if size.is_none() || fs::metadata(path).ok().map(|m| m.len()) != size {
+			return false
+		}
+
+		let mut verified = self.verified.lock().expect("Lock is never poisoned; qed");
+		if verified.contains(path) {
+			return true
+		}
+
+		match fs::read(path) {
+			Ok(artifact) if array_bytes::bytes2hex("", &blake2_256(&artifact)) == hash => {
+				verified.insert(path.to_path_buf());
+				true
+			},
+			_ => false,
+		}
+	}
+
+	fn remove(&self, path: &Path) {
+		self.verified.lock().expect("Lock is never poisoned; qed").remove(path);
+		let _ = fs::remove_file(path);
+		let _ = fs::remove_file(path.with_extension(CHECKSUM_EXTENSION));
+	}
+}
+
+/// Create a new `WasmtimeRuntime`, loading it from the `artifact_cache` when possible.
+///
+/// When the cache doesn't contain an artifact for the runtime yet, the runtime is compiled into
+/// the cache first. Artifacts that fail to load, e.g. because they were produced by a different
+/// version of wasmtime, are compiled again. If the cache can't be used at all, this falls back to
+/// [`create_runtime`].
+pub fn create_runtime_with_artifact_cache<H>(
+	artifact_cache: &ArtifactCache,
+	code_hash: &[u8],
+	blob: RuntimeBlob,
+	config: Config,
+) -> Result<WasmtimeRuntime, WasmError>
+where
+	H: HostFunctions,
+{
+	// The legacy instance reuse needs the runtime blob and can't be used with artifacts.
+	if matches!(config.semantics.instantiation_strategy, InstantiationStrategy::LegacyInstanceReuse)
+	{
+		return create_runtime::<H>(blob, config)
+	}
+
+	if let Some(path) = artifact_cache.load(code_hash, &config.semantics) {
+		// SAFETY: The artifact was produced by `prepare_runtime_artifact` and its checksum was
+		//         verified above.
+		match unsafe { create_runtime_from_artifact::<H>(&path, config.clone()) } {
+			Ok(runtime) => return Ok(runtime),
+			Err(e) => {
+				log::debug!(
+					target: "wasm-runtime",
+					"Cannot load runtime artifact {}, compiling it again: {}",
+					path.display(),
+					e,
+				);
+				artifact_cache.remove(&path);
+			},
+		}
+	}
+
+	match artifact_cache.precompile(code_hash, blob.clone(), &config.semantics) {
+		// SAFETY: The artifact was just produced by `prepare_runtime_artifact`.
+		Ok(path) => unsafe { create_runtime_from_artifact::<H>(&path, config) },
+		Err(e) => {
+			log::warn!(
+				target: "wasm-runtime",
+				"Cannot store the runtime in {}: {}",
+				artifact_cache.dir().display(),
+				e,
+			);
+			create_runtime::<H>(blob, config)
+		},
+	}
+}
+
+/// A fingerprint of everything that influences the produced machine code.
+///
+/// This covers the wasmtime version and engine configuration as well as the preparation of the
+/// runtime blob.
+fn semantics_fingerprint(semantics: &Semantics) -> Result<[u8; 32], WasmError> {
+	let mut hasher = FingerprintHasher::default();
+	hash_artifact_engine(semantics, &mut hasher)?;
+
+	let stack_limit = semantics
+		.deterministic_stack_limit
+		.as_ref()
+		.map(|limit| (limit.logical_max, limit.native_stack_max));
+	hasher.write(
+		format!(
+			"{:?}:{:?}:{}:{:?}:{:?}",
+			semantics.instantiation_strategy,
+			stack_limit,
+			semantics.canonicalize_nans,
+			semantics.heap_alloc_strategy,
+			semantics.max_memory_pages,
+		)
+		.as_bytes(),
+	);
+
+	Ok(blake2_256(&hasher.0))
+}
+
+/// A [`Hasher`] collecting everything written into it.
+///
+/// Unlike the std hashers its output only depends on the written data.
+#[derive(Default)]
+struct FingerprintHasher(Vec<u8>);
+
+impl Hasher for FingerprintHasher {
+	fn finish(&self) -> u64 {
+		u64::from_le_bytes(blake2_64(&self.0))
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		self.0.extend_from_slice(bytes);
+	}
+}
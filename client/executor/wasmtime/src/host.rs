@@ -37,12 +37,14 @@ pub struct HostState {
 	/// once.
 	allocator: Option<FreeingBumpHeapAllocator>,
 	panic_message: Option<String>,
+	/// Set when an allocation failed because the linear memory is exhausted.
+	memory_exhausted: bool,
 }
 
 impl HostState {
 	/// Constructs a new `HostState`.
 	pub fn new(allocator: FreeingBumpHeapAllocator) -> Self {
-		HostState { allocator: Some(allocator), panic_message: None }
+		HostState { allocator: Some(allocator), panic_message: None, memory_exhausted: false }
 	}
 
 	/// Takes the error message out of the host state, leaving a `None` in its place.
@@ -50,6 +52,11 @@ impl HostState {
 		self.panic_message.take()
 	}
 
+	/// Whether an allocation failed because the linear memory is exhausted.
+	pub fn memory_exhausted(&self) -> bool {
+		self.memory_exhausted
+	}
+
 	pub(crate) fn allocation_stats(&self) -> AllocationStats {
 		self.allocator.as_ref()
 			.expect("Allocator is always set and only unavailable when doing an allocation/deallocation; qed")
@@ -95,11 +102,15 @@ impl<'a> sp_wasm_interface::FunctionContext for HostContext<'a> {
 			.expect("allocator is not empty when calling a function in wasm; qed");
 
 		// We can not return on error early, as we need to store back allocator.
-		let res = allocator
-			.allocate(&mut MemoryWrapper(&memory, &mut self.caller), size)
-			.map_err(|e| e.to_string());
+		let res = allocator.allocate(&mut MemoryWrapper(&memory, &mut self.caller), size);
 
-		self.host_state_mut().allocator = Some(allocator);
+		let host_state = self.host_state_mut();
+		host_state.allocator = Some(allocator);
+		if matches!(res, Err(ref e) if e.is_memory_exhausted()) {
+			host_state.memory_exhausted = true;
+		}
+
+		let res = res.map_err(|e| e.to_string());
 
 		res
 	}
@@ -22,6 +22,7 @@
 use crate::runtime::{Store, StoreData};
 use sc_executor_common::{
 	error::{Backtrace, Error, MessageWithBacktrace, Result, WasmError},
+	runtime_blob::STACK_HEIGHT_GLOBAL_EXPORT,
 	wasm_runtime::InvokeMethod,
 };
 use sp_wasm_interface::{Pointer, Value, WordSize};
@@ -69,12 +70,31 @@ impl EntryPoint {
 				dispatcher.call(&mut *store, (func, data_ptr, data_len)),
 		}
 		.map_err(|trap| {
+			// The counter of the deterministic stack limit isn't decreased when the execution
+			// traps, so it stays above the limit only if the limit caused the trap.
+			//
+			// Overflowing the native stack is not reported as `StackExhausted`, since where that
+			// happens depends on the host and not only on the executed code.
+			let stack_height = store.data().stack_height;
+			let stack_exhausted = stack_height
+				.map(|(global, limit)| {
+					global.get(&mut *store).i32().unwrap_or_default() as u32 > limit
+				})
+				.unwrap_or_default();
+
 			let host_state = store
 				.data_mut()
 				.host_state
 				.as_mut()
 				.expect("host state cannot be empty while a function is being called; qed");
 
+			if host_state.memory_exhausted() {
+				return Error::MemoryExhausted
+			}
+			if stack_exhausted {
+				return Error::StackExhausted
+			}
+
 			let backtrace = trap.downcast_ref::<wasmtime::WasmBacktrace>().map(|backtrace| {
 				// The logic to print out a backtrace is somewhat complicated,
 				// so let's get wasmtime to print it out for us.
@@ -162,7 +182,11 @@ pub struct InstanceWrapper {
 }
 
 impl InstanceWrapper {
-	pub(crate) fn new(engine: &Engine, instance_pre: &InstancePre<StoreData>) -> Result<Self> {
+	pub(crate) fn new(
+		engine: &Engine,
+		instance_pre: &InstancePre<StoreData>,
+		stack_depth_limit: Option<u32>,
+	) -> Result<Self> {
 		let mut store = Store::new(engine, Default::default());
 		let instance = instance_pre.instantiate(&mut store).map_err(|error| {
 			WasmError::Other(format!(
@@ -174,8 +198,14 @@ impl InstanceWrapper {
 		let memory = get_linear_memory(&instance, &mut store)?;
 		let table = get_table(&instance, &mut store);
 
+		let stack_height = stack_depth_limit.and_then(|limit| {
+			let global = instance.get_global(&mut store, STACK_HEIGHT_GLOBAL_EXPORT)?;
+			Some((global, limit))
+		});
+
 		store.data_mut().memory = Some(memory);
 		store.data_mut().table = table;
+		store.data_mut().stack_height = stack_height;
 
 		Ok(InstanceWrapper { instance, memory, store })
 	}
@@ -397,7 +427,7 @@ fn decommit_works() {
 	let module = wasmtime::Module::new(&engine, code).unwrap();
 	let linker = wasmtime::Linker::new(&engine);
 	let instance_pre = linker.instantiate_pre(&module).unwrap();
-	let mut wrapper = InstanceWrapper::new(&engine, &instance_pre).unwrap();
+	let mut wrapper = InstanceWrapper::new(&engine, &instance_pre, None).unwrap();
 	unsafe { *wrapper.memory.data_ptr(&wrapper.store) = 42 };
 	assert_eq!(unsafe { *wrapper.memory.data_ptr(&wrapper.store) }, 42);
 	wrapper.decommit();
@@ -27,6 +27,7 @@
 //! | `"jitdump"` | jitdump profiling       |
 //! | other value | No profiling (warning)  |
 
+mod artifact;
 mod host;
 mod imports;
 mod instance_wrapper;
@@ -36,6 +37,7 @@ mod util;
 #[cfg(test)]
 mod tests;
 
+pub use artifact::{create_runtime_with_artifact_cache, ArtifactCache, ARTIFACT_VERSION};
 pub use runtime::{
 	create_runtime, create_runtime_from_artifact, prepare_runtime_artifact, Config,
 	DeterministicStackLimit, InstantiationStrategy, Semantics,
@@ -42,7 +42,7 @@ use std::{
 		Arc,
 	},
 };
-use wasmtime::{AsContext, Engine, Memory, Table};
+use wasmtime::{AsContext, Engine, Global, Memory, Table};
 
 #[derive(Default)]
 pub(crate) struct StoreData {
@@ -52,6 +52,10 @@ pub(crate) struct StoreData {
 	pub(crate) memory: Option<Memory>,
 	/// This will be set only if the runtime actually contains a table.
 	pub(crate) table: Option<Table>,
+	/// The stack height counter of the deterministic stack limit along with the limit.
+	///
+	/// This will be set only if the runtime is instrumented with a deterministic stack limit.
+	pub(crate) stack_height: Option<(Global, u32)>,
 }
 
 impl StoreData {
@@ -81,11 +85,12 @@ enum Strategy {
 struct InstanceCreator {
 	engine: wasmtime::Engine,
 	instance_pre: Arc<wasmtime::InstancePre<StoreData>>,
+	stack_depth_limit: Option<u32>,
 }
 
 impl InstanceCreator {
 	fn instantiate(&mut self) -> Result<InstanceWrapper> {
-		InstanceWrapper::new(&self.engine, &self.instance_pre)
+		InstanceWrapper::new(&self.engine, &self.instance_pre, self.stack_depth_limit)
 	}
 }
 
@@ -125,13 +130,16 @@ pub struct WasmtimeRuntime {
 	engine: wasmtime::Engine,
 	instance_pre: Arc<wasmtime::InstancePre<StoreData>>,
 	instantiation_strategy: InternalInstantiationStrategy,
+	/// The logical stack limit the runtime was instrumented with, if any.
+	stack_depth_limit: Option<u32>,
 }
 
 impl WasmModule for WasmtimeRuntime {
 	fn new_instance(&self) -> Result<Box<dyn WasmInstance>> {
 		let strategy = match self.instantiation_strategy {
 			InternalInstantiationStrategy::LegacyInstanceReuse(ref snapshot_data) => {
-				let mut instance_wrapper = InstanceWrapper::new(&self.engine, &self.instance_pre)?;
+				let mut instance_wrapper =
+					InstanceWrapper::new(&self.engine, &self.instance_pre, self.stack_depth_limit)?;
 				let heap_base = instance_wrapper.extract_heap_base()?;
 
 				// This function panics if the instance was created from a runtime blob different
@@ -153,6 +161,7 @@ impl WasmModule for WasmtimeRuntime {
 			InternalInstantiationStrategy::Builtin => Strategy::RecreateInstance(InstanceCreator {
 				engine: self.engine.clone(),
 				instance_pre: self.instance_pre.clone(),
+				stack_depth_limit: self.stack_depth_limit,
 			}),
 		};
 
@@ -358,6 +367,9 @@ fn common_config(semantics: &Semantics) -> std::result::Result<wasmtime::Config,
 				maximum_pages.map(|p| p as u64).unwrap_or(MAX_WASM_PAGES),
 			HeapAllocStrategy::Static { .. } => MAX_WASM_PAGES,
 		};
+		let memory_pages = semantics
+			.max_memory_pages
+			.map_or(memory_pages, |max| memory_pages.min(max as u64));
 
 		let mut pooling_config = wasmtime::PoolingAllocationConfig::default();
 		pooling_config
@@ -504,6 +516,15 @@ pub struct Semantics {
 
 	/// The heap allocation strategy to use.
 	pub heap_alloc_strategy: HeapAllocStrategy,
+
+	/// The maximum number of pages the linear memory may ever have, regardless of the
+	/// [`heap_alloc_strategy`](Self::heap_alloc_strategy).
+	///
+	/// A runtime that requests more initial memory than this is rejected, and allocations that
+	/// would need to grow the memory beyond it fail with
+	/// [`Error::MemoryExhausted`](sc_executor_common::error::Error::MemoryExhausted). Unlike the
+	/// amount of memory available on the host, this limit is the same on every node.
+	pub max_memory_pages: Option<u32>,
 }
 
 #[derive(Clone)]
@@ -660,7 +681,18 @@ where
 		.instantiate_pre(&module)
 		.map_err(|e| WasmError::Other(format!("cannot preinstantiate module: {:#}", e)))?;
 
-	Ok(WasmtimeRuntime { engine, instance_pre: Arc::new(instance_pre), instantiation_strategy })
+	let stack_depth_limit = config
+		.semantics
+		.deterministic_stack_limit
+		.as_ref()
+		.map(|limit| limit.logical_max);
+
+	Ok(WasmtimeRuntime {
+		engine,
+		instance_pre: Arc::new(instance_pre),
+		instantiation_strategy,
+		stack_depth_limit,
+	})
 }
 
 fn prepare_blob_for_compilation(
@@ -684,6 +716,9 @@ fn prepare_blob_for_compilation(
 	// to enable `wasmtime`'s instance pooling. (Imported memories are ineligible for pooling.)
 	blob.convert_memory_import_into_export()?;
 	blob.setup_memory_according_to_heap_alloc_strategy(semantics.heap_alloc_strategy)?;
+	if let Some(max_memory_pages) = semantics.max_memory_pages {
+		blob.limit_memory_pages(max_memory_pages)?;
+	}
 
 	Ok(blob)
 }
@@ -707,6 +742,30 @@ pub fn prepare_runtime_artifact(
 		.map_err(|e| WasmError::Other(format!("cannot precompile module: {:#}", e)))
 }
 
+/// Feed the wasmtime version and the engine configuration used by [`prepare_runtime_artifact`]
+/// for the given `semantics` into `state`.
+///
+/// Artifacts produced for semantics with differing fingerprints can't be loaded interchangeably.
+pub(crate) fn hash_artifact_engine(
+	semantics: &Semantics,
+	state: &mut impl std::hash::Hasher,
+) -> std::result::Result<(), WasmError> {
+	use std::hash::Hash;
+
+	let mut semantics = semantics.clone();
+	replace_strategy_if_broken(&mut semantics.instantiation_strategy);
+
+	let engine = Engine::new(&common_config(&semantics)?)
+		.map_err(|e| WasmError::Other(format!("cannot create the engine: {:#}", e)))?;
+	// The artifact of an empty module carries the wasmtime version and the compiler settings.
+	engine
+		.precompile_module(b"\0asm\x01\0\0\0")
+		.map_err(|e| WasmError::Other(format!("cannot precompile the empty module: {:#}", e)))?
+		.hash(state);
+
+	Ok(())
+}
+
 fn perform_call(
 	data: &[u8],
 	instance_wrapper: &mut InstanceWrapper,
@@ -83,6 +83,7 @@ struct RuntimeBuilder {
 	deterministic_stack: bool,
 	heap_pages: HeapAllocStrategy,
 	precompile_runtime: bool,
+	max_memory_pages: Option<u32>,
 	tmpdir: Option<tempfile::TempDir>,
 }
 
@@ -95,6 +96,7 @@ impl RuntimeBuilder {
 			deterministic_stack: false,
 			heap_pages: HeapAllocStrategy::Static { extra_pages: 1024 },
 			precompile_runtime: false,
+			max_memory_pages: None,
 			tmpdir: None,
 		}
 	}
@@ -124,6 +126,11 @@ impl RuntimeBuilder {
 		self
 	}
 
+	fn max_memory_pages(mut self, max_memory_pages: u32) -> Self {
+		self.max_memory_pages = Some(max_memory_pages);
+		self
+	}
+
 	fn build(&mut self) -> impl WasmModule + '_ {
 		let blob = {
 			let wasm: Vec<u8>;
@@ -155,6 +162,7 @@ impl RuntimeBuilder {
 				canonicalize_nans: self.canonicalize_nans,
 				parallel_compilation: true,
 				heap_alloc_strategy: self.heap_pages,
+				max_memory_pages: self.max_memory_pages,
 			},
 		};
 
@@ -304,10 +312,26 @@ fn test_stack_depth_reaching(instantiation_strategy: InstantiationStrategy) {
 	let mut instance = runtime.new_instance().expect("failed to instantiate a runtime");
 
 	match instance.call_export("test-many-locals", &[]).unwrap_err() {
-		Error::AbortedDueToTrap(error) => {
-			let expected = "wasm trap: wasm `unreachable` instruction executed";
-			assert_eq!(error.message, expected);
-		},
+		Error::StackExhausted => {},
+		error => panic!("unexpected error: {:?}", error),
+	}
+}
+
+test_wasm_execution!(test_memory_pages_limit_reaching);
+fn test_memory_pages_limit_reaching(instantiation_strategy: InstantiationStrategy) {
+	let mut builder = RuntimeBuilder::new(instantiation_strategy)
+		.heap_alloc_strategy(HeapAllocStrategy::Dynamic { maximum_pages: None })
+		.max_memory_pages(128);
+
+	let runtime = builder.build();
+	let mut instance = runtime.new_instance().expect("failed to instantiate a runtime");
+
+	// 16MiB don't fit into the 128 pages (8MiB) the memory is limited to.
+	match instance
+		.call_export("test_allocate_vec", &(16 * 1024 * 1024u32).encode())
+		.unwrap_err()
+	{
+		Error::MemoryExhausted => {},
 		error => panic!("unexpected error: {:?}", error),
 	}
 }
@@ -474,6 +498,7 @@ fn test_instances_without_reuse_are_not_leaked() {
 				canonicalize_nans: false,
 				parallel_compilation: true,
 				heap_alloc_strategy: HeapAllocStrategy::Static { extra_pages: 2048 },
+				max_memory_pages: None,
 			},
 		},
 	)
@@ -490,6 +515,62 @@ fn test_instances_without_reuse_are_not_leaked() {
 	}
 }
 
+#[test]
+fn test_artifact_cache_recompiles_corrupted_artifacts() {
+	let dir = tempfile::tempdir().unwrap();
+	let cache = crate::ArtifactCache::new(dir.path());
+	let code_hash = [1u8; 32];
+	let config = crate::Config {
+		allow_missing_func_imports: true,
+		cache_path: None,
+		semantics: crate::Semantics {
+			instantiation_strategy: InstantiationStrategy::RecreateInstance,
+			deterministic_stack_limit: None,
+			canonicalize_nans: false,
+			parallel_compilation: true,
+			heap_alloc_strategy: HeapAllocStrategy::Static { extra_pages: 2048 },
+			max_memory_pages: None,
+		},
+	};
+	let blob = || RuntimeBlob::uncompress_if_needed(wasm_binary_unwrap()).unwrap();
+
+	assert!(cache.load(&code_hash, &config.semantics).is_none());
+
+	let runtime = crate::create_runtime_with_artifact_cache::<HostFunctions>(
+		&cache,
+		&code_hash,
+		blob(),
+		config.clone(),
+	)
+	.unwrap();
+	runtime.new_instance().unwrap().call_export("test_empty_return", &[0]).unwrap();
+
+	let path = cache.load(&code_hash, &config.semantics).unwrap();
+	assert_eq!(path, cache.artifact_path(&code_hash, &config.semantics).unwrap());
+
+	// A different configuration must not pick up the artifact.
+	let mut semantics = config.semantics.clone();
+	semantics.canonicalize_nans = true;
+	assert!(cache.load(&code_hash, &semantics).is_none());
+
+	// A modified artifact is rejected and compiled again.
+	let mut artifact = std::fs::read(&path).unwrap();
+	artifact.truncate(artifact.len() / 2);
+	std::fs::write(&path, artifact).unwrap();
+	assert!(cache.load(&code_hash, &config.semantics).is_none());
+	assert!(!path.exists());
+
+	let runtime = crate::create_runtime_with_artifact_cache::<HostFunctions>(
+		&cache,
+		&code_hash,
+		blob(),
+		config.clone(),
+	)
+	.unwrap();
+	runtime.new_instance().unwrap().call_export("test_empty_return", &[0]).unwrap();
+	assert!(cache.load(&code_hash, &config.semantics).is_some());
+}
+
 #[test]
 fn test_rustix_version_matches_with_wasmtime() {
 	let metadata = cargo_metadata::MetadataCommand::new()
@@ -1189,3 +1189,49 @@ where
 		client.insert_aux(values, None)
 	})
 }
+
+/// Protocol aux data needed to continue finalizing from the last finalized block when only the
+/// state of that block is available, e.g. when starting from a state snapshot.
+///
+/// The returned entries hold the current authority set, without the changes announced after
+/// `hash`, and a fresh voter set state based on `hash`. They are meant to be written to the aux
+/// store of the importing node after the snapshot block itself was imported.
+pub fn snapshot_aux_data<Block, Client>(
+	client: &Client,
+	hash: Block::Hash,
+) -> ClientResult<Vec<(Vec<u8>, Vec<u8>)>>
+where
+	Block: BlockT,
+	Client: AuxStore + HeaderMetadata<Block, Error = ClientError> + HeaderBackend<Block>,
+{
+	let info = client.info();
+	if hash != info.finalized_hash {
+		return Err(ClientError::Backend(format!(
+			"GRANDPA data is only available for the last finalized block {:?}",
+			info.finalized_hash
+		)))
+	}
+
+	let persistent_data: PersistentData<Block> =
+		aux_schema::load_persistent(client, info.genesis_hash, Zero::zero(), || {
+			const MSG: &str = "Unexpected missing grandpa data during snapshot export";
+			Err(ClientError::Application(Box::from(MSG)))
+		})?;
+
+	let shared_authority_set = persistent_data.authority_set;
+	let mut authority_set = shared_authority_set.inner();
+
+	let is_descendent_of = is_descendent_of(client, None);
+	authority_set.revert(hash, info.finalized_number, &is_descendent_of);
+
+	let (set_id, set_ref) = authority_set.current();
+	let new_set = NewAuthoritySet {
+		canon_hash: hash,
+		canon_number: info.finalized_number,
+		set_id,
+		authorities: set_ref.to_vec(),
+	};
+	Ok(aux_schema::update_authority_set::<Block, _, _>(&authority_set, Some(&new_set), |values| {
+		values.iter().map(|(key, value)| (key.to_vec(), value.to_vec())).collect()
+	}))
+}
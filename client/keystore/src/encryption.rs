@@ -0,0 +1,220 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Encryption of the key files of the local keystore.
+//!
+//! An encrypted key file contains an [`Envelope`] serialized as JSON. The secret is encrypted
+//! with XChaCha20-Poly1305 using a key that is derived from the keystore password with
+//! PBKDF2-HMAC-SHA512. The KDF parameters are stored in every envelope, so they can be changed
+//! without breaking existing files. The name of the key file is authenticated along with the
+//! secret, an encrypted key can't be moved to another key type or public key.
+
+use chacha20poly1305::{
+	aead::{Aead, NewAead, Payload},
+	Key, XChaCha20Poly1305, XNonce,
+};
+use hmac::Hmac;
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+
+use crate::{Error, Result};
+
+/// The current version of the [`Envelope`] format.
+pub const ENVELOPE_VERSION: u32 = 1;
+
+/// The number of PBKDF2 iterations used for new envelopes.
+pub const KDF_ITERATIONS: u32 = 100_000;
+
+const KDF_ALGORITHM: &str = "pbkdf2-hmac-sha512";
+const CIPHER: &str = "xchacha20poly1305";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// The secret stored in a key file.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct KeyEntry {
+	/// The secret URI of the key.
+	pub suri: String,
+	/// The password the key pair is derived with.
+	///
+	/// Stored along with the secret URI, so that changing the keystore password doesn't change
+	/// the keys.
+	pub password: Option<String>,
+}
+
+/// Parameters of the key derivation function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+	/// The name of the algorithm.
+	pub algorithm: String,
+	/// The number of iterations.
+	pub iterations: u32,
+	/// The hex encoded salt.
+	pub salt: String,
+}
+
+/// An encrypted key file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Envelope {
+	/// The version of the format.
+	pub version: u32,
+	/// Parameters of the key derivation function.
+	pub kdf: KdfParams,
+	/// The name of the cipher.
+	pub cipher: String,
+	/// The hex encoded nonce.
+	pub nonce: String,
+	/// The hex encoded encrypted [`KeyEntry`].
+	pub ciphertext: String,
+}
+
+/// The contents of a key file.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum KeyFile {
+	/// The secret URI of a key in plain text, as written before key files were encrypted.
+	Plain(String),
+	/// An encrypted key.
+	Encrypted(Envelope),
+}
+
+/// A key derived from a keystore password.
+#[derive(Clone)]
+pub struct EncryptionKey {
+	kdf: KdfParams,
+	key: [u8; 32],
+}
+
+impl EncryptionKey {
+	/// Derive a key from the given password and a fresh random salt.
+	pub fn new(password: &str) -> Self {
+		let mut salt = [0u8; SALT_LEN];
+		OsRng.fill_bytes(&mut salt);
+		Self::derive(
+			password,
+			KdfParams {
+				algorithm: KDF_ALGORITHM.into(),
+				iterations: KDF_ITERATIONS,
+				salt: array_bytes::bytes2hex("", &salt),
+			},
+		)
+		.expect("the parameters are valid; qed")
+	}
+
+	/// Derive the key for the given KDF parameters.
+	pub fn derive(password: &str, kdf: KdfParams) -> Result<Self> {
+		if kdf.algorithm != KDF_ALGORITHM {
+			return Err(Error::InvalidKeyFile(format!("Unsupported KDF `{}`", kdf.algorithm)))
+		}
+		let salt = array_bytes::hex2bytes(&kdf.salt)
+			.map_err(|_| Error::InvalidKeyFile("Invalid KDF salt".into()))?;
+
+		let mut key = [0u8; 32];
+		pbkdf2::pbkdf2::<Hmac<Sha512>>(password.as_bytes(), &salt, kdf.iterations, &mut key);
+		Ok(Self { kdf, key })
+	}
+
+	/// The parameters this key was derived with.
+	pub fn kdf(&self) -> &KdfParams {
+		&self.kdf
+	}
+
+	/// Encrypt the given entry of the key file with the given name.
+	pub fn encrypt(&self, entry: &KeyEntry, file_name: &str) -> Result<Envelope> {
+		let mut nonce = [0u8; NONCE_LEN];
+		OsRng.fill_bytes(&mut nonce);
+
+		let plaintext = serde_json::to_vec(entry)?;
+		let ciphertext = XChaCha20Poly1305::new(Key::from_slice(&self.key))
+			.encrypt(
+				XNonce::from_slice(&nonce),
+				Payload { msg: &plaintext, aad: file_name.as_bytes() },
+			)
+			.map_err(|_| Error::InvalidKeyFile("Encryption failed".into()))?;
+
+		Ok(Envelope {
+			version: ENVELOPE_VERSION,
+			kdf: self.kdf.clone(),
+			cipher: CIPHER.into(),
+			nonce: array_bytes::bytes2hex("", &nonce),
+			ciphertext: array_bytes::bytes2hex("", &ciphertext),
+		})
+	}
+
+	/// Decrypt the given envelope of the key file with the given name.
+	///
+	/// The key must have been derived with the KDF parameters of the envelope.
+	pub fn decrypt(&self, envelope: &Envelope, file_name: &str) -> Result<KeyEntry> {
+		if envelope.version != ENVELOPE_VERSION {
+			return Err(Error::InvalidKeyFile(format!(
+				"Unsupported key file version {}",
+				envelope.version
+			)))
+		}
+		if envelope.cipher != CIPHER {
+			return Err(Error::InvalidKeyFile(format!("Unsupported cipher `{}`", envelope.cipher)))
+		}
+
+		let nonce = array_bytes::hex2bytes(&envelope.nonce)
+			.ok()
+			.filter(|nonce| nonce.len() == NONCE_LEN)
+			.ok_or_else(|| Error::InvalidKeyFile("Invalid nonce".into()))?;
+		let ciphertext = array_bytes::hex2bytes(&envelope.ciphertext)
+			.map_err(|_| Error::InvalidKeyFile("Invalid ciphertext".into()))?;
+
+		let plaintext = XChaCha20Poly1305::new(Key::from_slice(&self.key))
+			.decrypt(
+				XNonce::from_slice(&nonce),
+				Payload { msg: &ciphertext, aad: file_name.as_bytes() },
+			)
+			.map_err(|_| Error::InvalidPassword)?;
+
+		serde_json::from_slice(&plaintext).map_err(Into::into)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn entry() -> KeyEntry {
+		KeyEntry { suri: "//Alice".into(), password: Some("secret".into()) }
+	}
+
+	#[test]
+	fn encrypt_decrypt_roundtrip() {
+		let key = EncryptionKey::new("password");
+		let envelope = key.encrypt(&entry(), "file").unwrap();
+
+		let key = EncryptionKey::derive("password", envelope.kdf.clone()).unwrap();
+		let decrypted = key.decrypt(&envelope, "file").unwrap();
+		assert_eq!(decrypted.suri, "//Alice");
+		assert_eq!(decrypted.password.as_deref(), Some("secret"));
+	}
+
+	#[test]
+	fn decrypt_fails_with_wrong_password_or_file_name() {
+		let key = EncryptionKey::new("password");
+		let envelope = key.encrypt(&entry(), "file").unwrap();
+
+		let wrong = EncryptionKey::derive("wrong", envelope.kdf.clone()).unwrap();
+		assert!(matches!(wrong.decrypt(&envelope, "file"), Err(Error::InvalidPassword)));
+		assert!(matches!(key.decrypt(&envelope, "other"), Err(Error::InvalidPassword)));
+	}
+}
@@ -21,12 +21,20 @@
 #![warn(missing_docs)]
 use sp_core::crypto::KeyTypeId;
 use sp_keystore::Error as TraitError;
-use std::io;
+use std::{io, path::PathBuf};
 
+/// Encryption of key files
+mod encryption;
 /// Local keystore implementation
 mod local;
 pub use local::LocalKeystore;
 
+/// Remote keystore implementation
+#[cfg(unix)]
+pub mod remote;
+#[cfg(unix)]
+pub use remote::{RemoteKeystore, RemoteKeystoreServer};
+
 /// Keystore error.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -54,6 +62,21 @@ pub enum Error {
 	/// Keystore unavailable
 	#[error("Keystore unavailable")]
 	Unavailable,
+	/// The key file is encrypted, but no password was given.
+	#[error("The key file is encrypted, but the keystore was opened without a password")]
+	PasswordRequired,
+	/// Decrypting a key file failed.
+	#[error("Failed to decrypt the key file, the keystore password is probably incorrect")]
+	InvalidPassword,
+	/// The key file can't be read.
+	#[error("Invalid key file: {0}")]
+	InvalidKeyFile(String),
+	/// A key file can be accessed by the group or other users.
+	#[error("{0} can be accessed by other users, restrict its permissions to the owner")]
+	InsecurePermissions(PathBuf),
+	/// The remote signer misbehaved.
+	#[error("Remote signer protocol error: {0}")]
+	Protocol(String),
 }
 
 /// Keystore Result
@@ -63,11 +86,17 @@ impl From<Error> for TraitError {
 	fn from(error: Error) -> Self {
 		match error {
 			Error::KeyNotSupported(id) => TraitError::KeyNotSupported(id),
-			Error::InvalidSeed | Error::InvalidPhrase | Error::PublicKeyMismatch =>
-				TraitError::ValidationError(error.to_string()),
+			Error::InvalidSeed |
+			Error::InvalidPhrase |
+			Error::PublicKeyMismatch |
+			Error::PasswordRequired |
+			Error::InvalidPassword => TraitError::ValidationError(error.to_string()),
 			Error::Unavailable => TraitError::Unavailable,
 			Error::Io(e) => TraitError::Other(e.to_string()),
 			Error::Json(e) => TraitError::Other(e.to_string()),
+			Error::InvalidKeyFile(_) | Error::InsecurePermissions(_) =>
+				TraitError::Other(error.to_string()),
+			Error::Protocol(e) => TraitError::Other(e),
 		}
 	}
 }
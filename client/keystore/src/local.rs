@@ -18,7 +18,8 @@
 //! Local keystore implementation
 
 use async_trait::async_trait;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
 use sp_application_crypto::{ecdsa, ed25519, sr25519, AppKey, AppPair, IsWrappedBy};
 use sp_core::{
 	crypto::{
@@ -35,17 +36,23 @@ use std::{
 	collections::{HashMap, HashSet},
 	fs::{self, File},
 	io::Write,
-	path::PathBuf,
+	path::{Path, PathBuf},
 	sync::Arc,
 };
 
-use crate::{Error, Result};
+use crate::{
+	encryption::{EncryptionKey, KdfParams, KeyEntry, KeyFile, KDF_ITERATIONS},
+	Error, Result,
+};
 
 /// A local based keystore that is either memory-based or filesystem-based.
 pub struct LocalKeystore(RwLock<KeystoreInner>);
 
 impl LocalKeystore {
 	/// Create a local keystore from filesystem.
+	///
+	/// When a password is given, keys are stored encrypted with it. Fails if any key file can be
+	/// read by other users.
 	pub fn open<T: Into<PathBuf>>(path: T, password: Option<SecretString>) -> Result<Self> {
 		let inner = KeystoreInner::open(path, password)?;
 		Ok(Self(RwLock::new(inner)))
@@ -67,6 +74,19 @@ impl LocalKeystore {
 	) -> Result<Option<Pair>> {
 		self.0.read().key_pair::<Pair>(public)
 	}
+
+	/// Encrypt all key files with the given new password.
+	///
+	/// Key files that were written before keys were stored encrypted are migrated as well. The
+	/// keys themselves don't change, the password a key was derived with is kept in its encrypted
+	/// key file. Nothing is changed when any key can't be read with the current password or any
+	/// re-encrypted key file can't be written. Keys that are only kept in memory keep the password
+	/// they were derived with.
+	///
+	/// Returns the number of key files that were encrypted.
+	pub fn rekey(&self, new_password: SecretString) -> Result<usize> {
+		self.0.write().rekey(new_password)
+	}
 }
 
 #[async_trait]
@@ -320,7 +340,7 @@ impl SyncCryptoStore for LocalKeystore {
 	fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
 		public_keys
 			.iter()
-			.all(|(p, t)| self.0.read().key_phrase_by_type(p, *t).ok().flatten().is_some())
+			.all(|(p, t)| self.0.read().key_entry_by_type(p, *t).ok().flatten().is_some())
 	}
 
 	fn sr25519_vrf_sign(
@@ -371,9 +391,12 @@ impl Into<Arc<dyn CryptoStore>> for LocalKeystore {
 /// Every pair that is being generated by a `seed`, will be placed in memory.
 struct KeystoreInner {
 	path: Option<PathBuf>,
-	/// Map over `(KeyTypeId, Raw public key)` -> `Key phrase/seed`
-	additional: HashMap<(KeyTypeId, Vec<u8>), String>,
+	/// Map over `(KeyTypeId, Raw public key)` -> `Key phrase/seed` and the password the key is
+	/// derived with.
+	additional: HashMap<(KeyTypeId, Vec<u8>), KeyEntry>,
 	password: Option<SecretString>,
+	/// Keys derived from `password`, see [`KeystoreInner::encryption_key`].
+	encryption_keys: Mutex<Vec<EncryptionKey>>,
 }
 
 impl KeystoreInner {
@@ -382,9 +405,45 @@ impl KeystoreInner {
 	/// Optionally takes a password that will be used to encrypt/decrypt the keys.
 	fn open<T: Into<PathBuf>>(path: T, password: Option<SecretString>) -> Result<Self> {
 		let path = path.into();
-		fs::create_dir_all(&path)?;
+		if !path.exists() {
+			fs::create_dir_all(&path)?;
 
-		Ok(Self { path: Some(path), additional: HashMap::new(), password })
+			#[cfg(target_family = "unix")]
+			{
+				use std::os::unix::fs::PermissionsExt;
+				fs::set_permissions(&path, fs::Permissions::from_mode(0o700))?;
+			}
+		}
+		Self::check_permissions(&path)?;
+
+		Ok(Self {
+			path: Some(path),
+			additional: HashMap::new(),
+			password,
+			encryption_keys: Mutex::new(Vec::new()),
+		})
+	}
+
+	/// Ensure that no key file in `path` can be accessed by the group or other users.
+	fn check_permissions(path: &Path) -> Result<()> {
+		#[cfg(target_family = "unix")]
+		{
+			use std::os::unix::fs::PermissionsExt;
+
+			for entry in fs::read_dir(path)? {
+				let entry = entry?;
+				if Self::parse_key_file_name(&entry.path()).is_some() &&
+					entry.metadata()?.permissions().mode() & 0o077 != 0
+				{
+					return Err(Error::InsecurePermissions(entry.path()))
+				}
+			}
+		}
+
+		#[cfg(not(target_family = "unix"))]
+		let _ = path;
+
+		Ok(())
 	}
 
 	/// Get the password for this store.
@@ -394,21 +453,34 @@ impl KeystoreInner {
 
 	/// Create a new in-memory store.
 	fn new_in_memory() -> Self {
-		Self { path: None, additional: HashMap::new(), password: None }
+		Self {
+			path: None,
+			additional: HashMap::new(),
+			password: None,
+			encryption_keys: Mutex::new(Vec::new()),
+		}
 	}
 
-	/// Get the key phrase for the given public key and key type from the in-memory store.
-	fn get_additional_pair(&self, public: &[u8], key_type: KeyTypeId) -> Option<&String> {
+	/// Get the key entry for the given public key and key type from the in-memory store.
+	fn get_additional_pair(&self, public: &[u8], key_type: KeyTypeId) -> Option<&KeyEntry> {
 		let key = (key_type, public.to_vec());
 		self.additional.get(&key)
 	}
 
 	/// Insert the given public/private key pair with the given key type.
 	///
-	/// Does not place it into the file system store.
-	fn insert_ephemeral_pair<Pair: PairT>(&mut self, pair: &Pair, seed: &str, key_type: KeyTypeId) {
+	/// `password` is the password the pair was derived from `seed` with. Does not place it into
+	/// the file system store.
+	fn insert_ephemeral_pair<Pair: PairT>(
+		&mut self,
+		pair: &Pair,
+		seed: &str,
+		password: Option<&str>,
+		key_type: KeyTypeId,
+	) {
 		let key = (key_type, pair.public().to_raw_vec());
-		self.additional.insert(key, seed.into());
+		let entry = KeyEntry { suri: seed.into(), password: password.map(Into::into) };
+		self.additional.insert(key, entry);
 	}
 
 	/// Insert a new key with anonymous crypto.
@@ -416,7 +488,7 @@ impl KeystoreInner {
 	/// Places it into the file system store, if a path is configured.
 	fn insert_unknown(&self, key_type: KeyTypeId, suri: &str, public: &[u8]) -> Result<()> {
 		if let Some(path) = self.key_file_path(public, key_type) {
-			Self::write_to_file(path, suri)?;
+			self.write_key_file(path, suri)?;
 		}
 
 		Ok(())
@@ -429,29 +501,148 @@ impl KeystoreInner {
 	fn generate_by_type<Pair: PairT>(&mut self, key_type: KeyTypeId) -> Result<Pair> {
 		let (pair, phrase, _) = Pair::generate_with_phrase(self.password());
 		if let Some(path) = self.key_file_path(pair.public().as_slice(), key_type) {
-			Self::write_to_file(path, &phrase)?;
+			self.write_key_file(path, &phrase)?;
 		} else {
-			self.insert_ephemeral_pair(&pair, &phrase, key_type);
+			let password = self.password().map(ToOwned::to_owned);
+			self.insert_ephemeral_pair(&pair, &phrase, password.as_deref(), key_type);
 		}
 
 		Ok(pair)
 	}
 
+	/// Write the given secret URI to the key file at `path`.
+	///
+	/// The key file is encrypted if the store has a password.
+	fn write_key_file(&self, path: PathBuf, suri: &str) -> Result<()> {
+		match self.password() {
+			Some(password) => {
+				let entry = KeyEntry { suri: suri.into(), password: Some(password.into()) };
+				let envelope = self.encryption_key(password).encrypt(&entry, file_name(&path))?;
+				Self::write_to_file(path, &envelope)
+			},
+			None => Self::write_to_file(path, suri),
+		}
+	}
+
 	/// Write the given `data` to `file`.
-	fn write_to_file(file: PathBuf, data: &str) -> Result<()> {
-		let mut file = File::create(file)?;
+	///
+	/// The data is written to a temporary file first, which then replaces `file`.
+	fn write_to_file<T: Serialize + ?Sized>(file: PathBuf, data: &T) -> Result<()> {
+		let tmp_path = file.with_extension("tmp");
+		Self::write_new_file(&tmp_path, data)?;
+		fs::rename(tmp_path, file)?;
+		Ok(())
+	}
+
+	/// Write the given `data` to the file at `path` that only the owner can access.
+	fn write_new_file<T: Serialize + ?Sized>(path: &Path, data: &T) -> Result<()> {
+		let mut tmp_file = File::create(path)?;
 
 		#[cfg(target_family = "unix")]
 		{
 			use std::os::unix::fs::PermissionsExt;
-			file.set_permissions(fs::Permissions::from_mode(0o600))?;
+			tmp_file.set_permissions(fs::Permissions::from_mode(0o600))?;
 		}
 
-		serde_json::to_writer(&file, data)?;
-		file.flush()?;
+		serde_json::to_writer(&tmp_file, data)?;
+		tmp_file.flush()?;
+		tmp_file.sync_all()?;
 		Ok(())
 	}
 
+	/// Read the key file at `path`.
+	fn read_key_file(&self, path: &Path) -> Result<KeyEntry> {
+		let file = File::open(path)?;
+
+		match serde_json::from_reader(&file)? {
+			KeyFile::Plain(suri) =>
+				Ok(KeyEntry { suri, password: self.password().map(ToOwned::to_owned) }),
+			KeyFile::Encrypted(envelope) => {
+				let password = self.password().ok_or(Error::PasswordRequired)?;
+				self.decryption_key(password, &envelope.kdf)?
+					.decrypt(&envelope, file_name(path))
+			},
+		}
+	}
+
+	/// The key to encrypt new key files with.
+	///
+	/// Deriving a key is expensive on purpose, so every key derived from `password` is kept and
+	/// new key files reuse the KDF parameters of a key that was derived before.
+	fn encryption_key(&self, password: &str) -> EncryptionKey {
+		let mut keys = self.encryption_keys.lock();
+		if let Some(key) = keys.iter().find(|key| key.kdf().iterations == KDF_ITERATIONS) {
+			return key.clone()
+		}
+
+		let key = EncryptionKey::new(password);
+		keys.push(key.clone());
+		key
+	}
+
+	/// The key to decrypt a key file that was encrypted using the given KDF parameters.
+	fn decryption_key(&self, password: &str, kdf: &KdfParams) -> Result<EncryptionKey> {
+		let mut keys = self.encryption_keys.lock();
+		if let Some(key) = keys.iter().find(|key| key.kdf() == kdf) {
+			return Ok(key.clone())
+		}
+
+		let key = EncryptionKey::derive(password, kdf.clone())?;
+		keys.push(key.clone());
+		Ok(key)
+	}
+
+	/// Encrypt all key files with `new_password`, see [`LocalKeystore::rekey`].
+	fn rekey(&mut self, new_password: SecretString) -> Result<usize> {
+		let Some(dir) = self.path.clone() else {
+			// The in-memory keys keep the password they were derived with, only new keys use
+			// the new password.
+			self.password = Some(new_password);
+			self.encryption_keys.get_mut().clear();
+			return Ok(0)
+		};
+
+		let mut entries = Vec::new();
+		for entry in fs::read_dir(&dir)? {
+			let path = entry?.path();
+			let Some((_, public)) = Self::parse_key_file_name(&path) else { continue };
+
+			let entry = self.read_key_file(&path)?;
+			if !derives_public(&entry, &public) {
+				return Err(Error::PublicKeyMismatch)
+			}
+			entries.push((path, entry));
+		}
+
+		// Write all re-encrypted key files next to the current ones first and only replace the
+		// current ones once all of them were written, so a failure leaves the keystore unchanged.
+		let key = EncryptionKey::new(new_password.expose_secret());
+		let mut written = Vec::with_capacity(entries.len());
+		let result = entries.iter().try_for_each(|(path, entry)| {
+			let tmp_path = path.with_extension("rekey");
+			written.push(tmp_path.clone());
+			let envelope = key.encrypt(entry, file_name(path))?;
+			Self::write_new_file(&tmp_path, &envelope)
+		});
+		if let Err(e) = result {
+			for tmp_path in written {
+				let _ = fs::remove_file(tmp_path);
+			}
+			return Err(e)
+		}
+
+		for (path, _) in &entries {
+			fs::rename(path.with_extension("rekey"), path)?;
+		}
+
+		self.password = Some(new_password);
+		let encryption_keys = self.encryption_keys.get_mut();
+		encryption_keys.clear();
+		encryption_keys.push(key);
+
+		Ok(entries.len())
+	}
+
 	/// Create a new key from seed.
 	///
 	/// Does not place it into the file system store.
@@ -461,14 +652,14 @@ impl KeystoreInner {
 		key_type: KeyTypeId,
 	) -> Result<Pair> {
 		let pair = Pair::from_string(seed, None).map_err(|_| Error::InvalidSeed)?;
-		self.insert_ephemeral_pair(&pair, seed, key_type);
+		self.insert_ephemeral_pair(&pair, seed, None, key_type);
 		Ok(pair)
 	}
 
-	/// Get the key phrase for a given public key and key type.
-	fn key_phrase_by_type(&self, public: &[u8], key_type: KeyTypeId) -> Result<Option<String>> {
-		if let Some(phrase) = self.get_additional_pair(public, key_type) {
-			return Ok(Some(phrase.clone()))
+	/// Get the key entry for a given public key and key type.
+	fn key_entry_by_type(&self, public: &[u8], key_type: KeyTypeId) -> Result<Option<KeyEntry>> {
+		if let Some(entry) = self.get_additional_pair(public, key_type) {
+			return Ok(Some(entry.clone()))
 		}
 
 		let path = if let Some(path) = self.key_file_path(public, key_type) {
@@ -478,9 +669,7 @@ impl KeystoreInner {
 		};
 
 		if path.exists() {
-			let file = File::open(path)?;
-
-			serde_json::from_reader(&file).map_err(Into::into).map(Some)
+			self.read_key_file(&path).map(Some)
 		} else {
 			Ok(None)
 		}
@@ -492,13 +681,14 @@ impl KeystoreInner {
 		public: &Pair::Public,
 		key_type: KeyTypeId,
 	) -> Result<Option<Pair>> {
-		let phrase = if let Some(p) = self.key_phrase_by_type(public.as_slice(), key_type)? {
-			p
+		let entry = if let Some(e) = self.key_entry_by_type(public.as_slice(), key_type)? {
+			e
 		} else {
 			return Ok(None)
 		};
 
-		let pair = Pair::from_string(&phrase, self.password()).map_err(|_| Error::InvalidPhrase)?;
+		let pair = Pair::from_string(&entry.suri, entry.password.as_deref())
+			.map_err(|_| Error::InvalidPhrase)?;
 
 		if &pair.public() == public {
 			Ok(Some(pair))
@@ -518,6 +708,21 @@ impl KeystoreInner {
 		Some(buf)
 	}
 
+	/// Get the key type and the raw public key from the path of a key file.
+	///
+	/// Returns `None` for directories, non-unicode file names and other files that are not
+	/// key files.
+	fn parse_key_file_name(path: &Path) -> Option<(KeyTypeId, Vec<u8>)> {
+		let name = path.file_name().and_then(|n| n.to_str())?;
+		match array_bytes::hex2bytes(name) {
+			Ok(hex) if hex.len() > 4 => {
+				let key_type = KeyTypeId(hex[0..4].try_into().expect("length checked above; qed"));
+				Some((key_type, hex[4..].to_vec()))
+			},
+			_ => None,
+		}
+	}
+
 	/// Returns a list of raw public keys filtered by `KeyTypeId`
 	fn raw_public_keys(&self, id: KeyTypeId) -> Result<Vec<Vec<u8>>> {
 		let mut public_keys: Vec<Vec<u8>> = self
@@ -532,18 +737,9 @@ impl KeystoreInner {
 				let entry = entry?;
 				let path = entry.path();
 
-				// skip directories and non-unicode file names (hex is unicode)
-				if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-					match array_bytes::hex2bytes(name) {
-						Ok(ref hex) if hex.len() > 4 => {
-							if hex[0..4] != id.0 {
-								continue
-							}
-							let public = hex[4..].to_vec();
-							public_keys.push(public);
-						},
-						_ => continue,
-					}
+				match Self::parse_key_file_name(&path) {
+					Some((key_type, public)) if key_type == id => public_keys.push(public),
+					_ => continue,
 				}
 			}
 		}
@@ -564,6 +760,24 @@ impl KeystoreInner {
 	}
 }
 
+/// The file name of the key file at `path`, which is authenticated when encrypting the key.
+fn file_name(path: &Path) -> &str {
+	path.file_name().and_then(|n| n.to_str()).unwrap_or_default()
+}
+
+/// Returns whether the key pair of any supported crypto derived from `entry` has the given
+/// public key.
+fn derives_public(entry: &KeyEntry, public: &[u8]) -> bool {
+	fn derives<Pair: PairT>(entry: &KeyEntry, public: &[u8]) -> bool {
+		Pair::from_string(&entry.suri, entry.password.as_deref())
+			.map_or(false, |pair| pair.public().as_slice() == public)
+	}
+
+	derives::<sr25519::Pair>(entry, public) ||
+		derives::<ed25519::Pair>(entry, public) ||
+		derives::<ecdsa::Pair>(entry, public)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -769,6 +983,148 @@ mod tests {
 		assert_eq!(SyncCryptoStore::sr25519_public_keys(&store, TEST_KEY_TYPE).len(), 2);
 	}
 
+	#[test]
+	fn keys_are_encrypted_with_the_password() {
+		let temp_dir = TempDir::new().unwrap();
+		let password = || Some(SecretString::from_str("password").unwrap());
+		let store = LocalKeystore::open(temp_dir.path(), password()).unwrap();
+
+		let public = SyncCryptoStore::sr25519_generate_new(&store, TEST_KEY_TYPE, None).unwrap();
+		let path = store.0.read().key_file_path(public.as_ref(), TEST_KEY_TYPE).unwrap();
+		assert!(matches!(
+			serde_json::from_str::<KeyFile>(&fs::read_to_string(path).unwrap()).unwrap(),
+			KeyFile::Encrypted(_)
+		));
+
+		let store = LocalKeystore::open(temp_dir.path(), password()).unwrap();
+		let pair = store
+			.0
+			.read()
+			.key_pair_by_type::<sr25519::Pair>(&public, TEST_KEY_TYPE)
+			.unwrap();
+		assert_eq!(pair.map(|p| p.public()), Some(public));
+
+		let store =
+			LocalKeystore::open(temp_dir.path(), Some(SecretString::from_str("wrong").unwrap()))
+				.unwrap();
+		assert!(matches!(
+			store.0.read().key_pair_by_type::<sr25519::Pair>(&public, TEST_KEY_TYPE),
+			Err(Error::InvalidPassword)
+		));
+	}
+
+	#[test]
+	fn rekey_keeps_the_keys() {
+		let temp_dir = TempDir::new().unwrap();
+		let store =
+			LocalKeystore::open(temp_dir.path(), Some(SecretString::from_str("old").unwrap()))
+				.unwrap();
+		let public = SyncCryptoStore::ed25519_generate_new(&store, TEST_KEY_TYPE, None).unwrap();
+
+		assert_eq!(store.rekey(SecretString::from_str("new").unwrap()).unwrap(), 1);
+
+		let store =
+			LocalKeystore::open(temp_dir.path(), Some(SecretString::from_str("new").unwrap()))
+				.unwrap();
+		let pair = store
+			.0
+			.read()
+			.key_pair_by_type::<ed25519::Pair>(&public, TEST_KEY_TYPE)
+			.unwrap();
+		assert_eq!(pair.map(|p| p.public()), Some(public));
+
+		let store =
+			LocalKeystore::open(temp_dir.path(), Some(SecretString::from_str("old").unwrap()))
+				.unwrap();
+		assert!(store
+			.0
+			.read()
+			.key_pair_by_type::<ed25519::Pair>(&public, TEST_KEY_TYPE)
+			.is_err());
+	}
+
+	#[test]
+	fn rekey_keeps_the_in_memory_keys() {
+		let temp_dir = TempDir::new().unwrap();
+		let store =
+			LocalKeystore::open(temp_dir.path(), Some(SecretString::from_str("old").unwrap()))
+				.unwrap();
+		let public =
+			SyncCryptoStore::sr25519_generate_new(&store, TEST_KEY_TYPE, Some("//Alice")).unwrap();
+
+		assert_eq!(store.rekey(SecretString::from_str("new").unwrap()).unwrap(), 0);
+
+		let pair = store
+			.0
+			.read()
+			.key_pair_by_type::<sr25519::Pair>(&public, TEST_KEY_TYPE)
+			.unwrap();
+		assert_eq!(pair.map(|p| p.public()), Some(public));
+
+		let store = LocalKeystore::in_memory();
+		let public = SyncCryptoStore::ed25519_generate_new(&store, TEST_KEY_TYPE, None).unwrap();
+
+		assert_eq!(store.rekey(SecretString::from_str("new").unwrap()).unwrap(), 0);
+
+		let pair = store
+			.0
+			.read()
+			.key_pair_by_type::<ed25519::Pair>(&public, TEST_KEY_TYPE)
+			.unwrap();
+		assert_eq!(pair.map(|p| p.public()), Some(public));
+	}
+
+	#[test]
+	fn rekey_migrates_plain_text_key_files() {
+		let temp_dir = TempDir::new().unwrap();
+		let store = LocalKeystore::open(temp_dir.path(), None).unwrap();
+		let public = SyncCryptoStore::sr25519_generate_new(&store, TEST_KEY_TYPE, None).unwrap();
+		SyncCryptoStore::insert_unknown(&store, SR25519, "//Alice", &[0; 32]).unwrap();
+
+		// A key file that doesn't belong to its public key is refused and nothing is changed.
+		assert!(matches!(
+			store.rekey(SecretString::from_str("new").unwrap()),
+			Err(Error::PublicKeyMismatch)
+		));
+		fs::remove_file(store.0.read().key_file_path(&[0; 32], SR25519).unwrap()).unwrap();
+
+		assert_eq!(store.rekey(SecretString::from_str("new").unwrap()).unwrap(), 1);
+
+		let path = store.0.read().key_file_path(public.as_ref(), TEST_KEY_TYPE).unwrap();
+		assert!(matches!(
+			serde_json::from_str::<KeyFile>(&fs::read_to_string(path).unwrap()).unwrap(),
+			KeyFile::Encrypted(_)
+		));
+
+		let store =
+			LocalKeystore::open(temp_dir.path(), Some(SecretString::from_str("new").unwrap()))
+				.unwrap();
+		let pair = store
+			.0
+			.read()
+			.key_pair_by_type::<sr25519::Pair>(&public, TEST_KEY_TYPE)
+			.unwrap();
+		assert_eq!(pair.map(|p| p.public()), Some(public));
+	}
+
+	#[test]
+	#[cfg(target_family = "unix")]
+	fn refuses_key_files_readable_by_others() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let temp_dir = TempDir::new().unwrap();
+		let store = LocalKeystore::open(temp_dir.path(), None).unwrap();
+		let public = SyncCryptoStore::sr25519_generate_new(&store, TEST_KEY_TYPE, None).unwrap();
+
+		let path = store.0.read().key_file_path(public.as_ref(), TEST_KEY_TYPE).unwrap();
+		fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+		assert!(matches!(
+			LocalKeystore::open(temp_dir.path(), None),
+			Err(Error::InsecurePermissions(p)) if p == path
+		));
+	}
+
 	#[test]
 	#[cfg(target_family = "unix")]
 	fn uses_correct_file_permissions_on_unix() {
@@ -0,0 +1,1015 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Remote keystore implementation
+//!
+//! A [`RemoteKeystore`] keeps no keys itself and forwards every operation to a signer daemon
+//! listening on a Unix socket. [`RemoteKeystoreServer`] implements the daemon side on top of any
+//! [`SyncCryptoStore`], the `remote-signer` binary serves a local keystore with it.
+//!
+//! Every message on the socket is a SCALE encoded [`Request`] or [`Response`], prefixed by its
+//! length as a little endian `u32`. A connection starts with a [`Request::Handshake`] and is only
+//! used when both sides speak the same [`PROTOCOL_VERSION`].
+
+use async_trait::async_trait;
+use codec::{Decode, Encode};
+use futures::channel::oneshot;
+use parking_lot::Mutex;
+use sp_application_crypto::{ecdsa, ed25519, sr25519};
+use sp_core::crypto::{ByteArray, CryptoTypeId, CryptoTypePublicPair, KeyTypeId};
+use sp_keystore::{
+	vrf::{VRFSignature, VRFTranscriptData, VRFTranscriptValue},
+	CryptoStore, Error as TraitError, SyncCryptoStore, SyncCryptoStorePtr,
+};
+use std::{
+	io::{self, Read, Write},
+	os::unix::net::{UnixListener, UnixStream},
+	path::{Path, PathBuf},
+	sync::{mpsc, Arc},
+	time::Duration,
+};
+
+use crate::{Error, Result};
+
+/// The version of the remote signer protocol.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// The maximum size of a single message.
+const MAX_MESSAGE_SIZE: u32 = 16 * 1024 * 1024;
+
+/// The default time to wait for the signer to answer a request.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The maximum number of distinct VRF transcript labels the server keeps.
+const MAX_TRANSCRIPT_LABELS: usize = 256;
+
+/// A value of a VRF transcript, see [`VRFTranscriptValue`].
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum TranscriptValue {
+	/// Value is an array of bytes
+	Bytes(Vec<u8>),
+	/// Value is a u64 integer
+	U64(u64),
+}
+
+/// The data of a VRF transcript, see [`VRFTranscriptData`].
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TranscriptData {
+	/// The transcript's label
+	pub label: Vec<u8>,
+	/// Additional data to be registered into the transcript
+	pub items: Vec<(Vec<u8>, TranscriptValue)>,
+}
+
+impl From<VRFTranscriptData> for TranscriptData {
+	fn from(data: VRFTranscriptData) -> Self {
+		Self {
+			label: data.label.to_vec(),
+			items: data
+				.items
+				.into_iter()
+				.map(|(label, value)| {
+					let value = match value {
+						VRFTranscriptValue::Bytes(bytes) => TranscriptValue::Bytes(bytes),
+						VRFTranscriptValue::U64(val) => TranscriptValue::U64(val),
+					};
+					(label.as_bytes().to_vec(), value)
+				})
+				.collect(),
+		}
+	}
+}
+
+/// A request sent to the signer.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum Request {
+	/// Negotiate the protocol version. Must be the first request of a connection.
+	Handshake {
+		/// The protocol version of the client.
+		version: u32,
+	},
+	/// Check that the signer is able to serve requests.
+	Health,
+	/// List the public keys of the given crypto type.
+	PublicKeys {
+		/// The key type.
+		key_type: KeyTypeId,
+		/// The crypto type of the keys.
+		crypto_type: CryptoTypeId,
+	},
+	/// List all keys of the given key type, see [`CryptoStore::keys`].
+	Keys(KeyTypeId),
+	/// Check whether all the given keys exist, see [`CryptoStore::has_keys`].
+	HasKeys(Vec<(Vec<u8>, KeyTypeId)>),
+	/// Generate a new key.
+	Generate {
+		/// The key type.
+		key_type: KeyTypeId,
+		/// The crypto type of the new key.
+		crypto_type: CryptoTypeId,
+		/// An optional seed, such keys are only kept in memory by the signer.
+		seed: Option<String>,
+	},
+	/// Insert a key, see [`CryptoStore::insert_unknown`].
+	InsertUnknown {
+		/// The key type.
+		key_type: KeyTypeId,
+		/// The secret URI of the key.
+		suri: String,
+		/// The public key.
+		public: Vec<u8>,
+	},
+	/// Sign a message, see [`CryptoStore::sign_with`].
+	Sign {
+		/// The key type.
+		key_type: KeyTypeId,
+		/// The key to sign with.
+		key: CryptoTypePublicPair,
+		/// The message to sign.
+		msg: Vec<u8>,
+	},
+	/// Generate a VRF signature, see [`CryptoStore::sr25519_vrf_sign`].
+	Sr25519VrfSign {
+		/// The key type.
+		key_type: KeyTypeId,
+		/// The key to sign with.
+		public: sr25519::Public,
+		/// The transcript to sign.
+		transcript: TranscriptData,
+	},
+	/// Sign a pre-hashed message, see [`CryptoStore::ecdsa_sign_prehashed`].
+	EcdsaSignPrehashed {
+		/// The key type.
+		key_type: KeyTypeId,
+		/// The key to sign with.
+		public: ecdsa::Public,
+		/// The hashed message.
+		msg: [u8; 32],
+	},
+}
+
+/// An error reported by the signer.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum RemoteError {
+	/// See [`TraitError::KeyNotSupported`].
+	KeyNotSupported(KeyTypeId),
+	/// See [`TraitError::ValidationError`].
+	Validation(String),
+	/// See [`TraitError::Unavailable`].
+	Unavailable,
+	/// The protocol version of the client is not supported.
+	UnsupportedVersion(u32),
+	/// Any other error.
+	Other(String),
+}
+
+impl From<TraitError> for RemoteError {
+	fn from(error: TraitError) -> Self {
+		match error {
+			TraitError::KeyNotSupported(id) => Self::KeyNotSupported(id),
+			TraitError::ValidationError(e) => Self::Validation(e),
+			TraitError::Unavailable => Self::Unavailable,
+			TraitError::Other(e) => Self::Other(e),
+		}
+	}
+}
+
+impl From<RemoteError> for TraitError {
+	fn from(error: RemoteError) -> Self {
+		match error {
+			RemoteError::KeyNotSupported(id) => Self::KeyNotSupported(id),
+			RemoteError::Validation(e) => Self::ValidationError(e),
+			RemoteError::Unavailable => Self::Unavailable,
+			RemoteError::UnsupportedVersion(v) =>
+				Self::Other(format!("Remote signer doesn't support protocol version {}", v)),
+			RemoteError::Other(e) => Self::Other(e),
+		}
+	}
+}
+
+/// The answer of the signer to a [`Request`].
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum Response {
+	/// The protocol version of the signer.
+	Handshake {
+		/// The protocol version of the signer.
+		version: u32,
+	},
+	/// The signer is able to serve requests.
+	Healthy,
+	/// The requested raw public keys.
+	PublicKeys(Vec<Vec<u8>>),
+	/// The requested keys.
+	Keys(Vec<CryptoTypePublicPair>),
+	/// Whether all the requested keys exist.
+	HasKeys(bool),
+	/// The raw public key of the generated key.
+	Generated(Vec<u8>),
+	/// The key was inserted.
+	Inserted,
+	/// The SCALE encoded signature, `None` if the key doesn't exist.
+	Signature(Option<Vec<u8>>),
+	/// The VRF output and proof, `None` if the key doesn't exist.
+	VrfSignature(Option<([u8; 32], [u8; 64])>),
+	/// The ECDSA signature, `None` if the key doesn't exist.
+	EcdsaSignature(Option<ecdsa::Signature>),
+	/// The request failed.
+	Error(RemoteError),
+}
+
+fn write_message<T: Encode>(stream: &mut UnixStream, message: &T) -> io::Result<()> {
+	let encoded = message.encode();
+	let len = u32::try_from(encoded.len())
+		.ok()
+		.filter(|len| *len <= MAX_MESSAGE_SIZE)
+		.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "message too large"))?;
+
+	stream.write_all(&len.to_le_bytes())?;
+	stream.write_all(&encoded)?;
+	stream.flush()
+}
+
+fn read_message<T: Decode>(stream: &mut UnixStream) -> io::Result<T> {
+	let mut len = [0u8; 4];
+	stream.read_exact(&mut len)?;
+	let len = u32::from_le_bytes(len);
+	if len > MAX_MESSAGE_SIZE {
+		return Err(io::Error::new(io::ErrorKind::InvalidData, "message too large"))
+	}
+
+	let mut buf = vec![0u8; len as usize];
+	stream.read_exact(&mut buf)?;
+	T::decode(&mut &buf[..]).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// A connection to the signer.
+///
+/// The connection is opened on the first request and transparently re-established when it was
+/// closed in the meantime.
+struct Connection {
+	path: PathBuf,
+	timeout: Duration,
+	stream: Mutex<Option<UnixStream>>,
+}
+
+impl Connection {
+	fn open(&self) -> Result<UnixStream> {
+		let mut stream = UnixStream::connect(&self.path).map_err(|_| Error::Unavailable)?;
+		stream.set_read_timeout(Some(self.timeout))?;
+		stream.set_write_timeout(Some(self.timeout))?;
+
+		write_message(&mut stream, &Request::Handshake { version: PROTOCOL_VERSION })?;
+		match read_message(&mut stream)? {
+			Response::Handshake { version } if version == PROTOCOL_VERSION => Ok(stream),
+			Response::Handshake { version } => Err(Error::Protocol(format!(
+				"Remote signer speaks protocol version {}, expected {}",
+				version, PROTOCOL_VERSION,
+			))),
+			response => Err(unexpected(response)),
+		}
+	}
+
+	fn request(&self, request: &Request) -> Result<Response> {
+		let mut connection = self.stream.lock();
+
+		// A connection that was idle for a while may have been closed by the signer, in that case
+		// the request is sent once more over a new connection. Requests that were sent
+		// successfully are never repeated.
+		let mut stream = match connection.take() {
+			Some(mut stream) => match write_message(&mut stream, request) {
+				Ok(()) => stream,
+				Err(_) => self.reconnect(request)?,
+			},
+			None => self.reconnect(request)?,
+		};
+
+		let response = read_message(&mut stream)?;
+		*connection = Some(stream);
+		Ok(response)
+	}
+
+	/// Open a new connection and send `request` over it.
+	fn reconnect(&self, request: &Request) -> Result<UnixStream> {
+		let mut stream = self.open()?;
+		write_message(&mut stream, request)?;
+		Ok(stream)
+	}
+}
+
+/// A request along with the channel its response is sent back on.
+type Job = (Request, oneshot::Sender<Result<Response>>);
+
+/// A keystore that forwards all operations to a signer daemon over a Unix socket.
+///
+/// The requests are sent by a dedicated thread, so the asynchronous [`CryptoStore`] methods never
+/// block the executor they are polled on.
+pub struct RemoteKeystore {
+	connection: Arc<Connection>,
+	jobs: Mutex<mpsc::Sender<Job>>,
+}
+
+impl RemoteKeystore {
+	/// Create a keystore for the signer listening on the given socket.
+	///
+	/// The connection is only opened once the keystore is used, see [`Self::connect`] to connect
+	/// right away.
+	pub fn new<T: Into<PathBuf>>(path: T) -> Result<Self> {
+		Self::with_timeout(path, DEFAULT_TIMEOUT)
+	}
+
+	/// Create a keystore for the signer listening on the given socket that waits at most
+	/// `timeout` for the signer to answer a request.
+	pub fn with_timeout<T: Into<PathBuf>>(path: T, timeout: Duration) -> Result<Self> {
+		let connection =
+			Arc::new(Connection { path: path.into(), timeout, stream: Mutex::new(None) });
+
+		let (jobs, receiver) = mpsc::channel::<Job>();
+		let worker = connection.clone();
+		std::thread::Builder::new().name("remote-keystore".into()).spawn(move || {
+			// Ends once the keystore and with it the sender is dropped.
+			for (request, response) in receiver {
+				let _ = response.send(worker.request(&request));
+			}
+		})?;
+
+		Ok(Self { connection, jobs: Mutex::new(jobs) })
+	}
+
+	/// Connect to the signer listening on the given socket.
+	///
+	/// Fails if the signer is not reachable or doesn't speak [`PROTOCOL_VERSION`].
+	pub fn connect<T: Into<PathBuf>>(path: T) -> Result<Self> {
+		let keystore = Self::new(path)?;
+		keystore.health_check()?;
+		Ok(keystore)
+	}
+
+	/// Create a keystore for the signer at the given URI.
+	///
+	/// Only `unix://` URIs are supported, e.g. `unix:///run/signer.sock`. Like [`Self::new`], this
+	/// doesn't connect to the signer yet.
+	pub fn from_uri(uri: &str) -> Result<Self> {
+		let path = uri
+			.strip_prefix("unix://")
+			.ok_or_else(|| Error::Protocol(format!("Unsupported remote keystore URI `{}`", uri)))?;
+		Self::new(path)
+	}
+
+	/// The path of the socket the signer is listening on.
+	pub fn path(&self) -> &Path {
+		&self.connection.path
+	}
+
+	/// Check that the signer is reachable and able to serve requests.
+	pub fn health_check(&self) -> Result<()> {
+		match self.request(Request::Health)? {
+			Response::Healthy => Ok(()),
+			Response::Error(RemoteError::Unavailable) => Err(Error::Unavailable),
+			response => Err(unexpected(response)),
+		}
+	}
+
+	/// Hand the request to the worker thread.
+	fn send(&self, request: Request) -> oneshot::Receiver<Result<Response>> {
+		let (sender, receiver) = oneshot::channel();
+		// If the worker is gone, the dropped sender cancels the receiver.
+		let _ = self.jobs.lock().send((request, sender));
+		receiver
+	}
+
+	/// Send a request and block until the answer arrives.
+	fn request(&self, request: Request) -> Result<Response> {
+		futures::executor::block_on(self.send(request)).unwrap_or(Err(Error::Unavailable))
+	}
+
+	/// Send a request and wait for the answer without blocking.
+	async fn request_async(&self, request: Request) -> Result<Response> {
+		self.send(request).await.unwrap_or(Err(Error::Unavailable))
+	}
+}
+
+fn unexpected(response: Response) -> Error {
+	match response {
+		Response::Error(e) => Error::Protocol(format!("Remote signer failed: {:?}", e)),
+		response => Error::Protocol(format!("Unexpected response from signer: {:?}", response)),
+	}
+}
+
+fn decode_public<T: ByteArray>(public: &[u8]) -> std::result::Result<T, TraitError> {
+	T::from_slice(public)
+		.map_err(|()| TraitError::Other("Corrupted public key - Invalid size".into()))
+}
+
+fn into_keys(
+	response: Result<Response>,
+) -> std::result::Result<Vec<CryptoTypePublicPair>, TraitError> {
+	match response? {
+		Response::Keys(keys) => Ok(keys),
+		Response::Error(e) => Err(e.into()),
+		response => Err(unexpected(response).into()),
+	}
+}
+
+fn into_public_keys<T: ByteArray>(response: Result<Response>) -> Vec<T> {
+	match response {
+		Ok(Response::PublicKeys(keys)) =>
+			keys.into_iter().filter_map(|k| T::from_slice(&k).ok()).collect(),
+		Ok(response) => {
+			log::warn!(target: "keystore", "{}", unexpected(response));
+			Vec::new()
+		},
+		Err(e) => {
+			log::warn!(target: "keystore", "Failed to list remote keys: {}", e);
+			Vec::new()
+		},
+	}
+}
+
+fn into_generated<T: ByteArray>(response: Result<Response>) -> std::result::Result<T, TraitError> {
+	match response? {
+		Response::Generated(public) => decode_public(&public),
+		Response::Error(e) => Err(e.into()),
+		response => Err(unexpected(response).into()),
+	}
+}
+
+fn into_inserted(response: Result<Response>) -> std::result::Result<(), ()> {
+	match response {
+		Ok(Response::Inserted) => Ok(()),
+		_ => Err(()),
+	}
+}
+
+fn into_has_keys(response: Result<Response>) -> bool {
+	matches!(response, Ok(Response::HasKeys(true)))
+}
+
+fn into_signature(response: Result<Response>) -> std::result::Result<Option<Vec<u8>>, TraitError> {
+	match response? {
+		Response::Signature(signature) => Ok(signature),
+		Response::Error(e) => Err(e.into()),
+		response => Err(unexpected(response).into()),
+	}
+}
+
+fn into_vrf_signature(
+	response: Result<Response>,
+) -> std::result::Result<Option<VRFSignature>, TraitError> {
+	match response? {
+		Response::VrfSignature(None) => Ok(None),
+		Response::VrfSignature(Some((output, proof))) => {
+			let invalid = |e| TraitError::Other(format!("Invalid VRF signature: {}", e));
+			Ok(Some(VRFSignature {
+				output: schnorrkel::vrf::VRFOutput::from_bytes(&output).map_err(invalid)?,
+				proof: schnorrkel::vrf::VRFProof::from_bytes(&proof).map_err(invalid)?,
+			}))
+		},
+		Response::Error(e) => Err(e.into()),
+		response => Err(unexpected(response).into()),
+	}
+}
+
+fn into_ecdsa_signature(
+	response: Result<Response>,
+) -> std::result::Result<Option<ecdsa::Signature>, TraitError> {
+	match response? {
+		Response::EcdsaSignature(signature) => Ok(signature),
+		Response::Error(e) => Err(e.into()),
+		response => Err(unexpected(response).into()),
+	}
+}
+
+fn public_keys_request(key_type: KeyTypeId, crypto_type: CryptoTypeId) -> Request {
+	Request::PublicKeys { key_type, crypto_type }
+}
+
+fn generate_request(key_type: KeyTypeId, crypto_type: CryptoTypeId, seed: Option<&str>) -> Request {
+	Request::Generate { key_type, crypto_type, seed: seed.map(ToOwned::to_owned) }
+}
+
+#[async_trait]
+impl CryptoStore for RemoteKeystore {
+	async fn keys(
+		&self,
+		id: KeyTypeId,
+	) -> std::result::Result<Vec<CryptoTypePublicPair>, TraitError> {
+		into_keys(self.request_async(Request::Keys(id)).await)
+	}
+
+	async fn sr25519_public_keys(&self, id: KeyTypeId) -> Vec<sr25519::Public> {
+		into_public_keys(self.request_async(public_keys_request(id, sr25519::CRYPTO_ID)).await)
+	}
+
+	async fn sr25519_generate_new(
+		&self,
+		id: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<sr25519::Public, TraitError> {
+		into_generated(self.request_async(generate_request(id, sr25519::CRYPTO_ID, seed)).await)
+	}
+
+	async fn ed25519_public_keys(&self, id: KeyTypeId) -> Vec<ed25519::Public> {
+		into_public_keys(self.request_async(public_keys_request(id, ed25519::CRYPTO_ID)).await)
+	}
+
+	async fn ed25519_generate_new(
+		&self,
+		id: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ed25519::Public, TraitError> {
+		into_generated(self.request_async(generate_request(id, ed25519::CRYPTO_ID, seed)).await)
+	}
+
+	async fn ecdsa_public_keys(&self, id: KeyTypeId) -> Vec<ecdsa::Public> {
+		into_public_keys(self.request_async(public_keys_request(id, ecdsa::CRYPTO_ID)).await)
+	}
+
+	async fn ecdsa_generate_new(
+		&self,
+		id: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ecdsa::Public, TraitError> {
+		into_generated(self.request_async(generate_request(id, ecdsa::CRYPTO_ID, seed)).await)
+	}
+
+	async fn insert_unknown(
+		&self,
+		id: KeyTypeId,
+		suri: &str,
+		public: &[u8],
+	) -> std::result::Result<(), ()> {
+		let request =
+			Request::InsertUnknown { key_type: id, suri: suri.into(), public: public.to_vec() };
+		into_inserted(self.request_async(request).await)
+	}
+
+	async fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
+		into_has_keys(self.request_async(Request::HasKeys(public_keys.to_vec())).await)
+	}
+
+	async fn supported_keys(
+		&self,
+		id: KeyTypeId,
+		keys: Vec<CryptoTypePublicPair>,
+	) -> std::result::Result<Vec<CryptoTypePublicPair>, TraitError> {
+		let all_keys = CryptoStore::keys(self, id).await?;
+		Ok(keys.into_iter().filter(|key| all_keys.contains(key)).collect())
+	}
+
+	async fn sign_with(
+		&self,
+		id: KeyTypeId,
+		key: &CryptoTypePublicPair,
+		msg: &[u8],
+	) -> std::result::Result<Option<Vec<u8>>, TraitError> {
+		let request = Request::Sign { key_type: id, key: key.clone(), msg: msg.to_vec() };
+		into_signature(self.request_async(request).await)
+	}
+
+	async fn sr25519_vrf_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		transcript_data: VRFTranscriptData,
+	) -> std::result::Result<Option<VRFSignature>, TraitError> {
+		let request = Request::Sr25519VrfSign {
+			key_type,
+			public: *public,
+			transcript: transcript_data.into(),
+		};
+		into_vrf_signature(self.request_async(request).await)
+	}
+
+	async fn ecdsa_sign_prehashed(
+		&self,
+		id: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8; 32],
+	) -> std::result::Result<Option<ecdsa::Signature>, TraitError> {
+		let request = Request::EcdsaSignPrehashed { key_type: id, public: *public, msg: *msg };
+		into_ecdsa_signature(self.request_async(request).await)
+	}
+}
+
+impl SyncCryptoStore for RemoteKeystore {
+	fn keys(&self, id: KeyTypeId) -> std::result::Result<Vec<CryptoTypePublicPair>, TraitError> {
+		into_keys(self.request(Request::Keys(id)))
+	}
+
+	fn supported_keys(
+		&self,
+		id: KeyTypeId,
+		keys: Vec<CryptoTypePublicPair>,
+	) -> std::result::Result<Vec<CryptoTypePublicPair>, TraitError> {
+		let all_keys = SyncCryptoStore::keys(self, id)?;
+		Ok(keys.into_iter().filter(|key| all_keys.contains(key)).collect())
+	}
+
+	fn sign_with(
+		&self,
+		id: KeyTypeId,
+		key: &CryptoTypePublicPair,
+		msg: &[u8],
+	) -> std::result::Result<Option<Vec<u8>>, TraitError> {
+		let request = Request::Sign { key_type: id, key: key.clone(), msg: msg.to_vec() };
+		into_signature(self.request(request))
+	}
+
+	fn sr25519_public_keys(&self, key_type: KeyTypeId) -> Vec<sr25519::Public> {
+		into_public_keys(self.request(public_keys_request(key_type, sr25519::CRYPTO_ID)))
+	}
+
+	fn sr25519_generate_new(
+		&self,
+		id: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<sr25519::Public, TraitError> {
+		into_generated(self.request(generate_request(id, sr25519::CRYPTO_ID, seed)))
+	}
+
+	fn ed25519_public_keys(&self, key_type: KeyTypeId) -> Vec<ed25519::Public> {
+		into_public_keys(self.request(public_keys_request(key_type, ed25519::CRYPTO_ID)))
+	}
+
+	fn ed25519_generate_new(
+		&self,
+		id: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ed25519::Public, TraitError> {
+		into_generated(self.request(generate_request(id, ed25519::CRYPTO_ID, seed)))
+	}
+
+	fn ecdsa_public_keys(&self, key_type: KeyTypeId) -> Vec<ecdsa::Public> {
+		into_public_keys(self.request(public_keys_request(key_type, ecdsa::CRYPTO_ID)))
+	}
+
+	fn ecdsa_generate_new(
+		&self,
+		id: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ecdsa::Public, TraitError> {
+		into_generated(self.request(generate_request(id, ecdsa::CRYPTO_ID, seed)))
+	}
+
+	fn insert_unknown(
+		&self,
+		key_type: KeyTypeId,
+		suri: &str,
+		public: &[u8],
+	) -> std::result::Result<(), ()> {
+		let request =
+			Request::InsertUnknown { key_type, suri: suri.into(), public: public.to_vec() };
+		into_inserted(self.request(request))
+	}
+
+	fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
+		into_has_keys(self.request(Request::HasKeys(public_keys.to_vec())))
+	}
+
+	fn sr25519_vrf_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		transcript_data: VRFTranscriptData,
+	) -> std::result::Result<Option<VRFSignature>, TraitError> {
+		let request = Request::Sr25519VrfSign {
+			key_type,
+			public: *public,
+			transcript: transcript_data.into(),
+		};
+		into_vrf_signature(self.request(request))
+	}
+
+	fn ecdsa_sign_prehashed(
+		&self,
+		id: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8; 32],
+	) -> std::result::Result<Option<ecdsa::Signature>, TraitError> {
+		let request = Request::EcdsaSignPrehashed { key_type: id, public: *public, msg: *msg };
+		into_ecdsa_signature(self.request(request))
+	}
+}
+
+/// The signer side of the remote keystore protocol.
+///
+/// Serves the requests of [`RemoteKeystore`]s using the wrapped keystore.
+pub struct RemoteKeystoreServer {
+	keystore: SyncCryptoStorePtr,
+	/// Labels of VRF transcripts, see [`RemoteKeystoreServer::intern`].
+	labels: Mutex<Vec<&'static [u8]>>,
+}
+
+impl RemoteKeystoreServer {
+	/// Create a new server for the given keystore.
+	pub fn new(keystore: SyncCryptoStorePtr) -> Self {
+		Self { keystore, labels: Mutex::new(Vec::new()) }
+	}
+
+	/// Accept connections on the given listener and serve them until accepting fails.
+	///
+	/// Every connection is served by its own thread.
+	pub fn serve(self, listener: UnixListener) -> io::Result<()> {
+		let server = Arc::new(self);
+		loop {
+			let (stream, _) = listener.accept()?;
+			let server = server.clone();
+			std::thread::spawn(move || {
+				if let Err(e) = server.serve_connection(stream) {
+					log::debug!(target: "keystore", "Remote keystore connection closed: {}", e);
+				}
+			});
+		}
+	}
+
+	/// Serve the requests of a single connection until it is closed.
+	pub fn serve_connection(&self, mut stream: UnixStream) -> io::Result<()> {
+		match read_message(&mut stream)? {
+			Request::Handshake { version } if version == PROTOCOL_VERSION => {
+				write_message(&mut stream, &Response::Handshake { version: PROTOCOL_VERSION })?;
+			},
+			Request::Handshake { version } => {
+				let response = Response::Error(RemoteError::UnsupportedVersion(version));
+				return write_message(&mut stream, &response)
+			},
+			_ => return Err(io::Error::new(io::ErrorKind::InvalidData, "handshake expected")),
+		}
+
+		loop {
+			let request = match read_message(&mut stream) {
+				Ok(request) => request,
+				Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+				Err(e) => return Err(e),
+			};
+			write_message(&mut stream, &self.handle(request))?;
+		}
+	}
+
+	/// Handle a single request.
+	pub fn handle(&self, request: Request) -> Response {
+		self.try_handle(request).unwrap_or_else(Response::Error)
+	}
+
+	fn try_handle(&self, request: Request) -> std::result::Result<Response, RemoteError> {
+		let keystore = &*self.keystore;
+		let response = match request {
+			Request::Handshake { .. } => Response::Handshake { version: PROTOCOL_VERSION },
+			Request::Health => Response::Healthy,
+			Request::PublicKeys { key_type, crypto_type } =>
+				Response::PublicKeys(match crypto_type {
+					sr25519::CRYPTO_ID => SyncCryptoStore::sr25519_public_keys(keystore, key_type)
+						.into_iter()
+						.map(|k| k.to_raw_vec())
+						.collect(),
+					ed25519::CRYPTO_ID => SyncCryptoStore::ed25519_public_keys(keystore, key_type)
+						.into_iter()
+						.map(|k| k.to_raw_vec())
+						.collect(),
+					ecdsa::CRYPTO_ID => SyncCryptoStore::ecdsa_public_keys(keystore, key_type)
+						.into_iter()
+						.map(|k| k.to_raw_vec())
+						.collect(),
+					_ => return Err(RemoteError::KeyNotSupported(key_type)),
+				}),
+			Request::Keys(key_type) => Response::Keys(SyncCryptoStore::keys(keystore, key_type)?),
+			Request::HasKeys(keys) => Response::HasKeys(SyncCryptoStore::has_keys(keystore, &keys)),
+			Request::Generate { key_type, crypto_type, seed } => {
+				let seed = seed.as_deref();
+				Response::Generated(match crypto_type {
+					sr25519::CRYPTO_ID =>
+						SyncCryptoStore::sr25519_generate_new(keystore, key_type, seed)?
+							.to_raw_vec(),
+					ed25519::CRYPTO_ID =>
+						SyncCryptoStore::ed25519_generate_new(keystore, key_type, seed)?
+							.to_raw_vec(),
+					ecdsa::CRYPTO_ID =>
+						SyncCryptoStore::ecdsa_generate_new(keystore, key_type, seed)?.to_raw_vec(),
+					_ => return Err(RemoteError::KeyNotSupported(key_type)),
+				})
+			},
+			Request::InsertUnknown { key_type, suri, public } => {
+				SyncCryptoStore::insert_unknown(keystore, key_type, &suri, &public)
+					.map_err(|()| RemoteError::Other("Failed to insert the key".into()))?;
+				Response::Inserted
+			},
+			Request::Sign { key_type, key, msg } =>
+				Response::Signature(SyncCryptoStore::sign_with(keystore, key_type, &key, &msg)?),
+			Request::Sr25519VrfSign { key_type, public, transcript } => {
+				let transcript = self.transcript_data(transcript)?;
+				let signature =
+					SyncCryptoStore::sr25519_vrf_sign(keystore, key_type, &public, transcript)?;
+				Response::VrfSignature(signature.map(|s| (s.output.to_bytes(), s.proof.to_bytes())))
+			},
+			Request::EcdsaSignPrehashed { key_type, public, msg } => Response::EcdsaSignature(
+				SyncCryptoStore::ecdsa_sign_prehashed(keystore, key_type, &public, &msg)?,
+			),
+		};
+
+		Ok(response)
+	}
+
+	fn transcript_data(
+		&self,
+		data: TranscriptData,
+	) -> std::result::Result<VRFTranscriptData, RemoteError> {
+		let mut items = Vec::with_capacity(data.items.len());
+		for (label, value) in data.items {
+			let label = std::str::from_utf8(self.intern(label)?)
+				.map_err(|_| RemoteError::Validation("Transcript label is not UTF-8".into()))?;
+			let value = match value {
+				TranscriptValue::Bytes(bytes) => VRFTranscriptValue::Bytes(bytes),
+				TranscriptValue::U64(val) => VRFTranscriptValue::U64(val),
+			};
+			items.push((label, value));
+		}
+
+		Ok(VRFTranscriptData { label: self.intern(data.label)?, items })
+	}
+
+	/// Returns a `'static` copy of the given transcript label.
+	///
+	/// [`VRFTranscriptData`] only holds `'static` labels. Protocols only use a handful of
+	/// distinct labels, so every label is leaked once and reused afterwards. The number of labels
+	/// is bounded to not leak memory without limit.
+	fn intern(&self, label: Vec<u8>) -> std::result::Result<&'static [u8], RemoteError> {
+		let mut labels = self.labels.lock();
+		if let Some(label) = labels.iter().find(|l| **l == &label[..]) {
+			return Ok(label)
+		}
+		if labels.len() >= MAX_TRANSCRIPT_LABELS {
+			return Err(RemoteError::Other("Too many distinct transcript labels".into()))
+		}
+
+		let label: &'static [u8] = Box::leak(label.into_boxed_slice());
+		labels.push(label);
+		Ok(label)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::LocalKeystore;
+	use sp_core::{testing::SR25519, Pair};
+	use sp_keystore::vrf::make_transcript;
+	use tempfile::TempDir;
+
+	const TEST_KEY_TYPE: KeyTypeId = KeyTypeId(*b"test");
+
+	fn spawn_signer(keystore: Arc<LocalKeystore>) -> (TempDir, PathBuf) {
+		let dir = TempDir::new().unwrap();
+		let path = dir.path().join("signer.sock");
+		let listener = UnixListener::bind(&path).unwrap();
+		std::thread::spawn(move || RemoteKeystoreServer::new(keystore).serve(listener));
+		(dir, path)
+	}
+
+	#[test]
+	fn sign_and_list_keys_through_the_signer() {
+		let local = Arc::new(LocalKeystore::in_memory());
+		let (_dir, path) = spawn_signer(local.clone());
+		let remote = RemoteKeystore::connect(&path).unwrap();
+
+		remote.health_check().unwrap();
+
+		let sr25519 = SyncCryptoStore::sr25519_generate_new(&remote, TEST_KEY_TYPE, None).unwrap();
+		let ed25519 =
+			SyncCryptoStore::ed25519_generate_new(&remote, TEST_KEY_TYPE, Some("//Alice")).unwrap();
+		let ecdsa = SyncCryptoStore::ecdsa_generate_new(&remote, TEST_KEY_TYPE, None).unwrap();
+
+		// The local keystore lists every key of the key type that decodes as the requested type.
+		assert!(SyncCryptoStore::sr25519_public_keys(&remote, TEST_KEY_TYPE).contains(&sr25519));
+		assert_eq!(
+			SyncCryptoStore::sr25519_public_keys(&remote, TEST_KEY_TYPE),
+			SyncCryptoStore::sr25519_public_keys(&*local, TEST_KEY_TYPE),
+		);
+		assert_eq!(
+			SyncCryptoStore::ed25519_public_keys(&remote, TEST_KEY_TYPE),
+			SyncCryptoStore::ed25519_public_keys(&*local, TEST_KEY_TYPE),
+		);
+		assert!(SyncCryptoStore::has_keys(&remote, &[(ecdsa.to_raw_vec(), TEST_KEY_TYPE)]));
+		assert!(!SyncCryptoStore::has_keys(&remote, &[(vec![0; 32], TEST_KEY_TYPE)]));
+
+		let msg = b"hello";
+		let key = CryptoTypePublicPair(ed25519::CRYPTO_ID, ed25519.to_raw_vec());
+		let signature = SyncCryptoStore::sign_with(&remote, TEST_KEY_TYPE, &key, msg)
+			.unwrap()
+			.map(|s| ed25519::Signature::decode(&mut &s[..]).unwrap())
+			.unwrap();
+		assert!(ed25519::Pair::verify(&signature, msg, &ed25519));
+
+		let signature =
+			SyncCryptoStore::ecdsa_sign_prehashed(&remote, TEST_KEY_TYPE, &ecdsa, &[1; 32])
+				.unwrap()
+				.unwrap();
+		assert!(ecdsa::Pair::verify_prehashed(&signature, &[1; 32], &ecdsa));
+
+		let unknown = CryptoTypePublicPair(sr25519::CRYPTO_ID, vec![0; 32]);
+		assert_eq!(
+			SyncCryptoStore::sign_with(&remote, TEST_KEY_TYPE, &unknown, msg).unwrap(),
+			None
+		);
+	}
+
+	#[test]
+	fn vrf_sign_through_the_signer() {
+		// Keys of unknown origin are only stored by keystores backed by a directory.
+		let keystore_dir = TempDir::new().unwrap();
+		let local = Arc::new(LocalKeystore::open(keystore_dir.path(), None).unwrap());
+		let (_dir, path) = spawn_signer(local);
+		let remote = RemoteKeystore::connect(&path).unwrap();
+
+		let key_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let public = key_pair.public();
+		SyncCryptoStore::insert_unknown(&remote, SR25519, "//Alice", public.as_ref()).unwrap();
+
+		let transcript_data = || VRFTranscriptData {
+			label: b"Test",
+			items: vec![
+				("one", VRFTranscriptValue::U64(1)),
+				("two", VRFTranscriptValue::Bytes("test".as_bytes().to_vec())),
+			],
+		};
+
+		let signature =
+			SyncCryptoStore::sr25519_vrf_sign(&remote, SR25519, &public, transcript_data())
+				.unwrap()
+				.unwrap();
+		let expected = key_pair.as_ref().vrf_sign(make_transcript(transcript_data())).0;
+		assert_eq!(signature.output, expected.to_output());
+	}
+
+	#[test]
+	fn reconnects_after_the_connection_was_closed() {
+		let local = Arc::new(LocalKeystore::in_memory());
+		let (_dir, path) = spawn_signer(local);
+		let remote = RemoteKeystore::connect(&path).unwrap();
+
+		remote
+			.connection
+			.stream
+			.lock()
+			.as_ref()
+			.unwrap()
+			.shutdown(std::net::Shutdown::Both)
+			.unwrap();
+
+		remote.health_check().unwrap();
+	}
+
+	#[test]
+	fn async_requests_are_answered_by_the_signer() {
+		let local = Arc::new(LocalKeystore::in_memory());
+		let (_dir, path) = spawn_signer(local.clone());
+		let remote = RemoteKeystore::connect(&path).unwrap();
+
+		let public = futures::executor::block_on(CryptoStore::sr25519_generate_new(
+			&remote,
+			TEST_KEY_TYPE,
+			None,
+		))
+		.unwrap();
+		assert_eq!(
+			futures::executor::block_on(CryptoStore::sr25519_public_keys(&remote, TEST_KEY_TYPE)),
+			vec![public],
+		);
+		assert_eq!(SyncCryptoStore::sr25519_public_keys(&*local, TEST_KEY_TYPE), vec![public]);
+	}
+
+	#[test]
+	fn unreachable_signer_is_unavailable() {
+		let dir = TempDir::new().unwrap();
+		assert!(matches!(
+			RemoteKeystore::connect(dir.path().join("missing.sock")),
+			Err(Error::Unavailable)
+		));
+	}
+
+	#[test]
+	fn rejects_unsupported_protocol_versions() {
+		let server = RemoteKeystoreServer::new(Arc::new(LocalKeystore::in_memory()));
+		let (mut client, stream) = UnixStream::pair().unwrap();
+		std::thread::spawn(move || server.serve_connection(stream));
+
+		write_message(&mut client, &Request::Handshake { version: PROTOCOL_VERSION + 1 }).unwrap();
+		assert_eq!(
+			read_message::<Response>(&mut client).unwrap(),
+			Response::Error(RemoteError::UnsupportedVersion(PROTOCOL_VERSION + 1)),
+		);
+	}
+}
@@ -20,13 +20,13 @@ use std::{collections::HashSet, str::FromStr, sync::Arc, thread::sleep};
 
 use crate::NetworkProvider;
 use codec::{Decode, Encode};
-use futures::Future;
+use futures::{Future, FutureExt};
 pub use http::SharedClient;
 use libp2p::{Multiaddr, PeerId};
 use sp_core::{
 	offchain::{
 		self, HttpError, HttpRequestId, HttpRequestStatus, OffchainStorage, OpaqueMultiaddr,
-		OpaqueNetworkState, StorageKind, Timestamp,
+		OpaqueNetworkState, StorageKind, Timestamp, WebSocketError, WebSocketId,
 	},
 	OpaquePeerId,
 };
@@ -35,6 +35,7 @@ pub use sp_offchain::STORAGE_PREFIX;
 mod http;
 
 mod timestamp;
+mod websocket;
 
 fn unavailable_yet<R: Default>(name: &str) -> R {
 	tracing::error!(
@@ -151,6 +152,8 @@ pub(crate) struct Api {
 	is_validator: bool,
 	/// Everything HTTP-related is handled by a different struct.
 	http: http::HttpApi,
+	/// Everything WebSocket-related is handled by a different struct.
+	websocket: websocket::WebSocketApi,
 }
 
 impl offchain::Externalities for Api {
@@ -225,6 +228,44 @@ impl offchain::Externalities for Api {
 		self.http.response_read_body(request_id, buffer, deadline)
 	}
 
+	fn http_response_read_chunk(
+		&mut self,
+		request_id: HttpRequestId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, HttpError> {
+		self.http.response_read_chunk(request_id, deadline)
+	}
+
+	fn ws_open(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WebSocketId, WebSocketError> {
+		self.websocket.open(uri, deadline)
+	}
+
+	fn ws_send(
+		&mut self,
+		id: WebSocketId,
+		message: &[u8],
+		binary: bool,
+		deadline: Option<Timestamp>,
+	) -> Result<(), WebSocketError> {
+		self.websocket.send(id, message, binary, deadline)
+	}
+
+	fn ws_receive(
+		&mut self,
+		id: WebSocketId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, WebSocketError> {
+		self.websocket.receive(id, deadline)
+	}
+
+	fn ws_close(&mut self, id: WebSocketId) {
+		self.websocket.close(id)
+	}
+
 	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
 		let peer_ids: HashSet<PeerId> =
 			nodes.into_iter().filter_map(|node| PeerId::from_bytes(&node.0).ok()).collect();
@@ -297,6 +338,8 @@ impl TryFrom<OpaqueNetworkState> for NetworkState {
 pub(crate) struct AsyncApi {
 	/// Everything HTTP-related is handled by a different struct.
 	http: Option<http::HttpWorker>,
+	/// Everything WebSocket-related is handled by a different struct.
+	websocket: Option<websocket::WebSocketWorker>,
 }
 
 impl AsyncApi {
@@ -307,17 +350,20 @@ impl AsyncApi {
 		shared_http_client: SharedClient,
 	) -> (Api, Self) {
 		let (http_api, http_worker) = http::http(shared_http_client);
+		let (websocket_api, websocket_worker) = websocket::websocket();
 
-		let api = Api { network_provider, is_validator, http: http_api };
+		let api = Api { network_provider, is_validator, http: http_api, websocket: websocket_api };
 
-		let async_api = Self { http: Some(http_worker) };
+		let async_api = Self { http: Some(http_worker), websocket: Some(websocket_worker) };
 
 		(api, async_api)
 	}
 
 	/// Run a processing task for the API
 	pub fn process(self) -> impl Future<Output = ()> {
-		self.http.expect("`process` is only called once; qed")
+		let http = self.http.expect("`process` is only called once; qed");
+		let websocket = self.websocket.expect("`process` is only called once; qed");
+		futures::future::join(http, websocket).map(drop)
 	}
 }
 
@@ -554,6 +554,57 @@ impl HttpApi {
 			}
 		}
 	}
+
+	/// Mimics the corresponding method in the offchain API.
+	pub fn response_read_chunk(
+		&mut self,
+		request_id: HttpRequestId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, HttpError> {
+		// Do an implicit wait on the request.
+		let _ = self.response_wait(&[request_id], deadline);
+
+		let mut response = match self.requests.remove(&request_id) {
+			Some(HttpApiRequest::Response(r)) => r,
+			Some(rq @ HttpApiRequest::Dispatched(_)) => {
+				self.requests.insert(request_id, rq);
+				return Err(HttpError::DeadlineReached)
+			},
+			Some(HttpApiRequest::Fail { .. }) => return Err(HttpError::IoError),
+			Some(rq @ HttpApiRequest::NotDispatched(_, _)) => {
+				self.requests.insert(request_id, rq);
+				return Err(HttpError::Invalid)
+			},
+			None => return Err(HttpError::Invalid),
+		};
+
+		// What is left of a chunk that was partially read with `response_read_body` is returned
+		// first.
+		if let Some(current_read_chunk) = response.current_read_chunk.take() {
+			let chunk = current_read_chunk.into_inner();
+			if chunk.has_remaining() {
+				self.requests.insert(request_id, HttpApiRequest::Response(response));
+				return Ok(Some(chunk.to_vec()))
+			}
+		}
+
+		let mut deadline = timestamp::deadline_to_future(deadline);
+		let mut next_body = future::maybe_done(response.body.next());
+		futures::executor::block_on(future::select(&mut next_body, &mut deadline));
+
+		match next_body {
+			future::MaybeDone::Done(Some(Ok(chunk))) => {
+				self.requests.insert(request_id, HttpApiRequest::Response(response));
+				Ok(Some(chunk.to_vec()))
+			},
+			future::MaybeDone::Done(Some(Err(_))) => Err(HttpError::IoError),
+			future::MaybeDone::Done(None) => Ok(None), // eof
+			future::MaybeDone::Future(_) | future::MaybeDone::Gone => {
+				self.requests.insert(request_id, HttpApiRequest::Response(response));
+				Err(HttpError::DeadlineReached)
+			},
+		}
+	}
 }
 
 impl fmt::Debug for HttpApi {
@@ -858,6 +909,33 @@ mod tests {
 		assert_eq!(&buf[..n], b"Hello World!");
 	}
 
+	#[test]
+	fn streamed_response_chunks() {
+		let deadline = timestamp::now().add(Duration::from_millis(10_000));
+
+		let (mut api, addr) = build_api_server!(hyper::Response::new(hyper::Body::wrap_stream(
+			futures::stream::iter(vec![
+				Ok::<_, Infallible>("price: 1"),
+				Ok("price: 2"),
+				Ok("price: 3"),
+			])
+		)));
+
+		let id = api.request_start("GET", &format!("http://{}", addr)).unwrap();
+		api.request_write_body(id, &[], Some(deadline)).unwrap();
+
+		let mut chunks = Vec::new();
+		while let Some(chunk) = api.response_read_chunk(id, Some(deadline)).unwrap() {
+			chunks.push(chunk);
+		}
+		assert_eq!(chunks.concat(), b"price: 1price: 2price: 3");
+
+		match api.response_read_chunk(id, Some(deadline)) {
+			Err(HttpError::Invalid) => {},
+			v => panic!("Reading a finished response succeeded: {:?}", v),
+		}
+	}
+
 	#[test]
 	fn request_start_invalid_call() {
 		let (mut api, addr) = build_api_server!();
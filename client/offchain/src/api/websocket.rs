@@ -0,0 +1,516 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! WebSocket connections of offchain workers.
+//!
+//! Like for HTTP, calling the [`websocket`] function returns a pair of [`WebSocketApi`] and
+//! [`WebSocketWorker`]. The [`WebSocketApi`] is (indirectly) passed to the runtime, while the
+//! [`WebSocketWorker`] drives the connections in the background, so that messages continue to be
+//! received while the runtime isn't actively calling any function.
+//!
+//! Connections don't outlive the offchain worker run that opened them: once the
+//! [`WebSocketApi`] is dropped, all of its connections are closed.
+
+use crate::api::timestamp;
+use fnv::FnvHashMap;
+use futures::{
+	channel::{mpsc, oneshot},
+	future::{self, BoxFuture},
+	prelude::*,
+	stream::FuturesUnordered,
+};
+use hyper::{client::HttpConnector, service::Service as _};
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use once_cell::sync::Lazy;
+use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
+use soketto::{connection, handshake};
+use sp_core::offchain::{Timestamp, WebSocketError, WebSocketId};
+use std::{
+	pin::Pin,
+	task::{Context, Poll},
+};
+use tokio_util::compat::TokioAsyncReadCompatExt as _;
+
+const LOG_TARGET: &str = "offchain-worker::websocket";
+
+/// The maximum size of a received message.
+const MAX_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+/// The number of messages buffered in each direction of a connection.
+const MESSAGE_BUFFER: usize = 16;
+
+/// The connector that establishes the TCP and TLS connections.
+///
+/// Shared by all workers, since loading the native root certificates is expensive.
+static CONNECTOR: Lazy<HttpsConnector<HttpConnector>> = Lazy::new(|| {
+	HttpsConnectorBuilder::new()
+		.with_native_roots()
+		.https_or_http()
+		.enable_http1()
+		.build()
+});
+
+/// Creates a pair of [`WebSocketApi`] and [`WebSocketWorker`].
+pub fn websocket() -> (WebSocketApi, WebSocketWorker) {
+	let (to_worker, from_api) = tracing_unbounded("mpsc_ocw_to_websocket_worker", 100_000);
+
+	let api = WebSocketApi {
+		to_worker,
+		// Like for HTTP requests, start with a random ID to prevent runtime code from relying on
+		// hardcoded IDs.
+		next_id: WebSocketId(rand::random::<u16>() % 2000),
+		connections: FnvHashMap::default(),
+	};
+
+	let worker =
+		WebSocketWorker { from_api, api_closed: false, connections: FuturesUnordered::new() };
+
+	(api, worker)
+}
+
+/// Provides WebSocket capabilities.
+///
+/// Since this struct is a helper for offchain workers, its API is mimicking the API provided
+/// to offchain workers.
+pub struct WebSocketApi {
+	/// Used to sends messages to the worker.
+	to_worker: TracingUnboundedSender<ApiToWorker>,
+	/// Id to assign to the next connection that is opened.
+	next_id: WebSocketId,
+	/// List of open connections.
+	connections: FnvHashMap<WebSocketId, WebSocketApiConnection>,
+}
+
+/// One open connection within `WebSocketApi`.
+struct WebSocketApiConnection {
+	/// Messages to send to the remote.
+	to_remote: mpsc::Sender<Message>,
+	/// Messages received from the remote. Ends when the connection is closed.
+	from_remote: stream::Fuse<mpsc::Receiver<Result<Vec<u8>, connection::Error>>>,
+}
+
+/// A message sent to the remote.
+enum Message {
+	Text(String),
+	Binary(Vec<u8>),
+}
+
+impl WebSocketApi {
+	/// Mimics the corresponding method in the offchain API.
+	pub fn open(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WebSocketId, WebSocketError> {
+		let uri =
+			hyper::Uri::from_maybe_shared(uri.to_owned()).map_err(|_| WebSocketError::Invalid)?;
+		if !matches!(uri.scheme_str(), Some("ws") | Some("wss")) || uri.host().is_none() {
+			return Err(WebSocketError::Invalid)
+		}
+
+		let new_id = self.next_id;
+		match self.next_id.0.checked_add(1) {
+			Some(new_id) => self.next_id.0 = new_id,
+			None => {
+				tracing::error!(
+					target: LOG_TARGET,
+					"Overflow in offchain worker WebSocket ID assignment"
+				);
+				return Err(WebSocketError::Invalid)
+			},
+		};
+
+		let (to_remote, outgoing) = mpsc::channel(MESSAGE_BUFFER);
+		let (incoming, from_remote) = mpsc::channel(MESSAGE_BUFFER);
+		let (opened_tx, opened_rx) = oneshot::channel();
+		tracing::trace!(target: LOG_TARGET, id = %new_id.0, %uri, "Opening connection");
+		let _ = self.to_worker.unbounded_send(ApiToWorker::Open {
+			id: new_id,
+			uri,
+			opened: opened_tx,
+			outgoing,
+			incoming,
+		});
+
+		let mut opened = future::maybe_done(opened_rx);
+		let mut deadline = timestamp::deadline_to_future(deadline);
+		futures::executor::block_on(future::select(&mut opened, &mut deadline));
+
+		match opened {
+			future::MaybeDone::Done(Ok(true)) => {
+				self.connections.insert(
+					new_id,
+					WebSocketApiConnection { to_remote, from_remote: from_remote.fuse() },
+				);
+				Ok(new_id)
+			},
+			future::MaybeDone::Done(_) => Err(WebSocketError::IoError),
+			future::MaybeDone::Future(_) | future::MaybeDone::Gone => {
+				debug_assert!(matches!(deadline, future::MaybeDone::Done(..)));
+				tracing::debug!(target: LOG_TARGET, id = %new_id.0, "Deadline reached while opening");
+				Err(WebSocketError::DeadlineReached)
+			},
+		}
+	}
+
+	/// Mimics the corresponding method in the offchain API.
+	pub fn send(
+		&mut self,
+		id: WebSocketId,
+		message: &[u8],
+		binary: bool,
+		deadline: Option<Timestamp>,
+	) -> Result<(), WebSocketError> {
+		let connection = self.connections.get_mut(&id).ok_or(WebSocketError::Invalid)?;
+		let message = if binary {
+			Message::Binary(message.to_owned())
+		} else {
+			Message::Text(
+				String::from_utf8(message.to_owned()).map_err(|_| WebSocketError::Invalid)?,
+			)
+		};
+
+		let mut deadline = timestamp::deadline_to_future(deadline);
+		let mut when_ready =
+			future::maybe_done(future::poll_fn(|cx| connection.to_remote.poll_ready(cx)));
+		futures::executor::block_on(future::select(&mut when_ready, &mut deadline));
+
+		let result = match when_ready {
+			future::MaybeDone::Done(Ok(())) =>
+				connection.to_remote.start_send(message).map_err(|_| WebSocketError::IoError),
+			future::MaybeDone::Done(Err(_)) => Err(WebSocketError::IoError),
+			future::MaybeDone::Future(_) | future::MaybeDone::Gone => {
+				debug_assert!(matches!(deadline, future::MaybeDone::Done(..)));
+				return Err(WebSocketError::DeadlineReached)
+			},
+		};
+
+		if result.is_err() {
+			// The connection has been closed by the worker.
+			tracing::debug!(target: LOG_TARGET, id = %id.0, "Failed to send message");
+			self.connections.remove(&id);
+		}
+		result
+	}
+
+	/// Mimics the corresponding method in the offchain API.
+	pub fn receive(
+		&mut self,
+		id: WebSocketId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, WebSocketError> {
+		let connection = self.connections.get_mut(&id).ok_or(WebSocketError::Invalid)?;
+
+		let mut deadline = timestamp::deadline_to_future(deadline);
+		let mut next_message = future::maybe_done(connection.from_remote.next());
+		futures::executor::block_on(future::select(&mut next_message, &mut deadline));
+
+		let result = match next_message {
+			future::MaybeDone::Done(Some(Ok(message))) => return Ok(Some(message)),
+			future::MaybeDone::Done(Some(Err(error))) => {
+				tracing::debug!(target: LOG_TARGET, id = %id.0, ?error, "Connection failed");
+				Err(WebSocketError::IoError)
+			},
+			future::MaybeDone::Done(None) => {
+				tracing::debug!(target: LOG_TARGET, id = %id.0, "Connection closed by remote");
+				Ok(None)
+			},
+			future::MaybeDone::Future(_) | future::MaybeDone::Gone => {
+				debug_assert!(matches!(deadline, future::MaybeDone::Done(..)));
+				return Err(WebSocketError::DeadlineReached)
+			},
+		};
+
+		self.connections.remove(&id);
+		result
+	}
+
+	/// Mimics the corresponding method in the offchain API.
+	pub fn close(&mut self, id: WebSocketId) {
+		// Dropping the channels makes the worker close the connection.
+		if self.connections.remove(&id).is_some() {
+			tracing::trace!(target: LOG_TARGET, id = %id.0, "Closing connection");
+		}
+	}
+}
+
+/// Message send from the API to the worker.
+enum ApiToWorker {
+	/// Opens a new connection.
+	Open {
+		/// ID of the connection, used for logging.
+		id: WebSocketId,
+		/// The `ws://` or `wss://` URI to connect to.
+		uri: hyper::Uri,
+		/// Reports whether the connection has been established.
+		opened: oneshot::Sender<bool>,
+		/// Messages to send to the remote.
+		outgoing: mpsc::Receiver<Message>,
+		/// Messages received from the remote.
+		incoming: mpsc::Sender<Result<Vec<u8>, connection::Error>>,
+	},
+}
+
+/// Must be continuously polled for the [`WebSocketApi`] to properly work.
+pub struct WebSocketWorker {
+	/// Used to receive messages from the `WebSocketApi`.
+	from_api: TracingUnboundedReceiver<ApiToWorker>,
+	/// Whether the `WebSocketApi` has been dropped.
+	api_closed: bool,
+	/// The connections being worked on.
+	connections: FuturesUnordered<BoxFuture<'static, ()>>,
+}
+
+impl Future for WebSocketWorker {
+	type Output = ();
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+		let me = &mut *self;
+
+		while !me.api_closed {
+			match me.from_api.poll_next_unpin(cx) {
+				Poll::Ready(Some(ApiToWorker::Open { id, uri, opened, outgoing, incoming })) =>
+					me.connections.push(run_connection(id, uri, opened, outgoing, incoming).boxed()),
+				Poll::Ready(None) => me.api_closed = true,
+				Poll::Pending => break,
+			}
+		}
+
+		while let Poll::Ready(Some(())) = me.connections.poll_next_unpin(cx) {}
+
+		if me.api_closed && me.connections.is_empty() {
+			Poll::Ready(())
+		} else {
+			Poll::Pending
+		}
+	}
+}
+
+/// Establishes a connection and forwards messages until either side closes it.
+async fn run_connection(
+	id: WebSocketId,
+	uri: hyper::Uri,
+	opened: oneshot::Sender<bool>,
+	mut outgoing: mpsc::Receiver<Message>,
+	mut incoming: mpsc::Sender<Result<Vec<u8>, connection::Error>>,
+) {
+	let connected = async {
+		let authority = uri.authority().ok_or("The URI has no authority")?.clone();
+		let resource = uri.path_and_query().map_or("/", |p| p.as_str());
+		let scheme = if uri.scheme_str() == Some("wss") { "https" } else { "http" };
+		let target = hyper::Uri::builder()
+			.scheme(scheme)
+			.authority(authority.clone())
+			.path_and_query("/")
+			.build()?;
+
+		let socket = CONNECTOR.clone().call(target).await?;
+		let mut client = handshake::Client::new(socket.compat(), authority.as_str(), resource);
+		match client.handshake().await? {
+			handshake::ServerResponse::Accepted { .. } => {},
+			handshake::ServerResponse::Redirect { status_code, .. } |
+			handshake::ServerResponse::Rejected { status_code } =>
+				return Err(format!("Handshake rejected with status {}", status_code).into()),
+		}
+
+		let mut builder = client.into_builder();
+		builder.set_max_message_size(MAX_MESSAGE_SIZE);
+		Ok::<_, Box<dyn std::error::Error + Send + Sync>>(builder.finish())
+	}
+	.await;
+
+	let (mut sender, mut receiver) = match connected {
+		Ok(connection) => connection,
+		Err(error) => {
+			tracing::debug!(target: LOG_TARGET, id = %id.0, %error, "Failed to connect");
+			let _ = opened.send(false);
+			return
+		},
+	};
+	if opened.send(true).is_err() {
+		// The API stopped waiting for the connection.
+		return
+	}
+	tracing::debug!(target: LOG_TARGET, id = %id.0, "Connection opened");
+
+	let send = async move {
+		while let Some(message) = outgoing.next().await {
+			let sent = match message {
+				Message::Text(text) => sender.send_text(text).await,
+				Message::Binary(data) => sender.send_binary(data).await,
+			};
+			let flushed = match sent {
+				Ok(()) => sender.flush().await,
+				Err(error) => Err(error),
+			};
+			if let Err(error) = flushed {
+				tracing::debug!(target: LOG_TARGET, id = %id.0, %error, "Failed to send message");
+				return
+			}
+		}
+		// The API closed the connection.
+		let _ = sender.close().await;
+	};
+
+	let receive = async move {
+		loop {
+			let mut message = Vec::new();
+			let result = match receiver.receive_data(&mut message).await {
+				Ok(_) => Ok(message),
+				Err(connection::Error::Closed) => return,
+				Err(error) => Err(error),
+			};
+			let failed = result.is_err();
+			if incoming.send(result).await.is_err() || failed {
+				return
+			}
+		}
+	};
+
+	future::select(send.boxed(), receive.boxed()).await;
+	tracing::debug!(target: LOG_TARGET, id = %id.0, "Connection closed");
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::offchain::Duration;
+	use tokio::net::{TcpListener, TcpStream};
+
+	// Returns a `WebSocketApi` whose worker is ran in the background, and the address of a
+	// WebSocket server that runs in the background as well and echoes all messages back.
+	fn build_api_server() -> (WebSocketApi, std::net::SocketAddr) {
+		let (api, worker) = websocket();
+
+		let (addr_tx, addr_rx) = std::sync::mpsc::channel();
+		std::thread::spawn(move || {
+			let rt = tokio::runtime::Runtime::new().unwrap();
+			rt.block_on(async move {
+				let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+				let _ = addr_tx.send(listener.local_addr().unwrap());
+				let server = async move {
+					while let Ok((socket, _)) = listener.accept().await {
+						tokio::spawn(echo(socket));
+					}
+				};
+				future::join(worker, server).await;
+			});
+		});
+		(api, addr_rx.recv().unwrap())
+	}
+
+	// Echoes all messages back, until the client sends `close`.
+	async fn echo(socket: TcpStream) {
+		let mut server = handshake::Server::new(socket.compat());
+		let key = match server.receive_request().await {
+			Ok(request) => request.key(),
+			Err(_) => return,
+		};
+		let accept = handshake::server::Response::Accept { key, protocol: None };
+		if server.send_response(&accept).await.is_err() {
+			return
+		}
+
+		let (mut sender, mut receiver) = server.into_builder().finish();
+		loop {
+			let mut message = Vec::new();
+			let Ok(data) = receiver.receive_data(&mut message).await else { return };
+			if message == b"close" {
+				let _ = sender.close().await;
+				return
+			}
+
+			let sent = if data.is_text() {
+				sender.send_text(String::from_utf8(message).unwrap()).await
+			} else {
+				sender.send_binary(&message).await
+			};
+			if sent.is_err() || sender.flush().await.is_err() {
+				return
+			}
+		}
+	}
+
+	fn deadline() -> Option<Timestamp> {
+		Some(timestamp::now().add(Duration::from_millis(10_000)))
+	}
+
+	#[test]
+	fn exchanges_messages_with_localhost() {
+		let (mut api, addr) = build_api_server();
+
+		let id = api.open(&format!("ws://{}/feed", addr), deadline()).unwrap();
+		api.send(id, b"Hello", false, deadline()).unwrap();
+		assert_eq!(api.receive(id, deadline()), Ok(Some(b"Hello".to_vec())));
+		api.send(id, &[0, 1, 2, 255], true, deadline()).unwrap();
+		assert_eq!(api.receive(id, deadline()), Ok(Some(vec![0, 1, 2, 255])));
+
+		api.close(id);
+		assert_eq!(api.send(id, b"Hello", false, deadline()), Err(WebSocketError::Invalid));
+		assert_eq!(api.receive(id, deadline()), Err(WebSocketError::Invalid));
+	}
+
+	#[test]
+	fn receive_returns_none_once_the_remote_closed_the_connection() {
+		let (mut api, addr) = build_api_server();
+
+		let id = api.open(&format!("ws://{}", addr), deadline()).unwrap();
+		api.send(id, b"close", false, deadline()).unwrap();
+		assert_eq!(api.receive(id, deadline()), Ok(None));
+		assert_eq!(api.receive(id, deadline()), Err(WebSocketError::Invalid));
+	}
+
+	#[test]
+	fn receive_reaches_deadline() {
+		let (mut api, addr) = build_api_server();
+
+		let id = api.open(&format!("ws://{}", addr), deadline()).unwrap();
+		let deadline = timestamp::now().add(Duration::from_millis(100));
+		assert_eq!(api.receive(id, Some(deadline)), Err(WebSocketError::DeadlineReached));
+
+		// The connection is still usable after the deadline was reached.
+		api.send(id, b"Hello", false, None).unwrap();
+		assert_eq!(api.receive(id, None), Ok(Some(b"Hello".to_vec())));
+	}
+
+	#[test]
+	fn send_rejects_invalid_text_messages() {
+		let (mut api, addr) = build_api_server();
+
+		let id = api.open(&format!("ws://{}", addr), deadline()).unwrap();
+		assert_eq!(api.send(id, &[0xff, 0xfe], false, deadline()), Err(WebSocketError::Invalid));
+	}
+
+	#[test]
+	fn open_rejects_invalid_uris() {
+		let (mut api, _worker) = websocket();
+
+		assert_eq!(api.open("http://127.0.0.1:1", None), Err(WebSocketError::Invalid));
+		assert_eq!(api.open("ws:///feed", None), Err(WebSocketError::Invalid));
+		assert_eq!(api.open("not a uri", None), Err(WebSocketError::Invalid));
+	}
+
+	#[test]
+	fn open_fails_if_the_connection_is_refused() {
+		let (mut api, _) = build_api_server();
+		// Nothing listens on the port of a dropped listener.
+		let addr = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+
+		assert_eq!(api.open(&format!("ws://{}", addr), deadline()), Err(WebSocketError::IoError));
+	}
+}
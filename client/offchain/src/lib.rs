@@ -35,7 +35,7 @@
 
 #![warn(missing_docs)]
 
-use std::{fmt, marker::PhantomData, sync::Arc};
+use std::{fmt, marker::PhantomData, path::PathBuf, sync::Arc};
 
 use futures::{
 	future::{ready, Future},
@@ -49,8 +49,10 @@ use sp_runtime::traits::{self, Header};
 use threadpool::ThreadPool;
 
 mod api;
+mod recorder;
 
 pub use api::Db as OffchainDb;
+pub use recorder::{HttpRecorder, HttpRecording};
 pub use sp_offchain::{OffchainWorkerApi, STORAGE_PREFIX};
 
 const LOG_TARGET: &str = "offchain-worker";
@@ -67,6 +69,16 @@ pub struct OffchainWorkerOptions {
 	///
 	/// If not enabled, any http request will panic.
 	pub enable_http_requests: bool,
+	/// Enable WebSocket connections from offchain workers?
+	///
+	/// If not enabled, opening a connection will panic.
+	pub enable_websockets: bool,
+	/// Record the http requests of offchain workers and save them as a fixture at the given path
+	/// when the offchain workers are dropped.
+	///
+	/// The fixture can be replayed in tests, see
+	/// [`sp_core::offchain::testing::OffchainState::replay`].
+	pub http_recording: Option<PathBuf>,
 }
 
 /// An offchain workers manager.
@@ -76,12 +88,21 @@ pub struct OffchainWorkers<Client, Block: traits::Block> {
 	thread_pool: Mutex<ThreadPool>,
 	shared_http_client: api::SharedClient,
 	enable_http: bool,
+	enable_websockets: bool,
+	http_recording: Option<(HttpRecording, PathBuf)>,
 }
 
 impl<Client, Block: traits::Block> OffchainWorkers<Client, Block> {
 	/// Creates new [`OffchainWorkers`].
 	pub fn new(client: Arc<Client>) -> Self {
-		Self::new_with_options(client, OffchainWorkerOptions { enable_http_requests: true })
+		Self::new_with_options(
+			client,
+			OffchainWorkerOptions {
+				enable_http_requests: true,
+				enable_websockets: false,
+				http_recording: None,
+			},
+		)
 	}
 
 	/// Creates new [`OffchainWorkers`] using the given `options`.
@@ -95,6 +116,27 @@ impl<Client, Block: traits::Block> OffchainWorkers<Client, Block> {
 			)),
 			shared_http_client: api::SharedClient::new(),
 			enable_http: options.enable_http_requests,
+			enable_websockets: options.enable_websockets,
+			http_recording: options.http_recording.map(|path| (HttpRecording::default(), path)),
+		}
+	}
+}
+
+impl<Client, Block: traits::Block> Drop for OffchainWorkers<Client, Block> {
+	fn drop(&mut self) {
+		let Some((recording, path)) = &self.http_recording else { return };
+		match recording.save(path) {
+			Ok(()) => tracing::info!(
+				target: LOG_TARGET,
+				"Saved recorded http requests to {}",
+				path.display()
+			),
+			Err(e) => tracing::error!(
+				target: LOG_TARGET,
+				"Failed to save recorded http requests to {}: {}",
+				path.display(),
+				e
+			),
 		}
 	}
 }
@@ -150,13 +192,19 @@ where
 			tracing::debug!(target: LOG_TARGET, "Spawning offchain workers at {:?}", hash);
 			let header = header.clone();
 			let client = self.client.clone();
+			let http_recording =
+				self.http_recording.as_ref().map(|(recording, _)| recording.clone());
 
 			let mut capabilities = offchain::Capabilities::all();
 
 			capabilities.set(offchain::Capabilities::HTTP, self.enable_http);
+			capabilities.set(offchain::Capabilities::WEBSOCKET, self.enable_websockets);
 			self.spawn_worker(move || {
 				let runtime = client.runtime_api();
-				let api = Box::new(api);
+				let api: Box<dyn offchain::Externalities> = match http_recording {
+					Some(recording) => Box::new(HttpRecorder::new(api, recording)),
+					None => Box::new(api),
+				};
 				tracing::debug!(target: LOG_TARGET, "Running offchain workers at {:?}", hash);
 
 				let context = ExecutionContext::OffchainCall(Some((api, capabilities)));
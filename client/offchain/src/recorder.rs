@@ -0,0 +1,322 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Recording of the HTTP requests made by offchain workers.
+//!
+//! The recorded requests can be saved as an [`HttpFixture`] and replayed in tests with
+//! [`sp_core::offchain::testing::OffchainState::replay`].
+
+use std::{collections::BTreeMap, io, path::Path, sync::Arc};
+
+use parking_lot::RwLock;
+use sp_core::{
+	offchain::{
+		testing::{HttpFixture, RecordedRequest},
+		Externalities, HttpError, HttpRequestId as RequestId, HttpRequestStatus as RequestStatus,
+		OpaqueNetworkState, Timestamp, WebSocketError, WebSocketId,
+	},
+	OpaquePeerId,
+};
+
+/// The requests recorded by [`HttpRecorder`]s.
+///
+/// This is a shared handle, all clones refer to the same recording.
+#[derive(Debug, Clone, Default)]
+pub struct HttpRecording(Arc<RwLock<HttpFixture>>);
+
+impl HttpRecording {
+	/// Returns the requests recorded so far.
+	pub fn fixture(&self) -> HttpFixture {
+		self.0.read().clone()
+	}
+
+	/// Store the requests recorded so far as JSON at `path`.
+	pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+		self.0.read().save(path)
+	}
+
+	fn push(&self, request: RecordedRequest) {
+		self.0.write().requests.push(request);
+	}
+}
+
+/// Offchain externalities that record all HTTP requests made through the wrapped externalities.
+///
+/// A request is added to the [`HttpRecording`] once its response body was read completely.
+pub struct HttpRecorder<E> {
+	inner: E,
+	recording: HttpRecording,
+	in_flight: BTreeMap<RequestId, RecordedRequest>,
+}
+
+impl<E> HttpRecorder<E> {
+	/// Record the HTTP requests made through `inner` into `recording`.
+	pub fn new(inner: E, recording: HttpRecording) -> Self {
+		Self { inner, recording, in_flight: BTreeMap::new() }
+	}
+}
+
+impl<E: Externalities> Externalities for HttpRecorder<E> {
+	fn is_validator(&self) -> bool {
+		self.inner.is_validator()
+	}
+
+	fn network_state(&self) -> Result<OpaqueNetworkState, ()> {
+		self.inner.network_state()
+	}
+
+	fn timestamp(&mut self) -> Timestamp {
+		self.inner.timestamp()
+	}
+
+	fn sleep_until(&mut self, deadline: Timestamp) {
+		self.inner.sleep_until(deadline)
+	}
+
+	fn random_seed(&mut self) -> [u8; 32] {
+		self.inner.random_seed()
+	}
+
+	fn http_request_start(
+		&mut self,
+		method: &str,
+		uri: &str,
+		meta: &[u8],
+	) -> Result<RequestId, ()> {
+		let id = self.inner.http_request_start(method, uri, meta)?;
+		self.in_flight.insert(
+			id,
+			RecordedRequest { method: method.into(), uri: uri.into(), ..Default::default() },
+		);
+		Ok(id)
+	}
+
+	fn http_request_add_header(
+		&mut self,
+		request_id: RequestId,
+		name: &str,
+		value: &str,
+	) -> Result<(), ()> {
+		self.inner.http_request_add_header(request_id, name, value)?;
+		if let Some(req) = self.in_flight.get_mut(&request_id) {
+			req.headers.push((name.into(), value.into()));
+		}
+		Ok(())
+	}
+
+	fn http_request_write_body(
+		&mut self,
+		request_id: RequestId,
+		chunk: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<(), HttpError> {
+		let result = self.inner.http_request_write_body(request_id, chunk, deadline);
+		match (&result, self.in_flight.get_mut(&request_id)) {
+			(Ok(()), Some(req)) => req.body.extend(chunk),
+			(Err(_), _) => {
+				self.in_flight.remove(&request_id);
+			},
+			_ => {},
+		}
+		result
+	}
+
+	fn http_response_wait(
+		&mut self,
+		ids: &[RequestId],
+		deadline: Option<Timestamp>,
+	) -> Vec<RequestStatus> {
+		let statuses = self.inner.http_response_wait(ids, deadline);
+		for (id, status) in ids.iter().zip(&statuses) {
+			match status {
+				RequestStatus::Finished(code) =>
+					if let Some(req) = self.in_flight.get_mut(id) {
+						req.response_status = *code;
+					},
+				RequestStatus::DeadlineReached => {},
+				RequestStatus::IoError | RequestStatus::Invalid => {
+					self.in_flight.remove(id);
+				},
+			}
+		}
+		statuses
+	}
+
+	fn http_response_headers(&mut self, request_id: RequestId) -> Vec<(Vec<u8>, Vec<u8>)> {
+		let headers = self.inner.http_response_headers(request_id);
+		if let Some(req) = self.in_flight.get_mut(&request_id) {
+			req.response_headers = headers
+				.iter()
+				.map(|(k, v)| {
+					(
+						String::from_utf8_lossy(k).into_owned(),
+						String::from_utf8_lossy(v).into_owned(),
+					)
+				})
+				.collect();
+		}
+		headers
+	}
+
+	fn http_response_read_body(
+		&mut self,
+		request_id: RequestId,
+		buffer: &mut [u8],
+		deadline: Option<Timestamp>,
+	) -> Result<usize, HttpError> {
+		let result = self.inner.http_response_read_body(request_id, buffer, deadline);
+		match result {
+			Ok(0) =>
+				if let Some(req) = self.in_flight.remove(&request_id) {
+					self.recording.push(req);
+				},
+			Ok(read) =>
+				if let Some(req) = self.in_flight.get_mut(&request_id) {
+					req.response_body.extend(&buffer[..read]);
+				},
+			Err(HttpError::DeadlineReached) => {},
+			Err(_) => {
+				self.in_flight.remove(&request_id);
+			},
+		}
+		result
+	}
+
+	fn http_response_read_chunk(
+		&mut self,
+		request_id: RequestId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, HttpError> {
+		let result = self.inner.http_response_read_chunk(request_id, deadline);
+		match &result {
+			Ok(None) =>
+				if let Some(req) = self.in_flight.remove(&request_id) {
+					self.recording.push(req);
+				},
+			Ok(Some(chunk)) =>
+				if let Some(req) = self.in_flight.get_mut(&request_id) {
+					req.response_body.extend(chunk);
+				},
+			Err(HttpError::DeadlineReached) => {},
+			Err(_) => {
+				self.in_flight.remove(&request_id);
+			},
+		}
+		result
+	}
+
+	fn ws_open(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WebSocketId, WebSocketError> {
+		self.inner.ws_open(uri, deadline)
+	}
+
+	fn ws_send(
+		&mut self,
+		id: WebSocketId,
+		message: &[u8],
+		binary: bool,
+		deadline: Option<Timestamp>,
+	) -> Result<(), WebSocketError> {
+		self.inner.ws_send(id, message, binary, deadline)
+	}
+
+	fn ws_receive(
+		&mut self,
+		id: WebSocketId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, WebSocketError> {
+		self.inner.ws_receive(id, deadline)
+	}
+
+	fn ws_close(&mut self, id: WebSocketId) {
+		self.inner.ws_close(id)
+	}
+
+	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
+		self.inner.set_authorized_nodes(nodes, authorized_only)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::offchain::testing::{PendingRequest, TestOffchainExt};
+
+	fn get(ext: &mut impl Externalities, uri: &str) -> (RequestStatus, Vec<u8>) {
+		let id = ext.http_request_start("GET", uri, &[]).unwrap();
+		ext.http_request_add_header(id, "Accept", "application/json").unwrap();
+		ext.http_request_write_body(id, &[], None).unwrap();
+		let status = ext.http_response_wait(&[id], None)[0];
+		ext.http_response_headers(id);
+
+		let mut body = Vec::new();
+		let mut buffer = [0u8; 4];
+		loop {
+			match ext.http_response_read_body(id, &mut buffer, None).unwrap() {
+				0 => return (status, body),
+				read => body.extend(&buffer[..read]),
+			}
+		}
+	}
+
+	#[test]
+	fn recorded_requests_can_be_replayed() {
+		let (offchain, state) = TestOffchainExt::new();
+		state.write().expect_request(PendingRequest {
+			method: "GET".into(),
+			uri: "https://example.com/price".into(),
+			headers: vec![("Accept".into(), "application/json".into())],
+			response: Some(br#"{"USD": 1}"#.to_vec()),
+			response_headers: vec![("Content-Type".into(), "application/json".into())],
+			response_status: Some(201),
+			sent: true,
+			..Default::default()
+		});
+
+		let recording = HttpRecording::default();
+		let mut recorder = HttpRecorder::new(offchain, recording.clone());
+		assert_eq!(
+			get(&mut recorder, "https://example.com/price"),
+			(RequestStatus::Finished(201), br#"{"USD": 1}"#.to_vec()),
+		);
+
+		let fixture = recording.fixture();
+		assert_eq!(
+			fixture.requests,
+			vec![RecordedRequest {
+				method: "GET".into(),
+				uri: "https://example.com/price".into(),
+				headers: vec![("Accept".into(), "application/json".into())],
+				body: vec![],
+				response_status: 201,
+				response_headers: vec![("Content-Type".into(), "application/json".into())],
+				response_body: br#"{"USD": 1}"#.to_vec(),
+			}],
+		);
+
+		let (mut offchain, state) = TestOffchainExt::new();
+		state.write().replay(fixture);
+		assert_eq!(
+			get(&mut offchain, "https://example.com/price"),
+			(RequestStatus::Finished(201), br#"{"USD": 1}"#.to_vec()),
+		);
+	}
+}
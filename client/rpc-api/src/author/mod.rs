@@ -19,7 +19,7 @@
 //! Substrate block-author/full-node API.
 
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use sc_transaction_pool_api::TransactionStatus;
+use sc_transaction_pool_api::{PendingReason, TransactionStatus};
 use sp_core::Bytes;
 
 pub mod error;
@@ -58,6 +58,12 @@ pub trait AuthorApi<Hash, BlockHash> {
 	#[method(name = "author_pendingExtrinsics")]
 	fn pending_extrinsics(&self) -> RpcResult<Vec<Bytes>>;
 
+	/// Returns why the extrinsic with given hash has not been included in a block yet.
+	///
+	/// See [`PendingReason`](sc_transaction_pool_api::PendingReason) for possible reasons.
+	#[method(name = "author_pendingReason")]
+	fn pending_reason(&self, hash: Hash) -> RpcResult<PendingReason>;
+
 	/// Remove given extrinsic from the pool and temporarily ban it to prevent reimporting.
 	#[method(name = "author_removeExtrinsic")]
 	fn remove_extrinsic(
@@ -34,8 +34,8 @@ use jsonrpsee::{
 };
 use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::{
-	error::IntoPoolError, BlockHash, InPoolTransaction, TransactionFor, TransactionPool,
-	TransactionSource, TxHash,
+	error::IntoPoolError, BlockHash, InPoolTransaction, PendingReason, TransactionFor,
+	TransactionPool, TransactionSource, TxHash,
 };
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
@@ -153,6 +153,10 @@ where
 		Ok(self.pool.ready().map(|tx| tx.data().encode().into()).collect())
 	}
 
+	fn pending_reason(&self, hash: TxHash<P>) -> RpcResult<PendingReason> {
+		Ok(self.pool.pending_reason(&hash))
+	}
+
 	fn remove_extrinsic(
 		&self,
 		bytes_or_hash: Vec<hash::ExtrinsicOrHash<TxHash<P>>>,
@@ -177,6 +177,24 @@ async fn author_should_return_pending_extrinsics() {
 	assert_eq!(pending, vec![xt_bytes]);
 }
 
+#[tokio::test]
+async fn author_should_return_pending_reason() {
+	const METHOD: &'static str = "author_pendingReason";
+	let api = TestSetup::into_rpc();
+
+	let xt1 = to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true);
+	let xt1_hash: H256 = api.call("author_submitExtrinsic", [xt1]).await.unwrap();
+	let xt2 = to_hex(&uxt(AccountKeyring::Alice, 2).encode(), true);
+	let xt2_hash: H256 = api.call("author_submitExtrinsic", [xt2]).await.unwrap();
+
+	let reason: PendingReason = api.call(METHOD, [xt1_hash]).await.unwrap();
+	assert_eq!(reason, PendingReason::Ready);
+	let reason: PendingReason = api.call(METHOD, [xt2_hash]).await.unwrap();
+	assert_matches!(reason, PendingReason::Future { missing_tags } if missing_tags.len() == 1);
+	let reason: PendingReason = api.call(METHOD, [H256::repeat_byte(1)]).await.unwrap();
+	assert_eq!(reason, PendingReason::Unknown);
+}
+
 #[tokio::test]
 async fn author_should_remove_extrinsics() {
 	const METHOD: &'static str = "author_removeExtrinsic";
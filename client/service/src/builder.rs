@@ -25,6 +25,7 @@ use crate::{
 	start_rpc_servers, BuildGenesisBlock, GenesisBlockBuilder, RpcHandlers, SpawnTaskHandle,
 	TaskManager, TransactionPoolAdapter,
 };
+use codec::{Decode, Encode};
 use futures::{channel::oneshot, future::ready, FutureExt, StreamExt};
 use jsonrpsee::RpcModule;
 use log::info;
@@ -36,7 +37,10 @@ use sc_client_api::{
 };
 use sc_client_db::{Backend, DatabaseSettings};
 use sc_consensus::import_queue::ImportQueue;
-use sc_executor::RuntimeVersionOf;
+use sc_executor::{
+	sp_wasm_interface::HostFunctions, HeapAllocStrategy, NativeElseWasmExecutor,
+	NativeExecutionDispatch, PrecompileRuntime, RuntimeVersionOf, WasmExecutor,
+};
 use sc_keystore::LocalKeystore;
 use sc_network::{config::SyncMode, NetworkService};
 use sc_network_bitswap::BitswapRequestHandler;
@@ -68,9 +72,12 @@ use sp_blockchain::{HeaderBackend, HeaderMetadata};
 use sp_consensus::block_validation::{
 	BlockAnnounceValidator, Chain, DefaultBlockAnnounceValidator,
 };
-use sp_core::traits::{CodeExecutor, SpawnNamed};
+use sp_core::{
+	storage::{well_known_keys, StorageKey},
+	traits::{CodeExecutor, RuntimeCode, SpawnNamed, WrappedRuntimeCode},
+};
 use sp_keystore::{CryptoStore, SyncCryptoStore, SyncCryptoStorePtr};
-use sp_runtime::traits::{Block as BlockT, BlockIdTo, NumberFor, Zero};
+use sp_runtime::traits::{Block as BlockT, BlockIdTo, Hash, HashFor, NumberFor, Zero};
 use std::{str::FromStr, sync::Arc, time::SystemTime};
 
 /// Full client type.
@@ -134,6 +141,24 @@ impl KeystoreContainer {
 		self.remote = Some(Box::new(remote))
 	}
 
+	/// Use the remote signer at `uri` as keystore, see [`Self::set_remote_keystore`].
+	///
+	/// The signer is only contacted once the keystore is used, so commands that never access a
+	/// key don't need the signer to be reachable.
+	#[cfg(unix)]
+	pub fn set_remote_keystore_uri(&mut self, uri: &str) -> Result<(), Error> {
+		self.set_remote_keystore(Arc::new(sc_keystore::RemoteKeystore::from_uri(uri)?));
+		Ok(())
+	}
+
+	/// Use the remote signer at `uri` as keystore.
+	///
+	/// Remote signers are only supported on Unix, so this always fails.
+	#[cfg(not(unix))]
+	pub fn set_remote_keystore_uri(&mut self, uri: &str) -> Result<(), Error> {
+		Err(Error::Other(format!("Remote keystore `{}` is only supported on Unix", uri)))
+	}
+
 	/// Returns an adapter to the asynchronous keystore that implements `CryptoStore`
 	pub fn keystore(&self) -> Arc<dyn CryptoStore> {
 		if let Some(c) = self.remote.as_ref() {
@@ -166,6 +191,111 @@ impl KeystoreContainer {
 	}
 }
 
+/// Creates a [`NativeElseWasmExecutor`] according to [`Configuration`].
+pub fn new_native_or_wasm_executor<D: NativeExecutionDispatch>(
+	config: &Configuration,
+) -> NativeElseWasmExecutor<D> {
+	NativeElseWasmExecutor::new_with_wasm_executor(new_wasm_executor(config))
+}
+
+/// Creates a [`WasmExecutor`] according to [`Configuration`].
+pub fn new_wasm_executor<H: HostFunctions>(config: &Configuration) -> WasmExecutor<H> {
+	let mut builder = WasmExecutor::<H>::builder(config.wasm_method)
+		.with_max_runtime_instances(config.max_runtime_instances)
+		.with_runtime_cache_size(config.runtime_cache_size);
+
+	if let Some(heap_pages) = config.default_heap_pages {
+		let strategy = HeapAllocStrategy::Static { extra_pages: heap_pages as _ };
+		builder = builder
+			.with_onchain_heap_alloc_strategy(strategy)
+			.with_offchain_heap_alloc_strategy(strategy);
+	}
+
+	if let Some(ref artifacts) = config.wasm_runtime_artifacts {
+		builder = builder.with_artifact_path(artifacts);
+	}
+
+	if let Some(max_memory_pages) = config.wasm_max_memory_pages {
+		builder = builder.with_max_memory_pages(max_memory_pages);
+	}
+
+	builder.build()
+}
+
+/// Compile runtime upgrades ahead of time.
+///
+/// Spawns a task that watches imported blocks for changes of `:code` and compiles the new runtime
+/// in the background, so that it is ready once the first block using it is executed. Does nothing
+/// unless [`Configuration::wasm_runtime_artifacts`] is set.
+pub fn spawn_runtime_precompilation<TBl, TBackend, TCl, TExec>(
+	config: &Configuration,
+	spawn_handle: SpawnTaskHandle,
+	client: Arc<TCl>,
+	executor: TExec,
+) -> Result<(), Error>
+where
+	TBl: BlockT,
+	TBackend: sc_client_api::Backend<TBl>,
+	TCl: BlockchainEvents<TBl> + StorageProvider<TBl, TBackend> + Send + Sync + 'static,
+	TExec: PrecompileRuntime + Clone + Send + Sync + 'static,
+{
+	if config.wasm_runtime_artifacts.is_none() {
+		return Ok(())
+	}
+
+	let code_key = StorageKey(well_known_keys::CODE.to_vec());
+	let mut code_changes = client.storage_changes_notification_stream(Some(&[code_key]), None)?;
+
+	let task_handle = spawn_handle.clone();
+	spawn_handle.spawn("runtime-precompilation", None, async move {
+		while let Some(notification) = code_changes.next().await {
+			let Some(code) = notification
+				.changes
+				.iter()
+				.find_map(|(child, key, value)| {
+					(child.is_none() && key.0 == well_known_keys::CODE).then_some(value)
+				})
+				.flatten()
+				.map(|code| code.0.clone())
+			else {
+				continue
+			};
+
+			let heap_pages = client
+				.storage(notification.block, &StorageKey(well_known_keys::HEAP_PAGES.to_vec()))
+				.ok()
+				.flatten()
+				.and_then(|data| u64::decode(&mut &data.0[..]).ok());
+
+			let executor = executor.clone();
+			task_handle.spawn_blocking("runtime-precompilation", None, async move {
+				let hash = HashFor::<TBl>::hash(&code).encode();
+				let runtime_code = RuntimeCode {
+					code_fetcher: &WrappedRuntimeCode(code.into()),
+					heap_pages,
+					hash,
+				};
+
+				match executor.precompile_runtime(&runtime_code) {
+					Ok(()) => log::debug!(
+						target: "wasm-runtime",
+						"Precompiled the runtime upgrade in block {:?}",
+						notification.block,
+					),
+					Err(e) => log::warn!(
+						target: "wasm-runtime",
+						"Failed to precompile the runtime upgrade in block {:?}: {}",
+						notification.block,
+						e,
+					),
+				}
+			});
+		}
+	});
+
+	Ok(())
+}
+
 /// Creates a new full client for the given config.
 pub fn new_full_client<TBl, TRtApi, TExec>(
 	config: &Configuration,
@@ -217,7 +347,10 @@ where
 		BlockImportOperation = <Backend<TBl> as sc_client_api::backend::Backend<TBl>>::BlockImportOperation
 	>,
 {
-	let keystore_container = KeystoreContainer::new(&config.keystore)?;
+	let mut keystore_container = KeystoreContainer::new(&config.keystore)?;
+	if let Some(uri) = &config.keystore_remote {
+		keystore_container.set_remote_keystore_uri(uri)?;
+	}
 
 	let task_manager = {
 		let registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
@@ -410,7 +543,14 @@ where
 	TCl: Send + Sync + ProvideRuntimeApi<TBl> + BlockchainEvents<TBl> + 'static,
 	<TCl as ProvideRuntimeApi<TBl>>::Api: sc_offchain::OffchainWorkerApi<TBl>,
 {
-	let offchain_workers = Some(Arc::new(sc_offchain::OffchainWorkers::new(client.clone())));
+	let offchain_workers = Some(Arc::new(sc_offchain::OffchainWorkers::new_with_options(
+		client.clone(),
+		sc_offchain::OffchainWorkerOptions {
+			enable_http_requests: true,
+			enable_websockets: config.offchain_worker.websockets_enabled,
+			http_recording: config.offchain_worker.http_recording.clone(),
+		},
+	)));
 
 	// Inform the offchain worker about new imported blocks
 	if let Some(offchain) = offchain_workers.clone() {
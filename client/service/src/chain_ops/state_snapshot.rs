@@ -0,0 +1,805 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! State snapshots used to bootstrap a node without syncing the chain.
+//!
+//! A snapshot file is laid out as follows:
+//!
+//! ```text
+//! | magic (8 bytes) | header offset (u64 LE) | chunk 0 | chunk 1 | ... | header (SCALE) |
+//! ```
+//!
+//! Every chunk is a zstd compressed sequence of SCALE encoded [`SnapshotEntry`]s. The
+//! [`SnapshotHeader`] is written last, so that the snapshot can be exported in a single pass over
+//! the state, and contains the block the state belongs to together with the hash of every chunk.
+//!
+//! The entries of the default child tries come first, grouped by child trie in the order of their
+//! keys, followed by the entries of the top trie. Within a trie, entries are ordered by key. This
+//! allows to check the state root of a snapshot while streaming over its entries.
+
+use crate::error::Error;
+use codec::{Decode, Encode};
+use sc_client_api::{AuxStore, BlockBackend, HeaderBackend, StorageProvider, UsageProvider};
+use sc_consensus::{
+	BlockImport, BlockImportParams, ForkChoiceStrategy, ImportResult, ImportedState, StateAction,
+	StorageChanges,
+};
+use sp_api::CallApiAt;
+use sp_consensus::BlockOrigin;
+use sp_core::{
+	hashing::blake2_256,
+	storage::{well_known_keys, ChildInfo, StorageKey},
+};
+use sp_runtime::{
+	traits::{Block as BlockT, HashFor, Header as HeaderT, Zero},
+	Justifications, StateVersion,
+};
+use sp_state_machine::{KeyValueStates, KeyValueStorageLevel};
+use sp_trie::{trie_visit, LayoutV0, LayoutV1, TrieConfiguration, TrieHash, TrieRoot};
+use std::{
+	io::{Read, Seek, SeekFrom, Write},
+	iter, slice,
+	sync::Arc,
+};
+
+/// Magic bytes every snapshot file starts with.
+pub const SNAPSHOT_MAGIC: [u8; 8] = *b"substsnp";
+
+/// Version of the snapshot format written by this module.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Default amount of uncompressed entry bytes stored in one chunk.
+pub const DEFAULT_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Chunks bigger than this once decompressed are rejected when reading a snapshot.
+pub const MAX_CHUNK_SIZE: usize = 64 * 1024 * 1024;
+
+/// The zstd compression level used for chunks.
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// The size of the magic bytes and the header offset at the start of the file.
+const PREAMBLE_SIZE: u64 = SNAPSHOT_MAGIC.len() as u64 + 8;
+
+/// A single key value pair of the state.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotEntry {
+	/// Unprefixed key of the default child trie the entry belongs to, `None` for the top trie.
+	pub child: Option<Vec<u8>>,
+	/// The storage key.
+	pub key: Vec<u8>,
+	/// The storage value.
+	pub value: Vec<u8>,
+}
+
+/// Description of a chunk stored in a snapshot.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub struct ChunkInfo {
+	/// Blake2-256 hash of the compressed chunk.
+	pub hash: [u8; 32],
+	/// Length of the compressed chunk in the file.
+	pub compressed_len: u64,
+	/// Length of the chunk once decompressed.
+	pub uncompressed_len: u64,
+	/// Number of entries in the chunk.
+	pub entries: u32,
+}
+
+/// The header of a snapshot.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotHeader<B: BlockT> {
+	/// Version of the snapshot format.
+	pub version: u32,
+	/// Hash of the block the state belongs to.
+	pub block_hash: B::Hash,
+	/// State root of the block.
+	pub state_root: B::Hash,
+	/// Header of the block.
+	pub header: B::Header,
+	/// Justifications of the block, if any.
+	pub justifications: Option<Justifications>,
+	/// State version used to build the state trie.
+	pub state_version: StateVersion,
+	/// Consensus data written to the aux store of the importing node, so that it can continue
+	/// from the block of the snapshot.
+	pub aux: Vec<(Vec<u8>, Vec<u8>)>,
+	/// The chunks in the order they are stored in the file.
+	pub chunks: Vec<ChunkInfo>,
+}
+
+impl<B: BlockT> SnapshotHeader<B> {
+	/// Total number of state entries in the snapshot.
+	pub fn entries(&self) -> u64 {
+		self.chunks.iter().map(|chunk| chunk.entries as u64).sum()
+	}
+}
+
+/// Writes state entries into chunks of a snapshot.
+struct SnapshotWriter<W> {
+	output: W,
+	chunk_size: usize,
+	buffer: Vec<u8>,
+	buffered_entries: u32,
+	chunks: Vec<ChunkInfo>,
+}
+
+impl<W: Write + Seek> SnapshotWriter<W> {
+	fn new(mut output: W, chunk_size: usize) -> Result<Self, Error> {
+		if chunk_size == 0 || chunk_size > MAX_CHUNK_SIZE {
+			return Err(Error::Other(format!(
+				"Snapshot chunk size must be between 1 and {} bytes",
+				MAX_CHUNK_SIZE
+			)))
+		}
+
+		output.write_all(&SNAPSHOT_MAGIC)?;
+		// Placeholder for the header offset, written once all chunks are known.
+		output.write_all(&0u64.to_le_bytes())?;
+
+		Ok(Self { output, chunk_size, buffer: Vec::new(), buffered_entries: 0, chunks: Vec::new() })
+	}
+
+	fn push(&mut self, entry: &SnapshotEntry) -> Result<(), Error> {
+		if !self.buffer.is_empty() && self.buffer.len() + entry.size_hint() > self.chunk_size {
+			self.flush_chunk()?;
+		}
+		entry.encode_to(&mut self.buffer);
+		self.buffered_entries += 1;
+		Ok(())
+	}
+
+	fn flush_chunk(&mut self) -> Result<(), Error> {
+		if self.buffer.is_empty() {
+			return Ok(())
+		}
+
+		let compressed = zstd::encode_all(&self.buffer[..], COMPRESSION_LEVEL)?;
+		self.output.write_all(&compressed)?;
+		self.chunks.push(ChunkInfo {
+			hash: blake2_256(&compressed),
+			compressed_len: compressed.len() as u64,
+			uncompressed_len: self.buffer.len() as u64,
+			entries: self.buffered_entries,
+		});
+		self.buffer.clear();
+		self.buffered_entries = 0;
+		Ok(())
+	}
+
+	fn finish<B: BlockT>(
+		mut self,
+		header: B::Header,
+		justifications: Option<Justifications>,
+		state_version: StateVersion,
+		aux: Vec<(Vec<u8>, Vec<u8>)>,
+	) -> Result<SnapshotHeader<B>, Error> {
+		self.flush_chunk()?;
+
+		let header = SnapshotHeader::<B> {
+			version: SNAPSHOT_VERSION,
+			block_hash: header.hash(),
+			state_root: *header.state_root(),
+			header,
+			justifications,
+			state_version,
+			aux,
+			chunks: self.chunks,
+		};
+		let header_offset = self.output.stream_position()?;
+		self.output.write_all(&header.encode())?;
+		self.output.seek(SeekFrom::Start(SNAPSHOT_MAGIC.len() as u64))?;
+		self.output.write_all(&header_offset.to_le_bytes())?;
+		self.output.flush()?;
+
+		Ok(header)
+	}
+}
+
+/// Export the state of the block with the given `hash` as a snapshot into `output`.
+///
+/// Entries are grouped into chunks holding roughly `chunk_size` bytes before compression. The
+/// consensus `aux` data is stored in the header and written as is by [`import_state_snapshot`].
+pub fn export_state_snapshot<B, BA, C, W>(
+	client: Arc<C>,
+	hash: B::Hash,
+	chunk_size: usize,
+	aux: Vec<(Vec<u8>, Vec<u8>)>,
+	output: W,
+) -> Result<SnapshotHeader<B>, Error>
+where
+	B: BlockT,
+	BA: sc_client_api::backend::Backend<B>,
+	C: UsageProvider<B>
+		+ StorageProvider<B, BA>
+		+ HeaderBackend<B>
+		+ BlockBackend<B>
+		+ CallApiAt<B>,
+	W: Write + Seek,
+{
+	let header = client
+		.header(hash)?
+		.ok_or_else(|| Error::Other(format!("Header for block {:?} not found", hash)))?;
+	let justifications = client.justifications(hash)?;
+	let state_version = client
+		.runtime_version_at(hash)
+		.map_err(|e| Error::Other(format!("Failed to get the runtime version: {}", e)))?
+		.state_version();
+
+	let mut writer = SnapshotWriter::new(output, chunk_size)?;
+	let child_prefix = StorageKey(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX.to_vec());
+	for child_key in client.storage_keys(hash, Some(&child_prefix), None)? {
+		let child = child_key.0[child_prefix.0.len()..].to_vec();
+		let child_info = ChildInfo::new_default(&child);
+		for key in client.child_storage_keys(hash, child_info.clone(), None, None)? {
+			if let Some(value) = client.child_storage(hash, &child_info, &key)? {
+				writer.push(&SnapshotEntry {
+					child: Some(child.clone()),
+					key: key.0,
+					value: value.0,
+				})?;
+			}
+		}
+	}
+
+	for (key, value) in client.storage_pairs(hash, None, None)? {
+		// Child trie roots are rebuilt on import, only the child trie content is exported.
+		if key.0.starts_with(&child_prefix.0) {
+			continue
+		}
+
+		writer.push(&SnapshotEntry { child: None, key: key.0, value: value.0 })?;
+	}
+
+	writer.finish(header, justifications, state_version, aux)
+}
+
+/// Read the header of the snapshot in `input`, without reading or checking any chunk.
+pub fn read_state_snapshot_header<B, R>(input: &mut R) -> Result<SnapshotHeader<B>, Error>
+where
+	B: BlockT,
+	R: Read + Seek,
+{
+	input.seek(SeekFrom::Start(0))?;
+	let mut magic = [0u8; SNAPSHOT_MAGIC.len()];
+	input.read_exact(&mut magic)?;
+	if magic != SNAPSHOT_MAGIC {
+		return Err(Error::Other("Input is not a state snapshot".into()))
+	}
+
+	let mut offset = [0u8; 8];
+	input.read_exact(&mut offset)?;
+	let offset = u64::from_le_bytes(offset);
+	if offset < PREAMBLE_SIZE {
+		return Err(Error::Other("Snapshot is incomplete, the header is missing".into()))
+	}
+
+	input.seek(SeekFrom::Start(offset))?;
+	let mut encoded = Vec::new();
+	input.read_to_end(&mut encoded)?;
+	let header = SnapshotHeader::<B>::decode(&mut &encoded[..])
+		.map_err(|e| Error::Other(format!("Failed to decode the snapshot header: {}", e)))?;
+
+	if header.version != SNAPSHOT_VERSION {
+		return Err(Error::Other(format!(
+			"Unsupported snapshot version {}, expected {}",
+			header.version, SNAPSHOT_VERSION
+		)))
+	}
+	if header.header.hash() != header.block_hash || *header.header.state_root() != header.state_root
+	{
+		return Err(Error::Other("Snapshot header does not match the block header".into()))
+	}
+	let chunks_len = header.chunks.iter().map(|chunk| chunk.compressed_len).sum::<u64>();
+	if PREAMBLE_SIZE.checked_add(chunks_len) != Some(offset) {
+		return Err(Error::Other("Snapshot chunks do not match the header".into()))
+	}
+
+	Ok(header)
+}
+
+/// Read the snapshot in `input`, handing every entry over to `f`.
+///
+/// The hash of every chunk is checked and the state trie is rebuilt from the entries to make sure
+/// it matches the state root of the block. Only one chunk is kept in memory at a time, `f` may
+/// be called before an error is detected in a later part of the snapshot.
+pub fn read_state_snapshot<B, R, F>(input: R, f: F) -> Result<SnapshotHeader<B>, Error>
+where
+	B: BlockT,
+	R: Read + Seek,
+	F: FnMut(&SnapshotEntry),
+{
+	read_snapshot_entries::<B, _, _>(input, f).map(|(header, _)| header)
+}
+
+/// Like [`read_state_snapshot`], but also returns the prefixed storage key and the root of every
+/// child trie, in the order their entries are stored in.
+fn read_snapshot_entries<B, R, F>(
+	mut input: R,
+	f: F,
+) -> Result<(SnapshotHeader<B>, Vec<(Vec<u8>, B::Hash)>), Error>
+where
+	B: BlockT,
+	R: Read + Seek,
+	F: FnMut(&SnapshotEntry),
+{
+	let header = read_state_snapshot_header::<B, _>(&mut input)?;
+	input.seek(SeekFrom::Start(PREAMBLE_SIZE))?;
+
+	let (state_root, child_roots) = {
+		let mut entries = SnapshotEntries {
+			input,
+			chunks: header.chunks.iter().enumerate(),
+			current: Vec::new().into_iter(),
+			error: None,
+		};
+		let roots = match header.state_version {
+			StateVersion::V0 => visit_entries::<LayoutV0<HashFor<B>>, _, _>(&mut entries, f)?,
+			StateVersion::V1 => visit_entries::<LayoutV1<HashFor<B>>, _, _>(&mut entries, f)?,
+		};
+		if let Some(e) = entries.error {
+			return Err(e)
+		}
+		roots
+	};
+	if state_root != header.state_root {
+		return Err(Error::Other(format!(
+			"Snapshot state root mismatch: expected {:?}, got {:?}",
+			header.state_root, state_root
+		)))
+	}
+
+	Ok((header, child_roots))
+}
+
+/// Import the snapshot in `input` into a database that contains nothing but the genesis block.
+///
+/// The block of the snapshot is imported as finalized best block together with its state.
+pub async fn import_state_snapshot<B, C, I, R>(
+	client: Arc<C>,
+	mut block_import: I,
+	input: R,
+) -> Result<SnapshotHeader<B>, Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + AuxStore,
+	I: BlockImport<B>,
+	R: Read + Seek,
+{
+	let info = client.info();
+	if !info.best_number.is_zero() {
+		return Err(Error::Other(format!(
+			"State snapshots can only be imported into an empty database, best block is #{}",
+			info.best_number
+		)))
+	}
+
+	// The entries arrive in the order of the levels of the imported state, so every level is
+	// built up in place.
+	let mut state = vec![KeyValueStorageLevel {
+		state_root: Vec::new(),
+		parent_storage_keys: Vec::new(),
+		key_values: Vec::new(),
+	}];
+	let mut current_child = None;
+	let (header, child_roots) = read_snapshot_entries::<B, _, _>(input, |entry| {
+		if entry.child.is_some() && entry.child != current_child {
+			current_child = entry.child.clone();
+			state.push(KeyValueStorageLevel {
+				state_root: Vec::new(),
+				parent_storage_keys: Vec::new(),
+				key_values: Vec::new(),
+			});
+		}
+		let level = if entry.child.is_some() { state.len() - 1 } else { 0 };
+		state[level].key_values.push((entry.key.clone(), entry.value.clone()));
+	})?;
+	for (level, (prefixed_key, root)) in state[1..].iter_mut().zip(child_roots) {
+		level.state_root = root.as_ref().to_vec();
+		level.parent_storage_keys = vec![prefixed_key];
+	}
+
+	let mut params = BlockImportParams::new(BlockOrigin::File, header.header.clone());
+	params.justifications = header.justifications.clone();
+	params.post_hash = Some(header.block_hash);
+	params.finalized = true;
+	params.fork_choice = Some(ForkChoiceStrategy::Custom(true));
+	params.state_action = StateAction::ApplyChanges(StorageChanges::Import(ImportedState {
+		block: header.block_hash,
+		state: KeyValueStates(state),
+	}));
+
+	match block_import.import_block(params, Default::default()).await {
+		Ok(ImportResult::Imported(_)) => (),
+		Ok(result) =>
+			return Err(Error::Other(format!(
+				"Failed to import snapshot block {:?}: {:?}",
+				header.block_hash, result
+			))),
+		Err(e) =>
+			return Err(Error::Other(format!(
+				"Failed to import snapshot block {:?}: {}",
+				header.block_hash, e
+			))),
+	}
+
+	// Written after the import, as importing the state resets the consensus data of some block
+	// imports to what can be derived from the state alone.
+	let aux = header.aux.iter().map(|(key, value)| (&key[..], &value[..])).collect::<Vec<_>>();
+	client.insert_aux(&aux, &[])?;
+
+	Ok(header)
+}
+
+fn read_chunk<R: Read>(input: &mut R, chunk: &ChunkInfo) -> Result<Vec<SnapshotEntry>, Error> {
+	if chunk.uncompressed_len > MAX_CHUNK_SIZE as u64 ||
+		chunk.compressed_len > MAX_CHUNK_SIZE as u64
+	{
+		return Err(Error::Other("chunk is too big".into()))
+	}
+
+	let mut compressed = vec![0u8; chunk.compressed_len as usize];
+	input.read_exact(&mut compressed)?;
+	if blake2_256(&compressed) != chunk.hash {
+		return Err(Error::Other("hash mismatch".into()))
+	}
+
+	let mut data = Vec::with_capacity(chunk.uncompressed_len as usize);
+	zstd::Decoder::new(&compressed[..])?
+		.take(chunk.uncompressed_len + 1)
+		.read_to_end(&mut data)?;
+	if data.len() as u64 != chunk.uncompressed_len {
+		return Err(Error::Other("unexpected length".into()))
+	}
+
+	let mut data = &data[..];
+	let entries = (0..chunk.entries)
+		.map(|_| SnapshotEntry::decode(&mut data))
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|e| Error::Other(format!("failed to decode entry: {}", e)))?;
+	if !data.is_empty() {
+		return Err(Error::Other("trailing data".into()))
+	}
+
+	Ok(entries)
+}
+
+/// Iterator over the entries of a snapshot, reading one chunk at a time.
+///
+/// Iteration ends at the first invalid chunk, the reason is kept in `error`.
+struct SnapshotEntries<'a, R> {
+	input: R,
+	chunks: iter::Enumerate<slice::Iter<'a, ChunkInfo>>,
+	current: std::vec::IntoIter<SnapshotEntry>,
+	error: Option<Error>,
+}
+
+impl<'a, R: Read> Iterator for SnapshotEntries<'a, R> {
+	type Item = SnapshotEntry;
+
+	fn next(&mut self) -> Option<SnapshotEntry> {
+		loop {
+			if let Some(entry) = self.current.next() {
+				return Some(entry)
+			}
+			if self.error.is_some() {
+				return None
+			}
+
+			let (index, chunk) = self.chunks.next()?;
+			match read_chunk(&mut self.input, chunk) {
+				Ok(entries) => self.current = entries.into_iter(),
+				Err(Error::Other(e)) => {
+					self.error =
+						Some(Error::Other(format!("Invalid snapshot chunk {}: {}", index, e)));
+				},
+				Err(e) => self.error = Some(e),
+			}
+		}
+	}
+}
+
+/// Hand the `entries` over to `f` while rebuilding the state trie from them.
+///
+/// Returns the state root, together with the prefixed storage key and the root of every child
+/// trie. Entries that are not in snapshot order are rejected.
+fn visit_entries<L, R, F>(
+	entries: &mut SnapshotEntries<R>,
+	mut f: F,
+) -> Result<(TrieHash<L>, Vec<(Vec<u8>, TrieHash<L>)>), Error>
+where
+	L: TrieConfiguration,
+	R: Read,
+	F: FnMut(&SnapshotEntry),
+{
+	let mut entries = entries.peekable();
+	let mut last = None;
+	let mut error = None;
+	let mut next_entry = |entry: SnapshotEntry| -> Result<_, Error> {
+		check_entry_order(&mut last, &entry)?;
+		f(&entry);
+		Ok((entry.key, entry.value))
+	};
+
+	let mut child_roots = Vec::new();
+	while let Some(child) = entries.peek().and_then(|entry| entry.child.clone()) {
+		let mut root = TrieRoot::<L>::default();
+		trie_visit::<L, _, _, _, _>(
+			iter::from_fn(|| {
+				let entry = entries.next_if(|entry| entry.child.as_ref() == Some(&child))?;
+				next_entry(entry).map_err(|e| error = Some(e)).ok()
+			}),
+			&mut root,
+		);
+		if let Some(e) = error.take() {
+			return Err(e)
+		}
+		child_roots.push((
+			ChildInfo::new_default(&child).prefixed_storage_key().into_inner(),
+			root.root.unwrap_or_default(),
+		));
+	}
+
+	// The child trie roots are merged into the top trie entries in key order.
+	let mut roots = child_roots.iter().peekable();
+	let mut root = TrieRoot::<L>::default();
+	trie_visit::<L, _, _, _, _>(
+		iter::from_fn(|| {
+			let next_is_root = match (entries.peek(), roots.peek()) {
+				(Some(entry), Some((key, _))) => *key < entry.key,
+				(None, next_root) => next_root.is_some(),
+				(Some(_), None) => false,
+			};
+			if next_is_root {
+				roots.next().map(|(key, root)| (key.clone(), root.as_ref().to_vec()))
+			} else {
+				next_entry(entries.next()?).map_err(|e| error = Some(e)).ok()
+			}
+		}),
+		&mut root,
+	);
+	if let Some(e) = error {
+		return Err(e)
+	}
+
+	Ok((root.root.unwrap_or_default(), child_roots))
+}
+
+/// Check that `entry` comes after the `last` entry in snapshot order, and update `last`.
+fn check_entry_order(
+	last: &mut Option<(Option<Vec<u8>>, Vec<u8>)>,
+	entry: &SnapshotEntry,
+) -> Result<(), Error> {
+	if entry.child.is_none() &&
+		entry.key.starts_with(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX)
+	{
+		return Err(Error::Other("Snapshot contains a child trie root".into()))
+	}
+
+	// Child trie entries sort before the top trie entries.
+	let in_order = last.as_ref().map_or(true, |(child, key)| {
+		(child.is_none(), child, key) < (entry.child.is_none(), &entry.child, &entry.key)
+	});
+	if !in_order {
+		return Err(Error::Other("Snapshot entries are not in order".into()))
+	}
+
+	*last = Some((entry.child.clone(), entry.key.clone()));
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::storage::{Storage, StorageChild};
+	use sp_runtime::{
+		testing::{Block as RawBlock, ExtrinsicWrapper, Header},
+		traits::BlakeTwo256,
+	};
+	use std::{collections::BTreeMap, io::Cursor};
+
+	type Block = RawBlock<ExtrinsicWrapper<u64>>;
+
+	fn test_storage() -> Storage {
+		let mut storage = Storage::default();
+		for i in 0u32..200 {
+			storage.top.insert(i.to_le_bytes().to_vec(), vec![i as u8; i as usize]);
+		}
+		for name in [&b"child"[..], b"other_child"] {
+			let child_info = ChildInfo::new_default(name);
+			let mut child = StorageChild { data: Default::default(), child_info };
+			child.data.insert(b"key".to_vec(), name.to_vec());
+			child.data.insert(b"other_key".to_vec(), b"value".to_vec());
+			storage.children_default.insert(name.to_vec(), child);
+		}
+		storage
+	}
+
+	fn state_root(storage: &Storage) -> <Block as BlockT>::Hash {
+		let child_roots = storage
+			.children_default
+			.values()
+			.map(|child| {
+				let root = LayoutV1::<BlakeTwo256>::trie_root(&child.data);
+				(child.child_info.prefixed_storage_key().into_inner(), root)
+			})
+			.collect::<Vec<_>>();
+
+		let mut top = storage
+			.top
+			.iter()
+			.map(|(key, value)| (&key[..], &value[..]))
+			.collect::<BTreeMap<_, _>>();
+		top.extend(child_roots.iter().map(|(key, root)| (&key[..], root.as_ref())));
+
+		LayoutV1::<BlakeTwo256>::trie_root(top)
+	}
+
+	fn snapshot_entries(storage: &Storage) -> Vec<SnapshotEntry> {
+		let children = storage.children_default.iter().flat_map(|(child, content)| {
+			content.data.iter().map(|(key, value)| SnapshotEntry {
+				child: Some(child.clone()),
+				key: key.clone(),
+				value: value.clone(),
+			})
+		});
+		let top = storage.top.iter().map(|(key, value)| SnapshotEntry {
+			child: None,
+			key: key.clone(),
+			value: value.clone(),
+		});
+		children.chain(top).collect()
+	}
+
+	fn write_snapshot(
+		storage: &Storage,
+		entries: &[SnapshotEntry],
+		chunk_size: usize,
+	) -> (SnapshotHeader<Block>, Vec<u8>) {
+		let header = Header::new(
+			1,
+			Default::default(),
+			state_root(storage),
+			Default::default(),
+			Default::default(),
+		);
+
+		let mut output = Cursor::new(Vec::new());
+		let mut writer = SnapshotWriter::new(&mut output, chunk_size).unwrap();
+		for entry in entries {
+			writer.push(entry).unwrap();
+		}
+		let aux = vec![(b"aux_key".to_vec(), b"aux_value".to_vec())];
+		let header = writer.finish::<Block>(header, None, StateVersion::V1, aux).unwrap();
+
+		(header, output.into_inner())
+	}
+
+	fn read_entries(snapshot: Vec<u8>) -> Result<Vec<SnapshotEntry>, Error> {
+		let mut entries = Vec::new();
+		read_state_snapshot::<Block, _, _>(Cursor::new(snapshot), |entry| {
+			entries.push(entry.clone())
+		})?;
+		Ok(entries)
+	}
+
+	#[test]
+	fn snapshot_roundtrip() {
+		let storage = test_storage();
+		let entries = snapshot_entries(&storage);
+		let (header, snapshot) = write_snapshot(&storage, &entries, 1024);
+		assert!(header.chunks.len() > 1);
+		assert_eq!(header.entries(), 204);
+
+		let mut read_entries = Vec::new();
+		let (read_header, child_roots) =
+			read_snapshot_entries::<Block, _, _>(Cursor::new(snapshot), |entry| {
+				read_entries.push(entry.clone())
+			})
+			.unwrap();
+		assert_eq!(read_header, header);
+		assert_eq!(read_entries, entries);
+		assert_eq!(
+			child_roots,
+			storage
+				.children_default
+				.values()
+				.map(|child| (
+					child.child_info.prefixed_storage_key().into_inner(),
+					LayoutV1::<BlakeTwo256>::trie_root(&child.data)
+				))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn unordered_entries_are_rejected() {
+		let storage = test_storage();
+		let entries = snapshot_entries(&storage);
+
+		// Top trie entries before the child trie entries.
+		let mut top_first = entries[4..].to_vec();
+		top_first.extend_from_slice(&entries[..4]);
+		let (_, snapshot) = write_snapshot(&storage, &top_first, 1024);
+		let err = read_entries(snapshot).unwrap_err();
+		assert!(err.to_string().contains("Snapshot entries are not in order"));
+
+		// Unordered keys within a trie.
+		let mut swapped = entries.clone();
+		swapped.swap(10, 11);
+		let (_, snapshot) = write_snapshot(&storage, &swapped, 1024);
+		let err = read_entries(snapshot).unwrap_err();
+		assert!(err.to_string().contains("Snapshot entries are not in order"));
+
+		// Duplicated entries.
+		let mut duplicated = entries.clone();
+		duplicated.insert(10, entries[10].clone());
+		let (_, snapshot) = write_snapshot(&storage, &duplicated, 1024);
+		let err = read_entries(snapshot).unwrap_err();
+		assert!(err.to_string().contains("Snapshot entries are not in order"));
+
+		// Child trie roots are not part of the snapshot.
+		let mut with_root = entries.clone();
+		with_root.push(SnapshotEntry {
+			child: None,
+			key: ChildInfo::new_default(b"child").prefixed_storage_key().into_inner(),
+			value: vec![0; 32],
+		});
+		let (_, snapshot) = write_snapshot(&storage, &with_root, 1024);
+		let err = read_entries(snapshot).unwrap_err();
+		assert!(err.to_string().contains("Snapshot contains a child trie root"));
+	}
+
+	#[test]
+	fn corrupted_chunk_is_rejected() {
+		let storage = test_storage();
+		let (_, mut snapshot) = write_snapshot(&storage, &snapshot_entries(&storage), 1024);
+		snapshot[PREAMBLE_SIZE as usize + 1] ^= 1;
+
+		let err = read_entries(snapshot).unwrap_err();
+		assert!(err.to_string().contains("Invalid snapshot chunk 0: hash mismatch"));
+	}
+
+	#[test]
+	fn state_root_mismatch_is_rejected() {
+		let storage = test_storage();
+		let (mut header, snapshot) =
+			write_snapshot(&storage, &snapshot_entries(&storage), DEFAULT_CHUNK_SIZE);
+		let offset = PREAMBLE_SIZE + header.chunks.iter().map(|c| c.compressed_len).sum::<u64>();
+
+		// Replace the header with one claiming a different state root.
+		header.header.set_state_root(Default::default());
+		header.state_root = Default::default();
+		header.block_hash = header.header.hash();
+		let mut tampered = snapshot[..offset as usize].to_vec();
+		tampered.extend(header.encode());
+
+		let err = read_entries(tampered).unwrap_err();
+		assert!(err.to_string().contains("Snapshot state root mismatch"));
+	}
+
+	#[test]
+	fn incomplete_snapshot_is_rejected() {
+		let storage = test_storage();
+		let (_, snapshot) =
+			write_snapshot(&storage, &snapshot_entries(&storage), DEFAULT_CHUNK_SIZE);
+		let mut incomplete = snapshot.clone();
+		incomplete[SNAPSHOT_MAGIC.len()..PREAMBLE_SIZE as usize].copy_from_slice(&[0; 8]);
+
+		assert!(read_entries(incomplete).is_err());
+		assert!(read_entries(snapshot[1..].to_vec()).is_err());
+	}
+}
@@ -868,11 +868,26 @@ where
 				let runtime_api = self.runtime_api();
 				let execution_context = import_block.origin.into();
 
-				runtime_api.execute_block_with_context(
+				if let Err(e) = runtime_api.execute_block_with_context(
 					*parent_hash,
 					execution_context,
 					Block::new(import_block.header.clone(), body.clone()),
-				)?;
+				) {
+					let e = Error::from(e);
+					// Exhausting the stack or memory is deterministic, every node executing the
+					// block fails the same way.
+					if e.execution_error::<sc_executor::error::Error>()
+						.map_or(false, |e| e.is_resource_exhausted())
+					{
+						warn!(
+							"Block {:?} exhausted the stack or memory of the runtime: {}",
+							import_block.post_hash(),
+							e,
+						);
+						return Ok(PrepareStorageChangesResult::Discard(ImportResult::KnownBad))
+					}
+					return Err(e)
+				}
 
 				let state = self.backend.state_at(*parent_hash)?;
 				let gen_storage_changes = runtime_api
@@ -84,6 +84,13 @@ pub struct Configuration {
 	/// over on-chain runtimes when the spec version matches. Set to `None` to
 	/// disable overrides (default).
 	pub wasm_runtime_overrides: Option<PathBuf>,
+	/// Directory of ahead-of-time compiled runtimes. Runtimes are loaded from there instead of
+	/// being compiled and runtime upgrades are compiled into it in the background. Set to `None`
+	/// to disable precompiled runtimes (default).
+	pub wasm_runtime_artifacts: Option<PathBuf>,
+	/// The maximum number of pages the linear memory of the runtime may have. Set to `None` to
+	/// not limit the memory beyond the heap allocation strategy (default).
+	pub wasm_max_memory_pages: Option<u32>,
 	/// Execution strategies.
 	pub execution_strategies: ExecutionStrategies,
 	/// RPC over HTTP binding address. `None` if disabled.
@@ -190,6 +197,10 @@ pub struct OffchainWorkerConfig {
 	pub enabled: bool,
 	/// allow writes from the runtime to the offchain worker database.
 	pub indexing_enabled: bool,
+	/// allow offchain workers to open WebSocket connections.
+	pub websockets_enabled: bool,
+	/// record the http requests of offchain workers and save them to this file on shutdown.
+	pub http_recording: Option<PathBuf>,
 }
 
 /// Configuration of the Prometheus endpoint.
@@ -58,8 +58,9 @@ use sp_runtime::{
 pub use self::{
 	builder::{
 		build_network, build_offchain_workers, new_client, new_db_backend, new_full_client,
-		new_full_parts, spawn_tasks, BuildNetworkParams, KeystoreContainer, NetworkStarter,
-		SpawnTasksParams, TFullBackend, TFullCallExecutor, TFullClient,
+		new_full_parts, new_native_or_wasm_executor, new_wasm_executor,
+		spawn_runtime_precompilation, spawn_tasks, BuildNetworkParams, KeystoreContainer,
+		NetworkStarter, SpawnTasksParams, TFullBackend, TFullCallExecutor, TFullClient,
 	},
 	client::{
 		genesis::{BuildGenesisBlock, GenesisBlockBuilder},
@@ -76,7 +77,7 @@ pub use sc_chain_spec::{
 };
 
 pub use sc_consensus::ImportQueue;
-pub use sc_executor::NativeExecutionDispatch;
+pub use sc_executor::{NativeExecutionDispatch, PrecompileRuntime};
 pub use sc_network_common::sync::warp::WarpSyncParams;
 #[doc(hidden)]
 pub use sc_network_transactions::config::{TransactionImport, TransactionImportFuture};
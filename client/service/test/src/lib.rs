@@ -238,6 +238,8 @@ fn node_config<
 		chain_spec: Box::new((*spec).clone()),
 		wasm_method: sc_service::config::WasmExecutionMethod::Interpreted,
 		wasm_runtime_overrides: Default::default(),
+		wasm_runtime_artifacts: Default::default(),
+		wasm_max_memory_pages: Default::default(),
 		execution_strategies: Default::default(),
 		rpc_http: None,
 		rpc_ipc: None,
@@ -686,6 +686,37 @@ fn fetch_stored_pruning_mode<D: MetaDb>(db: &D) -> Result<Option<PruningMode>, E
 	}
 }
 
+/// Apply `f` to every node key referenced by the state-db journal entry stored under the meta
+/// key `meta_key`.
+///
+/// Returns the re-encoded journal entry, or `None` if `meta_key` is not a journal key and the
+/// value may be kept as is. This is used when moving a database to a backend that stores trie
+/// nodes under different keys.
+pub fn map_journal_keys<BlockHash: Hash, Key: Hash>(
+	meta_key: &[u8],
+	value: &[u8],
+	mut f: impl FnMut(&mut Key),
+) -> Result<Option<Vec<u8>>, codec::Error> {
+	if let Some(record) = noncanonical::map_journal_keys::<BlockHash, Key>(meta_key, value, &mut f)?
+	{
+		return Ok(Some(record))
+	}
+	pruning::map_journal_keys::<BlockHash, Key>(meta_key, value, &mut f)
+}
+
+/// Count the references a reference counting database needs to hold for every key referenced by
+/// the pruning journals among the meta column entries `meta`.
+///
+/// This is used when moving a database without reference counting to one with reference
+/// counting: pruning the blocks of the pruning window then releases every key at the same block
+/// at which it would have been deleted from the original database. Keys that are not part of the
+/// result need a single reference.
+pub fn pruning_window_references<BlockHash: Hash, Key: Hash>(
+	meta: impl IntoIterator<Item = (Vec<u8>, Vec<u8>)>,
+) -> Result<HashMap<Key, u32>, codec::Error> {
+	pruning::window_references::<BlockHash, Key>(meta)
+}
+
 fn choose_pruning_mode(
 	stored: PruningMode,
 	requested: PruningMode,
@@ -703,6 +734,7 @@ fn choose_pruning_mode(
 #[cfg(test)]
 mod tests {
 	use crate::{
+		map_journal_keys,
 		test::{make_changeset, make_db, TestDb},
 		Constraints, Error, IsPruned, PruningMode, StateDb, StateDbError,
 	};
@@ -944,4 +976,27 @@ mod tests {
 			check_stored_and_requested_mode_compatibility(created, reopened, expected);
 		}
 	}
+
+	#[test]
+	fn map_journal_keys_rewrites_all_journals() {
+		let (db, _) = make_test_db(PruningMode::blocks_pruning(2));
+		let replacement = H256::repeat_byte(0xff);
+
+		let mut journals = 0;
+		for (key, value) in db.meta_entries() {
+			let Some(mapped) =
+				map_journal_keys::<H256, H256>(&key, &value, |k| *k = replacement).unwrap()
+			else {
+				continue
+			};
+			journals += 1;
+			assert_eq!(
+				map_journal_keys::<H256, H256>(&key, &value, |_| ()).unwrap(),
+				Some(value.clone()),
+			);
+			map_journal_keys::<H256, H256>(&key, &mapped, |k| assert_eq!(*k, replacement)).unwrap();
+		}
+		// Two blocks in the pruning window and one in the non-canonical overlay.
+		assert_eq!(journals, 3);
+	}
 }
@@ -81,6 +81,24 @@ fn to_journal_key(block: u64, index: u64) -> Vec<u8> {
 	to_meta_key(NON_CANONICAL_JOURNAL, &(block, index))
 }
 
+/// Apply `f` to every key referenced by the journal record `value`, if `meta_key` is a
+/// non-canonical journal key. Returns the re-encoded record.
+pub(crate) fn map_journal_keys<BlockHash: Hash, Key: Hash>(
+	meta_key: &[u8],
+	value: &[u8],
+	f: &mut dyn FnMut(&mut Key),
+) -> Result<Option<Vec<u8>>, codec::Error> {
+	if meta_key.len() != 16 + NON_CANONICAL_JOURNAL.len() ||
+		!meta_key.ends_with(NON_CANONICAL_JOURNAL)
+	{
+		return Ok(None)
+	}
+	let mut record: JournalRecord<BlockHash, Key> = Decode::decode(&mut &value[..])?;
+	record.inserted.iter_mut().for_each(|(key, _)| f(key));
+	record.deleted.iter_mut().for_each(|key| f(key));
+	Ok(Some(record.encode()))
+}
+
 #[cfg_attr(test, derive(PartialEq, Debug))]
 struct BlockOverlay<BlockHash: Hash, Key: Hash> {
 	hash: BlockHash,
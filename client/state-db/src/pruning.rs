@@ -279,6 +279,61 @@ fn to_journal_key(block: u64) -> Vec<u8> {
 	to_meta_key(PRUNING_JOURNAL, &block)
 }
 
+/// Apply `f` to every key referenced by the journal record `value`, if `meta_key` is a pruning
+/// journal key. Returns the re-encoded record.
+pub(crate) fn map_journal_keys<BlockHash: Hash, Key: Hash>(
+	meta_key: &[u8],
+	value: &[u8],
+	f: &mut dyn FnMut(&mut Key),
+) -> Result<Option<Vec<u8>>, codec::Error> {
+	if meta_key.len() != 8 + PRUNING_JOURNAL.len() || !meta_key.ends_with(PRUNING_JOURNAL) {
+		return Ok(None)
+	}
+	let mut record: JournalRecord<BlockHash, Key> = Decode::decode(&mut &value[..])?;
+	record.inserted.iter_mut().for_each(|key| f(key));
+	record.deleted.iter_mut().for_each(|key| f(key));
+	Ok(Some(record.encode()))
+}
+
+/// Count the references a reference counting database needs to hold for every key of the
+/// pruning journals in `meta`, so that pruning the window releases each key at the same block at
+/// which a database without reference counting deletes it.
+///
+/// Such a database deletes a key when the last block that deletes it is pruned, unless a later
+/// block inserted it again. A reference counting database releases the key once per block that
+/// deletes it, so it needs one reference per deletion, plus one if the key outlives the window.
+pub(crate) fn window_references<BlockHash: Hash, Key: Hash>(
+	meta: impl IntoIterator<Item = (Vec<u8>, Vec<u8>)>,
+) -> Result<HashMap<Key, u32>, codec::Error> {
+	let mut records = Vec::new();
+	for (meta_key, value) in meta {
+		if meta_key.len() != 8 + PRUNING_JOURNAL.len() || !meta_key.ends_with(PRUNING_JOURNAL) {
+			continue
+		}
+		let block = u64::decode(&mut &meta_key[..8])?;
+		let record: JournalRecord<BlockHash, Key> = Decode::decode(&mut &value[..])?;
+		records.push((block, record));
+	}
+	records.sort_by_key(|(block, _)| *block);
+
+	// The number of deletions of each key and whether it is alive after the last block.
+	let mut keys = HashMap::<Key, (u32, bool)>::new();
+	for (_, record) in records {
+		for key in record.inserted {
+			keys.entry(key).or_default().1 = true;
+		}
+		for key in record.deleted {
+			let (deletions, alive) = keys.entry(key).or_default();
+			*deletions += 1;
+			*alive = false;
+		}
+	}
+	Ok(keys
+		.into_iter()
+		.map(|(key, (deletions, alive))| (key, deletions + alive as u32))
+		.collect())
+}
+
 /// The result return by `RefWindow::have_block`
 #[derive(Debug, PartialEq, Eq)]
 pub enum HaveBlock {
@@ -427,7 +482,10 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> RefWindow<BlockHash, Key, D> {
 
 #[cfg(test)]
 mod tests {
-	use super::{to_journal_key, DeathRowQueue, HaveBlock, JournalRecord, RefWindow, LAST_PRUNED};
+	use super::{
+		to_journal_key, window_references, DeathRowQueue, HaveBlock, JournalRecord, RefWindow,
+		LAST_PRUNED,
+	};
 	use crate::{
 		noncanonical::LAST_CANONICAL,
 		test::{make_commit, make_db, TestDb},
@@ -869,4 +927,31 @@ mod tests {
 		pruning.prune_one(&mut commit).unwrap();
 		db.commit(&commit);
 	}
+
+	#[test]
+	fn window_references_match_deletions() {
+		let key = |n| H256::from_low_u64_be(n);
+		let record = |block: u64, inserted: &[u64], deleted: &[u64]| {
+			let record = JournalRecord::<H256, H256> {
+				hash: H256::from_low_u64_be(block),
+				inserted: inserted.iter().map(|n| key(*n)).collect(),
+				deleted: deleted.iter().map(|n| key(*n)).collect(),
+			};
+			(to_journal_key(block), record.encode())
+		};
+		let meta = vec![
+			record(12, &[], &[1, 3]),
+			(to_meta_key(LAST_PRUNED, &()), 9u64.encode()),
+			record(10, &[1], &[2]),
+			record(11, &[2], &[1]),
+		];
+
+		let references = window_references::<H256, H256>(meta).unwrap();
+		// Deleted twice, the last time by the last block.
+		assert_eq!(references[&key(1)], 2);
+		// Deleted once, but inserted again afterwards.
+		assert_eq!(references[&key(2)], 2);
+		assert_eq!(references[&key(3)], 1);
+		assert_eq!(references.len(), 3);
+	}
 }
@@ -71,6 +71,16 @@ impl TestDb {
 	pub fn meta_len(&self) -> usize {
 		self.0.read().unwrap().meta.len()
 	}
+
+	pub fn meta_entries(&self) -> Vec<(Vec<u8>, DBValue)> {
+		self.0
+			.read()
+			.unwrap()
+			.meta
+			.iter()
+			.map(|(k, v)| (k.clone(), v.clone()))
+			.collect()
+	}
 }
 
 pub fn make_changeset(inserted: &[u64], deleted: &[u64]) -> ChangeSet<H256> {
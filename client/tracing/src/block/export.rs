@@ -0,0 +1,396 @@
+// Copyright Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Export of block execution traces into formats understood by standard trace viewers.
+//!
+//! - [Chrome trace event format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU),
+//!   which can be loaded into `chrome://tracing`, Perfetto or Speedscope.
+//! - [OTLP JSON](https://opentelemetry.io/docs/specs/otlp/#json-protobuf-encoding), which can be
+//!   sent to any OpenTelemetry collector.
+//!
+//! Host function calls are recorded as spans with an `sp_io` target, e.g. `sp_io::storage`.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Map, Value};
+
+use crate::{SpanDatum, TraceEvent, Values};
+
+/// The id of the span covering the whole block execution.
+const ROOT_SPAN_ID: u64 = u64::MAX;
+/// The name of the span covering the whole block execution.
+const ROOT_SPAN_NAME: &str = "execute_block";
+
+/// The format of an exported block trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+	/// Chrome trace event format.
+	ChromeTrace,
+	/// OpenTelemetry protocol JSON encoding.
+	OtlpJson,
+}
+
+/// A span recorded while executing a block.
+#[derive(Debug, Clone)]
+pub struct TimedSpan {
+	/// The recorded span. Its `overall_time` is the duration of the span.
+	pub span: SpanDatum,
+	/// The time the span started, relative to the start of the block execution.
+	pub start: Duration,
+}
+
+/// An event recorded while executing a block.
+#[derive(Debug, Clone)]
+pub struct TimedEvent {
+	/// The recorded event.
+	pub event: TraceEvent,
+	/// The time of the event, relative to the start of the block execution.
+	pub time: Duration,
+}
+
+/// The spans and events recorded while executing a block, along with their timing.
+#[derive(Debug, Clone)]
+pub struct BlockTimeline {
+	/// Hash of the traced block, hex encoded.
+	pub block_hash: String,
+	/// Hash of the parent block, hex encoded.
+	pub parent_hash: String,
+	/// Module targets that were recorded.
+	pub tracing_targets: String,
+	/// The wall clock time at which the execution started.
+	pub started_at: SystemTime,
+	/// The duration of the whole execution.
+	pub duration: Duration,
+	/// The recorded spans.
+	pub spans: Vec<TimedSpan>,
+	/// The recorded events.
+	pub events: Vec<TimedEvent>,
+}
+
+impl BlockTimeline {
+	/// Export the timeline in the given `format`.
+	pub fn export(&self, format: TraceFormat) -> Value {
+		match format {
+			TraceFormat::ChromeTrace => self.to_chrome_trace(),
+			TraceFormat::OtlpJson => self.to_otlp_json(),
+		}
+	}
+
+	/// Export the timeline in the Chrome trace event format.
+	///
+	/// Spans become complete (`X`) events and events become thread scoped instant (`i`)
+	/// events, all on a single thread. Timestamps are in microseconds since the start of the
+	/// execution.
+	pub fn to_chrome_trace(&self) -> Value {
+		let mut trace_events = Vec::with_capacity(self.spans.len() + self.events.len() + 1);
+		trace_events.push(json!({
+			"name": ROOT_SPAN_NAME,
+			"cat": "block",
+			"ph": "X",
+			"ts": 0,
+			"dur": micros(self.duration),
+			"pid": 1,
+			"tid": 1,
+			"args": { "block_hash": self.block_hash, "parent_hash": self.parent_hash },
+		}));
+
+		// Viewers expect the events of a thread to be sorted by time. Spans are ordered by their
+		// id if they start at the same time and precede the instant events of that time.
+		let mut spans: Vec<_> = self.spans.iter().collect();
+		spans.sort_by_key(|s| (s.start, s.span.id.into_u64()));
+		let mut timed: Vec<_> = spans
+			.into_iter()
+			.map(|TimedSpan { span, start }| {
+				let event = json!({
+					"name": span.name,
+					"cat": span.target,
+					"ph": "X",
+					"ts": micros(*start),
+					"dur": micros(span.overall_time),
+					"pid": 1,
+					"tid": 1,
+					"args": Value::Object(values_to_json(&span.values)),
+				});
+				(*start, event)
+			})
+			.collect();
+		timed.extend(self.events.iter().map(|TimedEvent { event, time }| {
+			let event = json!({
+				"name": event_name(event),
+				"cat": event.target,
+				"ph": "i",
+				"s": "t",
+				"ts": micros(*time),
+				"pid": 1,
+				"tid": 1,
+				"args": Value::Object(values_to_json(&event.values)),
+			});
+			(*time, event)
+		}));
+		timed.sort_by_key(|(time, _)| *time);
+		trace_events.extend(timed.into_iter().map(|(_, event)| event));
+
+		json!({
+			"traceEvents": trace_events,
+			"displayTimeUnit": "ns",
+			"otherData": {
+				"block_hash": self.block_hash,
+				"parent_hash": self.parent_hash,
+				"tracing_targets": self.tracing_targets,
+			},
+		})
+	}
+
+	/// Export the timeline as an OTLP JSON `TracesData` message.
+	///
+	/// All spans belong to one trace whose id is derived from the block hash. Spans without a
+	/// recorded parent are children of a root span covering the whole execution, events are
+	/// attached to their parent span or to the root span.
+	pub fn to_otlp_json(&self) -> Value {
+		let trace_id = trace_id(&self.block_hash);
+		let recorded: std::collections::HashSet<u64> =
+			self.spans.iter().map(|s| s.span.id.into_u64()).collect();
+		let parent_of = |parent: &Option<tracing::span::Id>| {
+			parent.as_ref().map(|p| p.into_u64()).filter(|p| recorded.contains(p))
+		};
+
+		let mut events: std::collections::HashMap<u64, Vec<Value>> = Default::default();
+		for TimedEvent { event, time } in &self.events {
+			events
+				.entry(parent_of(&event.parent_id).unwrap_or(ROOT_SPAN_ID))
+				.or_default()
+				.push(json!({
+					"timeUnixNano": self.unix_nanos(*time),
+					"name": event_name(event),
+					"attributes": values_to_attributes(&event.values, &event.target),
+				}));
+		}
+
+		let mut spans = Vec::with_capacity(self.spans.len() + 1);
+		spans.push(json!({
+			"traceId": trace_id,
+			"spanId": span_id(ROOT_SPAN_ID),
+			"name": ROOT_SPAN_NAME,
+			"kind": 1,
+			"startTimeUnixNano": self.unix_nanos(Duration::ZERO),
+			"endTimeUnixNano": self.unix_nanos(self.duration),
+			"attributes": [
+				string_attribute("block.hash", &self.block_hash),
+				string_attribute("block.parent_hash", &self.parent_hash),
+			],
+			"events": events.remove(&ROOT_SPAN_ID).unwrap_or_default(),
+		}));
+		spans.extend(self.spans.iter().map(|TimedSpan { span, start }| {
+			let id = span.id.into_u64();
+			json!({
+				"traceId": trace_id,
+				"spanId": span_id(id),
+				"parentSpanId": span_id(parent_of(&span.parent_id).unwrap_or(ROOT_SPAN_ID)),
+				"name": span.name,
+				"kind": 1,
+				"startTimeUnixNano": self.unix_nanos(*start),
+				"endTimeUnixNano": self.unix_nanos(*start + span.overall_time),
+				"attributes": values_to_attributes(&span.values, &span.target),
+				"events": events.remove(&id).unwrap_or_default(),
+			})
+		}));
+
+		json!({
+			"resourceSpans": [{
+				"resource": {
+					"attributes": [string_attribute("service.name", "substrate-node")],
+				},
+				"scopeSpans": [{
+					"scope": { "name": "sc-tracing" },
+					"spans": spans,
+				}],
+			}],
+		})
+	}
+
+	/// The wall clock time of the given offset as nanoseconds since the Unix epoch.
+	///
+	/// OTLP JSON encodes 64 bit integers as strings.
+	fn unix_nanos(&self, offset: Duration) -> String {
+		(self.started_at + offset)
+			.duration_since(UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_nanos()
+			.to_string()
+	}
+}
+
+fn micros(duration: Duration) -> f64 {
+	duration.as_nanos() as f64 / 1_000.0
+}
+
+/// Storage events carry the accessed method in the `method` field, which is more telling than
+/// the generated event name.
+fn event_name(event: &TraceEvent) -> &str {
+	event.values.string_values.get("method").map_or(&event.name, |m| m.as_str())
+}
+
+/// The trace id is the first 16 bytes of the block hash, hex encoded.
+fn trace_id(block_hash: &str) -> String {
+	let mut id: String = block_hash.trim_start_matches("0x").chars().take(32).collect();
+	while id.len() < 32 {
+		id.push('0');
+	}
+	id
+}
+
+fn span_id(id: u64) -> String {
+	format!("{:016x}", id)
+}
+
+fn values_to_json(values: &Values) -> Map<String, Value> {
+	let mut map = Map::new();
+	map.extend(values.bool_values.iter().map(|(k, v)| (k.clone(), Value::from(*v))));
+	map.extend(values.i64_values.iter().map(|(k, v)| (k.clone(), Value::from(*v))));
+	map.extend(values.u64_values.iter().map(|(k, v)| (k.clone(), Value::from(*v))));
+	map.extend(values.string_values.iter().map(|(k, v)| (k.clone(), Value::from(v.as_str()))));
+	map
+}
+
+fn values_to_attributes(values: &Values, target: &str) -> Vec<Value> {
+	let mut attributes = vec![string_attribute("target", target)];
+	attributes.extend(
+		values
+			.bool_values
+			.iter()
+			.map(|(k, v)| json!({ "key": k, "value": { "boolValue": v } })),
+	);
+	attributes.extend(
+		values
+			.i64_values
+			.iter()
+			.map(|(k, v)| json!({ "key": k, "value": { "intValue": v.to_string() } })),
+	);
+	attributes.extend(
+		values
+			.u64_values
+			.iter()
+			.map(|(k, v)| json!({ "key": k, "value": { "intValue": v.to_string() } })),
+	);
+	attributes.extend(values.string_values.iter().map(|(k, v)| string_attribute(k, v)));
+	attributes
+}
+
+fn string_attribute(key: &str, value: &str) -> Value {
+	json!({ "key": key, "value": { "stringValue": value } })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Instant;
+	use tracing::{span::Id, Level};
+
+	fn span(id: u64, parent: Option<u64>, name: &str, duration: u64) -> SpanDatum {
+		SpanDatum {
+			id: Id::from_u64(id),
+			parent_id: parent.map(Id::from_u64),
+			name: name.into(),
+			target: "pallet_balances".into(),
+			level: Level::INFO,
+			line: 0,
+			start_time: Instant::now(),
+			overall_time: Duration::from_micros(duration),
+			values: Values::default(),
+		}
+	}
+
+	fn timeline() -> BlockTimeline {
+		let mut values = Values::default();
+		values.string_values.insert("method".into(), "Get".into());
+		values.string_values.insert("key".into(), "26aa".into());
+
+		BlockTimeline {
+			block_hash: "aa".repeat(32),
+			parent_hash: "bb".repeat(32),
+			tracing_targets: "pallet,state".into(),
+			started_at: UNIX_EPOCH + Duration::from_secs(1),
+			duration: Duration::from_micros(100),
+			spans: vec![
+				TimedSpan {
+					span: span(2, Some(1), "transfer", 20),
+					start: Duration::from_micros(30),
+				},
+				TimedSpan { span: span(1, None, "dispatch", 50), start: Duration::from_micros(10) },
+			],
+			events: vec![
+				TimedEvent {
+					event: TraceEvent {
+						name: "event state.rs:42".into(),
+						target: "state".into(),
+						level: Level::TRACE,
+						values,
+						parent_id: Some(Id::from_u64(2)),
+					},
+					time: Duration::from_micros(35),
+				},
+				TimedEvent {
+					event: TraceEvent {
+						name: "event lib.rs:7".into(),
+						target: "pallet_balances".into(),
+						level: Level::TRACE,
+						values: Values::default(),
+						parent_id: Some(Id::from_u64(1)),
+					},
+					time: Duration::from_micros(20),
+				},
+			],
+		}
+	}
+
+	#[test]
+	fn chrome_trace_export() {
+		let trace = timeline().to_chrome_trace();
+		let events = trace["traceEvents"].as_array().unwrap();
+
+		let names: Vec<_> = events.iter().map(|e| e["name"].as_str().unwrap()).collect();
+		assert_eq!(names, vec!["execute_block", "dispatch", "event lib.rs:7", "transfer", "Get"]);
+		assert_eq!(events[1]["ph"], "X");
+		assert_eq!(events[1]["ts"], 10.0);
+		assert_eq!(events[1]["dur"], 50.0);
+		assert_eq!(events[2]["ph"], "i");
+		assert_eq!(events[2]["ts"], 20.0);
+		assert_eq!(events[4]["ph"], "i");
+		assert_eq!(events[4]["ts"], 35.0);
+		assert_eq!(events[4]["args"]["key"], "26aa");
+	}
+
+	#[test]
+	fn otlp_json_export() {
+		let trace = timeline().to_otlp_json();
+		let spans = trace["resourceSpans"][0]["scopeSpans"][0]["spans"].as_array().unwrap();
+		assert_eq!(spans.len(), 3);
+
+		let root = &spans[0];
+		assert_eq!(root["traceId"], "aa".repeat(16));
+		assert_eq!(root["startTimeUnixNano"], "1000000000");
+		assert_eq!(root["endTimeUnixNano"], "1000100000");
+
+		let transfer = spans.iter().find(|s| s["name"] == "transfer").unwrap();
+		assert_eq!(transfer["parentSpanId"], span_id(1));
+		assert_eq!(transfer["startTimeUnixNano"], "1000030000");
+		assert_eq!(transfer["endTimeUnixNano"], "1000050000");
+		assert_eq!(transfer["events"][0]["name"], "Get");
+
+		let dispatch = spans.iter().find(|s| s["name"] == "dispatch").unwrap();
+		assert_eq!(dispatch["parentSpanId"], span_id(ROOT_SPAN_ID));
+	}
+}
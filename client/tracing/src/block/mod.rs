@@ -22,7 +22,7 @@ use std::{
 		atomic::{AtomicU64, Ordering},
 		Arc,
 	},
-	time::Instant,
+	time::{Duration, Instant, SystemTime},
 };
 
 use parking_lot::Mutex;
@@ -45,6 +45,10 @@ use sp_runtime::{
 };
 use sp_tracing::{WASM_NAME_KEY, WASM_TARGET_KEY, WASM_TRACE_IDENTIFIER};
 
+mod export;
+
+pub use export::{BlockTimeline, TimedEvent, TimedSpan, TraceFormat};
+
 // Heuristic for average event size in bytes.
 const AVG_EVENT: usize = 600 * 8;
 // Heuristic for average span size in bytes.
@@ -62,6 +66,8 @@ const AVG_SPAN: usize = 100 * 8;
 const BASE_PAYLOAD: usize = 100;
 // Default to only pallet, frame support and state related traces
 const DEFAULT_TARGETS: &str = "pallet,frame,state";
+// The target of the spans `sp_runtime_interface` records for every host function call.
+const HOST_CALLS_TARGET: &str = "sp_io";
 const TRACE_TARGET: &str = "block_trace";
 // The name of a field required for all events.
 const REQUIRED_EVENT_FIELD: &str = "method";
@@ -87,7 +93,7 @@ struct BlockSubscriber {
 	targets: Vec<(String, Level)>,
 	next_id: AtomicU64,
 	spans: Mutex<HashMap<Id, SpanDatum>>,
-	events: Mutex<Vec<TraceEvent>>,
+	events: Mutex<Vec<(Instant, TraceEvent)>>,
 }
 
 impl BlockSubscriber {
@@ -163,12 +169,17 @@ impl Subscriber for BlockSubscriber {
 			values,
 			parent_id,
 		};
-		self.events.lock().push(trace_event);
+		self.events.lock().push((Instant::now(), trace_event));
 	}
 
 	fn enter(&self, _id: &Id) {}
 
-	fn exit(&self, _span: &Id) {}
+	fn exit(&self, span: &Id) {
+		// A span lasts from its creation until it is exited for the last time.
+		if let Some(s) = self.spans.lock().get_mut(span) {
+			s.overall_time = s.start_time.elapsed();
+		}
+	}
 }
 
 /// Holds a reference to the client in order to execute the given block.
@@ -215,6 +226,93 @@ where
 	/// and filter out events which do not have keys starting with one of the
 	/// prefixes in `Self::storage_keys`.
 	pub fn trace_block(&self) -> TraceBlockResult<TraceBlockResponse> {
+		let recorded = self.execute(false)?;
+		let spans: Vec<Span> = recorded.spans.into_iter().map(|s| s.into()).collect();
+		let events: Vec<_> = recorded
+			.events
+			.into_iter()
+			.map(|(_, e)| e)
+			.filter(|e| {
+				self.storage_keys
+					.as_ref()
+					.map(|keys| event_values_filter(e, "key", keys))
+					.unwrap_or(false)
+			})
+			.filter(|e| {
+				self.methods
+					.as_ref()
+					.map(|methods| event_values_filter(e, "method", methods))
+					.unwrap_or(false)
+			})
+			.map(|s| s.into())
+			.collect();
+		tracing::debug!(target: "state_tracing", "Captured {} spans and {} events", spans.len(), events.len());
+
+		let approx_payload_size = BASE_PAYLOAD + events.len() * AVG_EVENT + spans.len() * AVG_SPAN;
+		let response = if approx_payload_size > self.rpc_max_payload {
+			TraceBlockResponse::TraceError(TraceError {
+				error: "Payload likely exceeds max payload size of RPC server.".to_string(),
+			})
+		} else {
+			TraceBlockResponse::BlockTrace(BlockTrace {
+				block_hash: block_id_as_string(BlockId::<Block>::Hash(self.block)),
+				parent_hash: block_id_as_string(BlockId::<Block>::Hash(recorded.parent_hash)),
+				tracing_targets: recorded.targets,
+				storage_keys: self.storage_keys.clone().unwrap_or_default(),
+				methods: self.methods.clone().unwrap_or_default(),
+				spans,
+				events,
+			})
+		};
+
+		Ok(response)
+	}
+
+	/// Execute block and record the timing of all spans and events belonging to
+	/// `Self::targets`, as well as of all host function calls.
+	///
+	/// Unlike [`Self::trace_block`], events are only filtered by `Self::storage_keys` and
+	/// `Self::methods` if those are given, and the size of the trace isn't limited.
+	pub fn trace_block_timeline(&self) -> TraceBlockResult<BlockTimeline> {
+		let recorded = self.execute(true)?;
+		let offset = |time: Instant| time.saturating_duration_since(recorded.start);
+
+		let spans: Vec<_> = recorded
+			.spans
+			.into_iter()
+			.map(|span| TimedSpan { start: offset(span.start_time), span })
+			.collect();
+		let events: Vec<_> = recorded
+			.events
+			.into_iter()
+			.filter(|(_, e)| {
+				self.storage_keys
+					.as_ref()
+					.map_or(true, |keys| event_values_filter(e, "key", keys))
+			})
+			.filter(|(_, e)| {
+				self.methods
+					.as_ref()
+					.map_or(true, |methods| event_values_filter(e, "method", methods))
+			})
+			.map(|(time, event)| TimedEvent { time: offset(time), event })
+			.collect();
+		tracing::debug!(target: "state_tracing", "Captured {} spans and {} events", spans.len(), events.len());
+
+		Ok(BlockTimeline {
+			block_hash: block_id_as_string(BlockId::<Block>::Hash(self.block)),
+			parent_hash: block_id_as_string(BlockId::<Block>::Hash(recorded.parent_hash)),
+			tracing_targets: recorded.targets,
+			started_at: recorded.started_at,
+			duration: recorded.duration,
+			spans,
+			events,
+		})
+	}
+
+	/// Execute block and return everything the subscriber recorded for `Self::targets`, and for
+	/// the host function calls if `host_calls` is set.
+	fn execute(&self, host_calls: bool) -> TraceBlockResult<RecordedBlock<Block>> {
 		tracing::debug!(target: "state_tracing", "Tracing block: {}", self.block);
 		// Prepare the block
 		let mut header = self
@@ -235,9 +333,16 @@ where
 		let block = Block::new(header, extrinsics);
 
 		let targets = if let Some(t) = &self.targets { t } else { DEFAULT_TARGETS };
-		let block_subscriber = BlockSubscriber::new(targets);
+		let targets = if host_calls {
+			format!("{},{}", targets, HOST_CALLS_TARGET)
+		} else {
+			targets.to_owned()
+		};
+		let block_subscriber = BlockSubscriber::new(&targets);
 		let dispatch = Dispatch::new(block_subscriber);
 
+		let started_at = SystemTime::now();
+		let start = Instant::now();
 		{
 			let dispatcher_span = tracing::debug_span!(
 				target: "state_tracing",
@@ -256,6 +361,7 @@ where
 				)))
 			}
 		}
+		let duration = start.elapsed();
 
 		let block_subscriber = dispatch.downcast_ref::<BlockSubscriber>().ok_or_else(|| {
 			Error::Dispatch(
@@ -267,49 +373,25 @@ where
 			.lock()
 			.drain()
 			// Patch wasm identifiers
-			.filter_map(|(_, s)| patch_and_filter(s, targets))
+			.filter_map(|(_, s)| patch_and_filter(s, &targets))
 			.collect();
-		let events: Vec<_> = block_subscriber
-			.events
-			.lock()
-			.drain(..)
-			.filter(|e| {
-				self.storage_keys
-					.as_ref()
-					.map(|keys| event_values_filter(e, "key", keys))
-					.unwrap_or(false)
-			})
-			.filter(|e| {
-				self.methods
-					.as_ref()
-					.map(|methods| event_values_filter(e, "method", methods))
-					.unwrap_or(false)
-			})
-			.map(|s| s.into())
-			.collect();
-		tracing::debug!(target: "state_tracing", "Captured {} spans and {} events", spans.len(), events.len());
-
-		let approx_payload_size = BASE_PAYLOAD + events.len() * AVG_EVENT + spans.len() * AVG_SPAN;
-		let response = if approx_payload_size > self.rpc_max_payload {
-			TraceBlockResponse::TraceError(TraceError {
-				error: "Payload likely exceeds max payload size of RPC server.".to_string(),
-			})
-		} else {
-			TraceBlockResponse::BlockTrace(BlockTrace {
-				block_hash: block_id_as_string(BlockId::<Block>::Hash(self.block)),
-				parent_hash: block_id_as_string(BlockId::<Block>::Hash(parent_hash)),
-				tracing_targets: targets.to_string(),
-				storage_keys: self.storage_keys.clone().unwrap_or_default(),
-				methods: self.methods.clone().unwrap_or_default(),
-				spans,
-				events,
-			})
-		};
+		let events = std::mem::take(&mut *block_subscriber.events.lock());
 
-		Ok(response)
+		Ok(RecordedBlock { parent_hash, targets, started_at, start, duration, spans, events })
 	}
 }
 
+/// Everything recorded while executing a block.
+struct RecordedBlock<Block: BlockT> {
+	parent_hash: Block::Hash,
+	targets: String,
+	started_at: SystemTime,
+	start: Instant,
+	duration: Duration,
+	spans: Vec<SpanDatum>,
+	events: Vec<(Instant, TraceEvent)>,
+}
+
 fn event_values_filter(event: &TraceEvent, filter_kind: &str, values: &str) -> bool {
 	event
 		.values
@@ -327,7 +409,7 @@ fn event_values_filter(event: &TraceEvent, filter_kind: &str, values: &str) -> b
 // (normally they would be in the static metadata assembled at compile time). Here, if a special
 // WASM `name` or `target` key is found in the `values` we remove it and put the key value pair in
 // the span's metadata, making it consistent with spans that come from native code.
-fn patch_and_filter(mut span: SpanDatum, targets: &str) -> Option<Span> {
+fn patch_and_filter(mut span: SpanDatum, targets: &str) -> Option<SpanDatum> {
 	if span.name == WASM_TRACE_IDENTIFIER {
 		span.values.bool_values.insert("wasm".to_owned(), true);
 		if let Some(n) = span.values.string_values.remove(WASM_NAME_KEY) {
@@ -340,7 +422,7 @@ fn patch_and_filter(mut span: SpanDatum, targets: &str) -> Option<Span> {
 			return None
 		}
 	}
-	Some(span.into())
+	Some(span)
 }
 
 /// Check if a `target` matches any `targets` by prefix
@@ -24,6 +24,7 @@ pub mod error;
 use async_trait::async_trait;
 use futures::{Future, Stream};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_core::Bytes;
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block as BlockT, Member, NumberFor},
@@ -132,6 +133,29 @@ pub enum TransactionStatus<Hash, BlockHash> {
 	Invalid,
 }
 
+/// Why a transaction has not been included in a block yet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PendingReason {
+	/// Transaction is part of the ready queue and waits to be included in a block.
+	Ready,
+	/// Transaction is part of the future queue and waits for the given tags to be provided,
+	/// e.g. because of a nonce gap.
+	#[serde(rename_all = "camelCase")]
+	Future {
+		/// Tags that no transaction in the pool or recently included block provides yet.
+		missing_tags: Vec<Bytes>,
+	},
+	/// Transaction has been dropped because the pool reached its limits.
+	PoolLimit,
+	/// Transaction has been dropped because its sender reached the per-sender limits.
+	SenderLimit,
+	/// Transaction is temporarily banned from the pool, e.g. because it turned out invalid.
+	Banned,
+	/// Transaction is not known to the pool.
+	Unknown,
+}
+
 /// The stream of transaction events.
 pub type TransactionStatusStream<Hash, BlockHash> =
 	dyn Stream<Item = TransactionStatus<Hash, BlockHash>> + Send;
@@ -262,6 +286,12 @@ pub trait TransactionPool: Send + Sync {
 
 	/// Return specific ready transaction by hash, if there is one.
 	fn ready_transaction(&self, hash: &TxHash<Self>) -> Option<Arc<Self::InPoolTransaction>>;
+
+	// *** RPC
+	/// Returns why the transaction with given hash is still pending.
+	fn pending_reason(&self, _hash: &TxHash<Self>) -> PendingReason {
+		PendingReason::Unknown
+	}
 }
 
 /// An iterator of ready transactions.
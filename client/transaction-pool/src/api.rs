@@ -24,7 +24,7 @@ use futures::{
 	channel::{mpsc, oneshot},
 	future::{ready, Future, FutureExt, Ready},
 	lock::Mutex,
-	SinkExt, StreamExt,
+	SinkExt, StreamExt, TryFutureExt,
 };
 use std::{marker::PhantomData, pin::Pin, sync::Arc};
 
@@ -38,7 +38,7 @@ use sp_runtime::{
 	traits::{self, Block as BlockT, BlockIdTo},
 	transaction_validity::{TransactionSource, TransactionValidity},
 };
-use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
+use sp_transaction_pool::runtime_api::{TaggedTransactionQueue, TransactionInfo};
 
 use crate::{
 	error::{self, Error},
@@ -137,35 +137,18 @@ where
 		source: TransactionSource,
 		uxt: graph::ExtrinsicFor<Self>,
 	) -> Self::ValidationFuture {
-		let (tx, rx) = oneshot::channel();
-		let client = self.client.clone();
-		let at = *at;
-		let validation_pool = self.validation_pool.clone();
-		let metrics = self.metrics.clone();
-
-		async move {
-			metrics.report(|m| m.validations_scheduled.inc());
-
-			validation_pool
-				.lock()
-				.await
-				.send(
-					async move {
-						let res = validate_transaction_blocking(&*client, &at, source, uxt);
-						let _ = tx.send(res);
-						metrics.report(|m| m.validations_finished.inc());
-					}
-					.boxed(),
-				)
-				.await
-				.map_err(|e| Error::RuntimeApi(format!("Validation pool down: {:?}", e)))?;
+		self.validate_with_info(at, source, uxt)
+			.map_ok(|(validity, _)| validity)
+			.boxed()
+	}
 
-			match rx.await {
-				Ok(r) => r,
-				Err(_) => Err(Error::RuntimeApi("Validation was canceled".into())),
-			}
-		}
-		.boxed()
+	fn validate_transaction_with_info(
+		&self,
+		at: &BlockId<Self::Block>,
+		source: TransactionSource,
+		uxt: graph::ExtrinsicFor<Self>,
+	) -> graph::ValidationWithInfoFuture<'_, Self::Error> {
+		self.validate_with_info(at, source, uxt)
 	}
 
 	fn block_id_to_number(
@@ -212,7 +195,7 @@ fn validate_transaction_blocking<Client, Block>(
 	at: &BlockId<Block>,
 	source: TransactionSource,
 	uxt: graph::ExtrinsicFor<FullChainApi<Client, Block>>,
-) -> error::Result<TransactionValidity>
+) -> error::Result<(TransactionValidity, Option<TransactionInfo>)>
 where
 	Block: BlockT,
 	Client: ProvideRuntimeApi<Block>
@@ -244,8 +227,12 @@ where
 		sp_tracing::within_span!(
 			sp_tracing::Level::TRACE, "runtime::validate_transaction";
 		{
-			if api_version >= 3 {
+			if api_version >= 4 {
+				runtime_api.validate_transaction_with_info(block_hash, source, uxt, block_hash)
+					.map_err(|e| Error::RuntimeApi(e.to_string()))
+			} else if api_version == 3 {
 				runtime_api.validate_transaction(block_hash, source, uxt, block_hash)
+					.map(|validity| (validity, None))
 					.map_err(|e| Error::RuntimeApi(e.to_string()))
 			} else {
 				let block_number = client.to_number(at)
@@ -266,10 +253,12 @@ where
 				if api_version == 2 {
 					#[allow(deprecated)] // old validate_transaction
 					runtime_api.validate_transaction_before_version_3(block_hash, source, uxt)
+						.map(|validity| (validity, None))
 						.map_err(|e| Error::RuntimeApi(e.to_string()))
 				} else {
 					#[allow(deprecated)] // old validate_transaction
 					runtime_api.validate_transaction_before_version_2(block_hash, uxt)
+						.map(|validity| (validity, None))
 						.map_err(|e| Error::RuntimeApi(e.to_string()))
 				}
 			}
@@ -298,6 +287,56 @@ where
 		source: TransactionSource,
 		uxt: graph::ExtrinsicFor<Self>,
 	) -> error::Result<TransactionValidity> {
+		validate_transaction_blocking(&*self.client, at, source, uxt).map(|(validity, _)| validity)
+	}
+
+	/// Validates a transaction and returns its sender and fee, same as
+	/// `validate_transaction_with_info` but blocks the current thread when performing validation.
+	pub fn validate_transaction_with_info_blocking(
+		&self,
+		at: &BlockId<Block>,
+		source: TransactionSource,
+		uxt: graph::ExtrinsicFor<Self>,
+	) -> error::Result<(TransactionValidity, Option<TransactionInfo>)> {
 		validate_transaction_blocking(&*self.client, at, source, uxt)
 	}
+
+	/// Validates the transaction in the validation pool, the sender and fee are reported if the
+	/// runtime supports it.
+	fn validate_with_info(
+		&self,
+		at: &BlockId<Block>,
+		source: TransactionSource,
+		uxt: graph::ExtrinsicFor<Self>,
+	) -> graph::ValidationWithInfoFuture<'static, Error> {
+		let (tx, rx) = oneshot::channel();
+		let client = self.client.clone();
+		let at = *at;
+		let validation_pool = self.validation_pool.clone();
+		let metrics = self.metrics.clone();
+
+		async move {
+			metrics.report(|m| m.validations_scheduled.inc());
+
+			validation_pool
+				.lock()
+				.await
+				.send(
+					async move {
+						let res = validate_transaction_blocking(&*client, &at, source, uxt);
+						let _ = tx.send(res);
+						metrics.report(|m| m.validations_finished.inc());
+					}
+					.boxed(),
+				)
+				.await
+				.map_err(|e| Error::RuntimeApi(format!("Validation pool down: {:?}", e)))?;
+
+			match rx.await {
+				Ok(r) => r,
+				Err(_) => Err(Error::RuntimeApi("Validation was canceled".into())),
+			}
+		}
+		.boxed()
+	}
 }
@@ -20,13 +20,18 @@
 //!
 //! For a more full-featured pool, have a look at the `pool` module.
 
-use std::{cmp::Ordering, collections::HashSet, fmt, hash, sync::Arc};
+use std::{
+	cmp::Ordering,
+	collections::{HashMap, HashSet},
+	fmt, hash,
+	sync::Arc,
+};
 
 use crate::LOG_TARGET;
 use log::{debug, trace, warn};
 use sc_transaction_pool_api::{error, InPoolTransaction, PoolStatus};
 use serde::Serialize;
-use sp_core::hexdisplay::HexDisplay;
+use sp_core::{hexdisplay::HexDisplay, U256};
 use sp_runtime::{
 	traits::Member,
 	transaction_validity::{
@@ -34,6 +39,7 @@ use sp_runtime::{
 		TransactionSource as Source, TransactionTag as Tag,
 	},
 };
+use sp_transaction_pool::runtime_api::TransactionInfo;
 
 use super::{
 	future::{FutureTransactions, WaitingTransaction},
@@ -105,6 +111,8 @@ pub struct Transaction<Hash, Extrinsic> {
 	pub propagate: bool,
 	/// Source of that transaction.
 	pub source: Source,
+	/// Sender and fee of the transaction, if the runtime reports them.
+	pub info: Option<TransactionInfo>,
 }
 
 impl<Hash, Extrinsic> AsRef<Extrinsic> for Transaction<Hash, Extrinsic> {
@@ -163,10 +171,23 @@ impl<Hash: Clone, Extrinsic: Clone> Transaction<Hash, Extrinsic> {
 			requires: self.requires.clone(),
 			provides: self.provides.clone(),
 			propagate: self.propagate,
+			info: self.info.clone(),
 		}
 	}
 }
 
+impl<Hash, Extrinsic> Transaction<Hash, Extrinsic> {
+	/// Compares the fee per weight of two transactions.
+	///
+	/// Returns `None` unless the runtime reported the fee of both transactions.
+	pub fn cmp_fee_per_weight(&self, other: &Self) -> Option<Ordering> {
+		let (this, other) = (self.info.as_ref()?, other.info.as_ref()?);
+		let this_fee = U256::from(this.fee) * U256::from(other.weight.max(1));
+		let other_fee = U256::from(other.fee) * U256::from(this.weight.max(1));
+		Some(this_fee.cmp(&other_fee))
+	}
+}
+
 impl<Hash, Extrinsic> fmt::Debug for Transaction<Hash, Extrinsic>
 where
 	Hash: fmt::Debug,
@@ -219,6 +240,12 @@ pub struct BasePool<Hash: hash::Hash + Eq, Ex> {
 	/// transactions to future in case they were just stuck in verification.
 	recently_pruned: [HashSet<Tag>; RECENTLY_PRUNED_TAGS],
 	recently_pruned_index: usize,
+	/// Transactions in the pool by the sender reported by the runtime.
+	by_sender: HashMap<Vec<u8>, HashSet<Hash>>,
+	/// Senders of the transactions in `by_sender`.
+	senders: HashMap<Hash, Vec<u8>>,
+	/// Senders that may exceed the per-sender limits since they were last enforced.
+	dirty_senders: HashSet<Vec<u8>>,
 }
 
 impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> Default for BasePool<Hash, Ex> {
@@ -236,6 +263,9 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			ready: Default::default(),
 			recently_pruned: Default::default(),
 			recently_pruned_index: 0,
+			by_sender: Default::default(),
+			senders: Default::default(),
+			dirty_senders: Default::default(),
 		}
 	}
 
@@ -288,11 +318,18 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			}
 
 			let hash = tx.transaction.hash.clone();
+			self.index_sender(&tx.transaction);
 			self.future.import(tx);
 			return Ok(Imported::Future { hash })
 		}
 
-		self.import_to_ready(tx)
+		let hash = tx.transaction.hash.clone();
+		self.index_sender(&tx.transaction);
+		let imported = self.import_to_ready(tx);
+		if imported.is_err() {
+			self.forget_senders(std::iter::once(&hash));
+		}
+		imported
 	}
 
 	/// Imports transaction to ready queue.
@@ -346,12 +383,18 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		if removed.iter().any(|tx| tx.hash == hash) {
 			// We still need to remove all transactions that we promoted
 			// since they depend on each other and will never get to the best iterator.
-			self.ready.remove_subtree(&promoted);
+			let removed_promoted = self.ready.remove_subtree(&promoted);
+			self.forget_senders(removed.iter().chain(&removed_promoted).map(|tx| &tx.hash));
+			self.forget_senders(&failed);
 
 			debug!(target: LOG_TARGET, "[{:?}] Cycle detected, bailing.", hash);
 			return Err(error::Error::CycleDetected)
 		}
 
+		self.forget_senders(removed.iter().map(|tx| &tx.hash));
+		self.forget_senders(&failed);
+		self.mark_senders_dirty(std::iter::once(&hash).chain(&promoted));
+
 		Ok(Imported::Ready { hash, promoted, failed, removed })
 	}
 
@@ -376,6 +419,13 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		ready.into_iter().zip(future).map(|(a, b)| a.or(b)).collect()
 	}
 
+	/// Returns the tags a transaction in the future queue is still waiting for.
+	///
+	/// Returns `None` if the transaction is not part of the future queue.
+	pub fn missing_tags(&self, hash: &Hash) -> Option<Vec<Tag>> {
+		self.future.missing_tags(hash).map(|tags| tags.iter().cloned().collect())
+	}
+
 	/// Returns pool transaction by hash.
 	pub fn ready_by_hash(&self, hash: &Hash) -> Option<Arc<Transaction<Hash, Ex>>> {
 		self.ready.by_hash(hash)
@@ -385,8 +435,10 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 	///
 	/// Removes and returns worst transactions from the queues and all transactions that depend on
 	/// them. Technically the worst transaction should be evaluated by computing the entire pending
-	/// set. We use a simplified approach to remove transactions with the lowest priority first or
-	/// those that occupy the pool for the longest time in case priority is the same.
+	/// set. We use a simplified approach to remove transactions paying the lowest fee per weight
+	/// first, then those with the lowest priority or those that occupy the pool for the longest
+	/// time in case priority is the same. The fee per weight is only compared when the runtime
+	/// reports it for both transactions.
 	pub fn enforce_limits(
 		&mut self,
 		ready: &Limit,
@@ -404,7 +456,14 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 						// while it prefers priority like need here, it also prefers older
 						// transactions for inclusion purposes and limit enforcement needs to prefer
 						// newer transactions instead and drop the older ones.
-						match worst.transaction.priority.cmp(&transaction.transaction.priority) {
+						let ordering = worst
+							.transaction
+							.cmp_fee_per_weight(&transaction.transaction)
+							.unwrap_or(Ordering::Equal)
+							.then_with(|| {
+								worst.transaction.priority.cmp(&transaction.transaction.priority)
+							});
+						match ordering {
 							Ordering::Less => worst,
 							Ordering::Equal =>
 								if worst.insertion_id > transaction.insertion_id {
@@ -429,8 +488,17 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			// find the worst transaction
 			let worst = self.future.fold(|worst, current| match worst {
 				None => Some(current.clone()),
-				Some(ref tx) if tx.imported_at > current.imported_at => Some(current.clone()),
-				other => other,
+				Some(tx) => {
+					let ordering = tx
+						.transaction
+						.cmp_fee_per_weight(&current.transaction)
+						.unwrap_or(Ordering::Equal)
+						.then_with(|| tx.imported_at.cmp(&current.imported_at));
+					match ordering {
+						Ordering::Greater => Some(current.clone()),
+						_ => Some(tx),
+					}
+				},
 			});
 
 			if let Some(worst) = worst {
@@ -443,6 +511,88 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		removed
 	}
 
+	/// Makes sure that no sender exceeds the provided per-sender limits.
+	///
+	/// Removes and returns the newest transactions of every sender above the limits and all
+	/// transactions that depend on them. Transactions without a known sender are not limited.
+	/// Only the senders that got new transactions since the previous call are checked.
+	pub fn enforce_sender_limits(
+		&mut self,
+		ready: Option<&Limit>,
+		future: Option<&Limit>,
+	) -> Vec<Arc<Transaction<Hash, Ex>>> {
+		let dirty_senders = std::mem::take(&mut self.dirty_senders);
+		let mut removed = vec![];
+		if ready.is_none() && future.is_none() {
+			return removed
+		}
+
+		for sender in dirty_senders {
+			while let Some(hash) = self.sender_above_limits(&sender, ready, future) {
+				removed.append(&mut self.remove_subtree(&[hash]));
+			}
+		}
+
+		removed
+	}
+
+	/// Returns the newest transaction of the sender in a queue above its limit, if there is one.
+	fn sender_above_limits(
+		&self,
+		sender: &[u8],
+		ready: Option<&Limit>,
+		future: Option<&Limit>,
+	) -> Option<Hash> {
+		let mut ready_txs = vec![];
+		let mut future_txs = vec![];
+		for hash in self.by_sender.get(sender)? {
+			if let Some(tx) = self.ready.ref_by_hash(hash) {
+				ready_txs.push((tx.insertion_id, tx.transaction));
+			} else if let Some(tx) = self.future.get(hash) {
+				future_txs.push((tx.imported_at, tx.transaction.clone()));
+			}
+		}
+
+		newest_above_limit(ready, &ready_txs).or_else(|| newest_above_limit(future, &future_txs))
+	}
+
+	/// Adds the transaction to the index of its sender, if the runtime reported one.
+	fn index_sender(&mut self, tx: &Transaction<Hash, Ex>) {
+		if let Some(info) = tx.info.as_ref() {
+			self.by_sender.entry(info.sender.clone()).or_default().insert(tx.hash.clone());
+			self.senders.insert(tx.hash.clone(), info.sender.clone());
+			self.dirty_senders.insert(info.sender.clone());
+		}
+	}
+
+	/// Marks the senders of the transactions to be checked against the per-sender limits.
+	fn mark_senders_dirty<'a>(&mut self, hashes: impl IntoIterator<Item = &'a Hash>)
+	where
+		Hash: 'a,
+	{
+		for hash in hashes {
+			if let Some(sender) = self.senders.get(hash) {
+				self.dirty_senders.insert(sender.clone());
+			}
+		}
+	}
+
+	/// Removes the transactions that left the pool from the index of their senders.
+	fn forget_senders<'a>(&mut self, hashes: impl IntoIterator<Item = &'a Hash>)
+	where
+		Hash: 'a,
+	{
+		for hash in hashes {
+			let Some(sender) = self.senders.remove(hash) else { continue };
+			if let Some(hashes) = self.by_sender.get_mut(&sender) {
+				hashes.remove(hash);
+				if hashes.is_empty() {
+					self.by_sender.remove(&sender);
+				}
+			}
+		}
+	}
+
 	/// Removes all transactions represented by the hashes and all other transactions
 	/// that depend on them.
 	///
@@ -454,12 +604,15 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 	pub fn remove_subtree(&mut self, hashes: &[Hash]) -> Vec<Arc<Transaction<Hash, Ex>>> {
 		let mut removed = self.ready.remove_subtree(hashes);
 		removed.extend(self.future.remove(hashes));
+		self.forget_senders(removed.iter().map(|tx| &tx.hash));
 		removed
 	}
 
 	/// Removes and returns all transactions from the future queue.
 	pub fn clear_future(&mut self) -> Vec<Arc<Transaction<Hash, Ex>>> {
-		self.future.clear()
+		let removed = self.future.clear();
+		self.forget_senders(removed.iter().map(|tx| &tx.hash));
+		removed
 	}
 
 	/// Prunes transactions that provide given list of tags.
@@ -499,6 +652,8 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 				},
 			}
 		}
+		self.forget_senders(pruned.iter().map(|tx| &tx.hash));
+		self.forget_senders(&failed);
 
 		PruneStatus { pruned, failed, promoted }
 	}
@@ -530,6 +685,21 @@ impl Limit {
 	}
 }
 
+/// Returns the newest of the transactions of a sender if together they exceed the limit.
+///
+/// Transactions are given together with a key that orders them by age.
+fn newest_above_limit<Hash: Clone, Ex, K: Ord>(
+	limit: Option<&Limit>,
+	txs: &[(K, Arc<Transaction<Hash, Ex>>)],
+) -> Option<Hash> {
+	let bytes = txs.iter().map(|(_, tx)| tx.bytes).sum();
+	if !limit?.is_exceeded(txs.len(), bytes) {
+		return None
+	}
+
+	txs.iter().max_by(|a, b| a.0.cmp(&b.0)).map(|(_, tx)| tx.hash.clone())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -550,6 +720,7 @@ mod tests {
 		provides: vec![],
 		propagate: true,
 		source: Source::External,
+		info: None,
 	};
 
 	#[test]
@@ -1019,6 +1190,35 @@ source: TransactionSource::External, requires: [03, 02], provides: [04], data: [
 		assert_eq!(pool.future.len(), 0);
 	}
 
+	#[test]
+	fn should_only_check_senders_with_new_transactions() {
+		// given
+		let mut pool = pool();
+		let info = |sender: u8| Some(TransactionInfo { sender: vec![sender], fee: 1, weight: 1 });
+		let limit = Limit { count: 1, total_bytes: usize::MAX };
+		pool.import(Transaction { hash: 1, info: info(1), ..DEFAULT_TX.clone() })
+			.unwrap();
+		pool.import(Transaction { hash: 2, info: info(1), ..DEFAULT_TX.clone() })
+			.unwrap();
+		pool.import(Transaction { hash: 3, info: info(2), ..DEFAULT_TX.clone() })
+			.unwrap();
+
+		// when
+		let removed = pool.enforce_sender_limits(Some(&limit), None);
+
+		// then
+		assert_eq!(removed.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![2]);
+		assert!(pool.dirty_senders.is_empty());
+		assert_eq!(pool.by_sender.get(&vec![1u8]).unwrap().len(), 1);
+
+		// and when
+		pool.remove_subtree(&[1, 3]);
+
+		// then
+		assert!(pool.by_sender.is_empty());
+		assert!(pool.senders.is_empty());
+	}
+
 	#[test]
 	fn should_accept_future_transactions_when_explicitly_asked_to() {
 		// given
@@ -157,6 +157,16 @@ impl<Hash: hash::Hash + Eq + Clone, Ex> FutureTransactions<Hash, Ex> {
 		self.waiting.contains_key(hash)
 	}
 
+	/// Returns the waiting transaction with given hash.
+	pub fn get(&self, hash: &Hash) -> Option<&WaitingTransaction<Hash, Ex>> {
+		self.waiting.get(hash)
+	}
+
+	/// Returns the tags the transaction with given hash is still waiting for.
+	pub fn missing_tags(&self, hash: &Hash) -> Option<&HashSet<Tag>> {
+		self.waiting.get(hash).map(|tx| &tx.missing_tags)
+	}
+
 	/// Returns a list of known transactions
 	pub fn by_hashes(&self, hashes: &[Hash]) -> Vec<Option<Arc<Transaction<Hash, Ex>>>> {
 		hashes
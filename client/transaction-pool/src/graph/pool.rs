@@ -16,10 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{collections::HashMap, path::PathBuf, pin::Pin, sync::Arc, time::Duration};
 
 use crate::LOG_TARGET;
-use futures::{channel::mpsc::Receiver, Future};
+use futures::{channel::mpsc::Receiver, Future, TryFutureExt};
 use sc_transaction_pool_api::error;
 use sp_blockchain::TreeRoute;
 use sp_runtime::{
@@ -29,6 +29,7 @@ use sp_runtime::{
 		TransactionSource, TransactionTag as Tag, TransactionValidity, TransactionValidityError,
 	},
 };
+use sp_transaction_pool::runtime_api::TransactionInfo;
 use std::time::Instant;
 
 use super::{
@@ -50,6 +51,10 @@ pub type ExtrinsicFor<A> = <<A as ChainApi>::Block as traits::Block>::Extrinsic;
 pub type NumberFor<A> = traits::NumberFor<<A as ChainApi>::Block>;
 /// A type of transaction stored in the pool
 pub type TransactionFor<A> = Arc<base::Transaction<ExtrinsicHash<A>, ExtrinsicFor<A>>>;
+/// Future returned by [`ChainApi::validate_transaction_with_info`].
+pub type ValidationWithInfoFuture<'a, E> = Pin<
+	Box<dyn Future<Output = Result<(TransactionValidity, Option<TransactionInfo>), E>> + Send + 'a>,
+>;
 /// A type of validated transaction stored in the pool.
 pub type ValidatedTransactionFor<A> =
 	ValidatedTransaction<ExtrinsicHash<A>, ExtrinsicFor<A>, <A as ChainApi>::Error>;
@@ -106,6 +111,19 @@ pub trait ChainApi: Send + Sync {
 		from: <Self::Block as BlockT>::Hash,
 		to: <Self::Block as BlockT>::Hash,
 	) -> Result<TreeRoute<Self::Block>, Self::Error>;
+
+	/// Verify extrinsic at given block and return its sender and fee.
+	///
+	/// The sender and the fee are used for per-sender limits and fee per weight eviction, they are
+	/// `None` if the runtime does not report them. By default they are never reported.
+	fn validate_transaction_with_info(
+		&self,
+		at: &BlockId<Self::Block>,
+		source: TransactionSource,
+		uxt: ExtrinsicFor<Self>,
+	) -> ValidationWithInfoFuture<'_, Self::Error> {
+		Box::pin(self.validate_transaction(at, source, uxt).map_ok(|validity| (validity, None)))
+	}
 }
 
 /// Pool configuration options.
@@ -119,6 +137,18 @@ pub struct Options {
 	pub reject_future_transactions: bool,
 	/// How long the extrinsic is banned for.
 	pub ban_time: Duration,
+	/// Ready queue limits per sender.
+	///
+	/// Only applies to transactions whose sender is reported by the runtime.
+	pub ready_per_sender: Option<base::Limit>,
+	/// Future queue limits per sender.
+	///
+	/// Only applies to transactions whose sender is reported by the runtime.
+	pub future_per_sender: Option<base::Limit>,
+	/// Path of the journal the pool is persisted to across restarts.
+	///
+	/// Journaling is disabled if `None`.
+	pub journal: Option<PathBuf>,
 }
 
 impl Default for Options {
@@ -128,6 +158,9 @@ impl Default for Options {
 			future: base::Limit { count: 512, total_bytes: 1 * 1024 * 1024 },
 			reject_future_transactions: false,
 			ban_time: Duration::from_secs(60 * 30),
+			ready_per_sender: None,
+			future_per_sender: None,
+			journal: None,
 		}
 	}
 }
@@ -410,11 +443,11 @@ impl<B: ChainApi> Pool<B> {
 		let validation_result = self
 			.validated_pool
 			.api()
-			.validate_transaction(block_id, source, xt.clone())
+			.validate_transaction_with_info(block_id, source, xt.clone())
 			.await;
 
-		let status = match validation_result {
-			Ok(status) => status,
+		let (status, info) = match validation_result {
+			Ok(result) => result,
 			Err(e) => return (hash, ValidatedTransaction::Invalid(hash, e)),
 		};
 
@@ -430,6 +463,7 @@ impl<B: ChainApi> Pool<B> {
 						xt,
 						bytes,
 						validity,
+						info,
 					)
 				},
 			Err(TransactionValidityError::Invalid(e)) =>
@@ -460,7 +494,7 @@ mod tests {
 	use assert_matches::assert_matches;
 	use futures::executor::block_on;
 	use parking_lot::Mutex;
-	use sc_transaction_pool_api::TransactionStatus;
+	use sc_transaction_pool_api::{PendingReason, TransactionStatus};
 	use sp_runtime::transaction_validity::TransactionSource;
 	use std::{collections::HashMap, time::Instant};
 	use substrate_test_runtime::{AccountId, Extrinsic, Transfer, H256};
@@ -701,6 +735,84 @@ mod tests {
 		assert!(!pool.validated_pool.is_banned(&hash2));
 	}
 
+	#[test]
+	fn should_limit_futures_per_sender() {
+		// given
+		let options = Options {
+			future_per_sender: Some(Limit { count: 2, total_bytes: usize::MAX }),
+			..Default::default()
+		};
+		let api = TestApi { report_info: true, ..Default::default() };
+		let pool = Pool::new(options, true.into(), api.into());
+		let transfer = |from, nonce| {
+			uxt(Transfer {
+				from: AccountId::from_h256(H256::from_low_u64_be(from)),
+				to: AccountId::from_h256(H256::from_low_u64_be(3)),
+				amount: 5,
+				nonce,
+			})
+		};
+
+		let hash1 =
+			block_on(pool.submit_one(&BlockId::Number(0), SOURCE, transfer(1, 10))).unwrap();
+		block_on(pool.submit_one(&BlockId::Number(0), SOURCE, transfer(1, 11))).unwrap();
+
+		// when
+		let err =
+			block_on(pool.submit_one(&BlockId::Number(0), SOURCE, transfer(1, 12))).unwrap_err();
+		block_on(pool.submit_one(&BlockId::Number(0), SOURCE, transfer(2, 20))).unwrap();
+
+		// then
+		assert_matches!(err, error::Error::ImmediatelyDropped);
+		assert_eq!(pool.validated_pool().status().future, 3);
+		assert_eq!(
+			pool.validated_pool().pending_reason(&pool.hash_of(&transfer(1, 12))),
+			PendingReason::SenderLimit,
+		);
+		assert_eq!(
+			pool.validated_pool().pending_reason(&hash1),
+			PendingReason::Future { missing_tags: vec![vec![9].into()] },
+		);
+	}
+
+	#[test]
+	fn should_evict_lowest_fee_per_weight_first() {
+		// given
+		let limit = Limit { count: 1, total_bytes: usize::MAX };
+		let options = Options { ready: limit, ..Default::default() };
+		let api = TestApi { report_info: true, ..Default::default() };
+		let pool = Pool::new(options, true.into(), api.into());
+
+		let hash1 = block_on(pool.submit_one(
+			&BlockId::Number(0),
+			SOURCE,
+			uxt(Transfer {
+				from: AccountId::from_h256(H256::from_low_u64_be(1)),
+				to: AccountId::from_h256(H256::from_low_u64_be(3)),
+				amount: 10,
+				nonce: 0,
+			}),
+		))
+		.unwrap();
+
+		// when
+		let res = block_on(pool.submit_one(
+			&BlockId::Number(1),
+			SOURCE,
+			uxt(Transfer {
+				from: AccountId::from_h256(H256::from_low_u64_be(2)),
+				to: AccountId::from_h256(H256::from_low_u64_be(3)),
+				amount: 5,
+				nonce: 1,
+			}),
+		));
+
+		// then
+		assert_matches!(res, Err(error::Error::ImmediatelyDropped));
+		assert_eq!(pool.validated_pool().ready().map(|v| v.hash).collect::<Vec<_>>(), vec![hash1]);
+		assert_eq!(pool.validated_pool().pending_reason(&hash1), PendingReason::Ready);
+	}
+
 	#[test]
 	fn should_error_if_reject_immediately() {
 		// given
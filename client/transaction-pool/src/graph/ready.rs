@@ -250,6 +250,11 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		self.by_hashes(&[hash.clone()]).into_iter().next().unwrap_or(None)
 	}
 
+	/// Retrieve a transaction together with its insertion id by hash.
+	pub fn ref_by_hash(&self, hash: &Hash) -> Option<TransactionRef<Hash, Ex>> {
+		self.ready.read().get(hash).map(|x| x.transaction.clone())
+	}
+
 	/// Retrieve transactions by hash
 	pub fn by_hashes(&self, hashes: &[Hash]) -> Vec<Option<Arc<Transaction<Hash, Ex>>>> {
 		let ready = self.ready.read();
@@ -602,6 +607,7 @@ mod tests {
 			provides: vec![vec![3], vec![4]],
 			propagate: true,
 			source: Source::External,
+			info: None,
 		}
 	}
 
@@ -712,6 +718,7 @@ mod tests {
 			provides: vec![],
 			propagate: true,
 			source: Source::External,
+			info: None,
 		};
 
 		// when
@@ -25,13 +25,14 @@ use std::{
 use crate::LOG_TARGET;
 use futures::channel::mpsc::{channel, Sender};
 use parking_lot::{Mutex, RwLock};
-use sc_transaction_pool_api::{error, PoolStatus, ReadyTransactions};
+use sc_transaction_pool_api::{error, PendingReason, PoolStatus, ReadyTransactions};
 use serde::Serialize;
 use sp_runtime::{
 	generic::BlockId,
 	traits::{self, SaturatedConversion},
 	transaction_validity::{TransactionSource, TransactionTag as Tag, ValidTransaction},
 };
+use sp_transaction_pool::runtime_api::TransactionInfo;
 use std::time::Instant;
 
 use super::{
@@ -44,6 +45,9 @@ use super::{
 	watcher::Watcher,
 };
 
+/// Expected number of transactions remembered as dropped because of a limit.
+const LIMITED_EXPECTED_SIZE: usize = 2048;
+
 /// Pre-validated transaction. Validated pool only accepts transactions wrapped in this enum.
 #[derive(Debug)]
 pub enum ValidatedTransaction<Hash, Ex, Error> {
@@ -66,6 +70,7 @@ impl<Hash, Ex, Error> ValidatedTransaction<Hash, Ex, Error> {
 		data: Ex,
 		bytes: usize,
 		validity: ValidTransaction,
+		info: Option<TransactionInfo>,
 	) -> Self {
 		Self::Valid(base::Transaction {
 			data,
@@ -77,6 +82,7 @@ impl<Hash, Ex, Error> ValidatedTransaction<Hash, Ex, Error> {
 			provides: validity.provides,
 			propagate: validity.propagate,
 			valid_till: at.saturated_into::<u64>().saturating_add(validity.longevity),
+			info,
 		})
 	}
 }
@@ -109,6 +115,8 @@ pub struct ValidatedPool<B: ChainApi> {
 	pool: RwLock<base::BasePool<ExtrinsicHash<B>, ExtrinsicFor<B>>>,
 	import_notification_sinks: Mutex<Vec<Sender<ExtrinsicHash<B>>>>,
 	rotator: PoolRotator<ExtrinsicHash<B>>,
+	/// Why the transactions dropped because of a limit have been dropped.
+	limited: RwLock<HashMap<ExtrinsicHash<B>, PendingReason>>,
 }
 
 impl<B: ChainApi> ValidatedPool<B> {
@@ -124,6 +132,7 @@ impl<B: ChainApi> ValidatedPool<B> {
 			pool: RwLock::new(base_pool),
 			import_notification_sinks: Default::default(),
 			rotator: PoolRotator::new(ban_time),
+			limited: Default::default(),
 		}
 	}
 
@@ -227,6 +236,31 @@ impl<B: ChainApi> ValidatedPool<B> {
 	}
 
 	fn enforce_limits(&self) -> HashSet<ExtrinsicHash<B>> {
+		let mut removed = self.enforce_sender_limits();
+		removed.extend(self.enforce_pool_limits());
+		removed
+	}
+
+	fn enforce_sender_limits(&self) -> HashSet<ExtrinsicHash<B>> {
+		let ready_limit = self.options.ready_per_sender.as_ref();
+		let future_limit = self.options.future_per_sender.as_ref();
+		// the base pool forgets the senders to check even if there are no limits
+		let removed = self
+			.pool
+			.write()
+			.enforce_sender_limits(ready_limit, future_limit)
+			.into_iter()
+			.map(|x| x.hash)
+			.collect::<HashSet<_>>();
+		if !removed.is_empty() {
+			log::debug!(target: LOG_TARGET, "Enforcing sender limits: {} dropped", removed.len());
+			self.drop_limited(&removed, PendingReason::SenderLimit);
+		}
+
+		removed
+	}
+
+	fn enforce_pool_limits(&self) -> HashSet<ExtrinsicHash<B>> {
 		let status = self.pool.read().status();
 		let ready_limit = &self.options.ready;
 		let future_limit = &self.options.future;
@@ -245,26 +279,17 @@ impl<B: ChainApi> ValidatedPool<B> {
 			);
 
 			// clean up the pool
-			let removed = {
-				let mut pool = self.pool.write();
-				let removed = pool
-					.enforce_limits(ready_limit, future_limit)
-					.into_iter()
-					.map(|x| x.hash)
-					.collect::<HashSet<_>>();
-				// ban all removed transactions
-				self.rotator.ban(&Instant::now(), removed.iter().copied());
-				removed
-			};
+			let removed = self
+				.pool
+				.write()
+				.enforce_limits(ready_limit, future_limit)
+				.into_iter()
+				.map(|x| x.hash)
+				.collect::<HashSet<_>>();
 			if !removed.is_empty() {
 				log::debug!(target: LOG_TARGET, "Enforcing limits: {} dropped", removed.len());
 			}
-
-			// run notifications
-			let mut listener = self.listener.write();
-			for h in &removed {
-				listener.dropped(h, None);
-			}
+			self.drop_limited(&removed, PendingReason::PoolLimit);
 
 			removed
 		} else {
@@ -272,6 +297,27 @@ impl<B: ChainApi> ValidatedPool<B> {
 		}
 	}
 
+	/// Bans transactions dropped because of a limit and notifies their watchers.
+	fn drop_limited(&self, removed: &HashSet<ExtrinsicHash<B>>, reason: PendingReason) {
+		// ban all removed transactions
+		self.rotator.ban(&Instant::now(), removed.iter().copied());
+		{
+			let mut limited = self.limited.write();
+			for h in removed {
+				limited.insert(*h, reason.clone());
+			}
+			if limited.len() > 2 * LIMITED_EXPECTED_SIZE {
+				limited.retain(|h, _| self.rotator.is_banned(h));
+			}
+		}
+
+		// run notifications
+		let mut listener = self.listener.write();
+		for h in removed {
+			listener.dropped(h, None);
+		}
+	}
+
 	/// Import a single extrinsic and starts to watch their progress in the pool.
 	pub fn submit_and_watch(
 		&self,
@@ -557,6 +603,7 @@ impl<B: ChainApi> ValidatedPool<B> {
 		self.remove_invalid(&futures_to_remove);
 		// clear banned transactions timeouts
 		self.rotator.clear_timeouts(&now);
+		self.limited.write().retain(|h, _| self.rotator.is_banned(h));
 
 		Ok(())
 	}
@@ -625,11 +672,39 @@ impl<B: ChainApi> ValidatedPool<B> {
 		self.pool.read().futures().map(|tx| (tx.hash, tx.data.clone())).collect()
 	}
 
+	/// Returns all transactions in the pool, ready ones first.
+	pub fn transactions(&self) -> Vec<TransactionFor<B>> {
+		let pool = self.pool.read();
+		// future transactions are duplicated, since they are not shared with the ready queue
+		pool.ready().chain(pool.futures().map(|tx| Arc::new(tx.duplicate()))).collect()
+	}
+
 	/// Returns pool status.
 	pub fn status(&self) -> PoolStatus {
 		self.pool.read().status()
 	}
 
+	/// Returns why the transaction with given hash is still pending.
+	pub fn pending_reason(&self, hash: &ExtrinsicHash<B>) -> PendingReason {
+		{
+			let pool = self.pool.read();
+			if pool.ready_by_hash(hash).is_some() {
+				return PendingReason::Ready
+			}
+			if let Some(missing_tags) = pool.missing_tags(hash) {
+				return PendingReason::Future {
+					missing_tags: missing_tags.into_iter().map(Into::into).collect(),
+				}
+			}
+		}
+
+		if self.is_banned(hash) {
+			self.limited.read().get(hash).cloned().unwrap_or(PendingReason::Banned)
+		} else {
+			PendingReason::Unknown
+		}
+	}
+
 	/// Notify all watchers that transactions in the block with hash have been finalized
 	pub async fn on_block_finalized(&self, block_hash: BlockHash<B>) -> Result<(), B::Error> {
 		log::trace!(
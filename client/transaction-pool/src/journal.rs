@@ -0,0 +1,291 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction pool journal.
+//!
+//! Persists the ready and future transactions of the pool to a file, so that they survive
+//! a restart of the node. On startup the journaled transactions are submitted again, which
+//! revalidates them against the best block.
+
+use std::{
+	fs, io,
+	path::{Path, PathBuf},
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use codec::{Decode, Encode};
+use sp_runtime::{generic::BlockId, transaction_validity::TransactionSource};
+
+use crate::{
+	graph::{self, BlockHash, ChainApi, ExtrinsicFor},
+	LOG_TARGET,
+};
+
+/// Version of the journal file format.
+const JOURNAL_VERSION: u32 = 1;
+
+/// How often the pool is written to the journal.
+const JOURNAL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A single journaled transaction.
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+struct JournalEntry {
+	/// Source of the transaction.
+	source: TransactionSource,
+	/// Block number until which the transaction is valid.
+	valid_till: u64,
+	/// Encoded extrinsic.
+	data: Vec<u8>,
+}
+
+/// Journal of the transaction pool, stored in a dedicated file.
+pub(crate) struct Journal {
+	path: PathBuf,
+	/// Whether the journaled transactions have been submitted to the pool again.
+	///
+	/// The journal is not written before, otherwise transactions that are not yet restored
+	/// would be lost.
+	restored: AtomicBool,
+}
+
+impl Journal {
+	/// Create a journal stored at the given path.
+	pub fn new(path: impl Into<PathBuf>) -> Self {
+		Self { path: path.into(), restored: AtomicBool::new(false) }
+	}
+
+	/// Path of the journal file.
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	/// Read the journaled transactions that are still valid after `best_number`.
+	///
+	/// A missing journal is treated as an empty one.
+	pub fn read<Xt: Decode>(&self, best_number: u64) -> io::Result<Vec<(TransactionSource, Xt)>> {
+		let bytes = match fs::read(&self.path) {
+			Ok(bytes) => bytes,
+			Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+			Err(e) => return Err(e),
+		};
+
+		let (version, entries) = <(u32, Vec<JournalEntry>)>::decode(&mut &bytes[..])
+			.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+		if version != JOURNAL_VERSION {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidData,
+				format!("unsupported journal version {}", version),
+			))
+		}
+
+		let total = entries.len();
+		let restored = entries
+			.into_iter()
+			.filter(|entry| entry.valid_till > best_number)
+			.filter_map(|entry| match Xt::decode(&mut &entry.data[..]) {
+				Ok(xt) => Some((entry.source, xt)),
+				Err(e) => {
+					log::debug!(target: LOG_TARGET, "Skipping undecodable journal entry: {}", e);
+					None
+				},
+			})
+			.collect::<Vec<_>>();
+		log::debug!(
+			target: LOG_TARGET,
+			"Read {} of {} journaled transactions",
+			restored.len(),
+			total,
+		);
+
+		Ok(restored)
+	}
+
+	/// Replace the journal with the given entries.
+	///
+	/// The entries are written to a temporary file first, so that an interrupted write
+	/// does not corrupt the existing journal.
+	fn write(&self, entries: Vec<JournalEntry>) -> io::Result<()> {
+		if let Some(parent) = self.path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		let tmp = self.path.with_extension("tmp");
+		fs::write(&tmp, (JOURNAL_VERSION, entries).encode())?;
+		fs::rename(&tmp, &self.path)
+	}
+
+	/// Mark the journaled transactions as submitted to the pool again.
+	pub fn set_restored(&self) {
+		self.restored.store(true, Ordering::Release);
+	}
+
+	/// Write all ready and future transactions of the pool to the journal.
+	///
+	/// Does nothing until the journaled transactions have been restored.
+	pub fn store<B: ChainApi>(&self, pool: &graph::Pool<B>) {
+		if !self.restored.load(Ordering::Acquire) {
+			log::debug!(target: LOG_TARGET, "Not journaling before the journal is restored");
+			return
+		}
+
+		let entries = pool
+			.validated_pool()
+			.transactions()
+			.into_iter()
+			.map(|tx| JournalEntry {
+				source: tx.source,
+				valid_till: tx.valid_till,
+				data: tx.data.encode(),
+			})
+			.collect::<Vec<_>>();
+		let count = entries.len();
+
+		match self.write(entries) {
+			Ok(()) => log::trace!(target: LOG_TARGET, "Journaled {} transactions", count),
+			Err(e) => log::warn!(
+				target: LOG_TARGET,
+				"Failed to write transaction pool journal {}: {}",
+				self.path.display(),
+				e,
+			),
+		}
+	}
+}
+
+/// Resubmit the journaled transactions and keep the journal up to date.
+///
+/// The restored transactions are validated against the block `at`. The task never finishes,
+/// since it is spawned as an essential task.
+pub(crate) async fn journal_task<B: ChainApi>(
+	journal: Arc<Journal>,
+	pool: Arc<graph::Pool<B>>,
+	restored: Vec<(TransactionSource, ExtrinsicFor<B>)>,
+	at: BlockHash<B>,
+) {
+	for source in
+		[TransactionSource::InBlock, TransactionSource::Local, TransactionSource::External]
+	{
+		let xts = restored
+			.iter()
+			.filter(|(s, _)| *s == source)
+			.map(|(_, xt)| xt.clone())
+			.collect::<Vec<_>>();
+		if xts.is_empty() {
+			continue
+		}
+
+		match pool.submit_at(&BlockId::Hash(at), source, xts).await {
+			Ok(results) => log::debug!(
+				target: LOG_TARGET,
+				"Restored {} of {} journaled {:?} transactions",
+				results.iter().filter(|r| r.is_ok()).count(),
+				results.len(),
+				source,
+			),
+			Err(e) => log::warn!(
+				target: LOG_TARGET,
+				"Failed to restore journaled {:?} transactions: {}",
+				source,
+				e,
+			),
+		}
+	}
+	journal.set_restored();
+
+	loop {
+		futures_timer::Delay::new(JOURNAL_INTERVAL).await;
+		journal.store(&pool);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::pool;
+
+	fn entry(valid_till: u64, data: Vec<u8>) -> JournalEntry {
+		JournalEntry { source: TransactionSource::External, valid_till, data: data.encode() }
+	}
+
+	#[test]
+	fn should_skip_expired_entries() {
+		// given
+		let dir = tempfile::tempdir().unwrap();
+		let journal = Journal::new(dir.path().join("journal"));
+		journal
+			.write(vec![entry(10, vec![1]), entry(11, vec![2]), entry(64, vec![3])])
+			.unwrap();
+
+		// when
+		let restored = journal.read::<Vec<u8>>(10).unwrap();
+
+		// then
+		assert_eq!(
+			restored,
+			vec![(TransactionSource::External, vec![2]), (TransactionSource::External, vec![3])],
+		);
+	}
+
+	#[test]
+	fn should_treat_missing_journal_as_empty() {
+		let dir = tempfile::tempdir().unwrap();
+		let journal = Journal::new(dir.path().join("journal"));
+
+		assert!(journal.read::<Vec<u8>>(0).unwrap().is_empty());
+	}
+
+	#[test]
+	fn should_reject_unknown_version() {
+		// given
+		let dir = tempfile::tempdir().unwrap();
+		let journal = Journal::new(dir.path().join("journal"));
+		fs::write(journal.path(), (JOURNAL_VERSION + 1, Vec::<JournalEntry>::new()).encode())
+			.unwrap();
+
+		// when
+		let result = journal.read::<Vec<u8>>(0);
+
+		// then
+		assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn should_not_overwrite_journal_before_restore() {
+		// given
+		let dir = tempfile::tempdir().unwrap();
+		let journal = Journal::new(dir.path().join("journal"));
+		journal.write(vec![entry(64, vec![1])]).unwrap();
+		let pool = pool();
+
+		// when
+		journal.store(&pool);
+
+		// then
+		assert_eq!(journal.read::<Vec<u8>>(0).unwrap().len(), 1);
+
+		// and when
+		journal.set_restored();
+		journal.store(&pool);
+
+		// then
+		assert!(journal.read::<Vec<u8>>(0).unwrap().is_empty());
+	}
+}
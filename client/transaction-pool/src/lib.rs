@@ -26,6 +26,7 @@ mod api;
 mod enactment_state;
 pub mod error;
 mod graph;
+mod journal;
 mod metrics;
 mod revalidation;
 #[cfg(test)]
@@ -52,13 +53,16 @@ use std::{
 use graph::{ExtrinsicHash, IsValidator};
 use sc_transaction_pool_api::{
 	error::Error as TxPoolError, ChainEvent, ImportNotificationStream, MaintainedTransactionPool,
-	PoolFuture, PoolStatus, ReadyTransactions, TransactionFor, TransactionPool, TransactionSource,
-	TransactionStatusStreamFor, TxHash,
+	PendingReason, PoolFuture, PoolStatus, ReadyTransactions, TransactionFor, TransactionPool,
+	TransactionSource, TransactionStatusStreamFor, TxHash,
 };
 use sp_core::traits::SpawnEssentialNamed;
 use sp_runtime::{
 	generic::BlockId,
-	traits::{AtLeast32Bit, Block as BlockT, Extrinsic, Header as HeaderT, NumberFor, Zero},
+	traits::{
+		AtLeast32Bit, Block as BlockT, Extrinsic, Header as HeaderT, NumberFor, SaturatedConversion,
+		Zero,
+	},
 };
 use std::time::Instant;
 
@@ -93,6 +97,7 @@ where
 	ready_poll: Arc<Mutex<ReadyPoll<ReadyIteratorFor<PoolApi>, Block>>>,
 	metrics: PrometheusMetrics,
 	enactment_state: Arc<Mutex<EnactmentState<Block>>>,
+	journal: Option<Arc<journal::Journal>>,
 }
 
 struct ReadyPoll<T, Block: BlockT> {
@@ -180,6 +185,7 @@ where
 					best_block_hash,
 					finalized_hash,
 				))),
+				journal: None,
 			},
 			background_task,
 		)
@@ -198,6 +204,7 @@ where
 		best_block_hash: Block::Hash,
 		finalized_hash: Block::Hash,
 	) -> Self {
+		let journal = options.journal.clone().map(|path| Arc::new(journal::Journal::new(path)));
 		let pool = Arc::new(graph::Pool::new(options, is_validator, pool_api.clone()));
 		let (revalidation_queue, background_task) = match revalidation_type {
 			RevalidationType::Light =>
@@ -213,6 +220,24 @@ where
 			spawner.spawn_essential("txpool-background", Some("transaction-pool"), background_task);
 		}
 
+		if let Some(journal) = &journal {
+			let restored = journal.read(best_block_number.saturated_into()).unwrap_or_else(|e| {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to read transaction pool journal {}: {}",
+					journal.path().display(),
+					e,
+				);
+				Vec::new()
+			});
+			spawner.spawn_essential(
+				"txpool-journal",
+				Some("transaction-pool"),
+				journal::journal_task(journal.clone(), pool.clone(), restored, best_block_hash)
+					.boxed(),
+			);
+		}
+
 		Self {
 			api: pool_api,
 			pool,
@@ -228,6 +253,7 @@ where
 				best_block_hash,
 				finalized_hash,
 			))),
+			journal,
 		}
 	}
 
@@ -242,6 +268,18 @@ where
 	}
 }
 
+impl<PoolApi, Block> Drop for BasicPool<PoolApi, Block>
+where
+	Block: BlockT,
+	PoolApi: graph::ChainApi<Block = Block>,
+{
+	fn drop(&mut self) {
+		if let Some(journal) = &self.journal {
+			journal.store(&self.pool);
+		}
+	}
+}
+
 impl<PoolApi, Block> TransactionPool for BasicPool<PoolApi, Block>
 where
 	Block: BlockT,
@@ -323,6 +361,10 @@ where
 		self.pool.validated_pool().on_broadcasted(propagations)
 	}
 
+	fn pending_reason(&self, hash: &TxHash<Self>) -> PendingReason {
+		self.pool.validated_pool().pending_reason(hash)
+	}
+
 	fn ready_transaction(&self, hash: &TxHash<Self>) -> Option<Arc<Self::InPoolTransaction>> {
 		self.pool.validated_pool().ready_by_hash(hash)
 	}
@@ -424,19 +466,17 @@ where
 		at: &BlockId<Self::Block>,
 		xt: sc_transaction_pool_api::LocalTransactionFor<Self>,
 	) -> Result<Self::Hash, Self::Error> {
-		use sp_runtime::{
-			traits::SaturatedConversion, transaction_validity::TransactionValidityError,
-		};
+		use sp_runtime::transaction_validity::TransactionValidityError;
 
-		let validity = self
+		let (validity, info) = self
 			.api
-			.validate_transaction_blocking(at, TransactionSource::Local, xt.clone())?
-			.map_err(|e| {
-				Self::Error::Pool(match e {
-					TransactionValidityError::Invalid(i) => TxPoolError::InvalidTransaction(i),
-					TransactionValidityError::Unknown(u) => TxPoolError::UnknownTransaction(u),
-				})
-			})?;
+			.validate_transaction_with_info_blocking(at, TransactionSource::Local, xt.clone())?;
+		let validity = validity.map_err(|e| {
+			Self::Error::Pool(match e {
+				TransactionValidityError::Invalid(i) => TxPoolError::InvalidTransaction(i),
+				TransactionValidityError::Unknown(u) => TxPoolError::UnknownTransaction(u),
+			})
+		})?;
 
 		let (hash, bytes) = self.pool.validated_pool().api().hash_and_length(&xt);
 		let block_number = self
@@ -451,6 +491,7 @@ where
 			xt,
 			bytes,
 			validity,
+			info,
 		);
 
 		self.pool.validated_pool().submit(vec![validated]).remove(0)
@@ -18,8 +18,9 @@
 
 //! Testing related primitives for internal usage in this crate.
 
-use crate::graph::{BlockHash, ChainApi, ExtrinsicFor, NumberFor, Pool};
+use crate::graph::{BlockHash, ChainApi, ExtrinsicFor, NumberFor, Pool, ValidationWithInfoFuture};
 use codec::Encode;
+use futures::TryFutureExt;
 use parking_lot::Mutex;
 use sc_transaction_pool_api::error;
 use sp_blockchain::TreeRoute;
@@ -30,6 +31,7 @@ use sp_runtime::{
 		InvalidTransaction, TransactionSource, TransactionValidity, ValidTransaction,
 	},
 };
+use sp_transaction_pool::runtime_api::TransactionInfo;
 use std::{collections::HashSet, sync::Arc};
 use substrate_test_runtime::{Block, Extrinsic, Hashing, Transfer, H256};
 
@@ -43,6 +45,8 @@ pub(crate) struct TestApi {
 	pub clear_requirements: Arc<Mutex<HashSet<H256>>>,
 	pub add_requirements: Arc<Mutex<HashSet<H256>>>,
 	pub validation_requests: Arc<Mutex<Vec<Extrinsic>>>,
+	/// Report the sender of transfers and their amount as the fee.
+	pub report_info: bool,
 }
 
 impl TestApi {
@@ -182,6 +186,23 @@ impl ChainApi for TestApi {
 	) -> Result<TreeRoute<Self::Block>, Self::Error> {
 		unimplemented!()
 	}
+
+	fn validate_transaction_with_info(
+		&self,
+		at: &BlockId<Self::Block>,
+		source: TransactionSource,
+		uxt: ExtrinsicFor<Self>,
+	) -> ValidationWithInfoFuture<'_, Self::Error> {
+		let info = match &uxt {
+			Extrinsic::Transfer { transfer, .. } if self.report_info => Some(TransactionInfo {
+				sender: transfer.from.encode(),
+				fee: transfer.amount as u128,
+				weight: 1,
+			}),
+			_ => None,
+		};
+		Box::pin(self.validate_transaction(at, source, uxt).map_ok(|validity| (validity, info)))
+	}
 }
 
 pub(crate) fn uxt(transfer: Transfer) -> Extrinsic {
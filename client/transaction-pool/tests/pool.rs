@@ -1568,3 +1568,74 @@ fn best_block_after_finalization_does_not_retract() {
 		assert_eq!(stream.next(), None);
 	}
 }
+
+fn journaled_pool(api: Arc<TestApi>, journal: &std::path::Path) -> BasicPool<TestApi, Block> {
+	let genesis_hash = api.chain().read().block_by_number.get(&0).unwrap()[0].0.header.hash();
+	BasicPool::with_revalidation_type(
+		Options { journal: Some(journal.to_path_buf()), ..Default::default() },
+		true.into(),
+		api,
+		None,
+		RevalidationType::Light,
+		sp_core::testing::TaskExecutor::new(),
+		0,
+		genesis_hash,
+		genesis_hash,
+	)
+}
+
+fn wait_for_status(pool: &BasicPool<TestApi, Block>, ready: usize, future: usize) {
+	for _ in 0..100 {
+		let status = pool.status();
+		if status.ready == ready && status.future == future {
+			return
+		}
+		std::thread::sleep(std::time::Duration::from_millis(50));
+	}
+	panic!("pool status {:?} did not reach ready {} and future {}", pool.status(), ready, future);
+}
+
+#[test]
+fn journaled_transactions_should_survive_restart() {
+	// given
+	let dir = tempfile::tempdir().unwrap();
+	let journal = dir.path().join("txpool").join("journal");
+	let api = Arc::new(TestApi::with_alice_nonce(209));
+	let pool = journaled_pool(api.clone(), &journal);
+	block_on(pool.submit_one(&BlockId::number(0), SOURCE, uxt(Alice, 209))).unwrap();
+	block_on(pool.submit_one(&BlockId::number(0), SOURCE, uxt(Bob, 1))).unwrap();
+	assert_eq!(pool.status().ready, 1);
+	assert_eq!(pool.status().future, 1);
+
+	// when
+	drop(pool);
+	let pool = journaled_pool(api, &journal);
+
+	// then
+	wait_for_status(&pool, 1, 1);
+	let ready: Vec<_> = pool.ready().map(|tx| tx.data.transfer().nonce).collect();
+	assert_eq!(ready, vec![209]);
+}
+
+#[test]
+fn journaled_transactions_should_be_revalidated_on_restart() {
+	// given
+	let dir = tempfile::tempdir().unwrap();
+	let journal = dir.path().join("journal");
+	let api = Arc::new(TestApi::with_alice_nonce(209));
+	let pool = journaled_pool(api.clone(), &journal);
+	let xt = uxt(Alice, 209);
+	block_on(pool.submit_one(&BlockId::number(0), SOURCE, xt.clone())).unwrap();
+	block_on(pool.submit_one(&BlockId::number(0), SOURCE, uxt(Bob, 1))).unwrap();
+	drop(pool);
+
+	// when
+	api.add_invalid(&xt);
+	let pool = journaled_pool(api, &journal);
+
+	// then
+	wait_for_status(&pool, 0, 1);
+	let future: Vec<_> =
+		pool.pool().validated_pool().futures().iter().map(|(_, xt)| xt.transfer().nonce).collect();
+	assert_eq!(future, vec![1]);
+}
@@ -63,6 +63,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Asset::<T, I>::get(id).map(|x| x.supply)
 	}
 
+	/// Whether the asset `id` exists and can be transferred between accounts.
+	pub fn is_transferable(id: T::AssetId) -> bool {
+		Asset::<T, I>::get(id).map_or(false, |x| x.is_transferable)
+	}
+
 	pub(super) fn new_account(
 		who: &T::AccountId,
 		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
@@ -226,7 +226,6 @@ pub mod pallet {
 		pub members: Vec<T::AccountId>,
 	}
 
-	#[cfg(feature = "std")]
 	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
 		fn default() -> Self {
 			Self { phantom: Default::default(), members: Default::default() }
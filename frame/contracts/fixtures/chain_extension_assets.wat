@@ -0,0 +1,48 @@
+;; Call the chain extension with the id and arguments passed as input, and return the return
+;; code of the chain extension followed by its output.
+;;
+;; The input is the id of the chain extension and function (4 bytes) followed by the arguments.
+(module
+	(import "seal0" "seal_call_chain_extension"
+		(func $seal_call_chain_extension (param i32 i32 i32 i32 i32) (result i32))
+	)
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 16 16))
+
+	;; [0, 4) len of input buffer
+	(data (i32.const 0) "\00\01")
+
+	;; [4, 8) len of output buffer
+	(data (i32.const 4) "\00\01")
+
+	;; [8, 12) return code of the chain extension
+
+	;; [12, 268) output buffer
+
+	;; [268, 524) input buffer: id followed by the arguments
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(call $seal_input (i32.const 268) (i32.const 0))
+
+		(i32.store
+			(i32.const 8)
+			(call $seal_call_chain_extension
+				(i32.load (i32.const 268))							;; id
+				(i32.const 272)										;; input_ptr
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4))	;; input_len
+				(i32.const 12)										;; output_ptr
+				(i32.const 4)										;; output_len_ptr
+			)
+		)
+
+		;; the return code followed by the output
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 8)
+			(i32.add (i32.load (i32.const 4)) (i32.const 4))
+		)
+	)
+)
@@ -0,0 +1,48 @@
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_caller" (func $seal_caller (param i32 i32)))
+	(import "seal0" "seal_terminate" (func $seal_terminate (param i32 i32)))
+	(import "seal0" "lock_delegate_dependency" (func $lock_delegate_dependency (param i32)))
+	(import "seal0" "unlock_delegate_dependency" (func $unlock_delegate_dependency (param i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 1) selector: 0 = lock, 1 = unlock, 2 = terminate
+
+	;; [1, 33) code hash of the dependency
+
+	;; [36, 40) input size
+	(data (i32.const 36) "\21")
+
+	;; [40, 72) reserved for $seal_caller output
+
+	;; [72, 76) length of the caller buffer
+	(data (i32.const 72) "\20")
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(local $selector i32)
+
+		(call $seal_input (i32.const 0) (i32.const 36))
+		(set_local $selector (i32.load8_u (i32.const 0)))
+
+		(if (i32.eq (get_local $selector) (i32.const 0))
+			(then
+				(call $lock_delegate_dependency (i32.const 1)) ;; Pointer to the code hash.
+			)
+		)
+
+		(if (i32.eq (get_local $selector) (i32.const 1))
+			(then
+				(call $unlock_delegate_dependency (i32.const 1)) ;; Pointer to the code hash.
+			)
+		)
+
+		(if (i32.eq (get_local $selector) (i32.const 2))
+			(then
+				(call $seal_caller (i32.const 40) (i32.const 72))
+				(call $seal_terminate (i32.const 40) (i32.const 32))
+			)
+		)
+	)
+)
@@ -81,6 +81,69 @@ pub type ContractExecResult<Balance> =
 pub type ContractInstantiateResult<AccountId, Balance> =
 	ContractResult<Result<InstantiateReturnValue<AccountId>, DispatchError>, Balance>;
 
+/// Result type of a `bare_call_traced` or `bare_instantiate_traced` call.
+///
+/// It contains the result of the call or instantiation together with a trace of all calls and
+/// instantiations made during the execution.
+#[derive(Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct TracedResult<R, AccountId, Balance> {
+	/// The result of the call or instantiation.
+	pub result: R,
+	/// The calls and instantiations in the order they were made, see [`CallTrace`].
+	pub trace: Vec<CallTrace<AccountId, Balance>>,
+}
+
+/// The kind of a traced call.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum CallKind {
+	/// A call into a contract.
+	Call,
+	/// A call executing the code of another contract in the context of the caller.
+	DelegateCall,
+	/// The instantiation of a new contract.
+	Instantiate,
+}
+
+/// A call or instantiation made during the execution of a contract.
+///
+/// The calls are traced in the order they were made. A call is followed by the calls it made
+/// itself, which have a [`Self::depth`] one higher than the call.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CallTrace<AccountId, Balance> {
+	/// The number of calls this call is nested in. Zero for the call made by the origin.
+	pub depth: u32,
+	/// Whether this is a call, delegate call or instantiation.
+	pub kind: CallKind,
+	/// The account making the call.
+	pub caller: AccountId,
+	/// The contract called or instantiated.
+	///
+	/// For a delegate call this is the contract whose context the code is executed in.
+	pub callee: AccountId,
+	/// The balance transferred to the callee.
+	pub value: Balance,
+	/// The input data passed to the callee.
+	pub input: Vec<u8>,
+	/// The data returned by the callee.
+	///
+	/// If the callee reverted this usually contains the reason for the revert.
+	pub output: Vec<u8>,
+	/// Whether the callee reverted the call.
+	pub reverted: bool,
+	/// The error the call failed with, if any.
+	pub error: Option<DispatchError>,
+	/// How much weight was consumed by the call, including the calls it made.
+	pub gas_consumed: Weight,
+	/// The storage deposit charged or refunded by the call, including the calls it made.
+	///
+	/// This is zero for calls which failed or reverted, since their changes are rolled back.
+	pub storage_deposit: StorageDeposit<Balance>,
+	/// The data of the events emitted by the callee.
+	///
+	/// The events of calls which failed or reverted are listed but rolled back.
+	pub events: Vec<Vec<u8>>,
+}
+
 /// Result type of a `bare_code_upload` call.
 pub type CodeUploadResult<CodeHash, Balance> =
 	Result<CodeUploadReturnValue<CodeHash, Balance>, DispatchError>;
@@ -95,6 +158,8 @@ pub enum ContractAccessError {
 	DoesntExist,
 	/// Storage key cannot be decoded from the provided input data.
 	KeyDecodingFailed,
+	/// Storage is migrating. Try again later.
+	MigrationInProgress,
 }
 
 bitflags! {
@@ -45,6 +45,11 @@
 //! **Chain specific extensions must use the reserved `ID = 0` so that they can't be registered with
 //! the registry.**
 //!
+//! # Off-the-shelf extensions
+//!
+//! The [`assets`] module provides the [`AssetsExtension`](assets::AssetsExtension) which gives
+//! contracts access to fungible assets and NFTs, e.g. those of `pallet-assets` and `pallet-nfts`.
+//!
 //! # Security
 //!
 //! The chain author alone is responsible for the security of the chain extension.
@@ -84,6 +89,8 @@ pub use crate::{exec::Ext, Config};
 pub use frame_system::Config as SysConfig;
 pub use pallet_contracts_primitives::ReturnFlags;
 
+pub mod assets;
+
 /// Result that returns a [`DispatchError`] on error.
 pub type Result<T> = sp_std::result::Result<T, DispatchError>;
 
@@ -0,0 +1,339 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A chain extension giving contracts access to fungible assets and NFTs.
+//!
+//! The [`AssetsExtension`] exposes the [`fungibles`] and [`nonfungibles_v2`] traits implemented
+//! by pallets like `pallet-assets` and `pallet-nfts` to contracts. A contract acts with its own
+//! account: It can only transfer the assets and items it owns, and the assets other accounts
+//! approved it to spend.
+//!
+//! # Function ids
+//!
+//! The extension id of the [`AssetsExtension`] is `0xa55e`, calls with any other extension id are
+//! rejected. The least significant byte of the `func_id` selects the [`Function`] and the most
+//! significant byte its version. The encoding of the input and output of a function never changes
+//! once it is released. Instead, a new version of the function is added. All functions are at
+//! version `0`.
+//!
+//! | `func_id` | Function | Input | Output |
+//! |-----------|----------|-------|--------|
+//! | `0x0000` | [`Function::Balance`] | `(AssetId, AccountId)` | `Balance` |
+//! | `0x0001` | [`Function::TotalSupply`] | `AssetId` | `Balance` |
+//! | `0x0002` | [`Function::Allowance`] | `(AssetId, AccountId, AccountId)` | `Balance` |
+//! | `0x0003` | [`Function::Transfer`] | `(AssetId, AccountId, Balance)` | - |
+//! | `0x0004` | [`Function::Approve`] | `(AssetId, AccountId, Balance)` | - |
+//! | `0x0005` | [`Function::TransferFrom`] | `(AssetId, AccountId, AccountId, Balance)` | - |
+//! | `0x0006` | [`Function::NftOwner`] | `(CollectionId, ItemId)` | `Option<AccountId>` |
+//! | `0x0007` | [`Function::NftTransfer`] | `(CollectionId, ItemId, AccountId)` | - |
+//!
+//! The input is read from and the output is written to the buffers passed to
+//! `seal_call_chain_extension`, both SCALE encoded. Every function returns a [`ReturnCode`].
+//! Functions which change state revert all their changes when they fail.
+
+use crate::chain_extension::{
+	ChainExtension, Config, Environment, Ext, InitState, RegisteredChainExtension, Result, RetVal,
+	SysConfig,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::DispatchResult,
+	storage::with_storage_layer,
+	traits::{
+		tokens::{
+			fungibles::{self, approvals},
+			nonfungibles_v2,
+		},
+		Contains,
+	},
+	weights::Weight,
+};
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+
+type AccountIdOf<C> = <C as SysConfig>::AccountId;
+type AssetIdOf<C, A> =
+	<<A as AssetsConfig<C>>::Assets as fungibles::Inspect<AccountIdOf<C>>>::AssetId;
+type BalanceOf<C, A> =
+	<<A as AssetsConfig<C>>::Assets as fungibles::Inspect<AccountIdOf<C>>>::Balance;
+type CollectionIdOf<C, A> =
+	<<A as AssetsConfig<C>>::Nfts as nonfungibles_v2::Inspect<AccountIdOf<C>>>::CollectionId;
+type ItemIdOf<C, A> =
+	<<A as AssetsConfig<C>>::Nfts as nonfungibles_v2::Inspect<AccountIdOf<C>>>::ItemId;
+
+/// The configuration of the [`AssetsExtension`].
+pub trait AssetsConfig<C: Config> {
+	/// The fungible assets accessible by contracts.
+	type Assets: fungibles::Transfer<AccountIdOf<C>>
+		+ approvals::Inspect<AccountIdOf<C>>
+		+ approvals::Mutate<AccountIdOf<C>>;
+
+	/// The assets contracts are allowed to transfer and approve.
+	///
+	/// Use this to enforce transfer restrictions of assets which [`Self::Assets`] doesn't
+	/// enforce by itself, like the `is_transferable` flag of `pallet-assets`.
+	type TransferableAssets: Contains<<Self::Assets as fungibles::Inspect<AccountIdOf<C>>>::AssetId>;
+
+	/// The NFTs accessible by contracts.
+	type Nfts: nonfungibles_v2::Transfer<AccountIdOf<C>>;
+
+	/// Describes the weights of the functions of the extension.
+	///
+	/// There is no default as the weights depend on the pallets behind [`Self::Assets`] and
+	/// [`Self::Nfts`].
+	type WeightInfo: WeightInfo;
+}
+
+/// A [`ChainExtension`] giving contracts access to fungible assets and NFTs.
+///
+/// Consult the [module documentation](self) for the functions it provides.
+pub struct AssetsExtension<A>(PhantomData<A>);
+
+impl<A> Default for AssetsExtension<A> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+/// The functions of the [`AssetsExtension`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u16)]
+pub enum Function {
+	/// The balance of an account.
+	Balance = 0x0000,
+	/// The total supply of an asset.
+	TotalSupply = 0x0001,
+	/// The amount an owner approved a delegate to spend.
+	Allowance = 0x0002,
+	/// Transfer an amount from the contract to an account.
+	Transfer = 0x0003,
+	/// Approve a delegate to spend an amount of the contract.
+	Approve = 0x0004,
+	/// Transfer an amount an owner approved the contract to spend to an account.
+	TransferFrom = 0x0005,
+	/// The owner of an item, if it exists.
+	NftOwner = 0x0006,
+	/// Transfer an item owned by the contract to an account.
+	NftTransfer = 0x0007,
+}
+
+impl TryFrom<u16> for Function {
+	type Error = DispatchError;
+
+	fn try_from(func_id: u16) -> sp_std::result::Result<Self, Self::Error> {
+		let function = match func_id {
+			0x0000 => Self::Balance,
+			0x0001 => Self::TotalSupply,
+			0x0002 => Self::Allowance,
+			0x0003 => Self::Transfer,
+			0x0004 => Self::Approve,
+			0x0005 => Self::TransferFrom,
+			0x0006 => Self::NftOwner,
+			0x0007 => Self::NftTransfer,
+			_ => return Err(DispatchError::Other("Unknown function of the assets chain extension")),
+		};
+		Ok(function)
+	}
+}
+
+/// The value returned by the functions of the [`AssetsExtension`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum ReturnCode {
+	/// The function succeeded.
+	Success = 0,
+	/// The asset or item can't be transferred.
+	NotTransferable = 1,
+	/// The contract doesn't own the item.
+	NoPermission = 2,
+	/// The pallet of the asset or item returned an error, e.g. because of a too low balance.
+	Failed = 3,
+}
+
+impl From<ReturnCode> for RetVal {
+	fn from(code: ReturnCode) -> Self {
+		RetVal::Converging(code as u32)
+	}
+}
+
+impl<C, A> ChainExtension<C> for AssetsExtension<A>
+where
+	C: Config,
+	A: AssetsConfig<C>,
+	CollectionIdOf<C, A>: Decode + MaxEncodedLen,
+	ItemIdOf<C, A>: Decode + MaxEncodedLen,
+{
+	fn call<E: Ext<T = C>>(&mut self, env: Environment<E, InitState>) -> Result<RetVal> {
+		// Without other chain extensions the calls aren't routed by their `ext_id`.
+		if env.ext_id() != <Self as RegisteredChainExtension<C>>::ID {
+			return Err(DispatchError::Other("Unknown chain extension"))
+		}
+		let function = Function::try_from(env.func_id())?;
+		let mut env = env.buf_in_buf_out();
+
+		let code = match function {
+			Function::Balance => {
+				env.charge_weight(A::WeightInfo::balance())?;
+				let (asset, who) = env.read_as::<(AssetIdOf<C, A>, AccountIdOf<C>)>()?;
+				let balance = <A::Assets as fungibles::Inspect<_>>::balance(asset, &who);
+				env.write(&balance.encode(), false, None)?;
+				ReturnCode::Success
+			},
+			Function::TotalSupply => {
+				env.charge_weight(A::WeightInfo::total_supply())?;
+				let asset = env.read_as::<AssetIdOf<C, A>>()?;
+				let supply = <A::Assets as fungibles::Inspect<_>>::total_issuance(asset);
+				env.write(&supply.encode(), false, None)?;
+				ReturnCode::Success
+			},
+			Function::Allowance => {
+				env.charge_weight(A::WeightInfo::allowance())?;
+				let (asset, owner, delegate) =
+					env.read_as::<(AssetIdOf<C, A>, AccountIdOf<C>, AccountIdOf<C>)>()?;
+				let allowance =
+					<A::Assets as approvals::Inspect<_>>::allowance(asset, &owner, &delegate);
+				env.write(&allowance.encode(), false, None)?;
+				ReturnCode::Success
+			},
+			Function::Transfer => {
+				env.charge_weight(A::WeightInfo::transfer())?;
+				let (asset, dest, amount) =
+					env.read_as::<(AssetIdOf<C, A>, AccountIdOf<C>, BalanceOf<C, A>)>()?;
+				let contract = env.ext().address().clone();
+				Self::transfer_asset::<C>(asset, || {
+					<A::Assets as fungibles::Transfer<_>>::transfer(
+						asset, &contract, &dest, amount, true,
+					)
+					.map(|_| ())
+				})
+			},
+			Function::Approve => {
+				env.charge_weight(A::WeightInfo::approve())?;
+				let (asset, delegate, amount) =
+					env.read_as::<(AssetIdOf<C, A>, AccountIdOf<C>, BalanceOf<C, A>)>()?;
+				let contract = env.ext().address().clone();
+				Self::transfer_asset::<C>(asset, || {
+					<A::Assets as approvals::Mutate<_>>::approve(
+						asset, &contract, &delegate, amount,
+					)
+				})
+			},
+			Function::TransferFrom => {
+				env.charge_weight(A::WeightInfo::transfer_from())?;
+				let (asset, owner, dest, amount) = env.read_as::<(
+					AssetIdOf<C, A>,
+					AccountIdOf<C>,
+					AccountIdOf<C>,
+					BalanceOf<C, A>,
+				)>()?;
+				let contract = env.ext().address().clone();
+				Self::transfer_asset::<C>(asset, || {
+					<A::Assets as approvals::Mutate<_>>::transfer_from(
+						asset, &owner, &contract, &dest, amount,
+					)
+				})
+			},
+			Function::NftOwner => {
+				env.charge_weight(A::WeightInfo::nft_owner())?;
+				let (collection, item) = env.read_as::<(CollectionIdOf<C, A>, ItemIdOf<C, A>)>()?;
+				let owner = <A::Nfts as nonfungibles_v2::Inspect<_>>::owner(&collection, &item);
+				env.write(&owner.encode(), false, None)?;
+				ReturnCode::Success
+			},
+			Function::NftTransfer => {
+				env.charge_weight(A::WeightInfo::nft_transfer())?;
+				let (collection, item, dest) =
+					env.read_as::<(CollectionIdOf<C, A>, ItemIdOf<C, A>, AccountIdOf<C>)>()?;
+				let contract = env.ext().address().clone();
+				if <A::Nfts as nonfungibles_v2::Inspect<_>>::owner(&collection, &item) !=
+					Some(contract)
+				{
+					ReturnCode::NoPermission
+				} else if !<A::Nfts as nonfungibles_v2::Inspect<_>>::can_transfer(
+					&collection,
+					&item,
+				) {
+					ReturnCode::NotTransferable
+				} else {
+					Self::dispatch(|| {
+						<A::Nfts as nonfungibles_v2::Transfer<_>>::transfer(
+							&collection,
+							&item,
+							&dest,
+						)
+					})
+				}
+			},
+		};
+
+		Ok(code.into())
+	}
+}
+
+impl<C, A> RegisteredChainExtension<C> for AssetsExtension<A>
+where
+	C: Config,
+	A: AssetsConfig<C>,
+	CollectionIdOf<C, A>: Decode + MaxEncodedLen,
+	ItemIdOf<C, A>: Decode + MaxEncodedLen,
+{
+	const ID: u16 = 0xa55e;
+}
+
+impl<A> AssetsExtension<A> {
+	/// Runs `f` if the `asset` can be transferred.
+	fn transfer_asset<C: Config>(
+		asset: AssetIdOf<C, A>,
+		f: impl FnOnce() -> DispatchResult,
+	) -> ReturnCode
+	where
+		A: AssetsConfig<C>,
+	{
+		if A::TransferableAssets::contains(&asset) {
+			Self::dispatch(f)
+		} else {
+			ReturnCode::NotTransferable
+		}
+	}
+
+	/// Runs `f` in its own storage layer, so that its changes are reverted if it fails.
+	fn dispatch(f: impl FnOnce() -> DispatchResult) -> ReturnCode {
+		match with_storage_layer(f) {
+			Ok(()) => ReturnCode::Success,
+			Err(err) => {
+				log::debug!(target: "runtime::contracts", "Assets chain extension failed: {:?}", err);
+				ReturnCode::Failed
+			},
+		}
+	}
+}
+
+/// Weight functions needed for the [`AssetsExtension`].
+///
+/// The weights have to cover the calls into [`AssetsConfig::Assets`] and [`AssetsConfig::Nfts`].
+/// Runtimes have to derive them from the benchmarks of the pallets implementing those, which is
+/// why no implementation is provided here.
+pub trait WeightInfo {
+	fn balance() -> Weight;
+	fn total_supply() -> Weight;
+	fn allowance() -> Weight;
+	fn transfer() -> Weight;
+	fn approve() -> Weight;
+	fn transfer_from() -> Weight;
+	fn nft_owner() -> Weight;
+	fn nft_transfer() -> Weight;
+}
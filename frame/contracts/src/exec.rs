@@ -17,23 +17,30 @@
 
 use crate::{
 	gas::GasMeter,
-	storage::{self, DepositAccount, WriteOutcome},
-	BalanceOf, CodeHash, Config, ContractInfo, ContractInfoOf, DebugBufferVec, Determinism, Error,
-	Event, Nonce, Pallet as Contracts, Schedule, System,
+	storage::{self, meter::DepositOf, DepositAccount, WriteOutcome},
+	BalanceOf, CallTraceOf, CallTraceVec, CodeHash, Config, ContractInfo, ContractInfoOf,
+	DebugBufferVec, Determinism, Error, Event, Nonce, Pallet as Contracts, Schedule, System,
 };
 use frame_support::{
 	crypto::ecdsa::ECDSAExt,
 	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo, Dispatchable},
+	ensure,
 	storage::{with_transaction, TransactionOutcome},
-	traits::{Contains, Currency, ExistenceRequirement, OriginTrait, Randomness, Time},
+	traits::{Contains, Currency, ExistenceRequirement, Get, OriginTrait, Randomness, Time},
 	weights::Weight,
 	Blake2_128Concat, BoundedVec, StorageHasher,
 };
 use frame_system::RawOrigin;
-use pallet_contracts_primitives::ExecReturnValue;
+use pallet_contracts_primitives::{CallKind, CallTrace, ExecReturnValue, StorageDeposit};
 use smallvec::{Array, SmallVec};
-use sp_core::ecdsa::Public as ECDSAPublic;
-use sp_io::{crypto::secp256k1_ecdsa_recover_compressed, hashing::blake2_256};
+use sp_core::{
+	ecdsa::Public as ECDSAPublic,
+	sr25519::{Public as SR25519Public, Signature as SR25519Signature},
+};
+use sp_io::{
+	crypto::{secp256k1_ecdsa_recover_compressed, sr25519_verify},
+	hashing::blake2_256,
+};
 use sp_runtime::traits::{Convert, Hash};
 use sp_std::{marker::PhantomData, mem, prelude::*};
 
@@ -290,6 +297,9 @@ pub trait Ext: sealing::Sealed {
 	/// Returns Ethereum address from the ECDSA compressed public key.
 	fn ecdsa_to_eth_address(&self, pk: &[u8; 33]) -> Result<[u8; 20], ()>;
 
+	/// Verify a sr25519 signature.
+	fn sr25519_verify(&self, signature: &[u8; 64], message: &[u8], pub_key: &[u8; 32]) -> bool;
+
 	/// Tests sometimes need to modify and inspect the contract info directly.
 	#[cfg(test)]
 	fn contract_info(&mut self) -> &mut ContractInfo<Self::T>;
@@ -308,6 +318,29 @@ pub trait Ext: sealing::Sealed {
 
 	/// Returns a nonce that is incremented for every instantiated contract.
 	fn nonce(&mut self) -> u64;
+
+	/// Adds a delegate dependency to [`ContractInfo`]'s `delegate_dependencies` field.
+	///
+	/// This ensures that the delegated contract is not removed while it is still in use. It
+	/// increases the reference count of the code hash and charges a fraction (see
+	/// [`Config::CodeHashLockupDepositPercent`]) of the code deposit.
+	///
+	/// # Errors
+	///
+	/// - [`Error::MaxDelegateDependenciesReached`]
+	/// - [`Error::CannotAddSelfAsDelegateDependency`]
+	/// - [`Error::DelegateDependencyAlreadyExists`]
+	fn lock_delegate_dependency(&mut self, code_hash: CodeHash<Self::T>) -> DispatchResult;
+
+	/// Removes a delegate dependency from [`ContractInfo`]'s `delegate_dependencies` field.
+	///
+	/// This is the counterpart of [`Self::lock_delegate_dependency`]. It decreases the reference
+	/// count and refunds the deposit that was charged by [`Self::lock_delegate_dependency`].
+	///
+	/// # Errors
+	///
+	/// - [`Error::DelegateDependencyNotFound`]
+	fn unlock_delegate_dependency(&mut self, code_hash: &CodeHash<Self::T>) -> DispatchResult;
 }
 
 /// Describes the different functions that can be exported by an [`Executable`].
@@ -346,6 +379,13 @@ pub trait Executable<T: Config>: Sized {
 	/// Decrement the refcount by one if the code exists.
 	fn remove_user(code_hash: CodeHash<T>);
 
+	/// The deposit that was reserved by the uploader of the code.
+	///
+	/// # Errors
+	///
+	/// [`Error::CodeNotFound`] is returned if the specified `code_hash` does not exist.
+	fn deposit(code_hash: CodeHash<T>) -> Result<BalanceOf<T>, DispatchError>;
+
 	/// Execute the specified exported function and return the result.
 	///
 	/// When the specified function is `Constructor` the executable is stored and its
@@ -410,6 +450,11 @@ pub struct Stack<'a, T: Config, E> {
 	/// All the bytes added to this field should be valid UTF-8. The buffer has no defined
 	/// structure and is intended to be shown to users as-is for debugging purposes.
 	debug_message: Option<&'a mut DebugBufferVec<T>>,
+	/// A trace of all calls and instantiations made by this call stack.
+	///
+	/// Entries are pushed when a frame starts running and completed when it is popped. Calls
+	/// which fail before their frame is pushed get an entry holding their error.
+	trace: Option<&'a mut CallTraceVec<T>>,
 	/// The determinism requirement of this call stack.
 	determinism: Determinism,
 	/// No executable is held by the struct but influences its behaviour.
@@ -442,6 +487,8 @@ pub struct Frame<T: Config> {
 	allows_reentry: bool,
 	/// The caller of the currently executing frame which was spawned by `delegate_call`.
 	delegate_caller: Option<T::AccountId>,
+	/// The index of the entry of this frame in the call trace, if it is recorded.
+	trace_index: Option<usize>,
 }
 
 /// Used in a delegate call frame arguments in order to override the executable and caller.
@@ -615,8 +662,8 @@ where
 	///
 	/// # Note
 	///
-	/// `debug_message` should only ever be set to `Some` when executing as an RPC because
-	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
+	/// `debug_message` and `trace` should only ever be set to `Some` when executing as an RPC
+	/// because they add allocations and could be abused to drive the runtime into an OOM panic.
 	///
 	/// # Return Value
 	///
@@ -630,6 +677,7 @@ where
 		value: BalanceOf<T>,
 		input_data: Vec<u8>,
 		debug_message: Option<&'a mut DebugBufferVec<T>>,
+		trace: Option<&'a mut CallTraceVec<T>>,
 		determinism: Determinism,
 	) -> Result<ExecReturnValue, ExecError> {
 		let (mut stack, executable) = Self::new(
@@ -640,6 +688,7 @@ where
 			schedule,
 			value,
 			debug_message,
+			trace,
 			determinism,
 		)?;
 		stack.run(executable, input_data)
@@ -649,8 +698,8 @@ where
 	///
 	/// # Note
 	///
-	/// `debug_message` should only ever be set to `Some` when executing as an RPC because
-	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
+	/// `debug_message` and `trace` should only ever be set to `Some` when executing as an RPC
+	/// because they add allocations and could be abused to drive the runtime into an OOM panic.
 	///
 	/// # Return Value
 	///
@@ -665,6 +714,7 @@ where
 		input_data: Vec<u8>,
 		salt: &[u8],
 		debug_message: Option<&'a mut DebugBufferVec<T>>,
+		trace: Option<&'a mut CallTraceVec<T>>,
 	) -> Result<(T::AccountId, ExecReturnValue), ExecError> {
 		let (mut stack, executable) = Self::new(
 			FrameArgs::Instantiate {
@@ -680,6 +730,7 @@ where
 			schedule,
 			value,
 			debug_message,
+			trace,
 			Determinism::Deterministic,
 		)?;
 		let account_id = stack.top_frame().account_id.clone();
@@ -695,6 +746,7 @@ where
 		schedule: &'a Schedule<T>,
 		value: BalanceOf<T>,
 		debug_message: Option<&'a mut DebugBufferVec<T>>,
+		trace: Option<&'a mut CallTraceVec<T>>,
 		determinism: Determinism,
 	) -> Result<(Self, E), ExecError> {
 		let (first_frame, executable, nonce) = Self::new_frame(
@@ -717,6 +769,7 @@ where
 			first_frame,
 			frames: Default::default(),
 			debug_message,
+			trace,
 			determinism,
 			_phantom: Default::default(),
 		};
@@ -792,6 +845,7 @@ where
 			nested_gas: gas_meter.nested(gas_limit)?,
 			nested_storage: storage_meter.nested(),
 			allows_reentry: true,
+			trace_index: None,
 		};
 
 		Ok((frame, executable, nonce))
@@ -839,6 +893,8 @@ where
 	///
 	/// This can be either a call or an instantiate.
 	fn run(&mut self, executable: E, input_data: Vec<u8>) -> Result<ExecReturnValue, ExecError> {
+		let trace_index = self.start_trace(&input_data);
+		self.top_frame_mut().trace_index = trace_index;
 		let frame = self.top_frame();
 		let entry_point = frame.entry_point;
 		let delegated_code_hash =
@@ -921,7 +977,7 @@ where
 		// transactional storage depth.
 		let transaction_outcome =
 			with_transaction(|| -> TransactionOutcome<Result<_, DispatchError>> {
-				let output = do_transaction();
+				let output: Result<ExecReturnValue, ExecError> = do_transaction();
 				match &output {
 					Ok(result) if !result.did_revert() =>
 						TransactionOutcome::Commit(Ok((true, output))),
@@ -937,15 +993,110 @@ where
 			Err(error) => (false, Err(error.into())),
 		};
 
-		self.pop_frame(success);
+		let gas_consumed = self.top_frame().nested_gas.gas_consumed();
+		let storage_deposit = self.pop_frame(success);
+		if let (Some(trace), Some(index)) = (self.trace.as_mut(), trace_index) {
+			let entry = &mut trace[index];
+			match &output {
+				Ok(output) => {
+					entry.output = output.data.clone();
+					entry.reverted = output.did_revert();
+				},
+				Err(err) => entry.error = Some(err.error),
+			}
+			entry.gas_consumed = gas_consumed;
+			entry.storage_deposit = storage_deposit;
+		}
 		output
 	}
 
+	/// Add an entry for the current (top) frame to the trace, if one is recorded.
+	///
+	/// Returns the index of the entry, which is completed once the frame returns.
+	fn start_trace(&mut self, input_data: &[u8]) -> Option<usize> {
+		self.trace.as_ref()?;
+		let frame = self.top_frame();
+		let kind = match (frame.entry_point, &frame.delegate_caller) {
+			(ExportedFunction::Constructor, _) => CallKind::Instantiate,
+			(ExportedFunction::Call, Some(_)) => CallKind::DelegateCall,
+			(ExportedFunction::Call, None) => CallKind::Call,
+		};
+		let entry = CallTrace {
+			depth: self.frames.len() as u32,
+			kind,
+			caller: self.caller().clone(),
+			callee: frame.account_id.clone(),
+			value: frame.value_transferred,
+			input: input_data.to_vec(),
+			output: Vec::new(),
+			reverted: false,
+			error: None,
+			gas_consumed: Weight::zero(),
+			storage_deposit: Default::default(),
+			events: Vec::new(),
+		};
+		self.push_trace(entry)
+	}
+
+	/// Add an entry for a call or instantiation which failed before its frame was pushed.
+	///
+	/// The entry is one level deeper than the current (top) frame, which made the call.
+	fn trace_failed_frame(
+		&mut self,
+		kind: CallKind,
+		callee: T::AccountId,
+		value: BalanceOf<T>,
+		input_data: &[u8],
+		error: DispatchError,
+	) {
+		if self.trace.is_none() {
+			return
+		}
+		// A delegate call keeps the caller of the frame making it.
+		let caller =
+			if kind == CallKind::DelegateCall { self.caller() } else { self.address() }.clone();
+		let entry = CallTrace {
+			depth: self.frames.len() as u32 + 1,
+			kind,
+			caller,
+			callee,
+			value,
+			input: input_data.to_vec(),
+			output: Vec::new(),
+			reverted: false,
+			error: Some(error),
+			gas_consumed: Weight::zero(),
+			storage_deposit: Default::default(),
+			events: Vec::new(),
+		};
+		self.push_trace(entry);
+	}
+
+	/// Push an entry to the trace unless it is full.
+	///
+	/// Returns the index of the entry if it was pushed.
+	fn push_trace(&mut self, entry: CallTraceOf<T>) -> Option<usize> {
+		let trace = self.trace.as_mut()?;
+		trace
+			.try_push(entry)
+			.map_err(|_| {
+				log::debug!(
+					target: "runtime::contracts",
+					"Call trace (of {} entries) exhausted!",
+					CallTraceVec::<T>::bound(),
+				)
+			})
+			.ok()?;
+		Some(trace.len() - 1)
+	}
+
 	/// Remove the current (top) frame from the stack.
 	///
 	/// This is called after running the current frame. It commits cached values to storage
 	/// and invalidates all stale references to it that might exist further down the call stack.
-	fn pop_frame(&mut self, persist: bool) {
+	///
+	/// Returns the storage deposit of the frame, which is zero if it isn't persisted.
+	fn pop_frame(&mut self, persist: bool) -> DepositOf<T> {
 		// Revert changes to the nonce in case of a failed instantiation.
 		if !persist && self.top_frame().entry_point == ExportedFunction::Constructor {
 			self.nonce.as_mut().map(|c| *c = c.wrapping_sub(1));
@@ -966,7 +1117,7 @@ where
 
 			// Only gas counter changes are persisted in case of a failure.
 			if !persist {
-				return
+				return Default::default()
 			}
 
 			// Record the storage meter changes of the nested call into the parent meter.
@@ -984,8 +1135,11 @@ where
 				)
 				.clone();
 			let mut contract = frame.contract_info.into_contract();
-			prev.nested_storage
-				.absorb(frame.nested_storage, deposit_account, contract.as_mut());
+			let deposit = prev.nested_storage.absorb(
+				frame.nested_storage,
+				deposit_account,
+				contract.as_mut(),
+			);
 
 			// In case the contract wasn't terminated we need to persist changes made to it.
 			if let Some(contract) = contract {
@@ -995,7 +1149,7 @@ where
 				// trigger a rollback.
 				if prev.account_id == *account_id {
 					prev.contract_info = CachedContract::Cached(contract);
-					return
+					return deposit
 				}
 
 				// Predecessor is a different contract: We persist the info and invalidate the first
@@ -1008,6 +1162,7 @@ where
 					c.contract_info = CachedContract::Invalidated;
 				}
 			}
+			deposit
 		} else {
 			if let Some((msg, false)) = self.debug_message.as_ref().map(|m| (m, m.is_empty())) {
 				log::debug!(
@@ -1018,14 +1173,14 @@ where
 			}
 			self.gas_meter.absorb_nested(mem::take(&mut self.first_frame.nested_gas));
 			if !persist {
-				return
+				return Default::default()
 			}
 			let deposit_account = self.first_frame.contract_info.deposit_account().expect(
 				"Is only `None` when the info is invalidated. The first frame can't be invalidated.
 				qed",
 			).clone();
 			let mut contract = self.first_frame.contract_info.as_contract();
-			self.storage_meter.absorb(
+			let deposit = self.storage_meter.absorb(
 				mem::take(&mut self.first_frame.nested_storage),
 				deposit_account,
 				contract.as_deref_mut(),
@@ -1036,6 +1191,7 @@ where
 			if let Some(nonce) = self.nonce {
 				<Nonce<T>>::set(nonce);
 			}
+			deposit
 		}
 	}
 
@@ -1126,7 +1282,7 @@ where
 		// is caught by it.
 		self.top_frame_mut().allows_reentry = allows_reentry;
 
-		let try_call = || {
+		let mut try_call = || {
 			if !self.allows_reentry(&to) {
 				return Err(<Error<T>>::ReentranceDenied.into())
 			}
@@ -1140,16 +1296,21 @@ where
 					CachedContract::Cached(contract) => Some(contract.clone()),
 					_ => None,
 				});
-			let executable = self.push_frame(
-				FrameArgs::Call { dest: to, cached_info, delegated_call: None },
+			self.push_frame(
+				FrameArgs::Call { dest: to.clone(), cached_info, delegated_call: None },
 				value,
 				gas_limit,
-			)?;
-			self.run(executable, input_data)
+			)
 		};
 
 		// We need to make sure to reset `allows_reentry` even on failure.
-		let result = try_call();
+		let result = match try_call() {
+			Ok(executable) => self.run(executable, input_data),
+			Err(err) => {
+				self.trace_failed_frame(CallKind::Call, to, value, &input_data, err.error);
+				Err(err)
+			},
+		};
 
 		// Protection is on a per call basis.
 		self.top_frame_mut().allows_reentry = true;
@@ -1162,21 +1323,38 @@ where
 		code_hash: CodeHash<Self::T>,
 		input_data: Vec<u8>,
 	) -> Result<ExecReturnValue, ExecError> {
-		let executable = E::from_storage(code_hash, self.schedule, self.gas_meter())?;
 		let top_frame = self.top_frame_mut();
 		let contract_info = top_frame.contract_info().clone();
 		let account_id = top_frame.account_id.clone();
 		let value = top_frame.value_transferred;
-		let executable = self.push_frame(
-			FrameArgs::Call {
-				dest: account_id,
-				cached_info: Some(contract_info),
-				delegated_call: Some(DelegatedCall { executable, caller: self.caller().clone() }),
+		let try_call = || {
+			let executable = E::from_storage(code_hash, self.schedule, self.gas_meter())?;
+			self.push_frame(
+				FrameArgs::Call {
+					dest: account_id.clone(),
+					cached_info: Some(contract_info),
+					delegated_call: Some(DelegatedCall {
+						executable,
+						caller: self.caller().clone(),
+					}),
+				},
+				value,
+				Weight::zero(),
+			)
+		};
+		match try_call() {
+			Ok(executable) => self.run(executable, input_data),
+			Err(err) => {
+				self.trace_failed_frame(
+					CallKind::DelegateCall,
+					account_id,
+					value,
+					&input_data,
+					err.error,
+				);
+				Err(err)
 			},
-			value,
-			Weight::zero(),
-		)?;
-		self.run(executable, input_data)
+		}
 	}
 
 	fn instantiate(
@@ -1187,19 +1365,42 @@ where
 		input_data: Vec<u8>,
 		salt: &[u8],
 	) -> Result<(AccountIdOf<T>, ExecReturnValue), ExecError> {
-		let executable = E::from_storage(code_hash, self.schedule, self.gas_meter())?;
-		let nonce = self.next_nonce();
-		let executable = self.push_frame(
-			FrameArgs::Instantiate {
-				sender: self.top_frame().account_id.clone(),
-				nonce,
-				executable,
-				salt,
-				input_data: input_data.as_ref(),
+		let mut try_instantiate = || {
+			let executable = E::from_storage(code_hash, self.schedule, self.gas_meter())?;
+			let nonce = self.next_nonce();
+			self.push_frame(
+				FrameArgs::Instantiate {
+					sender: self.top_frame().account_id.clone(),
+					nonce,
+					executable,
+					salt,
+					input_data: input_data.as_ref(),
+				},
+				value,
+				gas_limit,
+			)
+		};
+		let executable = match try_instantiate() {
+			Ok(executable) => executable,
+			Err(err) => {
+				if self.trace.is_some() {
+					let account_id = Contracts::<T>::contract_address(
+						self.address(),
+						&code_hash,
+						&input_data,
+						salt,
+					);
+					self.trace_failed_frame(
+						CallKind::Instantiate,
+						account_id,
+						value,
+						&input_data,
+						err.error,
+					);
+				}
+				return Err(err)
 			},
-			value,
-			gas_limit,
-		)?;
+		};
 		let account_id = self.top_frame().account_id.clone();
 		self.run(executable, input_data).map(|ret| (account_id, ret))
 	}
@@ -1222,6 +1423,9 @@ where
 		info.queue_trie_for_deletion()?;
 		ContractInfoOf::<T>::remove(&frame.account_id);
 		E::remove_user(info.code_hash);
+		for code_hash in info.delegate_dependencies().keys() {
+			E::remove_user(*code_hash);
+		}
 		Contracts::<T>::deposit_event(
 			vec![T::Hashing::hash_of(&frame.account_id), T::Hashing::hash_of(&beneficiary)],
 			Event::Terminated {
@@ -1331,6 +1535,10 @@ where
 	}
 
 	fn deposit_event(&mut self, topics: Vec<T::Hash>, data: Vec<u8>) {
+		let trace_index = self.top_frame().trace_index;
+		if let (Some(trace), Some(index)) = (self.trace.as_mut(), trace_index) {
+			trace[index].events.push(data.clone());
+		}
 		Contracts::<Self::T>::deposit_event(
 			topics,
 			Event::ContractEmitted { contract: self.top_frame().account_id.clone(), data },
@@ -1389,6 +1597,14 @@ where
 		ECDSAPublic(*pk).to_eth_address()
 	}
 
+	fn sr25519_verify(&self, signature: &[u8; 64], message: &[u8], pub_key: &[u8; 32]) -> bool {
+		sr25519_verify(
+			&SR25519Signature::from_raw(*signature),
+			message,
+			&SR25519Public::from_raw(*pub_key),
+		)
+	}
+
 	#[cfg(test)]
 	fn contract_info(&mut self) -> &mut ContractInfo<Self::T> {
 		self.top_frame_mut().contract_info()
@@ -1434,6 +1650,34 @@ where
 			current
 		}
 	}
+
+	fn lock_delegate_dependency(&mut self, code_hash: CodeHash<Self::T>) -> DispatchResult {
+		let frame = self.top_frame_mut();
+		let info = frame.contract_info.get(&frame.account_id);
+		ensure!(code_hash != info.code_hash, Error::<T>::CannotAddSelfAsDelegateDependency);
+
+		let deposit = T::CodeHashLockupDepositPercent::get().mul_ceil(E::deposit(code_hash)?);
+		info.lock_delegate_dependency(code_hash, deposit)?;
+		E::add_user(code_hash)?;
+		let deposit_account = info.deposit_account().clone();
+		frame
+			.nested_storage
+			.charge_deposit(deposit_account, StorageDeposit::Charge(deposit));
+		Ok(())
+	}
+
+	fn unlock_delegate_dependency(&mut self, code_hash: &CodeHash<Self::T>) -> DispatchResult {
+		let frame = self.top_frame_mut();
+		let info = frame.contract_info.get(&frame.account_id);
+
+		let deposit = info.unlock_delegate_dependency(code_hash)?;
+		E::remove_user(*code_hash);
+		let deposit_account = info.deposit_account().clone();
+		frame
+			.nested_storage
+			.charge_deposit(deposit_account, StorageDeposit::Refund(deposit));
+		Ok(())
+	}
 }
 
 mod sealing {
@@ -1462,7 +1706,7 @@ mod tests {
 		exec::ExportedFunction::*,
 		gas::GasMeter,
 		tests::{
-			test_utils::{get_balance, hash, place_contract, set_balance},
+			test_utils::{get_balance, get_contract, hash, place_contract, set_balance},
 			ExtBuilder, RuntimeCall, RuntimeEvent as MetaEvent, Test, TestFilter, ALICE, BOB,
 			CHARLIE, GAS_LIMIT,
 		},
@@ -1489,6 +1733,9 @@ mod tests {
 		static Loader: MockLoader = MockLoader::default();
 	}
 
+	/// The deposit reported by [`MockExecutable`] for every stored code.
+	const CODE_DEPOSIT: u64 = 100;
+
 	fn events() -> Vec<Event<Test>> {
 		System::events()
 			.into_iter()
@@ -1580,6 +1827,16 @@ mod tests {
 			MockLoader::decrement_refcount(code_hash);
 		}
 
+		fn deposit(code_hash: CodeHash<Test>) -> Result<BalanceOf<Test>, DispatchError> {
+			Loader::mutate(|loader| {
+				loader
+					.map
+					.get(&code_hash)
+					.map(|_| CODE_DEPOSIT)
+					.ok_or(Error::<Test>::CodeNotFound.into())
+			})
+		}
+
 		fn execute<E: Ext<T = Test>>(
 			self,
 			ext: &mut E,
@@ -1645,6 +1902,7 @@ mod tests {
 					value,
 					vec![],
 					None,
+					None,
 					Determinism::Deterministic,
 				),
 				Ok(_)
@@ -1699,6 +1957,7 @@ mod tests {
 				value,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -1741,6 +2000,7 @@ mod tests {
 				value,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -1777,6 +2037,7 @@ mod tests {
 				55,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -1829,6 +2090,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -1862,6 +2124,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -1893,6 +2156,7 @@ mod tests {
 				0,
 				vec![1, 2, 3, 4],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -1926,6 +2190,7 @@ mod tests {
 				vec![1, 2, 3, 4],
 				&[],
 				None,
+				None,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -1973,6 +2238,7 @@ mod tests {
 				value,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -2019,6 +2285,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -2053,6 +2320,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2083,6 +2351,7 @@ mod tests {
 				0,
 				vec![0],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2111,6 +2380,7 @@ mod tests {
 				0,
 				vec![0],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2147,6 +2417,7 @@ mod tests {
 				0,
 				vec![0],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2183,6 +2454,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -2212,6 +2484,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Err(_)
 			);
@@ -2245,6 +2518,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Ok((address, ref output)) if output.data == vec![80, 65, 83, 83] => address
 			);
@@ -2289,6 +2563,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Ok((address, ref output)) if output.data == vec![70, 65, 73, 76] => address
 			);
@@ -2342,6 +2617,7 @@ mod tests {
 					min_balance * 10,
 					vec![],
 					None,
+					None,
 					Determinism::Deterministic,
 				),
 				Ok(_)
@@ -2407,6 +2683,7 @@ mod tests {
 					0,
 					vec![],
 					None,
+					None,
 					Determinism::Deterministic,
 				),
 				Ok(_)
@@ -2444,6 +2721,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Err(Error::<Test>::TerminatedInConstructor.into())
 			);
@@ -2493,6 +2771,7 @@ mod tests {
 				0,
 				vec![0],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2528,6 +2807,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2559,6 +2839,7 @@ mod tests {
 				0,
 				vec![],
 				Some(&mut debug_buffer),
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -2593,6 +2874,7 @@ mod tests {
 				0,
 				vec![],
 				Some(&mut debug_buffer),
+				None,
 				Determinism::Deterministic,
 			);
 			assert!(result.is_err());
@@ -2630,6 +2912,7 @@ mod tests {
 				0,
 				vec![],
 				Some(&mut debug_buf_after),
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -2663,6 +2946,7 @@ mod tests {
 				0,
 				CHARLIE.encode(),
 				None,
+				None,
 				Determinism::Deterministic
 			));
 
@@ -2677,6 +2961,7 @@ mod tests {
 					0,
 					BOB.encode(),
 					None,
+					None,
 					Determinism::Deterministic
 				)
 				.map_err(|e| e.error),
@@ -2716,6 +3001,7 @@ mod tests {
 					0,
 					vec![0],
 					None,
+					None,
 					Determinism::Deterministic
 				)
 				.map_err(|e| e.error),
@@ -2751,6 +3037,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -2834,6 +3121,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -2936,6 +3224,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			)
 			.ok();
 			assert_eq!(<Nonce<Test>>::get(), 0);
@@ -2950,6 +3239,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			));
 			assert_eq!(<Nonce<Test>>::get(), 1);
 
@@ -2963,6 +3253,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			));
 			assert_eq!(<Nonce<Test>>::get(), 2);
 
@@ -2976,6 +3267,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			));
 			assert_eq!(<Nonce<Test>>::get(), 4);
 		});
@@ -3037,6 +3329,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3164,6 +3457,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3200,6 +3494,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3236,6 +3531,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3298,6 +3594,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3360,6 +3657,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3392,12 +3690,123 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
 		});
 	}
 
+	#[test]
+	fn sr25519_verify_works() {
+		use sp_core::{sr25519::Pair, Pair as _};
+
+		let bob_ch = MockLoader::insert(Call, |ctx, _| {
+			let pair = Pair::from_seed(&[1; 32]);
+			let message = b"Hello world";
+			let signature = pair.sign(message);
+			assert!(ctx.ext.sr25519_verify(&signature.0, message, &pair.public().0));
+			assert!(!ctx.ext.sr25519_verify(&signature.0, b"Goodbye world", &pair.public().0));
+			assert!(!ctx.ext.sr25519_verify(&[0; 64], message, &pair.public().0));
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, bob_ch);
+
+			let mut storage_meter = storage::meter::Meter::new(&ALICE, Some(0), 0).unwrap();
+			let result = MockStack::run_call(
+				ALICE,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				None,
+				Determinism::Deterministic,
+			);
+			assert_matches!(result, Ok(_));
+		});
+	}
+
+	#[test]
+	fn lock_delegate_dependency_works() {
+		let max_dependencies =
+			<<Test as Config>::MaxDelegateDependencies as Get<u32>>::get() as usize;
+		let code_hashes = (0..=max_dependencies)
+			.map(|_| MockLoader::insert(Call, |_, _| exec_success()))
+			.collect::<Vec<_>>();
+		let dependencies = code_hashes.clone();
+
+		let bob_ch = MockLoader::insert(Call, move |ctx, executable| {
+			// Locking the own code hash is not allowed.
+			assert_err!(
+				ctx.ext.lock_delegate_dependency(executable.code_hash),
+				<Error<Test>>::CannotAddSelfAsDelegateDependency
+			);
+
+			// Lock as many dependencies as allowed.
+			for code_hash in &dependencies[..max_dependencies] {
+				assert_ok!(ctx.ext.lock_delegate_dependency(*code_hash));
+			}
+
+			// The cap is enforced and dependencies can't be locked twice.
+			assert_err!(
+				ctx.ext.lock_delegate_dependency(dependencies[max_dependencies]),
+				<Error<Test>>::MaxDelegateDependenciesReached
+			);
+			assert_err!(
+				ctx.ext.lock_delegate_dependency(dependencies[0]),
+				<Error<Test>>::DelegateDependencyAlreadyExists
+			);
+
+			// Unlocking frees a slot.
+			assert_ok!(ctx.ext.unlock_delegate_dependency(&dependencies[0]));
+			assert_err!(
+				ctx.ext.unlock_delegate_dependency(&dependencies[0]),
+				<Error<Test>>::DelegateDependencyNotFound
+			);
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, bob_ch);
+			set_balance(&ALICE, 1_000_000);
+
+			let mut storage_meter = storage::meter::Meter::new(&ALICE, None, 0).unwrap();
+			let result = MockStack::run_call(
+				ALICE,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				None,
+				Determinism::Deterministic,
+			);
+			assert_matches!(result, Ok(_));
+
+			let refcount = |code_hash| Loader::get().map.get(code_hash).unwrap().refcount;
+			assert_eq!(refcount(&code_hashes[0]), 1);
+			for code_hash in &code_hashes[1..max_dependencies] {
+				assert_eq!(refcount(code_hash), 2);
+			}
+			assert_eq!(refcount(&code_hashes[max_dependencies]), 1);
+
+			let deposit = <Test as Config>::CodeHashLockupDepositPercent::get()
+				.mul_ceil(CODE_DEPOSIT) *
+				(max_dependencies as u64 - 1);
+			assert_eq!(storage_meter.into_deposit(&ALICE), StorageDeposit::Charge(deposit));
+			assert_eq!(get_contract(&BOB).delegate_dependencies().len(), max_dependencies - 1);
+		});
+	}
+
 	#[test]
 	fn nonce_api_works() {
 		let fail_code = MockLoader::insert(Constructor, |_, _| exec_trapped());
@@ -3438,6 +3847,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3468,6 +3878,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
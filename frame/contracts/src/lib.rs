@@ -88,7 +88,7 @@ mod gas;
 mod address;
 mod benchmarking;
 mod exec;
-mod migration;
+pub mod migration;
 mod schedule;
 mod storage;
 mod wasm;
@@ -102,13 +102,14 @@ mod tests;
 use crate::{
 	exec::{AccountIdOf, ExecError, Executable, Stack as ExecStack},
 	gas::GasMeter,
+	migration::MigrateSequence,
 	storage::{meter::Meter as StorageMeter, ContractInfo, DeletedContract},
 	wasm::{OwnerInfo, PrefabWasmModule, TryInstantiate},
 	weights::WeightInfo,
 };
 use codec::{Codec, Encode, HasCompact};
 use frame_support::{
-	dispatch::{Dispatchable, GetDispatchInfo, Pays, PostDispatchInfo},
+	dispatch::{Dispatchable, GetDispatchInfo, Pays, PostDispatchInfo, WithPostDispatchInfo},
 	ensure,
 	traits::{
 		tokens::fungible::Inspect, ConstU32, Contains, Currency, Get, Randomness,
@@ -119,13 +120,16 @@ use frame_support::{
 };
 use frame_system::Pallet as System;
 use pallet_contracts_primitives::{
-	Code, CodeUploadResult, CodeUploadReturnValue, ContractAccessError, ContractExecResult,
-	ContractInstantiateResult, ExecReturnValue, GetStorageResult, InstantiateReturnValue,
-	StorageDeposit,
+	CallTrace, Code, CodeUploadResult, CodeUploadReturnValue, ContractAccessError,
+	ContractExecResult, ContractInstantiateResult, ExecReturnValue, GetStorageResult,
+	InstantiateReturnValue, StorageDeposit, TracedResult,
 };
 use scale_info::TypeInfo;
 use smallvec::Array;
-use sp_runtime::traits::{Convert, Hash, Saturating, StaticLookup};
+use sp_runtime::{
+	traits::{Convert, Hash, Saturating, StaticLookup},
+	Perbill,
+};
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
 
 pub use crate::{
@@ -148,6 +152,8 @@ type CodeVec<T> = BoundedVec<u8, <T as Config>::MaxCodeLen>;
 type RelaxedCodeVec<T> = WeakBoundedVec<u8, <T as Config>::MaxCodeLen>;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 type DebugBufferVec<T> = BoundedVec<u8, <T as Config>::MaxDebugBufferLen>;
+type CallTraceOf<T> = CallTrace<AccountIdOf<T>, BalanceOf<T>>;
+type CallTraceVec<T> = BoundedVec<CallTraceOf<T>, <T as Config>::MaxCallTraceLen>;
 
 /// Used as a sentinel value when reading and writing contract memory.
 ///
@@ -164,7 +170,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -320,6 +326,41 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxDebugBufferLen: Get<u32>;
 
+		/// The maximum number of calls and instantiations recorded in the call trace.
+		///
+		/// Calls made after the trace is full are not recorded.
+		#[pallet::constant]
+		type MaxCallTraceLen: Get<u32>;
+
+		/// The maximum number of delegate dependencies that a contract can lock with
+		/// `lock_delegate_dependency`.
+		#[pallet::constant]
+		type MaxDelegateDependencies: Get<u32>;
+
+		/// The percentage of the code deposit that is held for locking a code hash.
+		///
+		/// Calling `lock_delegate_dependency` protects the code from being removed. In order to
+		/// prevent abuse this is charged from the calling contract's storage deposit.
+		#[pallet::constant]
+		type CodeHashLockupDepositPercent: Get<Perbill>;
+
+		/// The sequence of lazy migration steps that will be applied during a migration.
+		///
+		/// # Examples
+		/// ```
+		/// use pallet_contracts::migration::{v10, NoopMigration};
+		/// # struct Runtime {};
+		/// type Migrations = (v10::Migration<Runtime>, NoopMigration<11>);
+		/// ```
+		///
+		/// If you have a single migration step, you can use a tuple with a single element:
+		/// ```
+		/// use pallet_contracts::migration::v10;
+		/// # struct Runtime {};
+		/// type Migrations = (v10::Migration<Runtime>,);
+		/// ```
+		type Migrations: MigrateSequence;
+
 		/// Whether an account can voluntarily transfer any of its balance to another account
 		///
 		/// Note: This type has been added by Fragnova
@@ -329,9 +370,26 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_idle(_block: T::BlockNumber, remaining_weight: Weight) -> Weight {
+		fn on_idle(_block: T::BlockNumber, mut remaining_weight: Weight) -> Weight {
+			use migration::MigrateResult::*;
+
+			let mut consumed_weight = Weight::zero();
+			loop {
+				let (result, weight) = Migration::<T>::migrate(remaining_weight);
+				remaining_weight.saturating_reduce(weight);
+				consumed_weight.saturating_accrue(weight);
+
+				match result {
+					// There is not enough weight left to make any progress.
+					NoMigrationPerformed | InProgress { steps_done: 0 } => return consumed_weight,
+					InProgress { .. } => continue,
+					Completed | NoMigrationInProgress => break,
+				}
+			}
+
 			ContractInfo::<T>::process_deletion_queue_batch(remaining_weight)
 				.saturating_add(T::WeightInfo::on_process_deletion_queue_batch())
+				.saturating_add(consumed_weight)
 		}
 
 		fn on_initialize(_block: T::BlockNumber) -> Weight {
@@ -354,6 +412,8 @@ pub mod pallet {
 		}
 
 		fn integrity_test() {
+			Migration::<T>::integrity_test();
+
 			// Total runtime memory is expected to have 128Mb upper limit
 			const MAX_RUNTIME_MEM: u32 = 1024 * 1024 * 128;
 			// Memory limits for a single contract:
@@ -547,6 +607,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			code_hash: CodeHash<T>,
 		) -> DispatchResultWithPostInfo {
+			Migration::<T>::ensure_migrated()?;
 			let origin = ensure_signed(origin)?;
 			<PrefabWasmModule<T>>::remove(&origin, code_hash)?;
 			// we waive the fee because removing unused code is beneficial
@@ -570,6 +631,7 @@ pub mod pallet {
 			dest: AccountIdLookupOf<T>,
 			code_hash: CodeHash<T>,
 		) -> DispatchResult {
+			Migration::<T>::ensure_migrated()?;
 			ensure_root(origin)?;
 			let dest = T::Lookup::lookup(dest)?;
 			<ContractInfoOf<T>>::try_mutate(&dest, |contract| {
@@ -632,6 +694,7 @@ pub mod pallet {
 				storage_deposit_limit.map(Into::into),
 				data,
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			if let Ok(retval) = &output.result {
@@ -697,6 +760,7 @@ pub mod pallet {
 				data,
 				salt,
 				None,
+				None,
 			);
 			if let Ok(retval) = &output.result {
 				if retval.1.did_revert() {
@@ -741,6 +805,7 @@ pub mod pallet {
 				data,
 				salt,
 				None,
+				None,
 			);
 			if let Ok(retval) = &output.result {
 				if retval.1.did_revert() {
@@ -752,6 +817,33 @@ pub mod pallet {
 				T::WeightInfo::instantiate(data_len, salt_len),
 			)
 		}
+
+		/// When a migration is in progress, this dispatchable can be used to run migration steps.
+		/// Calls that contribute to advancing the migration have their fees waived, as it's helpful
+		/// for the chain. Note that while the migration is in progress, the pallet will also
+		/// leverage the `on_idle` hooks to run migration steps.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::migrate().saturating_add(*weight_limit))]
+		pub fn migrate(origin: OriginFor<T>, weight_limit: Weight) -> DispatchResultWithPostInfo {
+			use migration::MigrateResult::*;
+			ensure_signed(origin)?;
+
+			let weight_limit = weight_limit.saturating_add(T::WeightInfo::migrate());
+			let (result, weight) = Migration::<T>::migrate(weight_limit);
+
+			match result {
+				Completed =>
+					Ok(PostDispatchInfo { actual_weight: Some(weight), pays_fee: Pays::No }),
+				InProgress { steps_done, .. } if steps_done > 0 =>
+					Ok(PostDispatchInfo { actual_weight: Some(weight), pays_fee: Pays::No }),
+				InProgress { .. } =>
+					Ok(PostDispatchInfo { actual_weight: Some(weight), pays_fee: Pays::Yes }),
+				NoMigrationInProgress | NoMigrationPerformed => {
+					let err: DispatchError = <Error<T>>::NoMigrationPerformed.into();
+					Err(err.with_weight(T::WeightInfo::migrate()))
+				},
+			}
+		}
 	}
 
 	#[pallet::event]
@@ -911,6 +1003,18 @@ pub mod pallet {
 		CannotTransferNOVA,
 		/// An indetermistic code was used in a context where this is not permitted.
 		Indeterministic,
+		/// The contract has reached its maximum number of delegate dependencies.
+		MaxDelegateDependenciesReached,
+		/// The dependency was not found in the contract's delegate dependencies.
+		DelegateDependencyNotFound,
+		/// The contract already depends on the given delegate dependency.
+		DelegateDependencyAlreadyExists,
+		/// Can not add a delegate dependency to the code hash of the contract itself.
+		CannotAddSelfAsDelegateDependency,
+		/// A pending migration needs to complete before the extrinsic can be called.
+		MigrationInProgress,
+		/// Migrate dispatch call was attempted but no migration was performed.
+		NoMigrationPerformed,
 	}
 
 	/// A mapping from an original code hash to the original code, untouched by instrumentation.
@@ -926,6 +1030,12 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type OwnerInfoOf<T: Config> = StorageMap<_, Identity, CodeHash<T>, OwnerInfo<T>>;
 
+	/// A migration can span across multiple blocks. This storage defines a cursor to track the
+	/// progress of the migration, enabling us to resume from the last completed position.
+	#[pallet::storage]
+	pub(crate) type MigrationInProgress<T: Config> =
+		StorageValue<_, migration::Cursor, OptionQuery>;
+
 	/// This is a **monotonic** counter incremented on contract instantiation.
 	///
 	/// This is used in order to generate unique trie ids for contracts.
@@ -1006,24 +1116,46 @@ impl<T: Config> Pallet<T> {
 		debug: bool,
 		determinism: Determinism,
 	) -> ContractExecResult<BalanceOf<T>> {
-		let mut debug_message = if debug { Some(DebugBufferVec::<T>::default()) } else { None };
-		let output = Self::internal_call(
+		Self::bare_call_with_trace(
 			origin,
 			dest,
 			value,
 			gas_limit,
 			storage_deposit_limit,
 			data,
-			debug_message.as_mut(),
+			debug,
+			None,
+			determinism,
+		)
+	}
+
+	/// Perform a call to a specified contract and trace all calls made.
+	///
+	/// This is [`Self::bare_call`] with `debug` set, which additionally returns a trace of all
+	/// calls and instantiations made, see [`CallTrace`]. It should only ever be used when
+	/// executing as an RPC.
+	pub fn bare_call_traced(
+		origin: T::AccountId,
+		dest: T::AccountId,
+		value: BalanceOf<T>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+		data: Vec<u8>,
+		determinism: Determinism,
+	) -> TracedResult<ContractExecResult<BalanceOf<T>>, T::AccountId, BalanceOf<T>> {
+		let mut trace = CallTraceVec::<T>::default();
+		let result = Self::bare_call_with_trace(
+			origin,
+			dest,
+			value,
+			gas_limit,
+			storage_deposit_limit,
+			data,
+			true,
+			Some(&mut trace),
 			determinism,
 		);
-		ContractExecResult {
-			result: output.result.map_err(|r| r.error),
-			gas_consumed: output.gas_meter.gas_consumed(),
-			gas_required: output.gas_meter.gas_required(),
-			storage_deposit: output.storage_deposit,
-			debug_message: debug_message.unwrap_or_default().to_vec(),
-		}
+		TracedResult { result, trace: trace.into_inner() }
 	}
 
 	/// Instantiate a new contract.
@@ -1048,8 +1180,7 @@ impl<T: Config> Pallet<T> {
 		salt: Vec<u8>,
 		debug: bool,
 	) -> ContractInstantiateResult<T::AccountId, BalanceOf<T>> {
-		let mut debug_message = if debug { Some(DebugBufferVec::<T>::default()) } else { None };
-		let output = Self::internal_instantiate(
+		Self::bare_instantiate_with_trace(
 			origin,
 			value,
 			gas_limit,
@@ -1057,18 +1188,42 @@ impl<T: Config> Pallet<T> {
 			code,
 			data,
 			salt,
-			debug_message.as_mut(),
+			debug,
+			None,
+		)
+	}
+
+	/// Instantiate a new contract and trace all calls made.
+	///
+	/// This is [`Self::bare_instantiate`] with `debug` set, which additionally returns a trace
+	/// of all calls and instantiations made, see [`CallTrace`]. It should only ever be used
+	/// when executing as an RPC.
+	pub fn bare_instantiate_traced(
+		origin: T::AccountId,
+		value: BalanceOf<T>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+		code: Code<CodeHash<T>>,
+		data: Vec<u8>,
+		salt: Vec<u8>,
+	) -> TracedResult<
+		ContractInstantiateResult<T::AccountId, BalanceOf<T>>,
+		T::AccountId,
+		BalanceOf<T>,
+	> {
+		let mut trace = CallTraceVec::<T>::default();
+		let result = Self::bare_instantiate_with_trace(
+			origin,
+			value,
+			gas_limit,
+			storage_deposit_limit,
+			code,
+			data,
+			salt,
+			true,
+			Some(&mut trace),
 		);
-		ContractInstantiateResult {
-			result: output
-				.result
-				.map(|(account_id, result)| InstantiateReturnValue { result, account_id })
-				.map_err(|e| e.error),
-			gas_consumed: output.gas_meter.gas_consumed(),
-			gas_required: output.gas_meter.gas_required(),
-			storage_deposit: output.storage_deposit,
-			debug_message: debug_message.unwrap_or_default().to_vec(),
-		}
+		TracedResult { result, trace: trace.into_inner() }
 	}
 
 	/// Upload new code without instantiating a contract from it.
@@ -1081,6 +1236,7 @@ impl<T: Config> Pallet<T> {
 		storage_deposit_limit: Option<BalanceOf<T>>,
 		determinism: Determinism,
 	) -> CodeUploadResult<CodeHash<T>, BalanceOf<T>> {
+		Migration::<T>::ensure_migrated()?;
 		let schedule = T::Schedule::get();
 		let module = PrefabWasmModule::from_code(
 			code,
@@ -1101,6 +1257,9 @@ impl<T: Config> Pallet<T> {
 
 	/// Query storage of a specified contract under a specified key.
 	pub fn get_storage(address: T::AccountId, key: Vec<u8>) -> GetStorageResult {
+		if Migration::<T>::in_progress() {
+			return Err(ContractAccessError::MigrationInProgress)
+		}
 		let contract_info =
 			ContractInfoOf::<T>::get(&address).ok_or(ContractAccessError::DoesntExist)?;
 
@@ -1148,6 +1307,76 @@ impl<T: Config> Pallet<T> {
 		self::wasm::reinstrument(module, schedule).map(|_| ())
 	}
 
+	/// Perform a call, recording debug output if `debug` is set and calls into `trace` if given.
+	fn bare_call_with_trace(
+		origin: T::AccountId,
+		dest: T::AccountId,
+		value: BalanceOf<T>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+		data: Vec<u8>,
+		debug: bool,
+		trace: Option<&mut CallTraceVec<T>>,
+		determinism: Determinism,
+	) -> ContractExecResult<BalanceOf<T>> {
+		let mut debug_message = if debug { Some(DebugBufferVec::<T>::default()) } else { None };
+		let output = Self::internal_call(
+			origin,
+			dest,
+			value,
+			gas_limit,
+			storage_deposit_limit,
+			data,
+			debug_message.as_mut(),
+			trace,
+			determinism,
+		);
+		ContractExecResult {
+			result: output.result.map_err(|r| r.error),
+			gas_consumed: output.gas_meter.gas_consumed(),
+			gas_required: output.gas_meter.gas_required(),
+			storage_deposit: output.storage_deposit,
+			debug_message: debug_message.unwrap_or_default().to_vec(),
+		}
+	}
+
+	/// Instantiate a contract, recording debug output if `debug` is set and calls into `trace`
+	/// if given.
+	fn bare_instantiate_with_trace(
+		origin: T::AccountId,
+		value: BalanceOf<T>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+		code: Code<CodeHash<T>>,
+		data: Vec<u8>,
+		salt: Vec<u8>,
+		debug: bool,
+		trace: Option<&mut CallTraceVec<T>>,
+	) -> ContractInstantiateResult<T::AccountId, BalanceOf<T>> {
+		let mut debug_message = if debug { Some(DebugBufferVec::<T>::default()) } else { None };
+		let output = Self::internal_instantiate(
+			origin,
+			value,
+			gas_limit,
+			storage_deposit_limit,
+			code,
+			data,
+			salt,
+			debug_message.as_mut(),
+			trace,
+		);
+		ContractInstantiateResult {
+			result: output
+				.result
+				.map(|(account_id, result)| InstantiateReturnValue { result, account_id })
+				.map_err(|e| e.error),
+			gas_consumed: output.gas_meter.gas_consumed(),
+			gas_required: output.gas_meter.gas_required(),
+			storage_deposit: output.storage_deposit,
+			debug_message: debug_message.unwrap_or_default().to_vec(),
+		}
+	}
+
 	/// Internal function that does the actual call.
 	///
 	/// Called by dispatchables and public functions.
@@ -1159,9 +1388,17 @@ impl<T: Config> Pallet<T> {
 		storage_deposit_limit: Option<BalanceOf<T>>,
 		data: Vec<u8>,
 		debug_message: Option<&mut DebugBufferVec<T>>,
+		trace: Option<&mut CallTraceVec<T>>,
 		determinism: Determinism,
 	) -> InternalCallOutput<T> {
 		let mut gas_meter = GasMeter::new(gas_limit);
+		if let Err(err) = Migration::<T>::ensure_migrated() {
+			return InternalCallOutput {
+				result: Err(err.into()),
+				gas_meter,
+				storage_deposit: Default::default(),
+			}
+		}
 		let mut storage_meter = match StorageMeter::new(&origin, storage_deposit_limit, value) {
 			Ok(meter) => meter,
 			Err(err) =>
@@ -1181,6 +1418,7 @@ impl<T: Config> Pallet<T> {
 			value,
 			data,
 			debug_message,
+			trace,
 			determinism,
 		);
 		InternalCallOutput {
@@ -1202,10 +1440,12 @@ impl<T: Config> Pallet<T> {
 		data: Vec<u8>,
 		salt: Vec<u8>,
 		mut debug_message: Option<&mut DebugBufferVec<T>>,
+		trace: Option<&mut CallTraceVec<T>>,
 	) -> InternalInstantiateOutput<T> {
 		let mut storage_deposit = Default::default();
 		let mut gas_meter = GasMeter::new(gas_limit);
 		let try_exec = || {
+			Migration::<T>::ensure_migrated()?;
 			let schedule = T::Schedule::get();
 			let (extra_deposit, executable) = match code {
 				Code::Upload(binary) => {
@@ -1246,6 +1486,7 @@ impl<T: Config> Pallet<T> {
 				data,
 				&salt,
 				debug_message,
+				trace,
 			);
 			storage_deposit = storage_meter
 				.into_deposit(&origin)
@@ -1279,7 +1520,7 @@ impl<T: Config> Pallet<T> {
 
 sp_api::decl_runtime_apis! {
 	/// The API used to dry-run contract interactions.
-	#[api_version(2)]
+	#[api_version(3)]
 	pub trait ContractsApi<AccountId, Balance, BlockNumber, Hash> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -1311,6 +1552,30 @@ sp_api::decl_runtime_apis! {
 			salt: Vec<u8>,
 		) -> ContractInstantiateResult<AccountId, Balance>;
 
+		/// Perform a call from a specified account to a given contract and trace all calls made.
+		///
+		/// See [`crate::Pallet::bare_call_traced`].
+		fn trace_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> TracedResult<ContractExecResult<Balance>, AccountId, Balance>;
+
+		/// Instantiate a new contract and trace all calls made.
+		///
+		/// See [`crate::Pallet::bare_instantiate_traced`].
+		fn trace_instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			code: Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> TracedResult<ContractInstantiateResult<AccountId, Balance>, AccountId, Balance>;
 
 		/// Upload new code without instantiating a contract from it.
 		///
@@ -15,10 +15,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{BalanceOf, CodeHash, Config, Pallet, TrieId, Weight};
-use codec::{Decode, Encode};
+//! Multi-block migration framework for pallet-contracts.
+//!
+//! Storage versions up to [`LAST_EAGER_VERSION`] are migrated in one go when the runtime is
+//! upgraded. Every later version is migrated lazily: [`Migration::on_runtime_upgrade`] only
+//! stores a cursor in [`MigrationInProgress`], and the actual work is split into steps that are
+//! executed by the `on_idle` hook and the permissionless `migrate` dispatchable until the
+//! on-chain storage version matches the version of the pallet. Contracts can't be called or
+//! instantiated while a migration is in progress.
+//!
+//! A lazy migration is defined by implementing [`MigrationStep`] and adding it to the
+//! [`Config::Migrations`] sequence of the runtime.
+
+use crate::{
+	weights::WeightInfo, BalanceOf, CodeHash, Config, Error, MigrationInProgress, Pallet, TrieId,
+	Weight,
+};
+use codec::{Codec, Decode, Encode};
 use frame_support::{
 	codec,
+	dispatch::DispatchResult,
 	pallet_prelude::*,
 	storage::migration,
 	storage_alias,
@@ -28,41 +44,253 @@ use frame_support::{
 use sp_runtime::traits::Saturating;
 use sp_std::{marker::PhantomData, prelude::*};
 
-/// Performs all necessary migrations based on `StorageVersion`.
-pub struct Migration<T: Config>(PhantomData<T>);
-impl<T: Config> OnRuntimeUpgrade for Migration<T> {
-	fn on_runtime_upgrade() -> Weight {
-		let version = <Pallet<T>>::on_chain_storage_version();
-		let mut weight = Weight::zero();
+/// The last storage version that is migrated eagerly within a single runtime upgrade.
+const LAST_EAGER_VERSION: u16 = 9;
 
-		if version < 4 {
-			v4::migrate::<T>(&mut weight);
-		}
+/// The maximum length of an encoded [`MigrationStep`].
+const CURSOR_LEN: u32 = 1024;
 
-		if version < 5 {
-			v5::migrate::<T>(&mut weight);
+const PROOF_ENCODE: &str = "`CURSOR_LEN` is checked in `integrity_test`; qed";
+const PROOF_DECODE: &str = "We encode the MigrationStep in the same way; qed";
+
+fn invalid_version(version: StorageVersion) -> ! {
+	panic!("Required migration {:?} not supported by this runtime. This is a bug.", version);
+}
+
+/// The cursor used to store the state of the migration step that is currently in progress.
+pub type Cursor = BoundedVec<u8, ConstU32<CURSOR_LEN>>;
+
+/// Whether a [`MigrationStep`] is finished.
+#[derive(Debug, PartialEq, Eq)]
+pub enum IsFinished {
+	Yes,
+	No,
+}
+
+/// A migration from the previous storage version to [`Self::VERSION`].
+///
+/// The migration is executed in steps. Its state is kept in `Self` and stored in between
+/// blocks. It is finished once [`Self::step`] returns [`IsFinished::Yes`].
+pub trait MigrationStep: Codec + MaxEncodedLen + Default {
+	/// The storage version this migration migrates to.
+	const VERSION: u16;
+
+	/// The maximum weight that can be consumed by a single call to [`Self::step`].
+	fn max_step_weight() -> Weight;
+
+	/// Process one step of the migration.
+	///
+	/// Returns whether the migration is finished and the weight consumed.
+	fn step(&mut self) -> (IsFinished, Weight);
+
+	/// Verify that the migration state fits into a [`Cursor`] and that a single step fits into
+	/// a block.
+	fn integrity_test(max_block_weight: Weight) {
+		if Self::max_step_weight().any_gt(max_block_weight) {
+			panic!("Invalid max_step_weight for Migration {}", Self::VERSION)
 		}
 
-		if version < 6 {
-			v6::migrate::<T>(&mut weight);
+		let len = <Self as MaxEncodedLen>::max_encoded_len();
+		if len > CURSOR_LEN as usize {
+			panic!(
+				"Migration {} has size {} which is bigger than the maximum of {}",
+				Self::VERSION,
+				len,
+				CURSOR_LEN,
+			);
 		}
+	}
+}
+
+/// A migration that does nothing.
+///
+/// It can be used when a storage version bump doesn't require any storage changes.
+#[derive(frame_support::DefaultNoBound, Encode, Decode, MaxEncodedLen)]
+pub struct NoopMigration<const N: u16>;
+
+impl<const N: u16> MigrationStep for NoopMigration<N> {
+	const VERSION: u16 = N;
+
+	fn max_step_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn step(&mut self) -> (IsFinished, Weight) {
+		(IsFinished::Yes, Weight::zero())
+	}
+}
+
+mod private {
+	use super::MigrationStep;
+
+	pub trait Sealed {}
+
+	#[impl_trait_for_tuples::impl_for_tuples(10)]
+	#[tuple_types_custom_trait_bound(MigrationStep)]
+	impl Sealed for Tuple {}
+}
+
+/// A sequence of [`MigrationStep`]s.
+///
+/// It is implemented for tuples of migrations which must be ordered by their versions with
+/// no gaps, e.g. `(v10::Migration<T>, v11::Migration<T>)`.
+pub trait MigrateSequence: private::Sealed {
+	/// The range of versions this sequence migrates to.
+	const VERSION_RANGE: (u16, u16);
+
+	/// Returns the initial cursor of the migration to `version`.
+	fn new(version: StorageVersion) -> Cursor;
+
+	/// Execute steps of the migration to `version` until it is finished or `weight_left` is
+	/// exhausted.
+	fn steps(version: StorageVersion, cursor: &[u8], weight_left: &mut Weight) -> StepResult;
 
-		if version < 7 {
-			v7::migrate::<T>(&mut weight);
+	/// Run [`MigrationStep::integrity_test`] for every migration of the sequence.
+	fn integrity_test(max_block_weight: Weight);
+
+	/// Returns whether this sequence can migrate the storage from `in_storage` to `target`.
+	fn is_upgrade_supported(in_storage: StorageVersion, target: StorageVersion) -> bool {
+		let (low, high) = Self::VERSION_RANGE;
+		in_storage + 1 == StorageVersion::new(low) && target == high
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+#[tuple_types_custom_trait_bound(MigrationStep)]
+impl MigrateSequence for Tuple {
+	const VERSION_RANGE: (u16, u16) = {
+		let mut versions: (u16, u16) = (0, 0);
+		for_tuples!(
+			#(
+				match versions {
+					(0, 0) => {
+						versions = (Tuple::VERSION, Tuple::VERSION);
+					},
+					(min_version, last_version) if Tuple::VERSION == last_version + 1 => {
+						versions = (min_version, Tuple::VERSION);
+					},
+					_ => panic!("Migrations must be ordered by their versions with no gaps.")
+				}
+			)*
+		);
+		versions
+	};
+
+	fn new(version: StorageVersion) -> Cursor {
+		for_tuples!(
+			#(
+				if version == Tuple::VERSION {
+					return Tuple::default().encode().try_into().expect(PROOF_ENCODE)
+				}
+			)*
+		);
+		invalid_version(version)
+	}
+
+	fn steps(version: StorageVersion, mut cursor: &[u8], weight_left: &mut Weight) -> StepResult {
+		for_tuples!(
+			#(
+				if version == Tuple::VERSION {
+					let mut migration = <Tuple as Decode>::decode(&mut cursor)
+						.expect(PROOF_DECODE);
+					let max_weight = Tuple::max_step_weight();
+					let mut steps_done = 0u32;
+					while weight_left.all_gte(max_weight) {
+						let (finished, weight) = migration.step();
+						steps_done.saturating_accrue(1);
+						weight_left.saturating_reduce(weight);
+						if finished == IsFinished::Yes {
+							return StepResult::Completed { steps_done }
+						}
+					}
+					return StepResult::InProgress {
+						cursor: migration.encode().try_into().expect(PROOF_ENCODE),
+						steps_done,
+					}
+				}
+			)*
+		);
+		invalid_version(version)
+	}
+
+	fn integrity_test(max_block_weight: Weight) {
+		for_tuples!( #( Tuple::integrity_test(max_block_weight); )* );
+	}
+}
+
+/// The result of [`Migration::migrate`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum MigrateResult {
+	/// The weight limit was too low to perform any migration work.
+	NoMigrationPerformed,
+	/// No migration is in progress.
+	NoMigrationInProgress,
+	/// A migration is still in progress after `steps_done` steps.
+	InProgress { steps_done: u32 },
+	/// All migrations are completed.
+	Completed,
+}
+
+/// The result of [`MigrateSequence::steps`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum StepResult {
+	/// The migration is not finished. Its state is stored in `cursor`.
+	InProgress { cursor: Cursor, steps_done: u32 },
+	/// The migration is finished.
+	Completed { steps_done: u32 },
+}
+
+/// Performs all necessary migrations based on `StorageVersion`.
+///
+/// See the [module documentation](self) for how versions are migrated.
+pub struct Migration<T: Config, M: MigrateSequence = <T as Config>::Migrations>(
+	PhantomData<(T, M)>,
+);
+
+impl<T: Config, M: MigrateSequence> OnRuntimeUpgrade for Migration<T, M> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut weight = Weight::zero();
+		let mut on_chain_version = <Pallet<T>>::on_chain_storage_version();
+
+		if on_chain_version < LAST_EAGER_VERSION {
+			migrate_eagerly::<T>(on_chain_version, &mut weight);
+			on_chain_version = StorageVersion::new(LAST_EAGER_VERSION);
 		}
 
-		if version < 8 {
-			v8::migrate::<T>(&mut weight);
+		let current_version = <Pallet<T>>::current_storage_version();
+		if on_chain_version == current_version {
+			return weight.saturating_add(T::WeightInfo::on_runtime_upgrade_noop())
 		}
 
-		if version < 9 {
-			v9::migrate::<T>(&mut weight);
+		// The migration in progress creates the cursor for the next migration once it is
+		// finished.
+		if Self::in_progress() {
+			log::warn!(
+				target: "runtime::contracts",
+				"A migration to {:?} is already in progress",
+				on_chain_version + 1,
+			);
+			return weight.saturating_add(T::WeightInfo::on_runtime_upgrade_in_progress())
 		}
 
-		StorageVersion::new(9).put::<Pallet<T>>();
-		weight.saturating_accrue(T::DbWeight::get().writes(1));
+		if !M::is_upgrade_supported(on_chain_version, current_version) {
+			log::error!(
+				target: "runtime::contracts",
+				"Migrating from {:?} to {:?} is not supported by the configured migrations",
+				on_chain_version,
+				current_version,
+			);
+			return weight.saturating_add(T::WeightInfo::on_runtime_upgrade_noop())
+		}
 
-		weight
+		log::info!(
+			target: "runtime::contracts",
+			"Starting lazy migration from {:?} to {:?}",
+			on_chain_version,
+			current_version,
+		);
+		MigrationInProgress::<T>::set(Some(M::new(on_chain_version + 1)));
+		weight.saturating_add(T::WeightInfo::on_runtime_upgrade())
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -83,6 +311,109 @@ impl<T: Config> OnRuntimeUpgrade for Migration<T> {
 	}
 }
 
+impl<T: Config, M: MigrateSequence> Migration<T, M> {
+	/// Verify that every migration of the sequence fits into a [`Cursor`] and a block.
+	pub(crate) fn integrity_test() {
+		let max_weight = <T as frame_system::Config>::BlockWeights::get().max_block;
+		M::integrity_test(max_weight)
+	}
+
+	/// Execute steps of the migration in progress, consuming at most `weight_limit`.
+	///
+	/// Returns the progress that was made together with the weight consumed.
+	pub(crate) fn migrate(weight_limit: Weight) -> (MigrateResult, Weight) {
+		let mut weight_left = weight_limit;
+
+		if weight_left.checked_reduce(T::WeightInfo::migrate()).is_none() {
+			return (MigrateResult::NoMigrationPerformed, Weight::zero())
+		}
+
+		MigrationInProgress::<T>::mutate_exists(|progress| {
+			let cursor_before = match progress.as_ref() {
+				Some(cursor) => cursor,
+				None =>
+					return (MigrateResult::NoMigrationInProgress, T::WeightInfo::migration_noop()),
+			};
+
+			// A migration that is in progress always migrates to the next version.
+			let in_progress_version = <Pallet<T>>::on_chain_storage_version() + 1;
+
+			let result = match M::steps(in_progress_version, cursor_before, &mut weight_left) {
+				StepResult::InProgress { cursor, steps_done } => {
+					*progress = Some(cursor);
+					MigrateResult::InProgress { steps_done }
+				},
+				StepResult::Completed { steps_done } => {
+					in_progress_version.put::<Pallet<T>>();
+					if <Pallet<T>>::current_storage_version() != in_progress_version {
+						log::info!(
+							target: "runtime::contracts",
+							"Migration to {:?} done, starting the next one",
+							in_progress_version,
+						);
+						*progress = Some(M::new(in_progress_version + 1));
+						MigrateResult::InProgress { steps_done }
+					} else {
+						log::info!(
+							target: "runtime::contracts",
+							"All migrations done. At version {:?}",
+							in_progress_version,
+						);
+						*progress = None;
+						MigrateResult::Completed
+					}
+				},
+			};
+
+			(result, weight_limit.saturating_sub(weight_left))
+		})
+	}
+
+	/// Returns [`Error::MigrationInProgress`] while a migration is in progress.
+	pub(crate) fn ensure_migrated() -> DispatchResult {
+		if Self::in_progress() {
+			Err(Error::<T>::MigrationInProgress.into())
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Whether a migration is in progress.
+	pub(crate) fn in_progress() -> bool {
+		MigrationInProgress::<T>::exists()
+	}
+}
+
+/// Migrate all versions before [`LAST_EAGER_VERSION`] in one go.
+fn migrate_eagerly<T: Config>(version: StorageVersion, weight: &mut Weight) {
+	if version < 4 {
+		v4::migrate::<T>(weight);
+	}
+
+	if version < 5 {
+		v5::migrate::<T>(weight);
+	}
+
+	if version < 6 {
+		v6::migrate::<T>(weight);
+	}
+
+	if version < 7 {
+		v7::migrate::<T>(weight);
+	}
+
+	if version < 8 {
+		v8::migrate::<T>(weight);
+	}
+
+	if version < 9 {
+		v9::migrate::<T>(weight);
+	}
+
+	StorageVersion::new(LAST_EAGER_VERSION).put::<Pallet<T>>();
+	weight.saturating_accrue(T::DbWeight::get().writes(1));
+}
+
 /// V4: `Schedule` is changed to be a config item rather than an in-storage value.
 mod v4 {
 	use super::*;
@@ -400,6 +731,95 @@ mod v9 {
 	}
 }
 
+/// V10: Contracts can lock the code of their delegate dependencies.
+pub mod v10 {
+	use super::*;
+	use crate::storage::{DelegateDependencyMap, DepositAccount};
+	use frame_support::DefaultNoBound;
+
+	#[derive(Encode, Decode)]
+	struct OldContractInfo<T: Config> {
+		trie_id: TrieId,
+		deposit_account: DepositAccount<T>,
+		code_hash: CodeHash<T>,
+		storage_bytes: u32,
+		storage_items: u32,
+		storage_byte_deposit: BalanceOf<T>,
+		storage_item_deposit: BalanceOf<T>,
+		storage_base_deposit: BalanceOf<T>,
+	}
+
+	#[derive(Encode, Decode)]
+	struct ContractInfo<T: Config> {
+		trie_id: TrieId,
+		deposit_account: DepositAccount<T>,
+		code_hash: CodeHash<T>,
+		storage_bytes: u32,
+		storage_items: u32,
+		storage_byte_deposit: BalanceOf<T>,
+		storage_item_deposit: BalanceOf<T>,
+		storage_base_deposit: BalanceOf<T>,
+		delegate_dependencies: DelegateDependencyMap<T>,
+	}
+
+	#[storage_alias]
+	type ContractInfoOf<T: Config, V> =
+		StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, V>;
+
+	type OldContractInfoOf<T> = ContractInfoOf<T, OldContractInfo<T>>;
+
+	/// Adds an empty `delegate_dependencies` map to every contract, one contract per step.
+	#[derive(Encode, Decode, MaxEncodedLen, DefaultNoBound)]
+	pub struct Migration<T: Config> {
+		last_account: Option<T::AccountId>,
+	}
+
+	/// Store `info` in the layout that is expected before this migration.
+	#[cfg(any(feature = "runtime-benchmarks", test))]
+	pub fn store_old_contract_info<T: Config>(account: T::AccountId, info: crate::ContractInfo<T>) {
+		let info = OldContractInfo::<T>::decode(&mut &info.encode()[..])
+			.expect("The old layout is a prefix of the new one; qed");
+		OldContractInfoOf::<T>::insert(account, info);
+	}
+
+	impl<T: Config> MigrationStep for Migration<T> {
+		const VERSION: u16 = 10;
+
+		fn max_step_weight() -> Weight {
+			T::WeightInfo::v10_migration_step()
+		}
+
+		fn step(&mut self) -> (IsFinished, Weight) {
+			let mut iter = if let Some(last_account) = self.last_account.take() {
+				OldContractInfoOf::<T>::iter_from(OldContractInfoOf::<T>::hashed_key_for(
+					last_account,
+				))
+			} else {
+				OldContractInfoOf::<T>::iter()
+			};
+
+			if let Some((account, old)) = iter.next() {
+				let info = ContractInfo {
+					trie_id: old.trie_id,
+					deposit_account: old.deposit_account,
+					code_hash: old.code_hash,
+					storage_bytes: old.storage_bytes,
+					storage_items: old.storage_items,
+					storage_byte_deposit: old.storage_byte_deposit,
+					storage_item_deposit: old.storage_item_deposit,
+					storage_base_deposit: old.storage_base_deposit,
+					delegate_dependencies: Default::default(),
+				};
+				ContractInfoOf::<T, ContractInfo<T>>::insert(&account, info);
+				self.last_account = Some(account);
+				(IsFinished::No, T::WeightInfo::v10_migration_step())
+			} else {
+				(IsFinished::Yes, T::WeightInfo::v10_migration_step())
+			}
+		}
+	}
+}
+
 // Post checks always need to be run against the latest storage version. This is why we
 // do not scope them in the per version modules. They always need to be ported to the latest
 // version.
@@ -419,6 +419,12 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `seal_ecdsa_to_eth_address`.
 	pub ecdsa_to_eth_address: Weight,
 
+	/// Weight of calling `sr25519_verify`.
+	pub sr25519_verify: Weight,
+
+	/// Weight per byte of the message verified by `sr25519_verify`.
+	pub sr25519_verify_per_byte: Weight,
+
 	/// Weight of calling `reentrance_count`.
 	pub reentrance_count: Weight,
 
@@ -428,6 +434,12 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `instantiation_nonce`.
 	pub instantiation_nonce: Weight,
 
+	/// Weight of calling `lock_delegate_dependency`.
+	pub lock_delegate_dependency: Weight,
+
+	/// Weight of calling `unlock_delegate_dependency`.
+	pub unlock_delegate_dependency: Weight,
+
 	/// The type parameter is used in the default implementation.
 	#[codec(skip)]
 	pub _phantom: PhantomData<T>,
@@ -677,9 +689,13 @@ impl<T: Config> Default for HostFnWeights<T> {
 			hash_blake2_128_per_byte: cost_byte_batched!(seal_hash_blake2_128_per_kb),
 			ecdsa_recover: cost_batched!(seal_ecdsa_recover),
 			ecdsa_to_eth_address: cost_batched!(seal_ecdsa_to_eth_address),
+			sr25519_verify: cost_batched!(seal_sr25519_verify),
+			sr25519_verify_per_byte: cost_byte_batched!(seal_sr25519_verify_per_kb),
 			reentrance_count: cost_batched!(seal_reentrance_count),
 			account_reentrance_count: cost_batched!(seal_account_reentrance_count),
 			instantiation_nonce: cost_batched!(seal_instantiation_nonce),
+			lock_delegate_dependency: cost!(seal_lock_delegate_dependency),
+			unlock_delegate_dependency: cost!(seal_unlock_delegate_dependency),
 			_phantom: PhantomData,
 		}
 	}
@@ -28,9 +28,10 @@ use crate::{
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
+	ensure,
 	storage::child::{self, ChildInfo},
 	weights::Weight,
-	RuntimeDebugNoBound,
+	BoundedBTreeMap, RuntimeDebugNoBound,
 };
 use scale_info::TypeInfo;
 use sp_io::KillStorageResult;
@@ -40,6 +41,11 @@ use sp_runtime::{
 };
 use sp_std::{ops::Deref, prelude::*};
 
+/// The code hashes a contract locked as delegate dependencies, together with the deposit paid
+/// for each of them.
+pub type DelegateDependencyMap<T> =
+	BoundedBTreeMap<CodeHash<T>, BalanceOf<T>, <T as Config>::MaxDelegateDependencies>;
+
 /// Information for managing an account and its sub trie abstraction.
 /// This is the required info to cache for an account.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -66,6 +72,10 @@ pub struct ContractInfo<T: Config> {
 	/// We need to store this information separately so it is not used when calculating any refunds
 	/// since the base deposit can only ever be refunded on contract termination.
 	storage_base_deposit: BalanceOf<T>,
+	/// The code hashes this contract locked as delegate dependencies.
+	///
+	/// A locked code can't be removed as long as the contract exists or until it is unlocked.
+	delegate_dependencies: DelegateDependencyMap<T>,
 }
 
 impl<T: Config> ContractInfo<T> {
@@ -101,6 +111,7 @@ impl<T: Config> ContractInfo<T> {
 			storage_byte_deposit: Zero::zero(),
 			storage_item_deposit: Zero::zero(),
 			storage_base_deposit: Zero::zero(),
+			delegate_dependencies: Default::default(),
 		};
 
 		Ok(contract)
@@ -116,10 +127,16 @@ impl<T: Config> ContractInfo<T> {
 		self.storage_byte_deposit.saturating_add(self.storage_item_deposit)
 	}
 
-	/// Same as [`Self::extra_deposit`] but including the base deposit.
+	/// Same as [`Self::extra_deposit`] but including the base deposit and the deposits paid for
+	/// the delegate dependencies.
 	pub fn total_deposit(&self) -> BalanceOf<T> {
+		let dependencies_deposit = self
+			.delegate_dependencies
+			.values()
+			.fold(BalanceOf::<T>::zero(), |total, deposit| total.saturating_add(*deposit));
 		self.extra_deposit()
 			.saturating_add(self.storage_base_deposit)
+			.saturating_add(dependencies_deposit)
 			.saturating_sub(Pallet::<T>::min_balance())
 	}
 
@@ -128,6 +145,46 @@ impl<T: Config> ContractInfo<T> {
 		&self.deposit_account
 	}
 
+	/// The code hashes this contract locked as delegate dependencies.
+	pub fn delegate_dependencies(&self) -> &DelegateDependencyMap<T> {
+		&self.delegate_dependencies
+	}
+
+	/// Lock `code_hash` as a delegate dependency, recording the `deposit` paid for it.
+	///
+	/// # Errors
+	///
+	/// - [`Error::DelegateDependencyAlreadyExists`]
+	/// - [`Error::MaxDelegateDependenciesReached`]
+	pub fn lock_delegate_dependency(
+		&mut self,
+		code_hash: CodeHash<T>,
+		deposit: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(
+			!self.delegate_dependencies.contains_key(&code_hash),
+			Error::<T>::DelegateDependencyAlreadyExists
+		);
+		self.delegate_dependencies
+			.try_insert(code_hash, deposit)
+			.map_err(|_| Error::<T>::MaxDelegateDependenciesReached)?;
+		Ok(())
+	}
+
+	/// Unlock the delegate dependency on `code_hash`, returning the deposit paid for it.
+	///
+	/// # Errors
+	///
+	/// - [`Error::DelegateDependencyNotFound`]
+	pub fn unlock_delegate_dependency(
+		&mut self,
+		code_hash: &CodeHash<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		self.delegate_dependencies
+			.remove(code_hash)
+			.ok_or_else(|| Error::<T>::DelegateDependencyNotFound.into())
+	}
+
 	/// Reads a storage kv pair of a contract.
 	///
 	/// The read is performed from the `trie_id` only. The `address` is not necessary. If the
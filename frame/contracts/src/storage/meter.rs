@@ -279,25 +279,26 @@ where
 	/// - `origin`: The origin that spawned the original root meter.
 	/// - `deposit_account`: The contract's deposit account that this sub call belongs to.
 	/// - `info`: The info of the contract in question. `None` if the contract was terminated.
+	///
+	/// Returns the deposit charged or refunded by the sub call, including its own sub calls.
 	pub fn absorb(
 		&mut self,
 		absorbed: RawMeter<T, E, Nested>,
 		deposit_account: DepositAccount<T>,
 		info: Option<&mut ContractInfo<T>>,
-	) {
+	) -> DepositOf<T> {
 		let own_deposit = absorbed.own_contribution.update_contract(info);
-		self.total_deposit = self
-			.total_deposit
-			.saturating_add(&absorbed.total_deposit)
-			.saturating_add(&own_deposit);
+		let deposit = absorbed.total_deposit.saturating_add(&own_deposit);
+		self.total_deposit = self.total_deposit.saturating_add(&deposit);
+		self.charges.extend_from_slice(&absorbed.charges);
 		if !own_deposit.is_zero() {
-			self.charges.extend_from_slice(&absorbed.charges);
 			self.charges.push(Charge {
 				deposit_account,
 				amount: own_deposit,
 				terminated: absorbed.is_terminated(),
 			});
 		}
+		deposit
 	}
 
 	/// The amount of balance that is still available from the original `limit`.
@@ -365,6 +366,24 @@ where
 		};
 	}
 
+	/// Charge or refund a deposit which isn't the result of a storage change.
+	///
+	/// This is used for the deposits of delegate dependencies. They are tracked by the contract
+	/// info separately from its storage and therefore can't be charged through [`Self::charge`].
+	/// Deposits of the same account are merged, so that refunding a deposit which was charged by
+	/// the same call doesn't need to be covered by the balance of the deposit account.
+	pub fn charge_deposit(&mut self, deposit_account: DepositAccount<T>, amount: DepositOf<T>) {
+		self.total_deposit = self.total_deposit.saturating_add(&amount);
+		match self
+			.charges
+			.iter_mut()
+			.find(|charge| charge.deposit_account == deposit_account && !charge.terminated)
+		{
+			Some(charge) => charge.amount = charge.amount.saturating_add(&amount),
+			None => self.charges.push(Charge { deposit_account, amount, terminated: false }),
+		}
+	}
+
 	/// Charge from `origin` a storage deposit for contract instantiation.
 	///
 	/// This immediately transfers the balance in order to create the account.
@@ -644,6 +663,7 @@ mod tests {
 			storage_byte_deposit: info.bytes_deposit,
 			storage_item_deposit: info.items_deposit,
 			storage_base_deposit: Default::default(),
+			delegate_dependencies: Default::default(),
 		}
 	}
 
@@ -750,6 +770,55 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn charge_deposit_works() {
+		clear_ext();
+
+		let mut meter = TestMeter::new(&ALICE, Some(100), 0).unwrap();
+
+		let mut nested0_info =
+			new_info(StorageInfo { bytes: 0, items: 0, bytes_deposit: 0, items_deposit: 0 });
+		let mut nested0 = meter.nested();
+		nested0.charge_deposit(DepositAccount(BOB), Deposit::Charge(10));
+		// Refunds of deposits charged by the same call are merged into the charge.
+		nested0.charge_deposit(DepositAccount(BOB), Deposit::Refund(3));
+
+		// The deposits of nested calls are kept even if they didn't change their storage.
+		let mut nested1_info =
+			new_info(StorageInfo { bytes: 0, items: 0, bytes_deposit: 0, items_deposit: 0 });
+		let mut nested1 = nested0.nested();
+		nested1.charge_deposit(DepositAccount(CHARLIE), Deposit::Refund(4));
+		assert_eq!(
+			nested0.absorb(nested1, DepositAccount(CHARLIE), Some(&mut nested1_info)),
+			Deposit::Refund(4)
+		);
+
+		nested0.enforce_limit(Some(&mut nested0_info)).unwrap();
+		meter.absorb(nested0, DepositAccount(BOB), Some(&mut nested0_info));
+
+		assert_eq!(meter.into_deposit(&ALICE), Deposit::Charge(3));
+		assert_eq!(
+			TestExtTestValue::get(),
+			TestExt {
+				limit_checks: vec![LimitCheck { origin: ALICE, limit: 100, min_leftover: 0 }],
+				charges: vec![
+					Charge {
+						origin: ALICE,
+						contract: DepositAccount(CHARLIE),
+						amount: Deposit::Refund(4),
+						terminated: false
+					},
+					Charge {
+						origin: ALICE,
+						contract: DepositAccount(BOB),
+						amount: Deposit::Charge(7),
+						terminated: false
+					}
+				]
+			}
+		)
+	}
+
 	#[test]
 	fn termination_works() {
 		clear_ext();
@@ -18,37 +18,41 @@
 use self::test_utils::hash;
 use crate::{
 	chain_extension::{
+		assets::{self, AssetsConfig, AssetsExtension, ReturnCode as AssetsReturnCode},
 		ChainExtension, Environment, Ext, InitState, RegisteredChainExtension,
 		Result as ExtensionResult, RetVal, ReturnFlags, SysConfig,
 	},
-	exec::{FixSizedKey, Frame},
+	exec::{Executable, FixSizedKey, Frame},
+	migration::{self, Migration},
 	tests::test_utils::{get_contract, get_contract_checked},
 	wasm::{Determinism, PrefabWasmModule, ReturnCode as RuntimeReturnCode},
 	weights::WeightInfo,
 	BalanceOf, Code, CodeStorage, Config, ContractInfo, ContractInfoOf, DefaultAddressGenerator,
-	DeletionQueue, Error, Pallet, Schedule,
+	DeletionQueue, Error, MigrationInProgress, Pallet, Schedule,
 };
 use assert_matches::assert_matches;
-use codec::Encode;
+use codec::{Compact, Decode, Encode};
 use frame_support::{
 	assert_err, assert_err_ignore_postinfo, assert_noop, assert_ok,
-	dispatch::{DispatchClass, DispatchErrorWithPostInfo, PostDispatchInfo},
+	dispatch::{DispatchClass, DispatchErrorWithPostInfo, Pays, PostDispatchInfo},
 	parameter_types,
 	storage::child,
 	traits::{
-		ConstBool, ConstU32, ConstU64, Contains, Currency, ExistenceRequirement, Get, LockableCurrency,
-		OnIdle, OnInitialize, ReservableCurrency, WithdrawReasons,
+		AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, Contains, Currency,
+		ExistenceRequirement, Get, LockableCurrency, OnIdle, OnInitialize, OnRuntimeUpgrade,
+		ReservableCurrency, StorageVersion, WithdrawReasons,
 	},
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
 use frame_system::{self as system, EventRecord, Phase};
+use pallet_contracts_primitives::{CallKind, ContractAccessError, StorageDeposit, TracedResult};
 use pretty_assertions::{assert_eq, assert_ne};
 use sp_io::hashing::blake2_256;
 use sp_keystore::{testing::KeyStore, KeystoreExt};
 use sp_runtime::{
 	testing::{Header, H256},
 	traits::{BlakeTwo256, Convert, Hash, IdentityLookup},
-	AccountId32,
+	AccountId32, MultiSignature, MultiSigner, Perbill, Permill,
 };
 use std::{ops::Deref, sync::Arc};
 
@@ -69,6 +73,8 @@ frame_support::construct_runtime!(
 		Randomness: pallet_insecure_randomness_collective_flip::{Pallet, Storage},
 		Utility: pallet_utility::{Pallet, Call, Storage, Event},
 		Contracts: pallet_contracts::{Pallet, Call, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Nfts: pallet_nfts::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -274,6 +280,71 @@ impl RegisteredChainExtension<Test> for TempStorageExtension {
 	const ID: u16 = 3;
 }
 
+pub struct TestAssets;
+
+impl AssetsConfig<Test> for TestAssets {
+	type Assets = Assets;
+	type TransferableAssets = TransferableAssets;
+	type Nfts = Nfts;
+	type WeightInfo = Self;
+}
+
+impl assets::WeightInfo for TestAssets {
+	fn balance() -> Weight {
+		Weight::zero()
+	}
+	fn total_supply() -> Weight {
+		Weight::zero()
+	}
+	fn allowance() -> Weight {
+		Weight::zero()
+	}
+	fn transfer() -> Weight {
+		Weight::zero()
+	}
+	fn approve() -> Weight {
+		Weight::zero()
+	}
+	fn transfer_from() -> Weight {
+		Weight::zero()
+	}
+	fn nft_owner() -> Weight {
+		Weight::zero()
+	}
+	fn nft_transfer() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct TransferableAssets;
+
+impl Contains<u32> for TransferableAssets {
+	fn contains(asset: &u32) -> bool {
+		Assets::is_transferable(*asset)
+	}
+}
+
+/// The [`AssetsExtension`] which can be disabled like the other test extensions.
+#[derive(Default)]
+pub struct TestAssetsExtension(AssetsExtension<TestAssets>);
+
+impl ChainExtension<Test> for TestAssetsExtension {
+	fn call<E>(&mut self, env: Environment<E, InitState>) -> ExtensionResult<RetVal>
+	where
+		E: Ext<T = Test>,
+	{
+		self.0.call(env)
+	}
+
+	fn enabled() -> bool {
+		TestExtensionTestValue::get().enabled
+	}
+}
+
+impl RegisteredChainExtension<Test> for TestAssetsExtension {
+	const ID: u16 = <AssetsExtension<TestAssets> as RegisteredChainExtension<Test>>::ID;
+}
+
 parameter_types! {
 	pub BlockWeights: frame_system::limits::BlockWeights =
 		frame_system::limits::BlockWeights::simple_max(
@@ -333,6 +404,69 @@ impl pallet_utility::Config for Test {
 	type PalletsOrigin = OriginCaller;
 	type WeightInfo = ();
 }
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetIdParameter = Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	pallet_assets::runtime_benchmarks_enabled! {
+		type BenchmarkHelper = ();
+	}
+}
+parameter_types! {
+	pub storage NftsFeatures: pallet_nfts::PalletFeatures =
+		pallet_nfts::PalletFeatures::all_enabled();
+	pub const MaxRoyalty: Permill = Permill::from_percent(20);
+}
+impl pallet_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type Assets = Assets;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type Locker = ();
+	type CollectionDeposit = ConstU64<2>;
+	type ItemDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type AttributeDepositBase = ConstU64<1>;
+	type DepositPerByte = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type ApprovalsLimit = ConstU32<10>;
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU64<10000>;
+	type MaxAttributesPerCall = ConstU32<2>;
+	type MaxRoyaltyRecipients = ConstU32<3>;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxAuctionEndingsPerBlock = ConstU32<2>;
+	type MaxNestingDepth = ConstU32<2>;
+	type MaxNestedChildren = ConstU32<2>;
+	type Features = NftsFeatures;
+	type OffchainSignature = MultiSignature;
+	type OffchainPublic = MultiSigner;
+	type WeightInfo = ();
+	pallet_nfts::runtime_benchmarks_enabled! {
+		type Helper = ();
+	}
+}
 parameter_types! {
 	pub MySchedule: Schedule<Test> = {
 		let mut schedule = <Schedule<Test>>::default();
@@ -382,6 +516,7 @@ impl Contains<RuntimeCall> for TestFilter {
 parameter_types! {
 	pub const DeletionWeightLimit: Weight = GAS_LIMIT;
 	pub static UnstableInterface: bool = true;
+	pub CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
 }
 
 impl Config for Test {
@@ -394,8 +529,13 @@ impl Config for Test {
 	type CallStack = [Frame<Self>; 5];
 	type WeightPrice = Self;
 	type WeightInfo = ();
-	type ChainExtension =
-		(TestExtension, DisabledExtension, RevertingExtension, TempStorageExtension);
+	type ChainExtension = (
+		TestExtension,
+		DisabledExtension,
+		RevertingExtension,
+		TempStorageExtension,
+		TestAssetsExtension,
+	);
 	type DeletionQueueDepth = ConstU32<1024>;
 	type DeletionWeightLimit = DeletionWeightLimit;
 	type Schedule = MySchedule;
@@ -406,6 +546,10 @@ impl Config for Test {
 	type MaxStorageKeyLen = ConstU32<128>;
 	type UnsafeUnstableInterface = UnstableInterface;
 	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+	type MaxCallTraceLen = ConstU32<1024>;
+	type MaxDelegateDependencies = ConstU32<32>;
+	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type Migrations = (migration::v10::Migration<Test>,);
 	type IsTransferable = ConstBool<true>;
 }
 
@@ -566,7 +710,7 @@ fn instantiate_and_call_and_deposit_event() {
 					phase: Phase::Initialization,
 					event: RuntimeEvent::Balances(pallet_balances::Event::Endowed {
 						account: deposit_account.clone(),
-						free_balance: 131,
+						free_balance: 132,
 					}),
 					topics: vec![],
 				},
@@ -575,7 +719,7 @@ fn instantiate_and_call_and_deposit_event() {
 					event: RuntimeEvent::Balances(pallet_balances::Event::Transfer {
 						from: ALICE,
 						to: deposit_account.clone(),
-						amount: 131,
+						amount: 132,
 					}),
 					topics: vec![],
 				},
@@ -898,7 +1042,7 @@ fn deploy_and_call_other_contract() {
 					phase: Phase::Initialization,
 					event: RuntimeEvent::Balances(pallet_balances::Event::Endowed {
 						account: deposit_account.clone(),
-						free_balance: 131,
+						free_balance: 132,
 					}),
 					topics: vec![],
 				},
@@ -907,7 +1051,7 @@ fn deploy_and_call_other_contract() {
 					event: RuntimeEvent::Balances(pallet_balances::Event::Transfer {
 						from: ALICE,
 						to: deposit_account.clone(),
-						amount: 131,
+						amount: 132,
 					}),
 					topics: vec![],
 				},
@@ -1985,6 +2129,146 @@ fn chain_extension_temp_storage_works() {
 	})
 }
 
+#[test]
+fn chain_extension_assets_works() {
+	let (code, _hash) = compile_module::<Test>("chain_extension_assets").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let min_balance = <Test as Config>::Currency::minimum_balance();
+		let _ = Balances::deposit_creating(&ALICE, 1000 * min_balance);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			min_balance * 100,
+			GAS_LIMIT,
+			None,
+			Code::Upload(code),
+			vec![],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		// Calls the function with the arguments and returns the return code and output.
+		let call = |function: assets::Function, args: Vec<u8>| {
+			let extension_id = <AssetsExtension<TestAssets> as RegisteredChainExtension<Test>>::ID;
+			let input: Vec<u8> =
+				ExtensionInput { extension_id, func_id: function as u16, extra: &args }.into();
+			let data = Contracts::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				input,
+				false,
+				Determinism::Deterministic,
+			)
+			.result
+			.unwrap()
+			.data;
+			(u32::from_le_bytes(data[..4].try_into().unwrap()), data[4..].to_vec())
+		};
+		let balance = |asset: u32, who: &AccountId32| Assets::balance(asset, who);
+
+		// Asset 0 is transferable, asset 1 isn't.
+		for (asset, transferable) in [(0, true), (1, false)] {
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				Compact(asset),
+				ALICE,
+				true,
+				1,
+				transferable
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(ALICE),
+				Compact(asset),
+				addr.clone(),
+				100
+			));
+		}
+
+		let (code, output) = call(assets::Function::Balance, (0u32, addr.clone()).encode());
+		assert_eq!(code, AssetsReturnCode::Success as u32);
+		assert_eq!(u64::decode(&mut &output[..]).unwrap(), 100);
+		let (code, output) = call(assets::Function::TotalSupply, 0u32.encode());
+		assert_eq!(code, AssetsReturnCode::Success as u32);
+		assert_eq!(u64::decode(&mut &output[..]).unwrap(), 100);
+
+		// The contract transfers its own assets, unless they aren't transferable.
+		let (code, _) = call(assets::Function::Transfer, (0u32, BOB, 30u64).encode());
+		assert_eq!(code, AssetsReturnCode::Success as u32);
+		assert_eq!(balance(0, &addr), 70);
+		assert_eq!(balance(0, &BOB), 30);
+		let (code, _) = call(assets::Function::Transfer, (0u32, BOB, 1000u64).encode());
+		assert_eq!(code, AssetsReturnCode::Failed as u32);
+		assert_eq!(balance(0, &addr), 70);
+		let (code, _) = call(assets::Function::Transfer, (1u32, BOB, 30u64).encode());
+		assert_eq!(code, AssetsReturnCode::NotTransferable as u32);
+		assert_eq!(balance(1, &addr), 100);
+		let (code, _) = call(assets::Function::Approve, (1u32, BOB, 30u64).encode());
+		assert_eq!(code, AssetsReturnCode::NotTransferable as u32);
+
+		// The contract approves its assets and spends the assets approved to it.
+		let (code, _) = call(assets::Function::Approve, (0u32, BOB, 20u64).encode());
+		assert_eq!(code, AssetsReturnCode::Success as u32);
+		let (code, output) = call(assets::Function::Allowance, (0u32, addr.clone(), BOB).encode());
+		assert_eq!(code, AssetsReturnCode::Success as u32);
+		assert_eq!(u64::decode(&mut &output[..]).unwrap(), 20);
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), Compact(0), ALICE, 100));
+		assert_ok!(Assets::approve_transfer(
+			RuntimeOrigin::signed(ALICE),
+			Compact(0),
+			addr.clone(),
+			10
+		));
+		let (code, _) =
+			call(assets::Function::TransferFrom, (0u32, ALICE, CHARLIE, 10u64).encode());
+		assert_eq!(code, AssetsReturnCode::Success as u32);
+		assert_eq!(balance(0, &ALICE), 90);
+		assert_eq!(balance(0, &CHARLIE), 10);
+		let (code, _) =
+			call(assets::Function::TransferFrom, (0u32, ALICE, CHARLIE, 10u64).encode());
+		assert_eq!(code, AssetsReturnCode::Failed as u32);
+
+		// The contract transfers the items it owns, unless they are locked.
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			ALICE,
+			pallet_nfts::CollectionConfig {
+				settings: pallet_nfts::CollectionSettings::all_enabled(),
+				max_supply: None,
+				mint_settings: Default::default(),
+			}
+		));
+		for item in [0, 1] {
+			assert_ok!(Nfts::force_mint(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				item,
+				addr.clone(),
+				Default::default(),
+			));
+		}
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		let (code, output) = call(assets::Function::NftOwner, (0u32, 0u32).encode());
+		assert_eq!(code, AssetsReturnCode::Success as u32);
+		assert_eq!(Option::<AccountId32>::decode(&mut &output[..]).unwrap(), Some(addr.clone()));
+
+		let (code, _) = call(assets::Function::NftTransfer, (0u32, 0u32, BOB).encode());
+		assert_eq!(code, AssetsReturnCode::Success as u32);
+		assert_eq!(Nfts::owner(0, 0), Some(BOB));
+		let (code, _) = call(assets::Function::NftTransfer, (0u32, 0u32, CHARLIE).encode());
+		assert_eq!(code, AssetsReturnCode::NoPermission as u32);
+		let (code, _) = call(assets::Function::NftTransfer, (0u32, 1u32, BOB).encode());
+		assert_eq!(code, AssetsReturnCode::NotTransferable as u32);
+		assert_eq!(Nfts::owner(0, 1), Some(addr.clone()));
+	});
+}
+
 #[test]
 fn lazy_removal_works() {
 	let (code, _hash) = compile_module::<Test>("self_destruct").unwrap();
@@ -2691,6 +2975,103 @@ fn debug_message_invalid_utf8() {
 	});
 }
 
+#[test]
+fn call_trace_works() {
+	let (caller_code, _caller_hash) = compile_module::<Test>("call_return_code").unwrap();
+	let (callee_code, _callee_hash) = compile_module::<Test>("ok_trap_revert").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let min_balance = <Test as Config>::Currency::minimum_balance();
+		let _ = Balances::deposit_creating(&ALICE, 1000 * min_balance);
+
+		let TracedResult { result, trace } = Contracts::bare_instantiate_traced(
+			ALICE,
+			min_balance * 100,
+			GAS_LIMIT,
+			None,
+			Code::Upload(caller_code),
+			vec![],
+			vec![],
+		);
+		let addr_caller = result.result.unwrap().account_id;
+		assert_eq!(trace.len(), 1);
+		assert_eq!(trace[0].depth, 0);
+		assert_eq!(trace[0].kind, CallKind::Instantiate);
+		assert_eq!(trace[0].caller, ALICE);
+		assert_eq!(trace[0].callee, addr_caller);
+		assert_eq!(trace[0].value, min_balance * 100);
+		assert!(!trace[0].reverted);
+		assert_matches!(trace[0].storage_deposit, StorageDeposit::Charge(deposit) if deposit > 0);
+
+		let addr_callee = Contracts::bare_instantiate(
+			ALICE,
+			min_balance * 100,
+			GAS_LIMIT,
+			None,
+			Code::Upload(callee_code),
+			vec![0],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		// The callee reverts, which the caller ignores.
+		let input: Vec<u8> = AsRef::<[u8]>::as_ref(&addr_callee)
+			.iter()
+			.chain(&1u32.to_le_bytes())
+			.cloned()
+			.collect();
+		let TracedResult { result, trace } = Contracts::bare_call_traced(
+			ALICE,
+			addr_caller.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			input.clone(),
+			Determinism::Deterministic,
+		);
+		assert_return_code!(result.result.unwrap(), RuntimeReturnCode::CalleeReverted);
+		assert_eq!(trace.len(), 2);
+
+		assert_eq!(trace[0].depth, 0);
+		assert_eq!(trace[0].kind, CallKind::Call);
+		assert_eq!(trace[0].caller, ALICE);
+		assert_eq!(trace[0].callee, addr_caller);
+		assert_eq!(trace[0].input, input);
+		assert!(!trace[0].reverted);
+		assert_eq!(trace[0].error, None);
+		assert!(trace[0].gas_consumed.ref_time() > trace[1].gas_consumed.ref_time());
+
+		assert_eq!(trace[1].depth, 1);
+		assert_eq!(trace[1].kind, CallKind::Call);
+		assert_eq!(trace[1].caller, addr_caller);
+		assert_eq!(trace[1].callee, addr_callee);
+		assert_eq!(trace[1].value, 100);
+		assert_eq!(trace[1].input, 1u32.to_le_bytes().to_vec());
+		assert!(trace[1].reverted);
+		assert_eq!(trace[1].storage_deposit, StorageDeposit::Charge(0));
+
+		// Calls failing before the callee runs are traced with their error.
+		let TracedResult { result, trace } = Contracts::bare_call_traced(
+			ALICE,
+			addr_caller.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			AsRef::<[u8]>::as_ref(&DJANGO).to_vec(),
+			Determinism::Deterministic,
+		);
+		assert_return_code!(result.result.unwrap(), RuntimeReturnCode::NotCallable);
+		assert_eq!(trace.len(), 2);
+		assert_eq!(trace[1].depth, 1);
+		assert_eq!(trace[1].kind, CallKind::Call);
+		assert_eq!(trace[1].caller, addr_caller);
+		assert_eq!(trace[1].callee, DJANGO);
+		assert_eq!(trace[1].error, Some(<Error<Test>>::ContractNotFound.into()));
+	});
+}
+
 #[test]
 fn gas_estimation_nested_call_fixed_limit() {
 	let (caller_code, _caller_hash) = compile_module::<Test>("call_with_limit").unwrap();
@@ -2769,7 +3150,6 @@ fn gas_estimation_nested_call_fixed_limit() {
 
 #[test]
 fn gas_estimation_call_runtime() {
-	use codec::Decode;
 	let (caller_code, _caller_hash) = compile_module::<Test>("call_runtime").unwrap();
 	let (callee_code, _callee_hash) = compile_module::<Test>("dummy").unwrap();
 	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
@@ -3908,6 +4288,96 @@ fn set_code_hash() {
 	});
 }
 
+#[test]
+fn lock_delegate_dependency_works() {
+	let (wasm_caller, self_code_hash) = compile_module::<Test>("lock_delegate_dependency").unwrap();
+	let (wasm_callee, code_hash) = compile_module::<Test>("dummy").unwrap();
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm_caller),
+			vec![],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		let call = |selector: u8, code_hash: &H256| {
+			Contracts::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				(selector, code_hash).encode(),
+				false,
+				Determinism::Deterministic,
+			)
+			.result
+		};
+
+		// Locking a code hash that does not exist fails.
+		assert_err!(call(0, &code_hash), <Error<Test>>::CodeNotFound);
+
+		// Locking the contract's own code hash fails.
+		assert_err!(call(0, &self_code_hash), <Error<Test>>::CannotAddSelfAsDelegateDependency);
+
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm_callee,
+			None,
+			Determinism::Deterministic
+		));
+		let deposit = CodeHashLockupDepositPercent::get()
+			.mul_ceil(PrefabWasmModule::<Test>::deposit(code_hash).unwrap());
+		let deposit_account = get_contract(&addr).deposit_account().deref().clone();
+		let balance_before = <Test as Config>::Currency::total_balance(&deposit_account);
+
+		// Locking the dependency increments the refcount and charges the deposit.
+		assert_ok!(call(0, &code_hash));
+		assert_refcount!(code_hash, 1);
+		assert_eq!(get_contract(&addr).delegate_dependencies().get(&code_hash), Some(&deposit));
+		assert_eq!(
+			<Test as Config>::Currency::total_balance(&deposit_account),
+			balance_before + deposit
+		);
+
+		// A locked code can't be removed.
+		assert_noop!(
+			Contracts::remove_code(RuntimeOrigin::signed(ALICE), code_hash),
+			<Error<Test>>::CodeInUse,
+		);
+
+		// Locking the same dependency twice fails.
+		assert_err!(call(0, &code_hash), <Error<Test>>::DelegateDependencyAlreadyExists);
+
+		// Unlocking the dependency decrements the refcount and refunds the deposit.
+		assert_ok!(call(1, &code_hash));
+		assert_refcount!(code_hash, 0);
+		assert!(get_contract(&addr).delegate_dependencies().is_empty());
+		assert_eq!(<Test as Config>::Currency::total_balance(&deposit_account), balance_before);
+
+		// Unlocking a dependency that is not locked fails.
+		assert_err!(call(1, &code_hash), <Error<Test>>::DelegateDependencyNotFound);
+
+		// Terminating the contract releases all of its dependencies.
+		assert_ok!(call(0, &code_hash));
+		assert_refcount!(code_hash, 1);
+		assert_ok!(call(2, &code_hash));
+		assert!(get_contract_checked(&addr).is_none());
+		assert_refcount!(code_hash, 0);
+		assert_ok!(Contracts::remove_code(RuntimeOrigin::signed(ALICE), code_hash));
+	});
+}
+
 #[test]
 fn storage_deposit_limit_is_enforced() {
 	let (wasm, _code_hash) = compile_module::<Test>("store").unwrap();
@@ -4609,3 +5079,172 @@ fn account_reentrance_count_works() {
 		assert_eq!(result2.data, 0.encode());
 	});
 }
+
+#[test]
+fn calls_are_rejected_while_migration_in_progress() {
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm.clone()),
+			vec![],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		MigrationInProgress::<Test>::set(Some(Default::default()));
+
+		assert_err_ignore_postinfo!(
+			Contracts::call(RuntimeOrigin::signed(ALICE), addr.clone(), 0, GAS_LIMIT, None, vec![]),
+			<Error<Test>>::MigrationInProgress,
+		);
+		assert_err_ignore_postinfo!(
+			Contracts::instantiate(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				GAS_LIMIT,
+				None,
+				code_hash,
+				vec![],
+				vec![1],
+			),
+			<Error<Test>>::MigrationInProgress,
+		);
+		assert_err!(
+			Contracts::upload_code(
+				RuntimeOrigin::signed(ALICE),
+				wasm,
+				None,
+				Determinism::Deterministic
+			),
+			<Error<Test>>::MigrationInProgress,
+		);
+		assert_err_ignore_postinfo!(
+			Contracts::remove_code(RuntimeOrigin::signed(ALICE), code_hash),
+			<Error<Test>>::MigrationInProgress,
+		);
+		assert_eq!(
+			Contracts::get_storage(addr, vec![0; 32]),
+			Err(ContractAccessError::MigrationInProgress),
+		);
+	});
+}
+
+#[test]
+fn migrate_fails_without_migration_in_progress() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_err_ignore_postinfo!(
+			Contracts::migrate(RuntimeOrigin::signed(ALICE), Weight::MAX),
+			<Error<Test>>::NoMigrationPerformed,
+		);
+
+		// Without enough weight for the base cost no progress can be made.
+		let (result, weight) = Migration::<Test>::migrate(Weight::zero());
+		assert_matches!(result, migration::MigrateResult::NoMigrationPerformed);
+		assert_eq!(weight, Weight::zero());
+	});
+}
+
+#[test]
+fn v10_migration_works() {
+	let (wasm, _code_hash) = compile_module::<Test>("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		let addrs: Vec<_> = (0u8..3)
+			.map(|salt| {
+				Contracts::bare_instantiate(
+					ALICE,
+					0,
+					GAS_LIMIT,
+					None,
+					Code::Upload(wasm.clone()),
+					vec![],
+					vec![salt],
+					false,
+				)
+				.result
+				.unwrap()
+				.account_id
+			})
+			.collect();
+
+		// Roll the contracts back to the layout before v10.
+		for addr in &addrs {
+			let info = get_contract(addr);
+			migration::v10::store_old_contract_info::<Test>(addr.clone(), info);
+			assert!(ContractInfoOf::<Test>::get(addr).is_none());
+		}
+		StorageVersion::new(9).put::<Contracts>();
+
+		// The upgrade itself doesn't migrate any contract.
+		Migration::<Test>::on_runtime_upgrade();
+		assert!(MigrationInProgress::<Test>::exists());
+		assert_eq!(StorageVersion::get::<Contracts>(), 9);
+		assert!(addrs.iter().all(|addr| ContractInfoOf::<Test>::get(addr).is_none()));
+
+		// A single migration step per call, which pays no fees while making progress.
+		let step_weight = <Test as Config>::WeightInfo::v10_migration_step();
+		let mut calls = 0;
+		while MigrationInProgress::<Test>::exists() {
+			let post_info = Contracts::migrate(RuntimeOrigin::signed(ALICE), step_weight).unwrap();
+			assert_eq!(post_info.pays_fee, Pays::No);
+			calls += 1;
+		}
+		// One step per contract and a final one that detects completion.
+		assert_eq!(calls, addrs.len() + 1);
+		assert_eq!(StorageVersion::get::<Contracts>(), 10);
+
+		for addr in &addrs {
+			let info = get_contract(addr);
+			assert!(info.delegate_dependencies().is_empty());
+		}
+
+		// Further upgrades are noops.
+		Migration::<Test>::on_runtime_upgrade();
+		assert!(!MigrationInProgress::<Test>::exists());
+	});
+}
+
+#[test]
+fn migration_is_advanced_on_idle() {
+	let (wasm, _code_hash) = compile_module::<Test>("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		migration::v10::store_old_contract_info::<Test>(addr.clone(), get_contract(&addr));
+		StorageVersion::new(9).put::<Contracts>();
+		Migration::<Test>::on_runtime_upgrade();
+
+		// Not enough weight to make any progress.
+		Contracts::on_idle(System::block_number(), Weight::zero());
+		assert!(MigrationInProgress::<Test>::exists());
+
+		Contracts::on_idle(System::block_number(), Weight::MAX);
+		assert!(!MigrationInProgress::<Test>::exists());
+		assert_eq!(StorageVersion::get::<Contracts>(), 10);
+		assert!(ContractInfoOf::<Test>::get(&addr).is_some());
+	});
+}
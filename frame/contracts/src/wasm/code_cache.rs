@@ -32,8 +32,8 @@ use crate::{
 	gas::{GasMeter, Token},
 	wasm::{prepare, PrefabWasmModule},
 	weights::WeightInfo,
-	CodeHash, CodeStorage, Config, Error, Event, OwnerInfoOf, Pallet, PristineCode, Schedule,
-	Weight,
+	BalanceOf, CodeHash, CodeStorage, Config, Error, Event, OwnerInfoOf, Pallet, PristineCode,
+	Schedule, Weight,
 };
 use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
@@ -138,6 +138,17 @@ pub fn increment_refcount<T: Config>(code_hash: CodeHash<T>) -> Result<(), Dispa
 	})
 }
 
+/// Returns the deposit that was reserved for storing the code with the given `code_hash`.
+///
+/// # Errors
+///
+/// [`Error::CodeNotFound`] is returned if the specified `code_hash` does not exist.
+pub fn deposit<T: Config>(code_hash: CodeHash<T>) -> Result<BalanceOf<T>, DispatchError> {
+	<OwnerInfoOf<T>>::get(code_hash)
+		.map(|info| info.deposit)
+		.ok_or_else(|| Error::<T>::CodeNotFound.into())
+}
+
 /// Try to remove code together with all associated information.
 pub fn try_remove<T: Config>(origin: &T::AccountId, code_hash: CodeHash<T>) -> DispatchResult {
 	<OwnerInfoOf<T>>::try_mutate_exists(&code_hash, |existing| {
@@ -308,6 +308,10 @@ impl<T: Config> Executable<T> for PrefabWasmModule<T> {
 		code_cache::decrement_refcount::<T>(code_hash)
 	}
 
+	fn deposit(code_hash: CodeHash<T>) -> Result<BalanceOf<T>, DispatchError> {
+		code_cache::deposit::<T>(code_hash)
+	}
+
 	fn execute<E: Ext<T = T>>(
 		self,
 		ext: &mut E,
@@ -388,7 +392,10 @@ mod tests {
 	use std::{
 		borrow::BorrowMut,
 		cell::RefCell,
-		collections::hash_map::{Entry, HashMap},
+		collections::{
+			hash_map::{Entry, HashMap},
+			HashSet,
+		},
 	};
 
 	#[derive(Debug, PartialEq, Eq)]
@@ -439,7 +446,9 @@ mod tests {
 		gas_meter: GasMeter<Test>,
 		debug_buffer: Vec<u8>,
 		ecdsa_recover: RefCell<Vec<([u8; 65], [u8; 32])>>,
+		sr25519_verify: RefCell<Vec<([u8; 64], Vec<u8>, [u8; 32])>>,
 		code_hashes: Vec<CodeHash<Test>>,
+		delegate_dependencies: RefCell<HashSet<CodeHash<Test>>>,
 	}
 
 	/// The call is mocked and just returns this hardcoded value.
@@ -463,6 +472,8 @@ mod tests {
 				gas_meter: GasMeter::new(Weight::from_parts(10_000_000_000, 10 * 1024 * 1024)),
 				debug_buffer: Default::default(),
 				ecdsa_recover: Default::default(),
+				sr25519_verify: Default::default(),
+				delegate_dependencies: Default::default(),
 			}
 		}
 	}
@@ -648,6 +659,10 @@ mod tests {
 		fn ecdsa_to_eth_address(&self, _pk: &[u8; 33]) -> Result<[u8; 20], ()> {
 			Ok([2u8; 20])
 		}
+		fn sr25519_verify(&self, signature: &[u8; 64], message: &[u8], pub_key: &[u8; 32]) -> bool {
+			self.sr25519_verify.borrow_mut().push((*signature, message.to_vec(), *pub_key));
+			true
+		}
 		fn reentrance_count(&self) -> u32 {
 			12
 		}
@@ -657,6 +672,14 @@ mod tests {
 		fn nonce(&mut self) -> u64 {
 			995
 		}
+		fn lock_delegate_dependency(&mut self, code_hash: CodeHash<Self::T>) -> DispatchResult {
+			self.delegate_dependencies.borrow_mut().insert(code_hash);
+			Ok(())
+		}
+		fn unlock_delegate_dependency(&mut self, code: &CodeHash<Self::T>) -> DispatchResult {
+			self.delegate_dependencies.borrow_mut().remove(code);
+			Ok(())
+		}
 	}
 
 	/// Execute the supplied code.
@@ -1314,6 +1337,71 @@ mod tests {
 		assert_eq!(mock_ext.ecdsa_recover.into_inner(), [([1; 65], [1; 32])]);
 	}
 
+	#[test]
+	fn contract_sr25519_verify() {
+		/// calls `sr25519_verify` and returns its return code.
+		const CODE_SR25519_VERIFY: &str = r#"
+(module
+	(import "seal0" "sr25519_verify" (func $sr25519_verify (param i32 i32 i32 i32) (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 64) signature
+	(data (i32.const 0)
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+	)
+
+	;; [64, 96) public key
+	(data (i32.const 64)
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+	)
+
+	;; [96, 107) message
+	(data (i32.const 96) "Hello world")
+
+	;; [108, 112) return code
+
+	(func (export "call")
+		(i32.store
+			(i32.const 108)
+			(call $sr25519_verify
+				(i32.const 0)	;; signature_ptr
+				(i32.const 64)	;; pub_key_ptr
+				(i32.const 11)	;; message_len
+				(i32.const 96)	;; message_ptr
+			)
+		)
+
+		;; Return the return code
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 108)
+			(i32.const 4)
+		)
+
+		;; seal_return doesn't return, so this is effectively unreachable.
+		(unreachable)
+	)
+	(func (export "deploy"))
+)
+"#;
+
+		let mut mock_ext = MockExt::default();
+		let output = execute(CODE_SR25519_VERIFY, vec![], &mut mock_ext).unwrap();
+		assert_eq!(
+			output,
+			ExecReturnValue { flags: ReturnFlags::empty(), data: 0u32.to_le_bytes().to_vec() }
+		);
+		assert_eq!(
+			mock_ext.sr25519_verify.into_inner(),
+			[([1; 64], b"Hello world".to_vec(), [2; 32])]
+		);
+	}
+
 	#[test]
 	fn contract_ecdsa_to_eth_address() {
 		/// calls `seal_ecdsa_to_eth_address` for the contstant and ensures the result equals the
@@ -3071,6 +3159,40 @@ mod tests {
 		execute(CODE, vec![], &mut mock_ext).unwrap();
 	}
 
+	#[test]
+	fn lock_unlock_delegate_dependency() {
+		const CODE_LOCK_UNLOCK_DELEGATE_DEPENDENCY: &str = r#"
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "lock_delegate_dependency" (func $lock_delegate_dependency (param i32)))
+	(import "seal0" "unlock_delegate_dependency" (func $unlock_delegate_dependency (param i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 64) two code hashes
+
+	;; [64, 68) input size
+	(data (i32.const 64) "\40")
+
+	(func (export "call")
+		(call $seal_input (i32.const 0) (i32.const 64))
+		(call $lock_delegate_dependency (i32.const 0))
+		(call $lock_delegate_dependency (i32.const 32))
+		(call $unlock_delegate_dependency (i32.const 0))
+	)
+	(func (export "deploy"))
+)
+"#;
+		let mut mock_ext = MockExt::default();
+		let first = H256::from_low_u64_be(1);
+		let second = H256::from_low_u64_be(2);
+		assert_ok!(execute(
+			CODE_LOCK_UNLOCK_DELEGATE_DEPENDENCY,
+			(first, second).encode(),
+			&mut mock_ext,
+		));
+		assert_eq!(mock_ext.delegate_dependencies.into_inner(), HashSet::from([second]));
+	}
+
 	/// Code with deprecated functions cannot be uploaded or instantiated. However, we
 	/// need to make sure that it still can be re-instrumented.
 	#[test]
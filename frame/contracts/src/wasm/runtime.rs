@@ -120,6 +120,8 @@ pub enum ReturnCode {
 	/// ECDSA compressed pubkey conversion into Ethereum address failed (most probably
 	/// wrong pubkey provided).
 	EcdsaRecoverFailed = 11,
+	/// sr25519 signature verification failed.
+	Sr25519VerifyFailed = 12,
 }
 
 impl From<ExecReturnValue> for ReturnCode {
@@ -270,12 +272,18 @@ pub enum RuntimeCosts {
 	SetCodeHash,
 	/// Weight of calling `ecdsa_to_eth_address`
 	EcdsaToEthAddress,
+	/// Weight of calling `sr25519_verify` for the given message length.
+	Sr25519Verify(u32),
 	/// Weight of calling `reentrance_count`
 	ReentrantCount,
 	/// Weight of calling `account_reentrance_count`
 	AccountEntranceCount,
 	/// Weight of calling `instantiation_nonce`
 	InstantationNonce,
+	/// Weight of calling `lock_delegate_dependency`
+	LockDelegateDependency,
+	/// Weight of calling `unlock_delegate_dependency`
+	UnlockDelegateDependency,
 }
 
 impl RuntimeCosts {
@@ -351,9 +359,14 @@ impl RuntimeCosts {
 			CallRuntime(weight) => weight,
 			SetCodeHash => s.set_code_hash,
 			EcdsaToEthAddress => s.ecdsa_to_eth_address,
+			Sr25519Verify(len) => s
+				.sr25519_verify
+				.saturating_add(s.sr25519_verify_per_byte.saturating_mul(len.into())),
 			ReentrantCount => s.reentrance_count,
 			AccountEntranceCount => s.account_reentrance_count,
 			InstantationNonce => s.instantiation_nonce,
+			LockDelegateDependency => s.lock_delegate_dependency,
+			UnlockDelegateDependency => s.unlock_delegate_dependency,
 		};
 		RuntimeToken {
 			#[cfg(test)]
@@ -2571,6 +2584,46 @@ pub mod env {
 		}
 	}
 
+	/// Verify a sr25519 signature.
+	///
+	/// # Parameters
+	///
+	/// - `signature_ptr`: the pointer into the linear memory where the signature is placed. Should
+	///   be decodable as a 64 bytes value. Traps otherwise.
+	/// - `pub_key_ptr`: the pointer into the linear memory where the public key is placed. Should
+	///   be decodable as a 32 bytes value. Traps otherwise.
+	/// - `message_len`: the length of the message payload.
+	/// - `message_ptr`: the pointer into the linear memory where the message is placed.
+	///
+	/// # Errors
+	///
+	/// - `ReturnCode::Sr25519VerifyFailed`
+	#[unstable]
+	fn sr25519_verify(
+		ctx: _,
+		memory: _,
+		signature_ptr: u32,
+		pub_key_ptr: u32,
+		message_len: u32,
+		message_ptr: u32,
+	) -> Result<ReturnCode, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::Sr25519Verify(message_len))?;
+
+		let mut signature: [u8; 64] = [0; 64];
+		ctx.read_sandbox_memory_into_buf(memory, signature_ptr, &mut signature)?;
+
+		let mut pub_key: [u8; 32] = [0; 32];
+		ctx.read_sandbox_memory_into_buf(memory, pub_key_ptr, &mut pub_key)?;
+
+		let message: Vec<u8> = ctx.read_sandbox_memory(memory, message_ptr, message_len)?;
+
+		if ctx.ext.sr25519_verify(&signature, &message, &pub_key) {
+			Ok(ReturnCode::Success)
+		} else {
+			Ok(ReturnCode::Sr25519VerifyFailed)
+		}
+	}
+
 	/// Returns the number of times the currently executing contract exists on the call stack in
 	/// addition to the calling instance.
 	///
@@ -2610,4 +2663,42 @@ pub mod env {
 		ctx.charge_gas(RuntimeCosts::InstantationNonce)?;
 		Ok(ctx.ext.nonce())
 	}
+
+	/// Adds a new delegate dependency to the contract.
+	///
+	/// This prevents the code of the dependency from being removed while the contract still
+	/// relies on it for `seal_delegate_call`. A fraction of the code's deposit is held from
+	/// the contract's deposit account and refunded by
+	/// [`unlock_delegate_dependency`][`Self::unlock_delegate_dependency`].
+	///
+	/// Traps if the maximum number of dependencies is reached, the dependency already exists,
+	/// the code hash is the one of the calling contract or no code exists under the hash.
+	///
+	/// # Parameters
+	///
+	/// - `code_hash_ptr`: A pointer to the code hash of the dependency.
+	#[unstable]
+	fn lock_delegate_dependency(ctx: _, memory: _, code_hash_ptr: u32) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::LockDelegateDependency)?;
+		let code_hash: CodeHash<<E as Ext>::T> =
+			ctx.read_sandbox_memory_as(memory, code_hash_ptr)?;
+		ctx.ext.lock_delegate_dependency(code_hash)?;
+		Ok(())
+	}
+
+	/// Removes the delegate dependency from the contract.
+	///
+	/// Traps if the delegate dependency does not exist.
+	///
+	/// # Parameters
+	///
+	/// - `code_hash_ptr`: A pointer to the code hash of the dependency.
+	#[unstable]
+	fn unlock_delegate_dependency(ctx: _, memory: _, code_hash_ptr: u32) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::UnlockDelegateDependency)?;
+		let code_hash: CodeHash<<E as Ext>::T> =
+			ctx.read_sandbox_memory_as(memory, code_hash_ptr)?;
+		ctx.ext.unlock_delegate_dependency(&code_hash)?;
+		Ok(())
+	}
 }
@@ -50,6 +50,12 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_contracts.
 pub trait WeightInfo {
 	fn on_process_deletion_queue_batch() -> Weight;
+	fn v10_migration_step() -> Weight;
+	fn migration_noop() -> Weight;
+	fn migrate() -> Weight;
+	fn on_runtime_upgrade_noop() -> Weight;
+	fn on_runtime_upgrade_in_progress() -> Weight;
+	fn on_runtime_upgrade() -> Weight;
 	fn on_initialize_per_trie_key(k: u32, ) -> Weight;
 	fn on_initialize_per_queue_item(q: u32, ) -> Weight;
 	fn reinstrument(c: u32, ) -> Weight;
@@ -111,10 +117,14 @@ pub trait WeightInfo {
 	fn seal_hash_blake2_128_per_kb(n: u32, ) -> Weight;
 	fn seal_ecdsa_recover(r: u32, ) -> Weight;
 	fn seal_ecdsa_to_eth_address(r: u32, ) -> Weight;
+	fn seal_sr25519_verify(r: u32, ) -> Weight;
+	fn seal_sr25519_verify_per_kb(n: u32, ) -> Weight;
 	fn seal_set_code_hash(r: u32, ) -> Weight;
 	fn seal_reentrance_count(r: u32, ) -> Weight;
 	fn seal_account_reentrance_count(r: u32, ) -> Weight;
 	fn seal_instantiation_nonce(r: u32, ) -> Weight;
+	fn seal_lock_delegate_dependency(r: u32, ) -> Weight;
+	fn seal_unlock_delegate_dependency(r: u32, ) -> Weight;
 	fn instr_i64const(r: u32, ) -> Weight;
 	fn instr_i64load(r: u32, ) -> Weight;
 	fn instr_i64store(r: u32, ) -> Weight;
@@ -183,6 +193,82 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_proof_size(604))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: Contracts ContractInfoOf (r:2 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	fn v10_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `5418`
+		// Minimum execution time: 18_223 nanoseconds.
+		Weight::from_ref_time(22_171_000)
+			.saturating_add(Weight::from_proof_size(5418))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:1)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	fn migration_noop() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `604`
+		// Minimum execution time: 4_091 nanoseconds.
+		Weight::from_ref_time(4_550_000)
+			.saturating_add(Weight::from_proof_size(604))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:1)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:1)
+	/// Proof Skipped: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:1)
+	fn migrate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3236`
+		// Minimum execution time: 9_893 nanoseconds.
+		Weight::from_ref_time(12_559_000)
+			.saturating_add(Weight::from_proof_size(3236))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Proof Skipped: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	fn on_runtime_upgrade_noop() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `2584`
+		// Minimum execution time: 3_430 nanoseconds.
+		Weight::from_ref_time(3_847_000)
+			.saturating_add(Weight::from_proof_size(2584))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Proof Skipped: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	fn on_runtime_upgrade_in_progress() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134`
+		//  Estimated: `3238`
+		// Minimum execution time: 8_864 nanoseconds.
+		Weight::from_ref_time(10_378_000)
+			.saturating_add(Weight::from_proof_size(3238))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Proof Skipped: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Storage: Contracts MigrationInProgress (r:1 w:1)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	fn on_runtime_upgrade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3188`
+		// Minimum execution time: 8_678 nanoseconds.
+		Weight::from_ref_time(11_724_000)
+			.saturating_add(Weight::from_proof_size(3188))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: Skipped Metadata (r:0 w:0)
 	/// Proof Skipped: Skipped Metadata (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `k` is `[0, 1024]`.
@@ -1592,6 +1678,57 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_proof_size(16810).saturating_mul(r.into()))
 	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 1]`.
+	fn seal_sr25519_verify(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `899 + r * (8725 ±0)`
+		//  Estimated: `18759 + r * (52350 ±0)`
+		// Minimum execution time: 462_667 nanoseconds.
+		Weight::from_ref_time(535_852_297)
+			.saturating_add(Weight::from_proof_size(18759))
+			// Standard Error: 7_409_088
+			.saturating_add(Weight::from_ref_time(4_898_128_002).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_proof_size(52350).saturating_mul(r.into()))
+	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 1023]`.
+	fn seal_sr25519_verify_per_kb(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2182`
+		//  Estimated: `26385`
+		// Minimum execution time: 4_928_778 nanoseconds.
+		Weight::from_ref_time(4_989_416_000)
+			.saturating_add(Weight::from_proof_size(26385))
+			// Standard Error: 2_384_770
+			.saturating_add(Weight::from_ref_time(347_647_723).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
 	/// Storage: Contracts ContractInfoOf (r:1 w:1)
@@ -1694,6 +1831,66 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_proof_size(1440).saturating_mul(r.into()))
 	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Contracts OwnerInfoOf (r:32 w:32)
+	/// Proof: Contracts OwnerInfoOf (max_values: None, max_size: Some(88), added: 2563, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 32]`.
+	fn seal_lock_delegate_dependency(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `954 + r * (159 ±0)`
+		//  Estimated: `20019 + r * (3594 ±0)`
+		// Minimum execution time: 481_622 nanoseconds.
+		Weight::from_ref_time(519_905_767)
+			.saturating_add(Weight::from_proof_size(20019))
+			// Standard Error: 508_716
+			.saturating_add(Weight::from_ref_time(18_524_564).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(3594).saturating_mul(r.into()))
+	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Contracts OwnerInfoOf (r:32 w:32)
+	/// Proof: Contracts OwnerInfoOf (max_values: None, max_size: Some(88), added: 2563, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 32]`.
+	fn seal_unlock_delegate_dependency(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `993 + r * (212 ±0)`
+		//  Estimated: `20336 + r * (3959 ±0)`
+		// Minimum execution time: 517_390 nanoseconds.
+		Weight::from_ref_time(812_522_451)
+			.saturating_add(Weight::from_proof_size(20336))
+			// Standard Error: 854_362
+			.saturating_add(Weight::from_ref_time(5_860_592).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(3959).saturating_mul(r.into()))
+	}
 	/// The range of component `r` is `[0, 50]`.
 	fn instr_i64const(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -2281,6 +2478,82 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_proof_size(604))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: Contracts ContractInfoOf (r:2 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	fn v10_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `5418`
+		// Minimum execution time: 18_223 nanoseconds.
+		Weight::from_ref_time(22_171_000)
+			.saturating_add(Weight::from_proof_size(5418))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:1)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	fn migration_noop() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `604`
+		// Minimum execution time: 4_091 nanoseconds.
+		Weight::from_ref_time(4_550_000)
+			.saturating_add(Weight::from_proof_size(604))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:1)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:1)
+	/// Proof Skipped: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:1)
+	fn migrate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3236`
+		// Minimum execution time: 9_893 nanoseconds.
+		Weight::from_ref_time(12_559_000)
+			.saturating_add(Weight::from_proof_size(3236))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Proof Skipped: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	fn on_runtime_upgrade_noop() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `2584`
+		// Minimum execution time: 3_430 nanoseconds.
+		Weight::from_ref_time(3_847_000)
+			.saturating_add(Weight::from_proof_size(2584))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Proof Skipped: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	fn on_runtime_upgrade_in_progress() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134`
+		//  Estimated: `3238`
+		// Minimum execution time: 8_864 nanoseconds.
+		Weight::from_ref_time(10_378_000)
+			.saturating_add(Weight::from_proof_size(3238))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Proof Skipped: unknown `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Storage: Contracts MigrationInProgress (r:1 w:1)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	fn on_runtime_upgrade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3188`
+		// Minimum execution time: 8_678 nanoseconds.
+		Weight::from_ref_time(11_724_000)
+			.saturating_add(Weight::from_proof_size(3188))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: Skipped Metadata (r:0 w:0)
 	/// Proof Skipped: Skipped Metadata (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `k` is `[0, 1024]`.
@@ -3690,6 +3963,57 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_proof_size(16810).saturating_mul(r.into()))
 	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 1]`.
+	fn seal_sr25519_verify(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `899 + r * (8725 ±0)`
+		//  Estimated: `18759 + r * (52350 ±0)`
+		// Minimum execution time: 462_667 nanoseconds.
+		Weight::from_ref_time(535_852_297)
+			.saturating_add(Weight::from_proof_size(18759))
+			// Standard Error: 7_409_088
+			.saturating_add(Weight::from_ref_time(4_898_128_002).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_proof_size(52350).saturating_mul(r.into()))
+	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 1023]`.
+	fn seal_sr25519_verify_per_kb(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2182`
+		//  Estimated: `26385`
+		// Minimum execution time: 4_928_778 nanoseconds.
+		Weight::from_ref_time(4_989_416_000)
+			.saturating_add(Weight::from_proof_size(26385))
+			// Standard Error: 2_384_770
+			.saturating_add(Weight::from_ref_time(347_647_723).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
 	/// Storage: Contracts ContractInfoOf (r:1 w:1)
@@ -3792,6 +4116,66 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_proof_size(1440).saturating_mul(r.into()))
 	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Contracts OwnerInfoOf (r:32 w:32)
+	/// Proof: Contracts OwnerInfoOf (max_values: None, max_size: Some(88), added: 2563, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 32]`.
+	fn seal_lock_delegate_dependency(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `954 + r * (159 ±0)`
+		//  Estimated: `20019 + r * (3594 ±0)`
+		// Minimum execution time: 481_622 nanoseconds.
+		Weight::from_ref_time(519_905_767)
+			.saturating_add(Weight::from_proof_size(20019))
+			// Standard Error: 508_716
+			.saturating_add(Weight::from_ref_time(18_524_564).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(3594).saturating_mul(r.into()))
+	}
+	/// Storage: Contracts MigrationInProgress (r:1 w:0)
+	/// Proof: Contracts MigrationInProgress (max_values: Some(1), max_size: Some(1026), added: 1521, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1827), added: 4302, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Contracts OwnerInfoOf (r:32 w:32)
+	/// Proof: Contracts OwnerInfoOf (max_values: None, max_size: Some(88), added: 2563, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 32]`.
+	fn seal_unlock_delegate_dependency(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `993 + r * (212 ±0)`
+		//  Estimated: `20336 + r * (3959 ±0)`
+		// Minimum execution time: 517_390 nanoseconds.
+		Weight::from_ref_time(812_522_451)
+			.saturating_add(Weight::from_proof_size(20336))
+			// Standard Error: 854_362
+			.saturating_add(Weight::from_ref_time(5_860_592).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(3959).saturating_mul(r.into()))
+	}
 	/// The range of component `r` is `[0, 50]`.
 	fn instr_i64const(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
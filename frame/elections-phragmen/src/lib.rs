@@ -711,7 +711,6 @@ pub mod pallet {
 		pub members: Vec<(T::AccountId, BalanceOf<T>)>,
 	}
 
-	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
 			Self { members: Default::default() }
@@ -742,7 +741,7 @@ pub mod pallet {
 					Members::<T>::mutate(|members| {
 						match members.binary_search_by(|m| m.who.cmp(member)) {
 							Ok(_) => {
-								panic!("Duplicate member in elections-phragmen genesis: {}", member)
+								panic!("Duplicate member in elections-phragmen genesis: {:?}", member)
 							},
 							Err(pos) => members.insert(
 								pos,
@@ -111,7 +111,6 @@ pub mod pallet {
 		pub phantom: PhantomData<I>,
 	}
 
-	#[cfg(feature = "std")]
 	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
 		fn default() -> Self {
 			Self { members: Default::default(), phantom: Default::default() }
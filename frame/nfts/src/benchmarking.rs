@@ -27,7 +27,10 @@ use frame_benchmarking::v1::{
 use frame_support::{
 	assert_ok,
 	dispatch::UnfilteredDispatchable,
-	traits::{EnsureOrigin, Get},
+	traits::{
+		tokens::fungibles::{Create, Mutate},
+		EnsureOrigin, Get,
+	},
 	BoundedVec,
 };
 use frame_system::RawOrigin as SystemOrigin;
@@ -126,6 +129,80 @@ fn add_item_attribute<T: Config<I>, I: 'static>(
 	(key, caller, caller_lookup)
 }
 
+/// Returns `recipients` royalties that add up to `MaxRoyalty`.
+fn make_royalties<T: Config<I>, I: 'static>(recipients: u32) -> RoyaltiesOf<T, I> {
+	let percentage = Permill::from_parts(T::MaxRoyalty::get().deconstruct() / recipients.max(1));
+	(0..recipients)
+		.map(|i| RoyaltyRecipient { account: account("royalty", i, SEED), percentage })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+/// Mints `depth + 1` items and nests each of them into the one minted before, along with
+/// `siblings` more items.
+fn nested_items<T: Config<I>, I: 'static>(
+	depth: u32,
+	siblings: u32,
+) -> (T::CollectionId, T::ItemId, T::AccountId) {
+	let (collection, caller, _) = create_collection::<T, I>();
+	let mut index = 0;
+	let (mut parent, ..) = mint_item::<T, I>(index);
+	for _ in 0..depth {
+		for _ in 0..=siblings {
+			index += 1;
+			let (item, ..) = mint_item::<T, I>(index);
+			assert_ok!(Nfts::<T, I>::nest(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				item,
+				collection,
+				parent,
+			));
+		}
+		parent = T::Helper::item(index);
+	}
+	(collection, parent, caller)
+}
+
+/// Creates an asset prices can be paid in and gives the `holder` plenty of it.
+fn create_asset<T: Config<I>, I: 'static>(holder: &T::AccountId) -> AssetIdOf<T, I>
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	let asset = T::Helper::asset(0);
+	assert_ok!(T::Assets::create(asset, account("asset_admin", 0, SEED), true, One::one()));
+	assert_ok!(T::Assets::mint_into(asset, holder, BalanceOf::<T, I>::max_value() / 2u32.into()));
+	asset
+}
+
+fn english_auction<T: Config<I>, I: 'static>() -> AuctionKindOf<T, I> {
+	let reserve_price = T::Currency::minimum_balance().saturating_mul(100u32.into());
+	AuctionKind::English {
+		reserve_price,
+		min_increment: T::Currency::minimum_balance(),
+		// Every bid extends the auction.
+		extension: T::MaxDeadlineDuration::get().saturating_add(One::one()),
+	}
+}
+
+fn create_english_auction<T: Config<I>, I: 'static>(
+) -> (T::CollectionId, T::ItemId, T::AccountId, ItemPrice<T, I>) {
+	let (collection, caller, _) = create_collection::<T, I>();
+	let (item, ..) = mint_item::<T, I>(0);
+	let kind = english_auction::<T, I>();
+	let AuctionKind::English { reserve_price, .. } = kind else { unreachable!() };
+	frame_system::Pallet::<T>::set_block_number(One::one());
+	assert_ok!(Nfts::<T, I>::create_auction(
+		SystemOrigin::Signed(caller.clone()).into(),
+		collection,
+		item,
+		kind,
+		T::MaxDeadlineDuration::get(),
+	));
+	(collection, item, caller, reserve_price)
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -165,6 +242,7 @@ benchmarks_instance_pallet! {
 		where
 			T::OffchainSignature: From<MultiSignature>,
 			T::AccountId: From<AccountId32>,
+			T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
 	}
 
 	create {
@@ -225,6 +303,29 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Issued { collection, item, owner: caller }.into());
 	}
 
+	// A public mint by an account without a role, which pays the price in an asset.
+	mint_priced_in_asset {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let minter: T::AccountId = account("minter", 0, SEED);
+		let minter_lookup = T::Lookup::unlookup(minter.clone());
+		T::Currency::make_free_balance_be(&minter, DepositBalanceOf::<T, I>::max_value());
+		let asset = create_asset::<T, I>(&minter);
+		Nfts::<T, I>::update_mint_settings(
+			SystemOrigin::Signed(caller).into(),
+			collection,
+			MintSettings {
+				mint_type: MintType::Public,
+				price: Some(ItemPrice::<T, I>::from(100u32)),
+				price_asset: Some(asset),
+				..Default::default()
+			},
+		)?;
+		let item = T::Helper::item(0);
+	}: mint(SystemOrigin::Signed(minter.clone()), collection, item, minter_lookup, None)
+	verify {
+		assert_last_event::<T, I>(Event::Issued { collection, item, owner: minter }.into());
+	}
+
 	force_mint {
 		let (collection, caller, caller_lookup) = create_collection::<T, I>();
 		let item = T::Helper::item(0);
@@ -234,11 +335,14 @@ benchmarks_instance_pallet! {
 	}
 
 	burn {
-		let (collection, caller, caller_lookup) = create_collection::<T, I>();
-		let (item, ..) = mint_item::<T, I>(0);
-	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(caller_lookup))
+		// Burning a nested item updates the heights of all its ancestors.
+		let siblings = T::MaxNestedChildren::get().saturating_sub(1);
+		let (collection, item, caller) = nested_items::<T, I>(T::MaxNestingDepth::get(), siblings);
+		let owner = Item::<T, I>::get(collection, item).unwrap().owner;
+		let owner_lookup = T::Lookup::unlookup(owner.clone());
+	}: _(SystemOrigin::Signed(caller), collection, item, Some(owner_lookup))
 	verify {
-		assert_last_event::<T, I>(Event::Burned { collection, item, owner: caller }.into());
+		assert_last_event::<T, I>(Event::Burned { collection, item, owner }.into());
 	}
 
 	transfer {
@@ -583,6 +687,7 @@ benchmarks_instance_pallet! {
 			end_block: Some(One::one()),
 			price: Some(ItemPrice::<T, I>::from(1u32)),
 			default_item_settings: ItemSettings::all_enabled(),
+			price_asset: None,
 		};
 	}: _(SystemOrigin::Signed(caller.clone()), collection, mint_settings)
 	verify {
@@ -595,24 +700,41 @@ benchmarks_instance_pallet! {
 		let delegate: T::AccountId = account("delegate", 0, SEED);
 		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
 		let price = ItemPrice::<T, I>::from(100u32);
-	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(price), Some(delegate_lookup))
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(price), None, Some(delegate_lookup))
 	verify {
 		assert_last_event::<T, I>(Event::ItemPriceSet {
 			collection,
 			item,
 			price,
+			asset: None,
 			whitelisted_buyer: Some(delegate),
 		}.into());
 	}
 
+	// Each royalty recipient is paid from the price, which is large enough for every royalty to
+	// create the account of its recipient.
 	buy_item {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
 		let (collection, seller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
+		Nfts::<T, I>::set_collection_royalties(
+			SystemOrigin::Signed(seller.clone()).into(),
+			collection,
+			make_royalties::<T, I>(r),
+		)?;
+		T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance());
 		let buyer: T::AccountId = account("buyer", 0, SEED);
 		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
-		let price = ItemPrice::<T, I>::from(0u32);
+		let price = T::Currency::minimum_balance().saturating_mul(1_000_000u32.into());
 		let origin = SystemOrigin::Signed(seller.clone()).into();
-		Nfts::<T, I>::set_price(origin, collection, item, Some(price.clone()), Some(buyer_lookup))?;
+		Nfts::<T, I>::set_price(
+			origin,
+			collection,
+			item,
+			Some(price.clone()),
+			None,
+			Some(buyer_lookup),
+		)?;
 		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
 	}: _(SystemOrigin::Signed(buyer.clone()), collection, item, price.clone())
 	verify {
@@ -620,6 +742,42 @@ benchmarks_instance_pallet! {
 			collection,
 			item,
 			price,
+			asset: None,
+			seller,
+			buyer,
+		}.into());
+	}
+
+	// Like `buy_item`, but the price is paid in an asset.
+	buy_item_priced_in_asset {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		Nfts::<T, I>::set_collection_royalties(
+			SystemOrigin::Signed(seller.clone()).into(),
+			collection,
+			make_royalties::<T, I>(r),
+		)?;
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
+		let asset = create_asset::<T, I>(&buyer);
+		let price = ItemPrice::<T, I>::from(1_000_000u32);
+		Nfts::<T, I>::set_price(
+			SystemOrigin::Signed(seller.clone()).into(),
+			collection,
+			item,
+			Some(price),
+			Some(asset),
+			Some(buyer_lookup),
+		)?;
+	}: buy_item(SystemOrigin::Signed(buyer.clone()), collection, item, price)
+	verify {
+		assert_last_event::<T, I>(Event::ItemBought {
+			collection,
+			item,
+			price,
+			asset: Some(asset),
 			seller,
 			buyer,
 		}.into());
@@ -693,20 +851,28 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	// Like `buy_item`, the price is paid out to `r` royalty recipients.
 	claim_swap {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item1, ..) = mint_item::<T, I>(0);
 		let (item2, ..) = mint_item::<T, I>(1);
-		let price = ItemPrice::<T, I>::from(0u32);
+		Nfts::<T, I>::set_collection_royalties(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			make_royalties::<T, I>(r),
+		)?;
+		let price = T::Currency::minimum_balance().saturating_mul(1_000_000u32.into());
 		let price_direction = PriceDirection::Receive;
 		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
 		let duration = T::MaxDeadlineDuration::get();
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
-		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(&target, DepositBalanceOf::<T, I>::max_value());
 		let origin = SystemOrigin::Signed(caller.clone());
 		frame_system::Pallet::<T>::set_block_number(One::one());
 		Nfts::<T, I>::transfer(origin.clone().into(), collection, item2, target_lookup)?;
+		T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
 		Nfts::<T, I>::create_swap(
 			origin.clone().into(),
 			collection,
@@ -823,5 +989,197 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	set_collection_royalties {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let royalties = make_royalties::<T, I>(T::MaxRoyaltyRecipients::get());
+	}: _(SystemOrigin::Signed(caller), collection, royalties.clone())
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltiesSet { collection, royalties }.into());
+	}
+
+	set_item_royalties {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let royalties = make_royalties::<T, I>(T::MaxRoyaltyRecipients::get());
+	}: _(SystemOrigin::Signed(caller), collection, item, royalties.clone())
+	verify {
+		assert_last_event::<T, I>(Event::ItemRoyaltiesSet { collection, item, royalties }.into());
+	}
+
+	create_auction {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let kind = english_auction::<T, I>();
+		let duration = T::MaxDeadlineDuration::get();
+		frame_system::Pallet::<T>::set_block_number(One::one());
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, kind.clone(), duration)
+	verify {
+		assert_last_event::<T, I>(Event::AuctionCreated {
+			collection,
+			item,
+			seller: caller,
+			kind,
+			start: One::one(),
+			end: duration.saturating_add(One::one()),
+		}.into());
+	}
+
+	// Outbidding another bidder extends the auction, which is the worst case of a bid in an
+	// English auction.
+	bid {
+		let (collection, item, _, reserve_price) = create_english_auction::<T, I>();
+		let previous_bidder: T::AccountId = account("bidder", 1, SEED);
+		T::Currency::make_free_balance_be(&previous_bidder, DepositBalanceOf::<T, I>::max_value());
+		Nfts::<T, I>::bid(
+			SystemOrigin::Signed(previous_bidder).into(),
+			collection,
+			item,
+			reserve_price,
+		)?;
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		T::Currency::make_free_balance_be(&bidder, DepositBalanceOf::<T, I>::max_value());
+		let amount = reserve_price.saturating_add(T::Currency::minimum_balance());
+		let now = T::BlockNumber::from(2u32);
+		frame_system::Pallet::<T>::set_block_number(now);
+	}: _(SystemOrigin::Signed(bidder.clone()), collection, item, amount)
+	verify {
+		let AuctionKind::English { extension, .. } = english_auction::<T, I>() else {
+			unreachable!()
+		};
+		assert_last_event::<T, I>(Event::BidPlaced {
+			collection,
+			item,
+			bidder,
+			amount,
+			end: now.saturating_add(extension),
+		}.into());
+	}
+
+	// A winning bid in a Dutch auction settles the auction.
+	dutch_bid {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let price = T::Currency::minimum_balance().saturating_mul(100u32.into());
+		let kind = AuctionKind::Dutch { start_price: price, end_price: price };
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		Nfts::<T, I>::create_auction(
+			SystemOrigin::Signed(seller.clone()).into(),
+			collection,
+			item,
+			kind,
+			T::MaxDeadlineDuration::get(),
+		)?;
+		Nfts::<T, I>::set_item_royalties(
+			SystemOrigin::Signed(seller.clone()).into(),
+			collection,
+			item,
+			make_royalties::<T, I>(r),
+		)?;
+		T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance());
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		T::Currency::make_free_balance_be(&bidder, DepositBalanceOf::<T, I>::max_value());
+	}: bid(SystemOrigin::Signed(bidder.clone()), collection, item, price)
+	verify {
+		assert_last_event::<T, I>(Event::AuctionSettled {
+			collection,
+			item,
+			seller,
+			winner: Some(bidder),
+			price: Some(price),
+		}.into());
+	}
+
+	settle_auction {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
+		let (collection, item, seller, reserve_price) = create_english_auction::<T, I>();
+		Nfts::<T, I>::set_item_royalties(
+			SystemOrigin::Signed(seller.clone()).into(),
+			collection,
+			item,
+			make_royalties::<T, I>(r),
+		)?;
+		T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance());
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		T::Currency::make_free_balance_be(&bidder, DepositBalanceOf::<T, I>::max_value());
+		Nfts::<T, I>::bid(
+			SystemOrigin::Signed(bidder.clone()).into(),
+			collection,
+			item,
+			reserve_price,
+		)?;
+		let end = AuctionOf::<T, I>::get(collection, item).unwrap().end;
+		frame_system::Pallet::<T>::set_block_number(end);
+	}: _(SystemOrigin::Signed(seller.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::AuctionSettled {
+			collection,
+			item,
+			seller,
+			winner: Some(bidder),
+			price: Some(reserve_price),
+		}.into());
+	}
+
+	cancel_auction {
+		let (collection, item, seller, _) = create_english_auction::<T, I>();
+	}: _(SystemOrigin::Signed(seller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::AuctionCancelled { collection, item }.into());
+	}
+
+	nest {
+		// The heights of all the ancestors change, each of them with the max number of children.
+		let siblings = T::MaxNestedChildren::get().saturating_sub(1);
+		let (collection, item, caller) = nested_items::<T, I>(T::MaxNestingDepth::get(), siblings);
+		let (_, parent) = ParentOf::<T, I>::get(collection, item).unwrap();
+		assert_ok!(Nfts::<T, I>::unnest(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			item,
+		));
+	}: _(SystemOrigin::Signed(caller), collection, item, collection, parent)
+	verify {
+		assert_last_event::<T, I>(Event::ItemNested {
+			collection,
+			item,
+			parent_collection: collection,
+			parent_item: parent,
+		}.into());
+	}
+
+	unnest {
+		let siblings = T::MaxNestedChildren::get().saturating_sub(1);
+		let (collection, item, caller) = nested_items::<T, I>(T::MaxNestingDepth::get(), siblings);
+		let (_, parent) = ParentOf::<T, I>::get(collection, item).unwrap();
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemUnnested {
+			collection,
+			item,
+			parent_collection: collection,
+			parent_item: parent,
+		}.into());
+	}
+
+	force_transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		Nfts::<T, I>::force_collection_config(
+			SystemOrigin::Root.into(),
+			collection,
+			make_collection_config::<T, I>(CollectionSetting::UnboundItems.into()),
+		)?;
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		let call = Call::<T, I>::force_transfer { collection, item, dest: target_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::Transferred { collection, item, from: caller, to: target }.into());
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -16,10 +16,7 @@
 // limitations under the License.
 
 use crate::*;
-use frame_support::{
-	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::KeepAlive},
-};
+use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub(crate) fn do_create_swap(
@@ -40,6 +37,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let item = Item::<T, I>::get(&offered_collection_id, &offered_item_id)
 			.ok_or(Error::<T, I>::UnknownItem)?;
 		ensure!(item.owner == caller, Error::<T, I>::NoPermission);
+		Self::ensure_not_soulbound(&offered_collection_id)?;
+		Self::ensure_not_auctioned(&offered_collection_id, &offered_item_id)?;
 
 		match maybe_desired_item_id {
 			Some(desired_item_id) => ensure!(
@@ -142,17 +141,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if let Some(ref price) = swap.price {
 			match price.direction {
-				PriceDirection::Send => T::Currency::transfer(
+				PriceDirection::Send => Self::do_pay_with_royalties(
+					None,
 					&receive_item.owner,
 					&send_item.owner,
+					send_collection_id,
+					send_item_id,
 					price.amount,
-					KeepAlive,
 				)?,
-				PriceDirection::Receive => T::Currency::transfer(
+				PriceDirection::Receive => Self::do_pay_with_royalties(
+					None,
 					&send_item.owner,
 					&receive_item.owner,
+					receive_collection_id,
+					receive_item_id,
 					price.amount,
-					KeepAlive,
 				)?,
 			};
 		}
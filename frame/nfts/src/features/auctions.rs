@@ -0,0 +1,301 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, storage::with_storage_layer, traits::ReservableCurrency};
+use sp_runtime::{traits::SaturatedConversion, Perbill};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_create_auction(
+		caller: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		kind: AuctionKindOf<T, I>,
+		duration: <T as SystemConfig>::BlockNumber,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Auctions),
+			Error::<T, I>::MethodDisabled
+		);
+		ensure!(
+			!duration.is_zero() && duration <= T::MaxDeadlineDuration::get(),
+			Error::<T, I>::WrongDuration
+		);
+		if let AuctionKind::Dutch { start_price, end_price } = &kind {
+			ensure!(end_price <= start_price, Error::<T, I>::WrongAuctionPrice);
+		}
+
+		// Nested items are sold along with the item they're nested into.
+		ensure!(!ParentOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::ItemNested);
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == caller, Error::<T, I>::NoPermission);
+		ensure!(!AuctionOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::AuctionExists);
+
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::TransferableItems),
+			Error::<T, I>::ItemsNonTransferable
+		);
+		Self::ensure_not_soulbound(&collection)?;
+
+		let item_config = Self::get_item_config(&collection, &item)?;
+		ensure!(
+			item_config.is_setting_enabled(ItemSetting::Transferable),
+			Error::<T, I>::ItemLocked
+		);
+		ItemPriceOf::<T, I>::remove(&collection, &item);
+		PendingSwapOf::<T, I>::remove(&collection, &item);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let end = now.saturating_add(duration);
+		Self::schedule_auction_settlement(collection, item, end);
+
+		// The item can't change hands until the auction is closed, see `ensure_not_auctioned`.
+		AuctionOf::<T, I>::insert(
+			&collection,
+			&item,
+			Auction {
+				seller: caller.clone(),
+				kind: kind.clone(),
+				start: now,
+				end,
+				highest_bid: None,
+			},
+		);
+
+		Self::deposit_event(Event::AuctionCreated {
+			collection,
+			item,
+			seller: caller,
+			kind,
+			start: now,
+			end,
+		});
+
+		Ok(())
+	}
+
+	/// Places a bid, which settles the auction if it's a Dutch auction.
+	///
+	/// Returns the number of royalty recipients of the item if the auction was settled.
+	pub(crate) fn do_bid(
+		bidder: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		amount: ItemPrice<T, I>,
+	) -> Result<Option<u32>, DispatchError> {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Auctions),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let mut auction =
+			AuctionOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownAuction)?;
+		ensure!(auction.seller != bidder, Error::<T, I>::NoPermission);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now < auction.end, Error::<T, I>::AuctionEnded);
+
+		match auction.kind {
+			AuctionKind::English { reserve_price, min_increment, extension } => {
+				let previous_bid = auction.highest_bid.take();
+				let min_bid = match &previous_bid {
+					Some((_, highest)) => highest.saturating_add(min_increment),
+					None => reserve_price,
+				};
+				ensure!(amount >= min_bid, Error::<T, I>::BidTooLow);
+
+				match previous_bid {
+					Some((previous_bidder, previous_amount)) if previous_bidder == bidder =>
+						T::Currency::reserve(&bidder, amount.saturating_sub(previous_amount))?,
+					Some((previous_bidder, previous_amount)) => {
+						T::Currency::reserve(&bidder, amount)?;
+						T::Currency::unreserve(&previous_bidder, previous_amount);
+					},
+					None => T::Currency::reserve(&bidder, amount)?,
+				}
+
+				// Extend the auction to give the other bidders a chance to respond.
+				if auction.end.saturating_sub(now) < extension {
+					auction.end = now.saturating_add(extension);
+					Self::schedule_auction_settlement(collection, item, auction.end);
+				}
+
+				auction.highest_bid = Some((bidder.clone(), amount));
+				AuctionOf::<T, I>::insert(&collection, &item, &auction);
+
+				Self::deposit_event(Event::BidPlaced {
+					collection,
+					item,
+					bidder,
+					amount,
+					end: auction.end,
+				});
+				Ok(None)
+			},
+			AuctionKind::Dutch { .. } => {
+				let price = Self::auction_price(&auction, now);
+				ensure!(amount >= price, Error::<T, I>::BidTooLow);
+
+				// The winning bid is paid like the highest bid of an English auction.
+				T::Currency::reserve(&bidder, price)?;
+				let royalties = Self::royalties(&collection, &item).len() as u32;
+				Self::close_auction(collection, item, Some((bidder, price)))?;
+				Ok(Some(royalties))
+			},
+		}
+	}
+
+	pub(crate) fn do_settle_auction(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let auction =
+			AuctionOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownAuction)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now >= auction.end, Error::<T, I>::AuctionNotEnded);
+
+		let highest_bid = auction.highest_bid;
+		if let Err(error) =
+			with_storage_layer(|| Self::close_auction(collection, item, highest_bid.clone()))
+		{
+			// Refund the highest bid, the funds of the bidder would stay reserved otherwise.
+			AuctionOf::<T, I>::remove(&collection, &item);
+			if let Some((bidder, amount)) = highest_bid {
+				T::Currency::unreserve(&bidder, amount);
+			}
+			Self::deposit_event(Event::AuctionSettlementFailed { collection, item, error });
+		}
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_auction(
+		caller: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let auction =
+			AuctionOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownAuction)?;
+		ensure!(auction.seller == caller, Error::<T, I>::NoPermission);
+		ensure!(auction.highest_bid.is_none(), Error::<T, I>::AuctionHasBids);
+
+		AuctionOf::<T, I>::remove(&collection, &item);
+
+		Self::deposit_event(Event::AuctionCancelled { collection, item });
+		Ok(())
+	}
+
+	/// Settles the auctions scheduled to end at block `now`.
+	///
+	/// Auctions that can't be settled are left for the `settle_auction` call.
+	pub(crate) fn settle_ended_auctions(now: <T as SystemConfig>::BlockNumber) -> Weight {
+		let endings = AuctionEndings::<T, I>::take(now);
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+		for (collection, item) in endings {
+			weight.saturating_accrue(T::WeightInfo::settle_auction(T::MaxRoyaltyRecipients::get()));
+			// The auction might have been extended or settled already.
+			if AuctionOf::<T, I>::get(&collection, &item).map_or(true, |a| a.end > now) {
+				continue
+			}
+			if let Err(e) = Self::do_settle_auction(collection, item) {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to settle the auction of item {:?} of collection {:?}: {:?}",
+					item,
+					collection,
+					e,
+				);
+			}
+		}
+
+		weight
+	}
+
+	/// The price a bid has to match at block `now`.
+	///
+	/// For English auctions this is the min amount of the next bid.
+	pub fn auction_price(
+		auction: &AuctionFor<T, I>,
+		now: <T as SystemConfig>::BlockNumber,
+	) -> ItemPrice<T, I> {
+		match auction.kind {
+			AuctionKind::English { reserve_price, min_increment, .. } => auction
+				.highest_bid
+				.as_ref()
+				.map_or(reserve_price, |(_, highest)| highest.saturating_add(min_increment)),
+			AuctionKind::Dutch { start_price, end_price } => {
+				let elapsed = now.saturating_sub(auction.start).saturated_into::<u32>();
+				let duration = auction.end.saturating_sub(auction.start).saturated_into::<u32>();
+				let decay = Perbill::from_rational(elapsed, duration)
+					.mul_floor(start_price.saturating_sub(end_price));
+				start_price.saturating_sub(decay)
+			},
+		}
+	}
+
+	fn schedule_auction_settlement(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		end: <T as SystemConfig>::BlockNumber,
+	) {
+		// If too many auctions end in the same block, the auction has to be settled by calling
+		// `settle_auction`.
+		let _ = AuctionEndings::<T, I>::try_mutate(end, |endings| {
+			if endings.contains(&(collection, item)) {
+				return Ok(())
+			}
+			endings.try_push((collection, item))
+		});
+	}
+
+	/// Removes the auction and hands the item over to the winner, if any.
+	///
+	/// The price of the winning bid is paid out of the funds the winner has reserved to the
+	/// current owner of the item.
+	fn close_auction(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		winning_bid: Option<(T::AccountId, ItemPrice<T, I>)>,
+	) -> DispatchResult {
+		AuctionOf::<T, I>::remove(&collection, &item);
+		let owner = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?.owner;
+
+		if let Some((winner, price)) = &winning_bid {
+			Self::do_pay_reserved_with_royalties(winner, &owner, collection, item, *price)?;
+			Self::do_transfer(collection, item, winner.clone(), |_, _| Ok(()))?;
+		}
+
+		Self::deposit_event(Event::AuctionSettled {
+			collection,
+			item,
+			seller: owner,
+			winner: winning_bid.as_ref().map(|(winner, _)| winner.clone()),
+			price: winning_bid.map(|(_, price)| price),
+		});
+		Ok(())
+	}
+
+	/// Ensures the `item` isn't auctioned, so that it can change hands.
+	pub(crate) fn ensure_not_auctioned(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> DispatchResult {
+		ensure!(!AuctionOf::<T, I>::contains_key(collection, item), Error::<T, I>::ItemAuctioned);
+		Ok(())
+	}
+}
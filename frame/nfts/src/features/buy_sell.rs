@@ -18,7 +18,14 @@
 use crate::*;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, ExistenceRequirement::KeepAlive},
+	traits::{
+		tokens::{
+			fungibles::{Inspect, Transfer},
+			DepositConsequence,
+		},
+		Currency,
+		ExistenceRequirement::KeepAlive,
+	},
 };
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -45,6 +52,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		item: T::ItemId,
 		sender: T::AccountId,
 		price: Option<ItemPrice<T, I>>,
+		asset: Option<AssetIdOf<T, I>>,
 		whitelisted_buyer: Option<T::AccountId>,
 	) -> DispatchResult {
 		ensure!(
@@ -60,19 +68,27 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			collection_config.is_setting_enabled(CollectionSetting::TransferableItems),
 			Error::<T, I>::ItemsNonTransferable
 		);
+		Self::ensure_not_soulbound(&collection)?;
 
 		let item_config = Self::get_item_config(&collection, &item)?;
 		ensure!(
 			item_config.is_setting_enabled(ItemSetting::Transferable),
 			Error::<T, I>::ItemLocked
 		);
+		Self::ensure_not_auctioned(&collection, &item)?;
 
 		if let Some(ref price) = price {
-			ItemPriceOf::<T, I>::insert(&collection, &item, (price, whitelisted_buyer.clone()));
+			Self::ensure_known_asset(asset)?;
+			ItemPriceOf::<T, I>::insert(
+				&collection,
+				&item,
+				(price, whitelisted_buyer.clone(), asset),
+			);
 			Self::deposit_event(Event::ItemPriceSet {
 				collection,
 				item,
 				price: *price,
+				asset,
 				whitelisted_buyer,
 			});
 		} else {
@@ -106,11 +122,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
-		T::Currency::transfer(
+		Self::do_pay_with_royalties(
+			price_info.2,
 			&buyer,
 			&details.owner,
+			collection,
+			item,
 			price_info.0,
-			ExistenceRequirement::KeepAlive,
 		)?;
 
 		let old_owner = details.owner.clone();
@@ -121,10 +139,50 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			collection,
 			item,
 			price: price_info.0,
+			asset: price_info.2,
 			seller: old_owner,
 			buyer,
 		});
 
 		Ok(())
 	}
+
+	/// Transfers the `amount` of a price from `source` to `dest`, either in the `asset` or, if
+	/// it's `None`, in `Currency`.
+	pub(crate) fn transfer_price(
+		asset: Option<AssetIdOf<T, I>>,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: ItemPrice<T, I>,
+	) -> DispatchResult {
+		match asset {
+			Some(asset) => T::Assets::transfer(asset, source, dest, amount, true).map(|_| ()),
+			None => T::Currency::transfer(source, dest, amount, KeepAlive),
+		}
+	}
+
+	/// Returns `true` if `dest` can receive the `amount` of a price, either in the `asset` or, if
+	/// it's `None`, in `Currency`.
+	///
+	/// This is `false` for an amount that would not bring `dest` up to the existential deposit.
+	pub(crate) fn can_receive_price(
+		asset: Option<AssetIdOf<T, I>>,
+		dest: &T::AccountId,
+		amount: ItemPrice<T, I>,
+	) -> bool {
+		match asset {
+			Some(asset) =>
+				T::Assets::can_deposit(asset, dest, amount, false) == DepositConsequence::Success,
+			None =>
+				T::Currency::total_balance(dest).saturating_add(amount) >=
+					T::Currency::minimum_balance(),
+		}
+	}
+
+	pub(crate) fn ensure_known_asset(asset: Option<AssetIdOf<T, I>>) -> DispatchResult {
+		if let Some(asset) = asset {
+			ensure!(T::Assets::asset_exists(asset), Error::<T, I>::UnknownAsset);
+		}
+		Ok(())
+	}
 }
@@ -28,6 +28,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		event: Event<T, I>,
 	) -> DispatchResult {
 		ensure!(!Collection::<T, I>::contains_key(collection), Error::<T, I>::CollectionIdInUse);
+		Self::ensure_known_asset(config.mint_settings.price_asset)?;
 
 		T::Currency::reserve(&owner, deposit)?;
 
@@ -77,6 +78,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Error::<T, I>::BadWitness
 			);
 			ensure!(collection_details.attributes == witness.attributes, Error::<T, I>::BadWitness);
+			ensure!(
+				ChildrenOf::<T, I>::iter_prefix(&collection).next().is_none(),
+				Error::<T, I>::HasChildren
+			);
+			Self::ensure_no_nested_items(&collection)?;
 
 			for (item, details) in Item::<T, I>::drain_prefix(&collection) {
 				Account::<T, I>::remove((&details.owner, &collection, &item));
@@ -87,6 +93,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					T::Currency::unreserve(&depositor, metadata.deposit.amount);
 				}
 			}
+			for (_, auction) in AuctionOf::<T, I>::drain_prefix(&collection) {
+				if let Some((bidder, amount)) = auction.highest_bid {
+					T::Currency::unreserve(&bidder, amount);
+				}
+			}
 			let _ = ItemPriceOf::<T, I>::clear_prefix(&collection, witness.items, None);
 			let _ = PendingSwapOf::<T, I>::clear_prefix(&collection, witness.items, None);
 			CollectionMetadataOf::<T, I>::remove(&collection);
@@ -106,6 +117,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let _ = ItemConfigOf::<T, I>::clear_prefix(&collection, witness.items, None);
 			let _ =
 				ItemAttributesApprovalsOf::<T, I>::clear_prefix(&collection, witness.items, None);
+			CollectionRoyaltiesOf::<T, I>::remove(&collection);
+			let _ = ItemRoyaltiesOf::<T, I>::clear_prefix(&collection, witness.items, None);
 
 			Self::deposit_event(Event::Destroyed { collection });
 
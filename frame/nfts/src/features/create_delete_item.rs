@@ -147,6 +147,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		with_details: impl FnOnce(&ItemDetailsFor<T, I>) -> DispatchResult,
 	) -> DispatchResult {
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
+		ensure!(!ChildrenOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::HasChildren);
 		let item_config = Self::get_item_config(&collection, &item)?;
 		let owner = Collection::<T, I>::try_mutate(
 			&collection,
@@ -182,11 +183,22 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			},
 		)?;
 
+		// Refund the highest bid if the item is auctioned.
+		if let Some(auction) = AuctionOf::<T, I>::take(&collection, &item) {
+			if let Some((bidder, amount)) = auction.highest_bid {
+				T::Currency::unreserve(&bidder, amount);
+			}
+		}
+
+		Self::remove_from_parent(collection, item);
 		Item::<T, I>::remove(&collection, &item);
 		Account::<T, I>::remove((&owner, &collection, &item));
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
 		ItemAttributesApprovalsOf::<T, I>::remove(&collection, &item);
+		if item_config.is_setting_enabled(ItemSetting::UnlockedRoyalties) {
+			ItemRoyaltiesOf::<T, I>::remove(&collection, &item);
+		}
 
 		// NOTE: if item's settings are not empty (e.g. item's metadata is locked)
 		// then we keep the record and don't remove it
@@ -32,6 +32,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			!lock_settings.is_disabled(CollectionSetting::DepositRequired),
 			Error::<T, I>::WrongSetting
 		);
+		if lock_settings.is_disabled(CollectionSetting::TransferableItems) ||
+			lock_settings.is_disabled(CollectionSetting::UnboundItems)
+		{
+			// Nested items move along with the item they're nested into.
+			Self::ensure_no_nested_items(&collection)?;
+		}
 		CollectionConfigOf::<T, I>::try_mutate(collection, |maybe_config| {
 			let config = maybe_config.as_mut().ok_or(Error::<T, I>::NoConfig)?;
 
@@ -53,6 +59,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::has_role(&collection, &origin, CollectionRole::Freezer),
 			Error::<T, I>::NoPermission
 		);
+		// Nested items move along with the item they're nested into.
+		ensure!(!ParentOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::ItemNested);
 
 		let mut config = Self::get_item_config(&collection, &item)?;
 		if !config.has_disabled_setting(ItemSetting::Transferable) {
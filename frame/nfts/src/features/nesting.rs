@@ -0,0 +1,199 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::*;
+use sp_io::hashing::blake2_256;
+use sp_runtime::traits::TrailingZeroInput;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_nest(
+		caller: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		parent_collection: T::CollectionId,
+		parent_item: T::ItemId,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Nesting),
+			Error::<T, I>::MethodDisabled
+		);
+
+		// Nested items are owned by the account of their parent, so only items which aren't
+		// nested yet pass this check.
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == caller, Error::<T, I>::NoPermission);
+		Self::ensure_not_soulbound(&collection)?;
+
+		let ancestors = Self::ancestors(parent_collection, parent_item);
+		ensure!(
+			(parent_collection, parent_item) != (collection, item) &&
+				!ancestors.contains(&(collection, item)),
+			Error::<T, I>::NestingCycle
+		);
+		let (root_collection, root_item) =
+			ancestors.last().copied().unwrap_or((parent_collection, parent_item));
+		ensure!(
+			Self::owner(root_collection, root_item).ok_or(Error::<T, I>::UnknownItem)? == caller,
+			Error::<T, I>::NoPermission
+		);
+		// The items nested into an auctioned item are sold along with it.
+		Self::ensure_not_auctioned(&root_collection, &root_item)?;
+
+		let depth = (ancestors.len() as u32)
+			.saturating_add(1)
+			.saturating_add(Self::nesting_height(&collection, &item));
+		ensure!(depth <= T::MaxNestingDepth::get(), Error::<T, I>::NestingTooDeep);
+
+		let mut nested =
+			ChildrenOf::<T, I>::get(&parent_collection, &parent_item).unwrap_or_default();
+		nested
+			.children
+			.try_push((collection, item))
+			.map_err(|_| Error::<T, I>::TooManyChildren)?;
+
+		// The deposit stays with the account that paid it, since the account of the parent item
+		// doesn't hold any funds.
+		Self::transfer_item(
+			collection,
+			item,
+			Self::nesting_account(parent_collection, parent_item),
+			false,
+			|_, _| Ok(()),
+		)?;
+
+		ChildrenOf::<T, I>::insert(&parent_collection, &parent_item, nested);
+		ParentOf::<T, I>::insert(&collection, &item, (parent_collection, parent_item));
+		Self::update_nesting_heights(parent_collection, parent_item);
+
+		Self::deposit_event(Event::ItemNested { collection, item, parent_collection, parent_item });
+		Ok(())
+	}
+
+	pub(crate) fn do_unnest(
+		caller: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let (parent_collection, parent_item) =
+			ParentOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::NotNested)?;
+		let (root_collection, root_item) = Self::root(collection, item);
+		ensure!(
+			Self::owner(root_collection, root_item) == Some(caller.clone()),
+			Error::<T, I>::NoPermission
+		);
+		Self::ensure_not_auctioned(&root_collection, &root_item)?;
+
+		Self::remove_from_parent(collection, item);
+		Self::transfer_item(collection, item, caller, true, |_, _| Ok(()))?;
+
+		Self::deposit_event(Event::ItemUnnested {
+			collection,
+			item,
+			parent_collection,
+			parent_item,
+		});
+		Ok(())
+	}
+
+	/// The account owning the items nested into the `item`.
+	pub fn nesting_account(collection: T::CollectionId, item: T::ItemId) -> T::AccountId {
+		let entropy = (b"modlpy/nftsnest", collection, item).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// Get the owner of the item at the top of the items the `item` is nested into, or the owner
+	/// of the `item` if it isn't nested.
+	pub fn root_owner(collection: T::CollectionId, item: T::ItemId) -> Option<T::AccountId> {
+		let (collection, item) = Self::root(collection, item);
+		Self::owner(collection, item)
+	}
+
+	/// The item at the top of the items the `item` is nested into, or the `item` itself if it
+	/// isn't nested.
+	fn root(collection: T::CollectionId, item: T::ItemId) -> (T::CollectionId, T::ItemId) {
+		Self::ancestors(collection, item).last().copied().unwrap_or((collection, item))
+	}
+
+	/// Ensures none of the items of the `collection` is nested into another item.
+	pub(crate) fn ensure_no_nested_items(collection: &T::CollectionId) -> DispatchResult {
+		ensure!(
+			ParentOf::<T, I>::iter_prefix(collection).next().is_none(),
+			Error::<T, I>::ItemNested
+		);
+		Ok(())
+	}
+
+	/// Removes the `item` from the item it's nested into, if any.
+	pub(crate) fn remove_from_parent(collection: T::CollectionId, item: T::ItemId) {
+		if let Some((parent_collection, parent_item)) = ParentOf::<T, I>::take(&collection, &item) {
+			ChildrenOf::<T, I>::mutate(&parent_collection, &parent_item, |maybe_children| {
+				if let Some(nested) = maybe_children {
+					nested.children.retain(|child| child != &(collection, item));
+				}
+			});
+			Self::update_nesting_heights(parent_collection, parent_item);
+		}
+	}
+
+	/// The items the `item` is nested into, starting with its parent.
+	fn ancestors(
+		mut collection: T::CollectionId,
+		mut item: T::ItemId,
+	) -> Vec<(T::CollectionId, T::ItemId)> {
+		let mut ancestors = Vec::new();
+		while let Some(parent) = ParentOf::<T, I>::get(&collection, &item) {
+			ancestors.push(parent);
+			(collection, item) = parent;
+		}
+		ancestors
+	}
+
+	fn nesting_height(collection: &T::CollectionId, item: &T::ItemId) -> u32 {
+		ChildrenOf::<T, I>::get(collection, item).map_or(0, |nested| nested.height)
+	}
+
+	/// Recalculates the height of the item and of the items it's nested into, after its nested
+	/// items have changed.
+	fn update_nesting_heights(mut collection: T::CollectionId, mut item: T::ItemId) {
+		while let Some(mut nested) = ChildrenOf::<T, I>::get(&collection, &item) {
+			let height = nested
+				.children
+				.iter()
+				.map(|(collection, item)| Self::nesting_height(collection, item).saturating_add(1))
+				.max()
+				.unwrap_or(0);
+			if height == nested.height && !nested.children.is_empty() {
+				return
+			}
+
+			if nested.children.is_empty() {
+				ChildrenOf::<T, I>::remove(&collection, &item);
+			} else {
+				nested.height = height;
+				ChildrenOf::<T, I>::insert(&collection, &item, nested);
+			}
+
+			match ParentOf::<T, I>::get(&collection, &item) {
+				Some(parent) => (collection, item) = parent,
+				None => return,
+			}
+		}
+	}
+}
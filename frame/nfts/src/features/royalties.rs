@@ -0,0 +1,165 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_set_collection_royalties(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		royalties: RoyaltiesOf<T, I>,
+	) -> DispatchResult {
+		let is_root = maybe_check_owner.is_none();
+		let collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
+		}
+
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			is_root || collection_config.is_setting_enabled(CollectionSetting::UnlockedRoyalties),
+			Error::<T, I>::LockedCollectionRoyalties
+		);
+		Self::ensure_valid_royalties(&royalties)?;
+
+		if royalties.is_empty() {
+			CollectionRoyaltiesOf::<T, I>::remove(&collection);
+		} else {
+			CollectionRoyaltiesOf::<T, I>::insert(&collection, &royalties);
+		}
+
+		Self::deposit_event(Event::CollectionRoyaltiesSet { collection, royalties });
+		Ok(())
+	}
+
+	pub(crate) fn do_set_item_royalties(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		royalties: RoyaltiesOf<T, I>,
+	) -> DispatchResult {
+		let is_root = maybe_check_owner.is_none();
+		let collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
+		}
+
+		let item_config = Self::get_item_config(&collection, &item)?;
+		ensure!(
+			is_root || item_config.is_setting_enabled(ItemSetting::UnlockedRoyalties),
+			Error::<T, I>::LockedItemRoyalties
+		);
+		Self::ensure_valid_royalties(&royalties)?;
+
+		if royalties.is_empty() {
+			ItemRoyaltiesOf::<T, I>::remove(&collection, &item);
+		} else {
+			ItemRoyaltiesOf::<T, I>::insert(&collection, &item, &royalties);
+		}
+
+		Self::deposit_event(Event::ItemRoyaltiesSet { collection, item, royalties });
+		Ok(())
+	}
+
+	/// Returns the royalties paid when the `item` is sold.
+	///
+	/// These are the royalties of the item if it has any, otherwise the royalties of its
+	/// collection.
+	pub fn royalties(collection: &T::CollectionId, item: &T::ItemId) -> RoyaltiesOf<T, I> {
+		ItemRoyaltiesOf::<T, I>::get(collection, item)
+			.or_else(|| CollectionRoyaltiesOf::<T, I>::get(collection))
+			.unwrap_or_default()
+	}
+
+	/// Transfers the `price` of an item from the `payer` to the `seller`, after paying out the
+	/// royalties of the item from it.
+	///
+	/// The price is paid in the `asset`, or in `Currency` if it's `None`. A royalty that is too
+	/// small for its recipient to receive, because it would not bring the recipient up to the
+	/// existential deposit, is paid to the `seller` instead.
+	pub(crate) fn do_pay_with_royalties(
+		asset: Option<AssetIdOf<T, I>>,
+		payer: &T::AccountId,
+		seller: &T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		Self::distribute_with_royalties(asset, seller, collection, item, price, |dest, amount| {
+			Self::transfer_price(asset, payer, dest, amount)
+		})
+	}
+
+	/// Like [`Self::do_pay_with_royalties`], but pays the `price` out of the funds the `payer` has
+	/// reserved.
+	///
+	/// The funds are unreserved and transferred, as the royalty recipients might not exist yet.
+	pub(crate) fn do_pay_reserved_with_royalties(
+		payer: &T::AccountId,
+		seller: &T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		Self::distribute_with_royalties(None, seller, collection, item, price, |dest, amount| {
+			let remaining = T::Currency::unreserve(payer, amount);
+			ensure!(remaining.is_zero(), Error::<T, I>::InsufficientReservedFunds);
+			Self::transfer_price(None, payer, dest, amount)
+		})
+	}
+
+	fn distribute_with_royalties(
+		asset: Option<AssetIdOf<T, I>>,
+		seller: &T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		price: ItemPrice<T, I>,
+		mut pay: impl FnMut(&T::AccountId, ItemPrice<T, I>) -> DispatchResult,
+	) -> DispatchResult {
+		let mut remainder = price;
+		for royalty in Self::royalties(&collection, &item) {
+			let amount = royalty.percentage.mul_floor(price);
+			if amount.is_zero() || !Self::can_receive_price(asset, &royalty.account, amount) {
+				continue
+			}
+			pay(&royalty.account, amount)?;
+			remainder.saturating_reduce(amount);
+
+			Self::deposit_event(Event::RoyaltyPaid {
+				collection,
+				item,
+				recipient: royalty.account,
+				amount,
+			});
+		}
+
+		pay(seller, remainder)
+	}
+
+	fn ensure_valid_royalties(royalties: &RoyaltiesOf<T, I>) -> DispatchResult {
+		let total = royalties
+			.iter()
+			.fold(0u32, |total, royalty| total.saturating_add(royalty.percentage.deconstruct()));
+		ensure!(total <= T::MaxRoyalty::get().deconstruct(), Error::<T, I>::RoyaltyTooHigh);
+		Ok(())
+	}
+}
@@ -24,6 +24,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		config: CollectionConfigFor<T, I>,
 	) -> DispatchResult {
 		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		if config.has_disabled_setting(CollectionSetting::TransferableItems) ||
+			config.has_disabled_setting(CollectionSetting::UnboundItems)
+		{
+			Self::ensure_no_nested_items(&collection)?;
+		}
 		CollectionConfigOf::<T, I>::insert(&collection, config);
 		Self::deposit_event(Event::CollectionConfigChanged { collection });
 		Ok(())
@@ -63,6 +68,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			BalanceOf<T, I>,
 			<T as SystemConfig>::BlockNumber,
 			T::CollectionId,
+			AssetIdOf<T, I>,
 		>,
 	) -> DispatchResult {
 		let details =
@@ -70,6 +76,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		if let Some(check_owner) = &maybe_check_owner {
 			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
 		}
+		Self::ensure_known_asset(mint_settings.price_asset)?;
 
 		CollectionConfigOf::<T, I>::try_mutate(collection, |maybe_config| {
 			let config = maybe_config.as_mut().ok_or(Error::<T, I>::NoConfig)?;
@@ -103,6 +110,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(collection_config.mint_settings.default_item_settings)
 	}
 
+	pub(crate) fn ensure_not_soulbound(collection_id: &T::CollectionId) -> DispatchResult {
+		let collection_config = Self::get_collection_config(collection_id)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::UnboundItems),
+			Error::<T, I>::ItemsSoulbound
+		);
+		Ok(())
+	}
+
 	pub(crate) fn is_pallet_feature_enabled(feature: PalletFeature) -> bool {
 		let features = T::Features::get();
 		return features.is_enabled(feature)
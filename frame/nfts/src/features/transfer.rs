@@ -27,10 +27,37 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			&CollectionDetailsFor<T, I>,
 			&mut ItemDetailsFor<T, I>,
 		) -> DispatchResult,
+	) -> DispatchResult {
+		ensure!(!ParentOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::ItemNested);
+		Self::ensure_not_soulbound(&collection)?;
+		Self::transfer_item(collection, item, dest, true, with_details)
+	}
+
+	pub(crate) fn do_force_transfer(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+	) -> DispatchResult {
+		ensure!(!ParentOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::ItemNested);
+		Self::transfer_item(collection, item, dest, true, |_, _| Ok(()))
+	}
+
+	/// Transfers the `item` to `dest`, moving the item's deposit along with it if `move_deposit`
+	/// is set and the deposit was paid by the owner.
+	pub(crate) fn transfer_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+		move_deposit: bool,
+		with_details: impl FnOnce(
+			&CollectionDetailsFor<T, I>,
+			&mut ItemDetailsFor<T, I>,
+		) -> DispatchResult,
 	) -> DispatchResult {
 		let collection_details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
+		Self::ensure_not_auctioned(&collection, &item)?;
 
 		let collection_config = Self::get_collection_config(&collection)?;
 		ensure!(
@@ -48,7 +75,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
 		with_details(&collection_details, &mut details)?;
 
-		if details.deposit.account == details.owner {
+		if move_deposit && details.deposit.account == details.owner {
 			// Move the deposit to the new owner.
 			T::Currency::repatriate_reserved(
 				&details.owner,
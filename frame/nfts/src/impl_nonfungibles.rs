@@ -115,7 +115,8 @@ impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Palle
 
 	/// Returns `true` if the `item` of `collection` may be transferred.
 	///
-	/// Default implementation is that all items are transferable.
+	/// Items that are auctioned can't be transferred until their auction ends, and nested items
+	/// only move along with the item they're nested into.
 	fn can_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
 		match (
 			CollectionConfigOf::<T, I>::get(collection),
@@ -123,11 +124,24 @@ impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Palle
 		) {
 			(Some(cc), Some(ic))
 				if cc.is_setting_enabled(CollectionSetting::TransferableItems) &&
+					cc.is_setting_enabled(CollectionSetting::UnboundItems) &&
 					ic.is_setting_enabled(ItemSetting::Transferable) =>
-				true,
+				!AuctionOf::<T, I>::contains_key(collection, item) &&
+					!ParentOf::<T, I>::contains_key(collection, item),
 			_ => false,
 		}
 	}
+
+	/// Returns the royalties of the `item`, or of its `collection` if the item has none.
+	fn royalties(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+	) -> Vec<(T::AccountId, Permill)> {
+		Self::royalties(collection, item)
+			.into_iter()
+			.map(|royalty| (royalty.account, royalty.percentage))
+			.collect()
+	}
 }
 
 impl<T: Config<I>, I: 'static> Create<<T as SystemConfig>::AccountId, CollectionConfigFor<T, I>>
@@ -41,18 +41,19 @@ mod impl_nonfungibles;
 mod types;
 
 pub mod macros;
+pub mod migration;
 pub mod weights;
 
 use codec::{Decode, Encode};
 use frame_support::traits::{
-	tokens::{AttributeNamespace, Locker},
+	tokens::{fungibles, AttributeNamespace, Locker},
 	BalanceStatus::Reserved,
 	Currency, EnsureOriginWithArg, ReservableCurrency,
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
 	traits::{Saturating, StaticLookup, Zero},
-	RuntimeDebug,
+	Permill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -60,32 +61,45 @@ pub use pallet::*;
 pub use types::*;
 pub use weights::WeightInfo;
 
+/// The log target of this pallet.
+pub const LOG_TARGET: &str = "runtime::nfts";
+
 type AccountIdLookupOf<T> = <<T as SystemConfig>::Lookup as StaticLookup>::Source;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
+	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::traits::{IdentifyAccount, Verify};
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[cfg(feature = "runtime-benchmarks")]
-	pub trait BenchmarkHelper<CollectionId, ItemId> {
+	pub trait BenchmarkHelper<CollectionId, ItemId, AssetId> {
 		fn collection(i: u16) -> CollectionId;
 		fn item(i: u16) -> ItemId;
+		fn asset(i: u16) -> AssetId;
 	}
 	#[cfg(feature = "runtime-benchmarks")]
-	impl<CollectionId: From<u16>, ItemId: From<u16>> BenchmarkHelper<CollectionId, ItemId> for () {
+	impl<CollectionId: From<u16>, ItemId: From<u16>, AssetId: From<u16>>
+		BenchmarkHelper<CollectionId, ItemId, AssetId> for ()
+	{
 		fn collection(i: u16) -> CollectionId {
 			i.into()
 		}
 		fn item(i: u16) -> ItemId {
 			i.into()
 		}
+		fn asset(i: u16) -> AssetId {
+			i.into()
+		}
 	}
 
 	#[pallet::config]
@@ -104,6 +118,9 @@ pub mod pallet {
 		/// The currency mechanism, used for paying for reserves.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// The assets mints and items can be priced in, besides `Currency`.
+		type Assets: fungibles::Transfer<Self::AccountId, Balance = BalanceOf<Self, I>>;
+
 		/// The origin which may forcibly create or destroy an item or otherwise alter privileged
 		/// attributes.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -117,6 +134,9 @@ pub mod pallet {
 		>;
 
 		/// Locker trait to enable Locking mechanism downstream.
+		///
+		/// Nested items move along with the item they're nested into, so the locker shouldn't
+		/// lock items while they're nested.
 		type Locker: Locker<Self::CollectionId, Self::ItemId>;
 
 		/// The basic amount of funds that must be reserved for collection.
@@ -172,6 +192,28 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAttributesPerCall: Get<u32>;
 
+		/// The max number of royalty recipients of a collection or an item.
+		#[pallet::constant]
+		type MaxRoyaltyRecipients: Get<u32>;
+
+		/// The max share of the sale price that can be paid out as royalties.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Permill>;
+
+		/// The max number of auctions that are settled automatically at the end of a block.
+		///
+		/// Auctions beyond this limit have to be settled by calling `settle_auction`.
+		#[pallet::constant]
+		type MaxAuctionEndingsPerBlock: Get<u32>;
+
+		/// The max number of items an item can be nested into, directly or indirectly.
+		#[pallet::constant]
+		type MaxNestingDepth: Get<u32>;
+
+		/// The max number of items that can be nested directly into an item.
+		#[pallet::constant]
+		type MaxNestedChildren: Get<u32>;
+
 		/// Disables some of pallet's features.
 		#[pallet::constant]
 		type Features: Get<PalletFeatures>;
@@ -188,7 +230,7 @@ pub mod pallet {
 
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
-		type Helper: BenchmarkHelper<Self::CollectionId, Self::ItemId>;
+		type Helper: BenchmarkHelper<Self::CollectionId, Self::ItemId, AssetIdOf<Self, I>>;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
@@ -304,7 +346,7 @@ pub mod pallet {
 		T::CollectionId,
 		Blake2_128Concat,
 		T::ItemId,
-		(ItemPrice<T, I>, Option<T::AccountId>),
+		(ItemPrice<T, I>, Option<T::AccountId>, Option<AssetIdOf<T, I>>),
 		OptionQuery,
 	>;
 
@@ -360,6 +402,69 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Royalties paid when an item of a collection is sold.
+	#[pallet::storage]
+	pub(super) type CollectionRoyaltiesOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltiesOf<T, I>, OptionQuery>;
+
+	/// Royalties paid when an item is sold, overriding the royalties of its collection.
+	#[pallet::storage]
+	pub(super) type ItemRoyaltiesOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltiesOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// Handles all the running auctions.
+	#[pallet::storage]
+	pub(super) type AuctionOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		AuctionFor<T, I>,
+		OptionQuery,
+	>;
+
+	/// The auctions to settle at the start of a block.
+	#[pallet::storage]
+	pub(super) type AuctionEndings<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		<T as SystemConfig>::BlockNumber,
+		AuctionEndingsOf<T, I>,
+		ValueQuery,
+	>;
+
+	/// The item an item is nested into.
+	#[pallet::storage]
+	pub(super) type ParentOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		(T::CollectionId, T::ItemId),
+		OptionQuery,
+	>;
+
+	/// The items nested into an item.
+	#[pallet::storage]
+	pub(super) type ChildrenOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		NestedItemsFor<T, I>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -477,6 +582,7 @@ pub mod pallet {
 			collection: T::CollectionId,
 			item: T::ItemId,
 			price: ItemPrice<T, I>,
+			asset: Option<AssetIdOf<T, I>>,
 			whitelisted_buyer: Option<T::AccountId>,
 		},
 		/// The price for the item was removed.
@@ -486,6 +592,7 @@ pub mod pallet {
 			collection: T::CollectionId,
 			item: T::ItemId,
 			price: ItemPrice<T, I>,
+			asset: Option<AssetIdOf<T, I>>,
 			seller: T::AccountId,
 			buyer: T::AccountId,
 		},
@@ -532,6 +639,68 @@ pub mod pallet {
 			item: T::ItemId,
 			namespace: AttributeNamespace<T::AccountId>,
 		},
+		/// The royalties of a `collection` have been set.
+		CollectionRoyaltiesSet { collection: T::CollectionId, royalties: RoyaltiesOf<T, I> },
+		/// The royalties of an `item` have been set.
+		ItemRoyaltiesSet {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			royalties: RoyaltiesOf<T, I>,
+		},
+		/// A royalty has been paid for the sale of an `item`.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			recipient: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
+		/// An auction of an `item` has been created.
+		AuctionCreated {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			kind: AuctionKindOf<T, I>,
+			start: <T as SystemConfig>::BlockNumber,
+			end: <T as SystemConfig>::BlockNumber,
+		},
+		/// A bid has been placed in an English auction.
+		BidPlaced {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			bidder: T::AccountId,
+			amount: ItemPrice<T, I>,
+			end: <T as SystemConfig>::BlockNumber,
+		},
+		/// An auction has been settled.
+		AuctionSettled {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			winner: Option<T::AccountId>,
+			price: Option<ItemPrice<T, I>>,
+		},
+		/// An auction has been cancelled by the seller.
+		AuctionCancelled { collection: T::CollectionId, item: T::ItemId },
+		/// An item was nested into another item.
+		ItemNested {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			parent_collection: T::CollectionId,
+			parent_item: T::ItemId,
+		},
+		/// An item was taken out of the item it was nested into.
+		ItemUnnested {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			parent_collection: T::CollectionId,
+			parent_item: T::ItemId,
+		},
+		/// An auction couldn't be settled, the highest bid has been refunded.
+		AuctionSettlementFailed {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::error]
@@ -622,6 +791,51 @@ pub mod pallet {
 		MaxAttributesLimitReached,
 		/// The provided namespace isn't supported in this call.
 		WrongNamespace,
+		/// The royalties exceed the max share of the sale price.
+		RoyaltyTooHigh,
+		/// Collection's royalties are locked.
+		LockedCollectionRoyalties,
+		/// Item's royalties are locked.
+		LockedItemRoyalties,
+		/// The reserved funds don't cover the payment.
+		InsufficientReservedFunds,
+		/// The item is already being auctioned.
+		AuctionExists,
+		/// The auction is unknown.
+		UnknownAuction,
+		/// The auction has ended and accepts no more bids.
+		AuctionEnded,
+		/// The auction hasn't ended yet.
+		AuctionNotEnded,
+		/// The auction can't be cancelled, because bids were placed.
+		AuctionHasBids,
+		/// The end price of a Dutch auction is higher than its start price.
+		WrongAuctionPrice,
+		/// The asset to pay the price in doesn't exist.
+		UnknownAsset,
+		/// The item is nested into another item and can only be moved by unnesting it.
+		ItemNested,
+		/// The item isn't nested into another item.
+		NotNested,
+		/// The item would be nested too deep.
+		NestingTooDeep,
+		/// An item can't be nested into itself or into an item nested into it.
+		NestingCycle,
+		/// The item has other items nested into it.
+		HasChildren,
+		/// The item has reached the max number of nested items.
+		TooManyChildren,
+		/// The items of this collection are soulbound and can't change their owner.
+		ItemsSoulbound,
+		/// The item is auctioned and can't change its owner until the auction is closed.
+		ItemAuctioned,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::settle_ended_auctions(now)
+		}
 	}
 
 	#[pallet::call]
@@ -717,6 +931,9 @@ pub mod pallet {
 		/// - `witness`: Information on the items minted in the collection. This must be
 		/// correct.
 		///
+		/// Collections with items nested into other items, or with other items nested into their
+		/// items, can't be destroyed.
+		///
 		/// Emits `Destroyed` event when successful.
 		///
 		/// Weight: `O(n + m)` where:
@@ -763,7 +980,7 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::mint())]
+		#[pallet::weight(T::WeightInfo::mint().max(T::WeightInfo::mint_priced_in_asset()))]
 		pub fn mint(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -835,11 +1052,11 @@ pub mod pallet {
 					}
 
 					if let Some(price) = mint_settings.price {
-						T::Currency::transfer(
+						Self::transfer_price(
+							mint_settings.price_asset,
 							&caller,
 							&collection_details.owner,
 							price,
-							ExistenceRequirement::KeepAlive,
 						)?;
 					}
 
@@ -912,7 +1129,12 @@ pub mod pallet {
 
 			Self::do_burn(collection, item, |details| {
 				let is_admin = Self::has_role(&collection, &origin, CollectionRole::Admin);
-				let is_permitted = is_admin || details.owner == origin;
+				// The issuer can revoke the items of a soulbound collection.
+				let is_soulbound = Self::get_collection_config(&collection)?
+					.has_disabled_setting(CollectionSetting::UnboundItems);
+				let is_revoking_issuer =
+					is_soulbound && Self::has_role(&collection, &origin, CollectionRole::Issuer);
+				let is_permitted = is_admin || is_revoking_issuer || details.owner == origin;
 				ensure!(is_permitted, Error::<T, I>::NoPermission);
 				ensure!(
 					check_owner.map_or(true, |o| o == details.owner),
@@ -1036,6 +1258,8 @@ pub mod pallet {
 		/// - `collection`: The collection of the item to be changed.
 		/// - `item`: The item to become non-transferable.
 		///
+		/// Nested items move along with the item they're nested into and can't be locked.
+		///
 		/// Emits `ItemTransferLocked`.
 		///
 		/// Weight: `O(1)`
@@ -1078,7 +1302,8 @@ pub mod pallet {
 		/// - `collection`: The collection to be locked.
 		/// - `lock_settings`: The settings to be locked.
 		///
-		/// Note: it's possible to only lock(set) the setting, but not to unset it.
+		/// Note: it's possible to only lock(set) the setting, but not to unset it. The transfers
+		/// of the items can't be locked while any of them is nested into another item.
 		/// Emits `CollectionLocked`.
 		///
 		/// Weight: `O(1)`
@@ -1174,7 +1399,8 @@ pub mod pallet {
 		/// Origin must be `ForceOrigin`.
 		///
 		/// - `collection`: The identifier of the collection.
-		/// - `config`: The new config of this collection.
+		/// - `config`: The new config of this collection. It can't lock the transfers of the items
+		///   while any of them is nested into another item.
 		///
 		/// Emits `CollectionConfigChanged`.
 		///
@@ -1643,6 +1869,7 @@ pub mod pallet {
 				BalanceOf<T, I>,
 				<T as SystemConfig>::BlockNumber,
 				T::CollectionId,
+				AssetIdOf<T, I>,
 			>,
 		) -> DispatchResult {
 			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
@@ -1658,6 +1885,7 @@ pub mod pallet {
 		/// - `collection`: The collection of the item.
 		/// - `item`: The item to set the price for.
 		/// - `price`: The price for the item. Pass `None`, to reset the price.
+		/// - `asset`: The asset the price is paid in. Pass `None` to be paid in `Currency`.
 		/// - `buyer`: Restricts the buy operation to a specific account.
 		///
 		/// Emits `ItemPriceSet` on success if the price is not `None`.
@@ -1669,11 +1897,12 @@ pub mod pallet {
 			collection: T::CollectionId,
 			item: T::ItemId,
 			price: Option<ItemPrice<T, I>>,
+			asset: Option<AssetIdOf<T, I>>,
 			whitelisted_buyer: Option<AccountIdLookupOf<T>>,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
 			let whitelisted_buyer = whitelisted_buyer.map(T::Lookup::lookup).transpose()?;
-			Self::do_set_price(collection, item, origin, price, whitelisted_buyer)
+			Self::do_set_price(collection, item, origin, price, asset, whitelisted_buyer)
 		}
 
 		/// Allows to buy an item if it's up for sale.
@@ -1686,7 +1915,10 @@ pub mod pallet {
 		///
 		/// Emits `ItemBought` on success.
 		#[pallet::call_index(32)]
-		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[pallet::weight(
+			T::WeightInfo::buy_item(T::MaxRoyaltyRecipients::get())
+				.max(T::WeightInfo::buy_item_priced_in_asset(T::MaxRoyaltyRecipients::get()))
+		)]
 		pub fn buy_item(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1786,7 +2018,7 @@ pub mod pallet {
 		///
 		/// Emits `SwapClaimed` on success.
 		#[pallet::call_index(36)]
-		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[pallet::weight(T::WeightInfo::claim_swap(T::MaxRoyaltyRecipients::get()))]
 		pub fn claim_swap(
 			origin: OriginFor<T>,
 			send_collection: T::CollectionId,
@@ -1859,6 +2091,244 @@ pub mod pallet {
 			ensure!(signature.verify(&*msg, &signer), Error::<T, I>::WrongSignature);
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Set the royalties paid when an item of a collection is sold.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// The royalties apply to all items of the collection that have no royalties of their own.
+		///
+		/// - `collection`: The identifier of the collection whose royalties to set.
+		/// - `royalties`: The recipients and their shares of the sale price. The total share is
+		///   limited by `MaxRoyalty`. An empty list removes the royalties.
+		///
+		/// Emits `CollectionRoyaltiesSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_collection_royalties())]
+		pub fn set_collection_royalties(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			royalties: RoyaltiesOf<T, I>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_collection_royalties(maybe_check_owner, collection, royalties)
+		}
+
+		/// Set the royalties paid when an item is sold.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// The royalties override the royalties of the collection.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item whose royalties to set.
+		/// - `royalties`: The recipients and their shares of the sale price. The total share is
+		///   limited by `MaxRoyalty`. An empty list removes the royalties.
+		///
+		/// Emits `ItemRoyaltiesSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_item_royalties())]
+		pub fn set_item_royalties(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			royalties: RoyaltiesOf<T, I>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_item_royalties(maybe_check_owner, collection, item, royalties)
+		}
+
+		/// Put an item up for auction.
+		///
+		/// Origin must be Signed and must be the owner of the `item`.
+		///
+		/// The item is locked for transfers until the auction is settled or cancelled. The items
+		/// nested into it are sold along with it and can't be nested or unnested in the meantime.
+		/// Nested items and the items of soulbound collections can't be auctioned.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to be auctioned.
+		/// - `kind`: The kind of the auction, English or Dutch, and its parameters.
+		/// - `duration`: The number of blocks the auction accepts bids for. Should be less than or
+		///   equal to `MaxDeadlineDuration`.
+		///
+		/// Emits `AuctionCreated` on success.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			kind: AuctionKindOf<T, I>,
+			duration: <T as SystemConfig>::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_create_auction(origin, collection, item, kind, duration)
+		}
+
+		/// Bid on an auctioned item.
+		///
+		/// Origin must be Signed and must not be the seller.
+		///
+		/// In an English auction the bid is reserved until the auction is settled or the bid is
+		/// outbid. A bid shortly before the end of the auction extends the auction.
+		///
+		/// In a Dutch auction the first bid that matches the current price wins the item, the
+		/// current price is paid immediately.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The auctioned item.
+		/// - `amount`: The bid in an English auction, the max price to pay in a Dutch auction.
+		///
+		/// Emits `BidPlaced` in an English auction, `AuctionSettled` in a Dutch auction.
+		#[pallet::call_index(42)]
+		#[pallet::weight(
+			T::WeightInfo::bid()
+				.max(T::WeightInfo::dutch_bid(T::MaxRoyaltyRecipients::get()))
+		)]
+		pub fn bid(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			amount: ItemPrice<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let weight = match Self::do_bid(origin, collection, item, amount)? {
+				Some(royalties) => T::WeightInfo::dutch_bid(royalties),
+				None => T::WeightInfo::bid(),
+			};
+			Ok(Some(weight).into())
+		}
+
+		/// Settle an ended auction.
+		///
+		/// Origin must be Signed or Root, so that the settlement can be scheduled. Auctions are
+		/// also settled automatically at the start of the block they end at.
+		///
+		/// The highest bid is paid to the seller, after paying out the royalties of the item, and
+		/// the item is transferred to the highest bidder. Without bids the item stays with the
+		/// seller. If this fails, e.g. because the item has been locked in the meantime, the
+		/// highest bid is refunded and the item stays with the seller as well.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The auctioned item.
+		///
+		/// Emits `AuctionSettled` on success, `AuctionSettlementFailed` if the bid was refunded.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::settle_auction(T::MaxRoyaltyRecipients::get()))]
+		pub fn settle_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			ensure_signed_or_root(origin)?;
+			Self::do_settle_auction(collection, item)
+		}
+
+		/// Cancel an auction without bids.
+		///
+		/// Origin must be Signed and must be the seller.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The auctioned item.
+		///
+		/// Emits `AuctionCancelled` on success.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_auction(origin, collection, item)
+		}
+
+		/// Nest an item into another item.
+		///
+		/// The nested item is owned by an account derived from the parent item and moves along
+		/// with it until it's unnested.
+		///
+		/// Origin must be Signed and the sender must own both items, the parent item directly or
+		/// through the items it's nested into. Items can't be nested into auctioned items, or
+		/// into the items nested into them.
+		///
+		/// - `collection`: The collection of the item to nest.
+		/// - `item`: The item to nest.
+		/// - `parent_collection`: The collection of the parent item.
+		/// - `parent_item`: The item to nest the `item` into.
+		///
+		/// Emits `ItemNested` on success.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::nest())]
+		pub fn nest(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			parent_collection: T::CollectionId,
+			parent_item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_nest(origin, collection, item, parent_collection, parent_item)
+		}
+
+		/// Take a nested item out of its parent item and transfer it to the sender.
+		///
+		/// Origin must be Signed and the sender must own the item the `item` is nested into,
+		/// directly or through the items it's nested into. Items can't be taken out of auctioned
+		/// items.
+		///
+		/// - `collection`: The collection of the nested item.
+		/// - `item`: The nested item.
+		///
+		/// Emits `ItemUnnested` on success.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::unnest())]
+		pub fn unnest(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_unnest(origin, collection, item)
+		}
+
+		/// Move an item to another account, regardless of the collection being soulbound.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Allows to recover the items of soulbound collections. Items that are locked or nested
+		/// into another item can't be moved.
+		///
+		/// - `collection`: The collection of the item to be transferred.
+		/// - `item`: The item to be transferred.
+		/// - `dest`: The account to receive ownership of the item.
+		///
+		/// Emits `Transferred`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::force_transfer())]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			dest: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_force_transfer(collection, item, dest)
+		}
 	}
 }
 
@@ -0,0 +1,136 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{log, traits::OnRuntimeUpgrade};
+
+pub mod v1 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct OldMintSettings<Price, BlockNumber, CollectionId> {
+		pub mint_type: MintType<CollectionId>,
+		pub price: Option<Price>,
+		pub start_block: Option<BlockNumber>,
+		pub end_block: Option<BlockNumber>,
+		pub default_item_settings: ItemSettings,
+	}
+
+	#[derive(Decode)]
+	pub struct OldCollectionConfig<Price, BlockNumber, CollectionId> {
+		pub settings: CollectionSettings,
+		pub max_supply: Option<u32>,
+		pub mint_settings: OldMintSettings<Price, BlockNumber, CollectionId>,
+	}
+
+	impl<Price, BlockNumber, CollectionId> OldCollectionConfig<Price, BlockNumber, CollectionId> {
+		fn migrate_to_v1<AssetId>(
+			self,
+		) -> CollectionConfig<Price, BlockNumber, CollectionId, AssetId> {
+			let mint_settings = MintSettings {
+				mint_type: self.mint_settings.mint_type,
+				price: self.mint_settings.price,
+				start_block: self.mint_settings.start_block,
+				end_block: self.mint_settings.end_block,
+				default_item_settings: self.mint_settings.default_item_settings,
+				price_asset: None,
+			};
+
+			CollectionConfig { settings: self.settings, max_supply: self.max_supply, mint_settings }
+		}
+	}
+
+	/// Prices the mints of all collections and all the items for sale in `Currency`.
+	pub struct MigrateToV1<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T, I>::current_storage_version();
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version == 0 && current_version == 1 {
+				let mut translated = 0u64;
+				CollectionConfigOf::<T, I>::translate::<
+					OldCollectionConfig<
+						BalanceOf<T, I>,
+						<T as SystemConfig>::BlockNumber,
+						T::CollectionId,
+					>,
+					_,
+				>(|_key, old_value| {
+					translated.saturating_inc();
+					Some(old_value.migrate_to_v1())
+				});
+				ItemPriceOf::<T, I>::translate::<(ItemPrice<T, I>, Option<T::AccountId>), _>(
+					|_collection, _item, (price, whitelisted_buyer)| {
+						translated.saturating_inc();
+						Some((price, whitelisted_buyer, None))
+					},
+				);
+				current_version.put::<Pallet<T, I>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Upgraded {} collection configs and item prices, storage to version {:?}",
+					translated,
+					current_version
+				);
+				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 0,
+				"must upgrade linearly"
+			);
+			let configs = CollectionConfigOf::<T, I>::iter_keys().count() as u32;
+			let prices = ItemPriceOf::<T, I>::iter_keys().count() as u32;
+			Ok((configs, prices).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_counts: Vec<u8>) -> Result<(), &'static str> {
+			let (prev_configs, prev_prices): (u32, u32) = Decode::decode(
+				&mut prev_counts.as_slice(),
+			)
+			.expect("the state parameter should be something that was generated by pre_upgrade");
+			let configs = CollectionConfigOf::<T, I>::iter().count() as u32;
+			let prices = ItemPriceOf::<T, I>::iter().count() as u32;
+			assert_eq!(
+				prev_configs, configs,
+				"the collection config count before and after the migration should be the same"
+			);
+			assert_eq!(
+				prev_prices, prices,
+				"the item price count before and after the migration should be the same"
+			);
+
+			frame_support::ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 1,
+				"must upgrade to version 1"
+			);
+			Ok(())
+		}
+	}
+}
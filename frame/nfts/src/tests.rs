@@ -23,13 +23,13 @@ use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::Dispatchable,
 	traits::{
-		tokens::nonfungibles_v2::{Destroy, Mutate},
-		Currency, Get,
+		tokens::nonfungibles_v2::{Destroy, Inspect, Mutate, Transfer},
+		Currency, Get, Hooks,
 	},
 };
 use pallet_balances::Error as BalancesError;
 use sp_core::{bounded::BoundedVec, Pair};
-use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner};
+use sp_runtime::{traits::IdentifyAccount, DispatchError, MultiSignature, MultiSigner};
 use sp_std::prelude::*;
 
 type AccountIdOf<Test> = <Test as frame_system::Config>::AccountId;
@@ -2104,6 +2104,7 @@ fn set_price_should_work() {
 			item_1,
 			Some(1),
 			None,
+			None,
 		));
 
 		assert_ok!(Nfts::set_price(
@@ -2111,6 +2112,7 @@ fn set_price_should_work() {
 			collection_id,
 			item_2,
 			Some(2),
+			None,
 			Some(account(3)),
 		));
 
@@ -2126,6 +2128,7 @@ fn set_price_should_work() {
 			collection: collection_id,
 			item: item_1,
 			price: 1,
+			asset: None,
 			whitelisted_buyer: None,
 		}));
 
@@ -2135,6 +2138,7 @@ fn set_price_should_work() {
 			collection_id,
 			item_2,
 			None,
+			None,
 			None
 		));
 		assert!(events().contains(&Event::<Test>::ItemPriceRemoved {
@@ -2167,6 +2171,7 @@ fn set_price_should_work() {
 				collection_id,
 				item_1,
 				Some(2),
+				None,
 				None
 			),
 			Error::<Test>::ItemsNonTransferable
@@ -2226,6 +2231,7 @@ fn buy_item_should_work() {
 			item_1,
 			Some(price_1),
 			None,
+			None,
 		));
 
 		assert_ok!(Nfts::set_price(
@@ -2233,6 +2239,7 @@ fn buy_item_should_work() {
 			collection_id,
 			item_2,
 			Some(price_2),
+			None,
 			Some(user_3.clone()),
 		));
 
@@ -2280,6 +2287,7 @@ fn buy_item_should_work() {
 			collection: collection_id,
 			item: item_2,
 			price: price_2,
+			asset: None,
 			seller: user_1.clone(),
 			buyer: user_3.clone(),
 		}));
@@ -2301,6 +2309,7 @@ fn buy_item_should_work() {
 				item_3,
 				Some(price_1),
 				None,
+				None,
 			));
 
 			// lock the collection
@@ -2876,6 +2885,7 @@ fn pallet_level_feature_flags_should_work() {
 				collection_id,
 				item_id,
 				Some(1),
+				None,
 				None
 			),
 			Error::<Test>::MethodDisabled
@@ -3484,3 +3494,1574 @@ fn pre_signed_attributes_should_work() {
 		);
 	})
 }
+
+fn royalties(recipients: Vec<(AccountIdOf<Test>, u32)>) -> RoyaltiesOf<Test> {
+	recipients
+		.into_iter()
+		.map(|(account, percent)| RoyaltyRecipient {
+			account,
+			percentage: Permill::from_percent(percent),
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn set_royalties_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_id = 1;
+		let locked_item_id = 2;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_2.clone(),
+			None,
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			locked_item_id,
+			user_2.clone(),
+			item_config_from_disabled_settings(ItemSetting::UnlockedRoyalties.into()),
+		));
+
+		let collection_royalties = royalties(vec![(user_1.clone(), 5), (user_3.clone(), 5)]);
+		assert_noop!(
+			Nfts::set_collection_royalties(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				collection_royalties.clone(),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_collection_royalties(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				royalties(vec![(user_1.clone(), 15), (user_3.clone(), 10)]),
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_ok!(Nfts::set_collection_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			collection_royalties.clone(),
+		));
+		assert!(events().contains(&Event::<Test>::CollectionRoyaltiesSet {
+			collection: collection_id,
+			royalties: collection_royalties.clone(),
+		}));
+		assert_eq!(
+			<Nfts as Inspect<_>>::royalties(&collection_id, &item_id),
+			vec![
+				(user_1.clone(), Permill::from_percent(5)),
+				(user_3.clone(), Permill::from_percent(5))
+			]
+		);
+
+		// item royalties override the collection royalties
+		let item_royalties = royalties(vec![(user_3.clone(), 20)]);
+		assert_ok!(Nfts::set_item_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			item_royalties.clone(),
+		));
+		assert_eq!(Nfts::royalties(&collection_id, &item_id), item_royalties);
+		assert_eq!(Nfts::royalties(&collection_id, &locked_item_id), collection_royalties);
+
+		assert_noop!(
+			Nfts::set_item_royalties(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				locked_item_id,
+				item_royalties.clone(),
+			),
+			Error::<Test>::LockedItemRoyalties
+		);
+		assert_ok!(Nfts::set_item_royalties(
+			RuntimeOrigin::root(),
+			collection_id,
+			locked_item_id,
+			item_royalties.clone(),
+		));
+
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedRoyalties.into())
+		));
+		assert_noop!(
+			Nfts::set_collection_royalties(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				royalties(vec![]),
+			),
+			Error::<Test>::LockedCollectionRoyalties
+		);
+
+		// an empty list removes the royalties
+		assert_ok!(Nfts::set_collection_royalties(
+			RuntimeOrigin::root(),
+			collection_id,
+			royalties(vec![]),
+		));
+		assert!(!CollectionRoyaltiesOf::<Test>::contains_key(collection_id));
+		assert_ok!(Nfts::set_item_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			royalties(vec![]),
+		));
+		assert!(<Nfts as Inspect<_>>::royalties(&collection_id, &item_id).is_empty());
+
+		// locked item royalties survive burning the item
+		assert_ok!(Nfts::burn(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			locked_item_id,
+			None
+		));
+		assert!(ItemRoyaltiesOf::<Test>::contains_key(collection_id, locked_item_id));
+	});
+}
+
+#[test]
+fn buy_item_should_pay_royalties() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let user_4 = account(4);
+		let collection_id = 0;
+		let item_id = 1;
+		let price = 100;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+		Balances::make_free_balance_be(&user_4, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_2.clone(),
+			None,
+		));
+		assert_ok!(Nfts::set_collection_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			royalties(vec![(user_1.clone(), 10), (user_4.clone(), 5)]),
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+			None,
+		));
+
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+
+		assert_eq!(Balances::total_balance(&user_3), initial_balance - price);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + 85);
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 10);
+		assert_eq!(Balances::total_balance(&user_4), initial_balance + 5);
+		let events = events();
+		assert!(events.contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_id,
+			recipient: user_4.clone(),
+			amount: 5,
+		}));
+		assert!(events.contains(&Event::<Test>::ItemBought {
+			collection: collection_id,
+			item: item_id,
+			price,
+			asset: None,
+			seller: user_2,
+			buyer: user_3,
+		}));
+	});
+}
+
+#[test]
+fn claim_swap_should_pay_royalties() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let price = 100;
+		let initial_balance = 1000;
+		let price_with_direction =
+			PriceWithDirection { amount: price, direction: PriceDirection::Send };
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_3.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_1,
+			user_1.clone(),
+			None,
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_2,
+			user_2.clone(),
+			None,
+		));
+		assert_ok!(Nfts::set_item_royalties(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_2,
+			royalties(vec![(user_3.clone(), 20)]),
+		));
+
+		// user 1 offers item 1 and the price for item 2
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			collection_id,
+			Some(item_2),
+			Some(price_with_direction.clone()),
+			2,
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_2,
+			collection_id,
+			item_1,
+			Some(price_with_direction),
+		));
+
+		// the royalties of the sold item 2 are paid out of the price
+		assert_eq!(Balances::total_balance(&user_1), initial_balance - price);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + 80);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 20);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_2,
+			recipient: user_3,
+			amount: 20,
+		}));
+	});
+}
+
+#[test]
+fn royalties_below_existential_deposit_should_go_to_the_seller() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let user_4 = account(4);
+		let user_5 = account(5);
+		let collection_id = 0;
+		let item_id = 1;
+		let asset_id = 0;
+		let price = 100;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			asset_id.into(),
+			user_1.clone(),
+			true,
+			10,
+			true
+		));
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			asset_id.into(),
+			user_2.clone(),
+			initial_balance
+		));
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			asset_id.into(),
+			user_3.clone(),
+			initial_balance
+		));
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_2.clone(),
+			None,
+		));
+		assert_ok!(Nfts::set_collection_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			royalties(vec![(user_4.clone(), 10), (user_5.clone(), 5)]),
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			Some(asset_id),
+			None,
+		));
+
+		// the royalty of `user_5` would not bring it up to the minimum balance of the asset
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+		assert_eq!(Assets::balance(asset_id, &user_3), initial_balance - price);
+		assert_eq!(Assets::balance(asset_id, &user_2), initial_balance + 90);
+		assert_eq!(Assets::balance(asset_id, &user_4), 10);
+		assert_eq!(Assets::balance(asset_id, &user_5), 0);
+		let events = events();
+		assert!(events.contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_id,
+			recipient: user_4,
+			amount: 10,
+		}));
+		assert!(!events.iter().any(|event| matches!(
+			event,
+			Event::<Test>::RoyaltyPaid { recipient, .. } if *recipient == user_5
+		)));
+	});
+}
+
+#[test]
+fn english_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_id = 1;
+		let initial_balance = 1000;
+		let kind = AuctionKind::English { reserve_price: 50, min_increment: 10, extension: 3 };
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None,
+		));
+
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_id,
+				kind.clone(),
+				10,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				kind.clone(),
+				10001,
+			),
+			Error::<Test>::WrongDuration
+		);
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			kind.clone(),
+			10,
+		));
+		assert!(events().contains(&Event::<Test>::AuctionCreated {
+			collection: collection_id,
+			item: item_id,
+			seller: user_1.clone(),
+			kind,
+			start: 1,
+			end: 11,
+		}));
+
+		// the item can't change hands during the auction
+		assert!(!<Nfts as Inspect<AccountIdOf<Test>>>::can_transfer(&collection_id, &item_id));
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				user_2.clone()
+			),
+			Error::<Test>::ItemAuctioned
+		);
+		assert_noop!(
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				Some(1),
+				None,
+				None,
+			),
+			Error::<Test>::ItemAuctioned
+		);
+
+		assert_noop!(
+			Nfts::bid(RuntimeOrigin::signed(user_1.clone()), collection_id, item_id, 50),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::bid(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id, 49),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Nfts::bid(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id, 50));
+		assert_eq!(Balances::reserved_balance(&user_2), 50);
+
+		assert_noop!(
+			Nfts::bid(RuntimeOrigin::signed(user_3.clone()), collection_id, item_id, 55),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Nfts::bid(RuntimeOrigin::signed(user_3.clone()), collection_id, item_id, 60));
+		assert_eq!(Balances::reserved_balance(&user_2), 0);
+		assert_eq!(Balances::reserved_balance(&user_3), 60);
+
+		assert_noop!(
+			Nfts::cancel_auction(RuntimeOrigin::signed(user_1.clone()), collection_id, item_id),
+			Error::<Test>::AuctionHasBids
+		);
+
+		// a bid shortly before the end extends the auction
+		System::set_block_number(9);
+		assert_ok!(Nfts::bid(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id, 70));
+		assert_eq!(Balances::reserved_balance(&user_3), 0);
+		assert!(events().contains(&Event::<Test>::BidPlaced {
+			collection: collection_id,
+			item: item_id,
+			bidder: user_2.clone(),
+			amount: 70,
+			end: 12,
+		}));
+
+		// nothing happens at the original end
+		System::set_block_number(11);
+		Nfts::on_initialize(11);
+		assert!(AuctionOf::<Test>::contains_key(collection_id, item_id));
+		assert_noop!(
+			Nfts::settle_auction(RuntimeOrigin::signed(user_3.clone()), collection_id, item_id),
+			Error::<Test>::AuctionNotEnded
+		);
+		assert_noop!(
+			Nfts::bid(RuntimeOrigin::signed(user_3.clone()), collection_id, item_id, 75),
+			Error::<Test>::BidTooLow
+		);
+
+		// the auction is settled at the start of the block it ends at
+		System::set_block_number(12);
+		let seller_balance = Balances::free_balance(&user_1);
+		Nfts::on_initialize(12);
+		assert!(!AuctionOf::<Test>::contains_key(collection_id, item_id));
+		assert_eq!(Item::<Test>::get(collection_id, item_id).unwrap().owner, user_2);
+		assert_eq!(Balances::free_balance(&user_1), seller_balance + 70);
+		assert_eq!(Balances::free_balance(&user_2), initial_balance - 70);
+		assert!(events().contains(&Event::<Test>::AuctionSettled {
+			collection: collection_id,
+			item: item_id,
+			seller: user_1.clone(),
+			winner: Some(user_2.clone()),
+			price: Some(70),
+		}));
+
+		// the item is transferable again
+		assert_ok!(Nfts::transfer(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			user_3.clone()
+		));
+	});
+}
+
+#[test]
+fn dutch_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let item_id = 1;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None,
+		));
+
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				AuctionKind::Dutch { start_price: 100, end_price: 101 },
+				10,
+			),
+			Error::<Test>::WrongAuctionPrice
+		);
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			AuctionKind::Dutch { start_price: 100, end_price: 0 },
+			10,
+		));
+
+		// the price decays linearly
+		System::set_block_number(6);
+		let auction = AuctionOf::<Test>::get(collection_id, item_id).unwrap();
+		assert_eq!(Nfts::auction_price(&auction, 6), 50);
+		assert_noop!(
+			Nfts::bid(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id, 49),
+			Error::<Test>::BidTooLow
+		);
+
+		// the first matching bid wins and pays the current price
+		let seller_balance = Balances::free_balance(&user_1);
+		assert_ok!(Nfts::bid(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id, 60));
+		assert!(!AuctionOf::<Test>::contains_key(collection_id, item_id));
+		assert_eq!(Item::<Test>::get(collection_id, item_id).unwrap().owner, user_2);
+		assert_eq!(Balances::free_balance(&user_1), seller_balance + 50);
+		assert_eq!(Balances::free_balance(&user_2), initial_balance - 50);
+		assert!(events().contains(&Event::<Test>::AuctionSettled {
+			collection: collection_id,
+			item: item_id,
+			seller: user_1,
+			winner: Some(user_2),
+			price: Some(50),
+		}));
+	});
+}
+
+#[test]
+fn auctions_should_pay_royalties_to_new_accounts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_id = 1;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None,
+		));
+		assert_ok!(Nfts::set_item_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			royalties(vec![(user_3.clone(), 10)]),
+		));
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			AuctionKind::Dutch { start_price: 100, end_price: 100 },
+			10,
+		));
+
+		// the royalty is paid out of the reserved bid, even though its recipient doesn't exist
+		let seller_balance = Balances::free_balance(&user_1);
+		assert_ok!(Nfts::bid(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id, 100));
+		assert_eq!(Item::<Test>::get(collection_id, item_id).unwrap().owner, user_2);
+		assert_eq!(Balances::free_balance(&user_1), seller_balance + 90);
+		assert_eq!(Balances::free_balance(&user_2), initial_balance - 100);
+		assert_eq!(Balances::free_balance(&user_3), 10);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_id,
+			recipient: user_3,
+			amount: 10,
+		}));
+	});
+}
+
+#[test]
+fn auctions_without_bids_should_return_the_item() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let kind = AuctionKind::English { reserve_price: 50, min_increment: 10, extension: 0 };
+
+		Balances::make_free_balance_be(&user_1, 100);
+		Balances::make_free_balance_be(&user_2, 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		for item_id in 1..=3 {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				user_1.clone(),
+				None,
+			));
+		}
+
+		Features::set(&PalletFeatures::from_disabled(PalletFeature::Auctions.into()));
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				1,
+				kind.clone(),
+				10,
+			),
+			Error::<Test>::MethodDisabled
+		);
+		Features::set(&PalletFeatures::all_enabled());
+
+		for item_id in 1..=3 {
+			assert_ok!(Nfts::create_auction(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				kind.clone(),
+				10,
+			));
+		}
+
+		// the seller can cancel an auction without bids
+		assert_noop!(
+			Nfts::cancel_auction(RuntimeOrigin::signed(user_2.clone()), collection_id, 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::cancel_auction(RuntimeOrigin::signed(user_1.clone()), collection_id, 1));
+		assert!(events()
+			.contains(&Event::<Test>::AuctionCancelled { collection: collection_id, item: 1 }));
+
+		// burning the item refunds the bid
+		assert_ok!(Nfts::bid(RuntimeOrigin::signed(user_2.clone()), collection_id, 2, 50));
+		assert_eq!(Balances::reserved_balance(&user_2), 50);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, 2, None));
+		assert_eq!(Balances::reserved_balance(&user_2), 0);
+		assert!(!AuctionOf::<Test>::contains_key(collection_id, 2));
+
+		// an ended auction without bids can be settled by anyone
+		System::set_block_number(11);
+		assert_noop!(
+			Nfts::bid(RuntimeOrigin::signed(user_2.clone()), collection_id, 3, 50),
+			Error::<Test>::AuctionEnded
+		);
+		assert_ok!(Nfts::settle_auction(RuntimeOrigin::signed(user_2.clone()), collection_id, 3));
+		assert_eq!(Item::<Test>::get(collection_id, 3).unwrap().owner, user_1);
+		assert!(events().contains(&Event::<Test>::AuctionSettled {
+			collection: collection_id,
+			item: 3,
+			seller: user_1.clone(),
+			winner: None,
+			price: None,
+		}));
+
+		for item_id in 1..=3 {
+			assert!(!ItemConfigOf::<Test>::get(collection_id, item_id)
+				.map_or(false, |c| c.has_disabled_setting(ItemSetting::Transferable)));
+		}
+	});
+}
+
+#[test]
+fn failed_auction_settlement_should_refund_the_bid() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let item_id = 1;
+		let initial_balance = 1000;
+		let kind = AuctionKind::English { reserve_price: 50, min_increment: 10, extension: 0 };
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None,
+		));
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			kind,
+			10,
+		));
+		assert_ok!(Nfts::bid(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id, 50));
+
+		// the freezer locks the item during the auction
+		assert_ok!(Nfts::lock_item_transfer(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id
+		));
+
+		System::set_block_number(11);
+		Nfts::on_initialize(11);
+		assert!(!AuctionOf::<Test>::contains_key(collection_id, item_id));
+		assert_eq!(Item::<Test>::get(collection_id, item_id).unwrap().owner, user_1);
+		assert_eq!(Balances::reserved_balance(&user_2), 0);
+		assert_eq!(Balances::free_balance(&user_2), initial_balance);
+		assert!(events().contains(&Event::<Test>::AuctionSettlementFailed {
+			collection: collection_id,
+			item: item_id,
+			error: Error::<Test>::ItemLocked.into(),
+		}));
+
+		// the lock of the freezer is kept
+		assert!(ItemConfigOf::<Test>::get(collection_id, item_id)
+			.unwrap()
+			.has_disabled_setting(ItemSetting::Transferable));
+	});
+}
+
+#[test]
+fn pay_in_assets_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let user_4 = account(4);
+		let collection_id = 0;
+		let item_id = 1;
+		let asset_id = 0;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			asset_id.into(),
+			user_1.clone(),
+			true,
+			1,
+			true
+		));
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			asset_id.into(),
+			user_2.clone(),
+			initial_balance
+		));
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			asset_id.into(),
+			user_3.clone(),
+			initial_balance
+		));
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+
+		// mints can only be priced in known assets
+		let mint_settings = |price_asset: Option<u32>| MintSettings {
+			mint_type: MintType::Public,
+			price: Some(10),
+			price_asset,
+			..Default::default()
+		};
+		assert_noop!(
+			Nfts::update_mint_settings(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				mint_settings(Some(asset_id + 1))
+			),
+			Error::<Test>::UnknownAsset
+		);
+		assert_ok!(Nfts::update_mint_settings(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			mint_settings(Some(asset_id))
+		));
+
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			user_2.clone(),
+			None,
+		));
+		assert_eq!(Assets::balance(asset_id, &user_2), initial_balance - 10);
+		assert_eq!(Assets::balance(asset_id, &user_1), 10);
+
+		// items can only be priced in known assets
+		assert_noop!(
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_id,
+				Some(100),
+				Some(asset_id + 1),
+				None,
+			),
+			Error::<Test>::UnknownAsset
+		);
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			Some(100),
+			Some(asset_id),
+			None,
+		));
+		assert!(events().contains(&Event::<Test>::ItemPriceSet {
+			collection: collection_id,
+			item: item_id,
+			price: 100,
+			asset: Some(asset_id),
+			whitelisted_buyer: None,
+		}));
+
+		// royalties are paid in the asset of the price
+		assert_ok!(Nfts::set_collection_royalties(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			royalties(vec![(user_4.clone(), 10)]),
+		));
+
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_id,
+			100
+		));
+		assert_eq!(Nfts::owner(collection_id, item_id), Some(user_3.clone()));
+		assert_eq!(Assets::balance(asset_id, &user_3), initial_balance - 100);
+		assert_eq!(Assets::balance(asset_id, &user_2), initial_balance - 10 + 90);
+		assert_eq!(Assets::balance(asset_id, &user_4), 10);
+		assert_eq!(Balances::free_balance(&user_3), initial_balance);
+		assert!(events().contains(&Event::<Test>::ItemBought {
+			collection: collection_id,
+			item: item_id,
+			price: 100,
+			asset: Some(asset_id),
+			seller: user_2,
+			buyer: user_3,
+		}));
+	});
+}
+
+#[test]
+fn nest_and_unnest_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let character = 1;
+		let sword = 2;
+		let armour = 3;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		for item in [character, sword, armour] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_1.clone(),
+				None,
+			));
+		}
+
+		// only the owner of both items can nest them
+		assert_noop!(
+			Nfts::nest(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				sword,
+				collection_id,
+				character
+			),
+			Error::<Test>::NoPermission
+		);
+		for item in [sword, armour] {
+			assert_ok!(Nfts::nest(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				collection_id,
+				character
+			));
+		}
+		assert!(events().contains(&Event::<Test>::ItemNested {
+			collection: collection_id,
+			item: sword,
+			parent_collection: collection_id,
+			parent_item: character,
+		}));
+		let character_account = Nfts::nesting_account(collection_id, character);
+		assert_eq!(Nfts::owner(collection_id, sword), Some(character_account.clone()));
+		assert_eq!(Nfts::root_owner(collection_id, sword), Some(user_1.clone()));
+
+		// nested items can't be moved on their own
+		assert!(!<Nfts as Inspect<AccountIdOf<Test>>>::can_transfer(&collection_id, &sword));
+		assert!(<Nfts as Inspect<AccountIdOf<Test>>>::can_transfer(&collection_id, &character));
+		assert_noop!(
+			<Nfts as Transfer<AccountIdOf<Test>>>::transfer(&collection_id, &sword, &user_2),
+			Error::<Test>::ItemNested
+		);
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				sword,
+				user_2.clone()
+			),
+			Error::<Test>::ItemNested
+		);
+
+		// the nested items move along with the parent, also when it's transferred by a delegate
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			character,
+			user_3.clone(),
+			None
+		));
+		assert_ok!(Nfts::transfer(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			character,
+			user_2.clone()
+		));
+		assert_eq!(Nfts::owner(collection_id, sword), Some(character_account));
+		assert_eq!(Nfts::root_owner(collection_id, sword), Some(user_2.clone()));
+		assert_eq!(Nfts::root_owner(collection_id, armour), Some(user_2.clone()));
+
+		// items with nested items can't be burned
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(user_2.clone()), collection_id, character, None),
+			Error::<Test>::HasChildren
+		);
+
+		assert_noop!(
+			Nfts::unnest(RuntimeOrigin::signed(user_1.clone()), collection_id, sword),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::unnest(RuntimeOrigin::signed(user_2.clone()), collection_id, character),
+			Error::<Test>::NotNested
+		);
+		assert_ok!(Nfts::unnest(RuntimeOrigin::signed(user_2.clone()), collection_id, sword));
+		assert_eq!(Nfts::owner(collection_id, sword), Some(user_2.clone()));
+		assert!(events().contains(&Event::<Test>::ItemUnnested {
+			collection: collection_id,
+			item: sword,
+			parent_collection: collection_id,
+			parent_item: character,
+		}));
+
+		// burning a nested item removes it from its parent
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, armour, None));
+		assert!(!ChildrenOf::<Test>::contains_key(collection_id, character));
+		assert_ok!(Nfts::burn(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			character,
+			None
+		));
+
+		// locked items can't be nested
+		assert_ok!(Nfts::lock_item_transfer(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			sword
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			character,
+			user_2.clone(),
+			None,
+		));
+		assert_noop!(
+			Nfts::nest(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				sword,
+				collection_id,
+				character
+			),
+			Error::<Test>::ItemLocked
+		);
+	});
+}
+
+#[test]
+fn nesting_limits_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_id = account(1);
+		let collection_id = 0;
+		let nest = |item, parent| {
+			Nfts::nest(
+				RuntimeOrigin::signed(user_id.clone()),
+				collection_id,
+				item,
+				collection_id,
+				parent,
+			)
+		};
+		let height = |item| ChildrenOf::<Test>::get(collection_id, item).map_or(0, |c| c.height);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_id.clone(),
+			default_collection_config()
+		));
+		for item in 1..=6 {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_id.clone()),
+				collection_id,
+				item,
+				user_id.clone(),
+				None,
+			));
+		}
+
+		assert_noop!(nest(1, 1), Error::<Test>::NestingCycle);
+		assert_ok!(nest(2, 1));
+		assert_ok!(nest(3, 2));
+		assert_eq!(height(1), 2);
+		assert_noop!(nest(4, 3), Error::<Test>::NestingTooDeep);
+
+		// items with nested items can be nested as long as the depth limit is kept
+		assert_ok!(nest(4, 5));
+		assert_noop!(nest(5, 2), Error::<Test>::NestingTooDeep);
+		assert_ok!(nest(5, 1));
+		assert_noop!(nest(6, 1), Error::<Test>::TooManyChildren);
+
+		// the heights are updated when items are taken out
+		assert_ok!(Nfts::unnest(RuntimeOrigin::signed(user_id.clone()), collection_id, 3));
+		assert_eq!(height(2), 0);
+		assert_eq!(height(1), 2);
+		assert_ok!(Nfts::unnest(RuntimeOrigin::signed(user_id.clone()), collection_id, 4));
+		assert_eq!(height(1), 1);
+		assert_ok!(nest(1, 3));
+		assert_noop!(nest(3, 2), Error::<Test>::NestingCycle);
+
+		// nesting can be disabled
+		Features::set(&PalletFeatures::from_disabled(PalletFeature::Nesting.into()));
+		assert_noop!(nest(6, 4), Error::<Test>::MethodDisabled);
+	});
+}
+
+#[test]
+fn nested_items_should_not_be_locked() {
+	new_test_ext().execute_with(|| {
+		let user_id = account(1);
+		let collection_id = 0;
+		let parent_collection = 1;
+		let item_id = 1;
+		let parent_item = 1;
+
+		for collection in [collection_id, parent_collection] {
+			assert_ok!(Nfts::force_create(
+				RuntimeOrigin::root(),
+				user_id.clone(),
+				default_collection_config()
+			));
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_id.clone()),
+				collection,
+				item_id,
+				user_id.clone(),
+				None,
+			));
+		}
+		assert_ok!(Nfts::nest(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_id,
+			parent_collection,
+			parent_item
+		));
+
+		// the nested item would move along with its parent despite the lock
+		assert_noop!(
+			Nfts::lock_item_transfer(
+				RuntimeOrigin::signed(user_id.clone()),
+				collection_id,
+				item_id
+			),
+			Error::<Test>::ItemNested
+		);
+		for setting in [CollectionSetting::TransferableItems, CollectionSetting::UnboundItems] {
+			assert_noop!(
+				Nfts::lock_collection(
+					RuntimeOrigin::signed(user_id.clone()),
+					collection_id,
+					CollectionSettings::from_disabled(setting.into())
+				),
+				Error::<Test>::ItemNested
+			);
+			assert_noop!(
+				Nfts::force_collection_config(
+					RuntimeOrigin::root(),
+					collection_id,
+					collection_config_from_disabled_settings(setting.into())
+				),
+				Error::<Test>::ItemNested
+			);
+		}
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedMetadata.into())
+		));
+
+		// collections with nested items can't be destroyed
+		let witness = Nfts::get_destroy_witness(&collection_id).unwrap();
+		assert_noop!(
+			Nfts::destroy(RuntimeOrigin::signed(user_id.clone()), collection_id, witness),
+			Error::<Test>::ItemNested
+		);
+
+		// the locks apply once the item is taken out
+		assert_ok!(Nfts::unnest(RuntimeOrigin::signed(user_id.clone()), collection_id, item_id));
+		assert_ok!(Nfts::lock_item_transfer(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_id
+		));
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into())
+		));
+	});
+}
+
+#[test]
+fn soulbound_collections_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let user_4 = account(4);
+		let soulbound_collection = 0;
+		let collection_id = 1;
+		let item_id = 1;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			collection_config_from_disabled_settings(
+				CollectionSetting::UnboundItems | CollectionSetting::DepositRequired
+			)
+		));
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			soulbound_collection,
+			item_id,
+			user_2.clone(),
+			None,
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_3.clone(),
+			None,
+		));
+
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed(user_2.clone()),
+				soulbound_collection,
+				item_id,
+				user_3.clone()
+			),
+			Error::<Test>::ItemsSoulbound
+		);
+		assert_noop!(
+			<Nfts as Transfer<AccountIdOf<Test>>>::transfer(
+				&soulbound_collection,
+				&item_id,
+				&user_3
+			),
+			Error::<Test>::ItemsSoulbound
+		);
+		assert_noop!(
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_2.clone()),
+				soulbound_collection,
+				item_id,
+				Some(1),
+				None,
+				None,
+			),
+			Error::<Test>::ItemsSoulbound
+		);
+		assert_noop!(
+			Nfts::create_swap(
+				RuntimeOrigin::signed(user_2.clone()),
+				soulbound_collection,
+				item_id,
+				collection_id,
+				Some(item_id),
+				None,
+				1,
+			),
+			Error::<Test>::ItemsSoulbound
+		);
+
+		// soulbound items can't be swapped in either direction
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_id,
+			soulbound_collection,
+			Some(item_id),
+			None,
+			1,
+		));
+		assert_noop!(
+			Nfts::claim_swap(
+				RuntimeOrigin::signed(user_2.clone()),
+				soulbound_collection,
+				item_id,
+				collection_id,
+				item_id,
+				None,
+			),
+			Error::<Test>::ItemsSoulbound
+		);
+
+		// only the `ForceOrigin` can unbind the items or recover them
+		assert_noop!(
+			Nfts::force_collection_config(
+				RuntimeOrigin::signed(user_1.clone()),
+				soulbound_collection,
+				default_collection_config()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Nfts::force_transfer(
+				RuntimeOrigin::signed(user_1.clone()),
+				soulbound_collection,
+				item_id,
+				user_4.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Nfts::force_transfer(
+			RuntimeOrigin::root(),
+			soulbound_collection,
+			item_id,
+			user_4.clone()
+		));
+		assert_eq!(Nfts::owner(soulbound_collection, item_id), Some(user_4.clone()));
+
+		// the issuer can revoke soulbound items only
+		for collection in [soulbound_collection, collection_id] {
+			assert_ok!(Nfts::set_team(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection,
+				user_2.clone(),
+				user_1.clone(),
+				user_1.clone(),
+			));
+		}
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id, None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::burn(
+			RuntimeOrigin::signed(user_2.clone()),
+			soulbound_collection,
+			item_id,
+			None
+		));
+		assert!(events().contains(&Event::<Test>::Burned {
+			collection: soulbound_collection,
+			item: item_id,
+			owner: user_4,
+		}));
+	});
+}
+
+#[test]
+fn soulbound_items_should_not_change_hands() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let bundles = 1;
+		let listed_item = 1;
+		let auctioned_item = 2;
+		let parent_item = 1;
+		let nested_item = 2;
+		let initial_balance = 1000;
+		let kind = AuctionKind::English { reserve_price: 50, min_increment: 10, extension: 0 };
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		for collection in [collection_id, bundles] {
+			assert_ok!(Nfts::force_create(
+				RuntimeOrigin::root(),
+				user_1.clone(),
+				default_collection_config()
+			));
+			for item in [1, 2] {
+				assert_ok!(Nfts::mint(
+					RuntimeOrigin::signed(user_1.clone()),
+					collection,
+					item,
+					user_1.clone(),
+					None,
+				));
+			}
+		}
+
+		// the items nested into an auctioned item are sold along with it
+		assert_ok!(Nfts::nest(
+			RuntimeOrigin::signed(user_1.clone()),
+			bundles,
+			nested_item,
+			bundles,
+			parent_item
+		));
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(user_1.clone()),
+				bundles,
+				nested_item,
+				kind.clone(),
+				10,
+			),
+			Error::<Test>::ItemNested
+		);
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(user_1.clone()),
+			bundles,
+			parent_item,
+			kind.clone(),
+			10,
+		));
+		assert_noop!(
+			Nfts::unnest(RuntimeOrigin::signed(user_1.clone()), bundles, nested_item),
+			Error::<Test>::ItemAuctioned
+		);
+		assert_noop!(
+			Nfts::nest(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				listed_item,
+				bundles,
+				nested_item
+			),
+			Error::<Test>::ItemAuctioned
+		);
+
+		// the items listed or auctioned before the collection becomes soulbound stay put
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			listed_item,
+			Some(10),
+			None,
+			None,
+		));
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			auctioned_item,
+			kind,
+			10,
+		));
+		assert_ok!(Nfts::bid(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			auctioned_item,
+			50
+		));
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnboundItems.into())
+		));
+
+		assert_noop!(
+			Nfts::buy_item(RuntimeOrigin::signed(user_2.clone()), collection_id, listed_item, 10),
+			Error::<Test>::ItemsSoulbound
+		);
+		System::set_block_number(11);
+		Nfts::on_initialize(11);
+		assert_eq!(Nfts::owner(collection_id, auctioned_item), Some(user_1.clone()));
+		assert_eq!(Balances::reserved_balance(&user_2), 0);
+		assert!(events().contains(&Event::<Test>::AuctionSettlementFailed {
+			collection: collection_id,
+			item: auctioned_item,
+			error: Error::<Test>::ItemsSoulbound.into(),
+		}));
+	});
+}
+
+#[test]
+fn migration_to_v1_should_price_in_currency() {
+	use codec::Encode;
+	use frame_support::{
+		storage::unhashed,
+		traits::{OnRuntimeUpgrade, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let collection_id = 0;
+		let item_id = 1;
+		let settings = CollectionSettings::all_enabled();
+		let item_settings = ItemSettings::all_enabled();
+
+		// the encoding of the configs and prices before the assets were added
+		let old_mint_settings =
+			(MintType::<u32>::Public, Some(10u64), Some(1u64), None::<u64>, item_settings);
+		unhashed::put_raw(
+			&CollectionConfigOf::<Test>::hashed_key_for(collection_id),
+			&(settings, Some(5u32), old_mint_settings).encode(),
+		);
+		unhashed::put_raw(
+			&ItemPriceOf::<Test>::hashed_key_for(collection_id, item_id),
+			&(20u64, Some(user_1.clone())).encode(),
+		);
+		StorageVersion::new(0).put::<Nfts>();
+
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Nfts>(), 1);
+		assert_eq!(
+			CollectionConfigOf::<Test>::get(collection_id),
+			Some(CollectionConfig {
+				settings,
+				max_supply: Some(5),
+				mint_settings: MintSettings {
+					mint_type: MintType::Public,
+					price: Some(10),
+					start_block: Some(1),
+					end_block: None,
+					default_item_settings: item_settings,
+					price_asset: None,
+				},
+			})
+		);
+		assert_eq!(
+			ItemPriceOf::<Test>::get(collection_id, item_id),
+			Some((20, Some(user_1), None))
+		);
+	});
+}
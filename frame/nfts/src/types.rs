@@ -23,7 +23,7 @@ use codec::EncodeLike;
 use enumflags2::{bitflags, BitFlags};
 use frame_support::{
 	pallet_prelude::{BoundedVec, MaxEncodedLen},
-	traits::Get,
+	traits::{tokens::fungibles, Get},
 	BoundedBTreeMap, BoundedBTreeSet,
 };
 use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
@@ -50,6 +50,12 @@ pub(super) type ItemDetailsFor<T, I> =
 pub(super) type BalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
 pub(super) type ItemPrice<T, I = ()> = BalanceOf<T, I>;
+pub(super) type AssetIdOf<T, I = ()> =
+	<<T as Config<I>>::Assets as fungibles::Inspect<<T as SystemConfig>::AccountId>>::AssetId;
+pub(super) type RoyaltiesOf<T, I = ()> = BoundedVec<
+	RoyaltyRecipient<<T as SystemConfig>::AccountId>,
+	<T as Config<I>>::MaxRoyaltyRecipients,
+>;
 pub(super) type ItemTipOf<T, I = ()> = ItemTip<
 	<T as Config<I>>::CollectionId,
 	<T as Config<I>>::ItemId,
@@ -60,6 +66,7 @@ pub(super) type CollectionConfigFor<T, I = ()> = CollectionConfig<
 	BalanceOf<T, I>,
 	<T as SystemConfig>::BlockNumber,
 	<T as Config<I>>::CollectionId,
+	AssetIdOf<T, I>,
 >;
 pub(super) type PreSignedMintOf<T, I = ()> = PreSignedMint<
 	<T as Config<I>>::CollectionId,
@@ -73,6 +80,20 @@ pub(super) type PreSignedAttributesOf<T, I = ()> = PreSignedAttributes<
 	<T as SystemConfig>::AccountId,
 	<T as SystemConfig>::BlockNumber,
 >;
+pub(super) type AuctionKindOf<T, I = ()> =
+	AuctionKind<ItemPrice<T, I>, <T as SystemConfig>::BlockNumber>;
+pub(super) type AuctionFor<T, I = ()> =
+	Auction<<T as SystemConfig>::AccountId, ItemPrice<T, I>, <T as SystemConfig>::BlockNumber>;
+pub(super) type AuctionEndingsOf<T, I = ()> = BoundedVec<
+	(<T as Config<I>>::CollectionId, <T as Config<I>>::ItemId),
+	<T as Config<I>>::MaxAuctionEndingsPerBlock,
+>;
+pub(super) type NestedItemsFor<T, I = ()> = NestedItems<
+	BoundedVec<
+		(<T as Config<I>>::CollectionId, <T as Config<I>>::ItemId),
+		<T as Config<I>>::MaxNestedChildren,
+	>,
+>;
 
 pub trait Incrementable {
 	fn increment(&self) -> Self;
@@ -203,6 +224,63 @@ pub struct PendingSwap<CollectionId, ItemId, ItemPriceWithDirection, Deadline> {
 	pub(super) deadline: Deadline,
 }
 
+/// A recipient of the royalties paid when an item is sold.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RoyaltyRecipient<AccountId> {
+	/// An account that receives the royalty.
+	pub account: AccountId,
+	/// A share of the sale price that goes to the `account`.
+	pub percentage: Permill,
+}
+
+/// The kind of an auction and its parameters.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AuctionKind<Price, BlockNumber> {
+	/// An ascending price auction, won by the highest bid at the end of the auction.
+	English {
+		/// The min amount of the first bid.
+		reserve_price: Price,
+		/// The min amount by which a bid has to exceed the previous one.
+		min_increment: Price,
+		/// A bid placed less than `extension` blocks before the end of the auction extends the
+		/// auction to end `extension` blocks after the bid.
+		extension: BlockNumber,
+	},
+	/// A descending price auction, won by the first bid at the current price.
+	///
+	/// The price decays linearly from `start_price` to `end_price` over the auction.
+	Dutch {
+		/// The price at the start of the auction.
+		start_price: Price,
+		/// The price at the end of the auction.
+		end_price: Price,
+	},
+}
+
+/// Information about an auction.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Auction<AccountId, Price, BlockNumber> {
+	/// The owner of the auctioned item.
+	pub(super) seller: AccountId,
+	/// The kind of the auction.
+	pub(super) kind: AuctionKind<Price, BlockNumber>,
+	/// The block the auction started at.
+	pub(super) start: BlockNumber,
+	/// The block the auction ends at, no bids are accepted from then on.
+	pub(super) end: BlockNumber,
+	/// The highest bid and the bidder, whose funds are reserved.
+	pub(super) highest_bid: Option<(AccountId, Price)>,
+}
+
+/// The items nested into an item.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct NestedItems<Children> {
+	/// The `(collection, item)` pairs of the nested items.
+	pub(super) children: Children,
+	/// The number of levels of items nested below the item.
+	pub(super) height: u32,
+}
+
 /// Information about the reserved attribute deposit.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct AttributeDeposit<DepositBalance, AccountId> {
@@ -254,6 +332,12 @@ pub enum CollectionSetting {
 	UnlockedMaxSupply,
 	/// When this isn't set then the deposit is required to hold the items of this collection.
 	DepositRequired,
+	/// The royalties of this collection can be modified.
+	UnlockedRoyalties,
+	/// Items in this collection can change their owner. Once disabled, the collection is
+	/// soulbound: its items can only be burned or recovered by the `ForceOrigin`, and the owner
+	/// can't enable it again.
+	UnboundItems,
 }
 
 /// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
@@ -292,7 +376,7 @@ pub enum MintType<CollectionId> {
 
 /// Holds the information about minting.
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct MintSettings<Price, BlockNumber, CollectionId> {
+pub struct MintSettings<Price, BlockNumber, CollectionId, AssetId> {
 	/// Whether anyone can mint or if minters are restricted to some subset.
 	pub mint_type: MintType<CollectionId>,
 	/// An optional price per mint.
@@ -303,9 +387,13 @@ pub struct MintSettings<Price, BlockNumber, CollectionId> {
 	pub end_block: Option<BlockNumber>,
 	/// Default settings each item will get during the mint.
 	pub default_item_settings: ItemSettings,
+	/// The asset the price is paid in, `None` means the price is paid in `Currency`.
+	pub price_asset: Option<AssetId>,
 }
 
-impl<Price, BlockNumber, CollectionId> Default for MintSettings<Price, BlockNumber, CollectionId> {
+impl<Price, BlockNumber, CollectionId, AssetId> Default
+	for MintSettings<Price, BlockNumber, CollectionId, AssetId>
+{
 	fn default() -> Self {
 		Self {
 			mint_type: MintType::Issuer,
@@ -313,6 +401,7 @@ impl<Price, BlockNumber, CollectionId> Default for MintSettings<Price, BlockNumb
 			start_block: None,
 			end_block: None,
 			default_item_settings: ItemSettings::all_enabled(),
+			price_asset: None,
 		}
 	}
 }
@@ -335,16 +424,18 @@ pub enum PalletAttributes<CollectionId> {
 #[derive(
 	Clone, Copy, Decode, Default, Encode, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
 )]
-pub struct CollectionConfig<Price, BlockNumber, CollectionId> {
+pub struct CollectionConfig<Price, BlockNumber, CollectionId, AssetId> {
 	/// Collection's settings.
 	pub settings: CollectionSettings,
 	/// Collection's max supply.
 	pub max_supply: Option<u32>,
 	/// Default settings each item will get during the mint.
-	pub mint_settings: MintSettings<Price, BlockNumber, CollectionId>,
+	pub mint_settings: MintSettings<Price, BlockNumber, CollectionId, AssetId>,
 }
 
-impl<Price, BlockNumber, CollectionId> CollectionConfig<Price, BlockNumber, CollectionId> {
+impl<Price, BlockNumber, CollectionId, AssetId>
+	CollectionConfig<Price, BlockNumber, CollectionId, AssetId>
+{
 	pub fn is_setting_enabled(&self, setting: CollectionSetting) -> bool {
 		!self.settings.is_disabled(setting)
 	}
@@ -370,6 +461,8 @@ pub enum ItemSetting {
 	UnlockedMetadata,
 	/// Attributes of this item can be modified.
 	UnlockedAttributes,
+	/// The royalties of this item can be modified.
+	UnlockedRoyalties,
 }
 
 /// Wrapper type for `BitFlags<ItemSetting>` that implements `Codec`.
@@ -433,6 +526,10 @@ pub enum PalletFeature {
 	Approvals,
 	/// Allow/disallow atomic items swap.
 	Swaps,
+	/// Allow/disallow auctions.
+	Auctions,
+	/// Allow/disallow nesting items into other items.
+	Nesting,
 }
 
 /// Wrapper type for `BitFlags<PalletFeature>` that implements `Codec`.
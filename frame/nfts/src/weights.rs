@@ -18,24 +18,23 @@
 //! Autogenerated weights for pallet_nfts
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-02-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-osnnfcqu-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor @ 2.10GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
 
 // Executed Command:
-// target/production/substrate
+// /tmp/benchnode/target/release/benchnode
 // benchmark
 // pallet
+// --chain=dev
 // --steps=50
 // --repeat=20
+// --pallet=pallet_nfts
 // --extrinsic=*
 // --execution=wasm
 // --wasm-execution=compiled
 // --heap-pages=4096
-// --json-file=/builds/parity/mirrors/substrate/.git/.artifacts/bench.json
-// --pallet=pallet_nfts
-// --chain=dev
 // --header=./HEADER-APACHE2
 // --output=./frame/nfts/src/weights.rs
 // --template=./.maintain/frame-weight-template.hbs
@@ -53,6 +52,7 @@ pub trait WeightInfo {
 	fn force_create() -> Weight;
 	fn destroy(n: u32, m: u32, a: u32, ) -> Weight;
 	fn mint() -> Weight;
+	fn mint_priced_in_asset() -> Weight;
 	fn force_mint() -> Weight;
 	fn burn() -> Weight;
 	fn transfer() -> Weight;
@@ -81,13 +81,24 @@ pub trait WeightInfo {
 	fn set_collection_max_supply() -> Weight;
 	fn update_mint_settings() -> Weight;
 	fn set_price() -> Weight;
-	fn buy_item() -> Weight;
+	fn buy_item(r: u32, ) -> Weight;
+	fn buy_item_priced_in_asset(r: u32, ) -> Weight;
 	fn pay_tips(n: u32, ) -> Weight;
 	fn create_swap() -> Weight;
 	fn cancel_swap() -> Weight;
-	fn claim_swap() -> Weight;
+	fn claim_swap(r: u32, ) -> Weight;
 	fn mint_pre_signed(n: u32, ) -> Weight;
 	fn set_attributes_pre_signed(n: u32, ) -> Weight;
+	fn set_collection_royalties() -> Weight;
+	fn set_item_royalties() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn dutch_bid(r: u32, ) -> Weight;
+	fn settle_auction(r: u32, ) -> Weight;
+	fn cancel_auction() -> Weight;
+	fn nest() -> Weight;
+	fn unnest() -> Weight;
+	fn force_transfer() -> Weight;
 }
 
 /// Weights for pallet_nfts using the Substrate node and recommended hardware.
@@ -100,15 +111,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:1)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `214`
 		//  Estimated: `3054`
-		// Minimum execution time: 33_769 nanoseconds.
-		Weight::from_ref_time(36_031_000)
+		// Minimum execution time: 47_917 nanoseconds.
+		Weight::from_ref_time(54_165_000)
 			.saturating_add(Weight::from_proof_size(3054))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
@@ -120,35 +131,43 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:1)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn force_create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `3054`
-		// Minimum execution time: 21_767 nanoseconds.
-		Weight::from_ref_time(22_565_000)
+		// Minimum execution time: 30_115 nanoseconds.
+		Weight::from_ref_time(36_372_000)
 			.saturating_add(Weight::from_proof_size(3054))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ChildrenOf (r:1 w:0)
+	/// Proof: Nfts ChildrenOf (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1001 w:1000)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1001 w:1000)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts Attribute (r:1001 w:1000)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1000 w:1000)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionMetadataOf (r:0 w:1)
 	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:0 w:1000)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1000)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:1)
@@ -158,26 +177,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `a` is `[0, 1000]`.
 	fn destroy(_n: u32, m: u32, a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `172781 + m * (127 ±0) + a * (402 ±0)`
-		//  Estimated: `3347427 + m * (2615 ±0) + a * (2921 ±0)`
-		// Minimum execution time: 26_973_627 nanoseconds.
-		Weight::from_ref_time(19_692_361_714)
-			.saturating_add(Weight::from_proof_size(3347427))
-			// Standard Error: 17_036
-			.saturating_add(Weight::from_ref_time(7_797_219).saturating_mul(m.into()))
-			// Standard Error: 17_036
-			.saturating_add(Weight::from_ref_time(9_504_128).saturating_mul(a.into()))
-			.saturating_add(T::DbWeight::get().reads(1004_u64))
+		//  Measured:  `172851 + m * (127 ±0) + a * (402 ±0)`
+		//  Estimated: `5880735 + m * (2615 ±0) + a * (2921 ±0)`
+		// Minimum execution time: 38_411_907 nanoseconds.
+		Weight::from_ref_time(67_832_682_921)
+			.saturating_add(Weight::from_proof_size(5880735))
+			// Standard Error: 1_159_676
+			.saturating_add(Weight::from_ref_time(4_483_996).saturating_mul(m.into()))
+			// Standard Error: 1_159_676
+			.saturating_add(Weight::from_ref_time(11_323_903).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2008_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
-			.saturating_add(T::DbWeight::get().writes(3005_u64))
+			.saturating_add(T::DbWeight::get().writes(3006_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_proof_size(2615).saturating_mul(m.into()))
 			.saturating_add(Weight::from_proof_size(2921).saturating_mul(a.into()))
 	}
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
@@ -190,14 +209,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `448`
-		//  Estimated: `13506`
-		// Minimum execution time: 44_837 nanoseconds.
-		Weight::from_ref_time(46_794_000)
-			.saturating_add(Weight::from_proof_size(13506))
+		//  Measured:  `449`
+		//  Estimated: `13511`
+		// Minimum execution time: 63_141 nanoseconds.
+		Weight::from_ref_time(74_449_000)
+			.saturating_add(Weight::from_proof_size(13511))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn mint_priced_in_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1130`
+		//  Estimated: `23954`
+		// Minimum execution time: 116_032 nanoseconds.
+		Weight::from_ref_time(132_678_000)
+			.saturating_add(Weight::from_proof_size(23954))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1 w:1)
@@ -205,21 +252,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:1)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	fn force_mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `448`
-		//  Estimated: `13506`
-		// Minimum execution time: 43_976 nanoseconds.
-		Weight::from_ref_time(44_831_000)
-			.saturating_add(Weight::from_proof_size(13506))
+		//  Measured:  `449`
+		//  Estimated: `13511`
+		// Minimum execution time: 62_178 nanoseconds.
+		Weight::from_ref_time(85_511_000)
+			.saturating_add(Weight::from_proof_size(13511))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: Nfts ChildrenOf (r:11 w:1)
+	/// Proof: Nfts ChildrenOf (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:1)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
@@ -228,30 +277,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:1)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1)
 	/// Proof: Nfts ItemAttributesApprovalsOf (max_values: None, max_size: Some(681), added: 3156, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `647`
-		//  Estimated: `13573`
-		// Minimum execution time: 48_233 nanoseconds.
-		Weight::from_ref_time(50_113_000)
-			.saturating_add(Weight::from_proof_size(13573))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+		//  Measured:  `2451`
+		//  Estimated: `49890`
+		// Minimum execution time: 157_187 nanoseconds.
+		Weight::from_ref_time(169_833_000)
+			.saturating_add(Weight::from_proof_size(49890))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1 w:1)
@@ -263,35 +324,35 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts Account (r:0 w:2)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `882`
-		//  Estimated: `16109`
-		// Minimum execution time: 55_452 nanoseconds.
-		Weight::from_ref_time(57_642_000)
-			.saturating_add(Weight::from_proof_size(16109))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+		//  Measured:  `883`
+		//  Estimated: `21278`
+		// Minimum execution time: 83_359 nanoseconds.
+		Weight::from_ref_time(92_347_000)
+			.saturating_add(Weight::from_proof_size(21278))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:5000 w:5000)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 5000]`.
 	fn redeposit(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `756 + i * (140 ±0)`
-		//  Estimated: `5103 + i * (3336 ±0)`
-		// Minimum execution time: 15_598 nanoseconds.
-		Weight::from_ref_time(15_926_000)
-			.saturating_add(Weight::from_proof_size(5103))
-			// Standard Error: 13_692
-			.saturating_add(Weight::from_ref_time(14_040_741).saturating_mul(i.into()))
+		//  Measured:  `757 + i * (140 ±0)`
+		//  Estimated: `5108 + i * (3336 ±0)`
+		// Minimum execution time: 19_757 nanoseconds.
+		Weight::from_ref_time(21_024_000)
+			.saturating_add(Weight::from_proof_size(5108))
+			// Standard Error: 54_333
+			.saturating_add(Weight::from_ref_time(23_189_446).saturating_mul(i.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
@@ -299,16 +360,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:1)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	fn lock_item_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `401`
-		//  Estimated: `5067`
-		// Minimum execution time: 19_686 nanoseconds.
-		Weight::from_ref_time(20_404_000)
-			.saturating_add(Weight::from_proof_size(5067))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		//  Estimated: `7590`
+		// Minimum execution time: 29_076 nanoseconds.
+		Weight::from_ref_time(39_733_000)
+			.saturating_add(Weight::from_proof_size(7590))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
@@ -319,24 +382,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `401`
 		//  Estimated: `5067`
-		// Minimum execution time: 19_172 nanoseconds.
-		Weight::from_ref_time(20_151_000)
+		// Minimum execution time: 26_228 nanoseconds.
+		Weight::from_ref_time(30_717_000)
 			.saturating_add(Weight::from_proof_size(5067))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	fn lock_collection() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `289`
-		//  Estimated: `5092`
-		// Minimum execution time: 17_063 nanoseconds.
-		Weight::from_ref_time(17_482_000)
-			.saturating_add(Weight::from_proof_size(5092))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		//  Measured:  `330`
+		//  Estimated: `7620`
+		// Minimum execution time: 27_511 nanoseconds.
+		Weight::from_ref_time(33_125_000)
+			.saturating_add(Weight::from_proof_size(7620))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts OwnershipAcceptance (r:1 w:1)
@@ -349,24 +414,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `381`
 		//  Estimated: `5082`
-		// Minimum execution time: 21_974 nanoseconds.
-		Weight::from_ref_time(22_770_000)
+		// Minimum execution time: 29_918 nanoseconds.
+		Weight::from_ref_time(34_606_000)
 			.saturating_add(Weight::from_proof_size(5082))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:0 w:4)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:4)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	fn set_team() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `362`
-		//  Estimated: `2555`
-		// Minimum execution time: 24_341 nanoseconds.
-		Weight::from_ref_time(25_059_000)
-			.saturating_add(Weight::from_proof_size(2555))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Estimated: `5099`
+		// Minimum execution time: 33_185 nanoseconds.
+		Weight::from_ref_time(37_942_000)
+			.saturating_add(Weight::from_proof_size(5099))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
@@ -377,8 +442,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `304`
 		//  Estimated: `2555`
-		// Minimum execution time: 16_897 nanoseconds.
-		Weight::from_ref_time(17_560_000)
+		// Minimum execution time: 22_875 nanoseconds.
+		Weight::from_ref_time(26_351_000)
 			.saturating_add(Weight::from_proof_size(2555))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
@@ -386,13 +451,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	fn force_collection_config() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `242`
 		//  Estimated: `2555`
-		// Minimum execution time: 13_239 nanoseconds.
-		Weight::from_ref_time(13_963_000)
+		// Minimum execution time: 17_454 nanoseconds.
+		Weight::from_ref_time(19_721_000)
 			.saturating_add(Weight::from_proof_size(2555))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
@@ -405,8 +470,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `445`
 		//  Estimated: `5078`
-		// Minimum execution time: 17_187 nanoseconds.
-		Weight::from_ref_time(17_942_000)
+		// Minimum execution time: 22_997 nanoseconds.
+		Weight::from_ref_time(26_577_000)
 			.saturating_add(Weight::from_proof_size(5078))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
@@ -414,18 +479,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Attribute (r:1 w:1)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
 	fn set_attribute() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `474`
-		//  Estimated: `10547`
-		// Minimum execution time: 40_925 nanoseconds.
-		Weight::from_ref_time(42_733_000)
-			.saturating_add(Weight::from_proof_size(10547))
+		//  Measured:  `475`
+		//  Estimated: `10552`
+		// Minimum execution time: 60_806 nanoseconds.
+		Weight::from_ref_time(69_011_000)
+			.saturating_add(Weight::from_proof_size(10552))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -437,8 +502,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `337`
 		//  Estimated: `5476`
-		// Minimum execution time: 24_486 nanoseconds.
-		Weight::from_ref_time(25_409_000)
+		// Minimum execution time: 36_965 nanoseconds.
+		Weight::from_ref_time(42_383_000)
 			.saturating_add(Weight::from_proof_size(5476))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
@@ -453,8 +518,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `916`
 		//  Estimated: `7999`
-		// Minimum execution time: 36_643 nanoseconds.
-		Weight::from_ref_time(37_805_000)
+		// Minimum execution time: 50_564 nanoseconds.
+		Weight::from_ref_time(55_615_000)
 			.saturating_add(Weight::from_proof_size(7999))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
@@ -467,8 +532,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `379`
 		//  Estimated: `6492`
-		// Minimum execution time: 16_798 nanoseconds.
-		Weight::from_ref_time(17_326_000)
+		// Minimum execution time: 22_635 nanoseconds.
+		Weight::from_ref_time(24_924_000)
 			.saturating_add(Weight::from_proof_size(6492))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
@@ -486,11 +551,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `899 + n * (396 ±0)`
 		//  Estimated: `12016 + n * (2921 ±0)`
-		// Minimum execution time: 25_524 nanoseconds.
-		Weight::from_ref_time(26_107_000)
+		// Minimum execution time: 34_072 nanoseconds.
+		Weight::from_ref_time(38_996_000)
 			.saturating_add(Weight::from_proof_size(12016))
-			// Standard Error: 5_460
-			.saturating_add(Weight::from_ref_time(9_030_830).saturating_mul(n.into()))
+			// Standard Error: 25_250
+			.saturating_add(Weight::from_ref_time(11_451_053).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
@@ -502,16 +567,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	fn set_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `474`
-		//  Estimated: `10241`
-		// Minimum execution time: 34_400 nanoseconds.
-		Weight::from_ref_time(35_469_000)
-			.saturating_add(Weight::from_proof_size(10241))
+		//  Measured:  `475`
+		//  Estimated: `10246`
+		// Minimum execution time: 48_728 nanoseconds.
+		Weight::from_ref_time(55_192_000)
+			.saturating_add(Weight::from_proof_size(10246))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -525,57 +590,57 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `609`
 		//  Estimated: `7693`
-		// Minimum execution time: 31_560 nanoseconds.
-		Weight::from_ref_time(33_081_000)
+		// Minimum execution time: 46_453 nanoseconds.
+		Weight::from_ref_time(50_390_000)
 			.saturating_add(Weight::from_proof_size(7693))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionMetadataOf (r:1 w:1)
 	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
 	fn set_collection_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `333`
-		//  Estimated: `7665`
-		// Minimum execution time: 28_821 nanoseconds.
-		Weight::from_ref_time(30_010_000)
-			.saturating_add(Weight::from_proof_size(7665))
+		//  Measured:  `334`
+		//  Estimated: `7670`
+		// Minimum execution time: 42_079 nanoseconds.
+		Weight::from_ref_time(68_136_000)
+			.saturating_add(Weight::from_proof_size(7670))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionMetadataOf (r:1 w:1)
 	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
 	fn clear_collection_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `476`
-		//  Estimated: `7665`
-		// Minimum execution time: 27_608 nanoseconds.
-		Weight::from_ref_time(28_766_000)
-			.saturating_add(Weight::from_proof_size(7665))
+		//  Measured:  `477`
+		//  Estimated: `7670`
+		// Minimum execution time: 37_603 nanoseconds.
+		Weight::from_ref_time(42_885_000)
+			.saturating_add(Weight::from_proof_size(7670))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	fn approve_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `466`
-		//  Estimated: `8428`
-		// Minimum execution time: 23_987 nanoseconds.
-		Weight::from_ref_time(24_819_000)
-			.saturating_add(Weight::from_proof_size(8428))
+		//  Measured:  `467`
+		//  Estimated: `8433`
+		// Minimum execution time: 34_299 nanoseconds.
+		Weight::from_ref_time(44_718_000)
+			.saturating_add(Weight::from_proof_size(8433))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -587,8 +652,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `474`
 		//  Estimated: `5880`
-		// Minimum execution time: 21_254 nanoseconds.
-		Weight::from_ref_time(21_826_000)
+		// Minimum execution time: 28_303 nanoseconds.
+		Weight::from_ref_time(34_769_000)
 			.saturating_add(Weight::from_proof_size(5880))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
@@ -601,8 +666,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `474`
 		//  Estimated: `5880`
-		// Minimum execution time: 20_272 nanoseconds.
-		Weight::from_ref_time(20_922_000)
+		// Minimum execution time: 28_664 nanoseconds.
+		Weight::from_ref_time(31_941_000)
 			.saturating_add(Weight::from_proof_size(5880))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
@@ -613,107 +678,171 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `2527`
-		// Minimum execution time: 14_287 nanoseconds.
-		Weight::from_ref_time(14_960_000)
+		// Minimum execution time: 19_307 nanoseconds.
+		Weight::from_ref_time(22_456_000)
 			.saturating_add(Weight::from_proof_size(2527))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts CollectionConfigOf (r:1 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	fn set_collection_max_supply() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `333`
-		//  Estimated: `5103`
-		// Minimum execution time: 17_948 nanoseconds.
-		Weight::from_ref_time(18_780_000)
-			.saturating_add(Weight::from_proof_size(5103))
+		//  Measured:  `334`
+		//  Estimated: `5108`
+		// Minimum execution time: 42_543 nanoseconds.
+		Weight::from_ref_time(60_011_000)
+			.saturating_add(Weight::from_proof_size(5108))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	fn update_mint_settings() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `333`
-		//  Estimated: `5103`
-		// Minimum execution time: 16_616 nanoseconds.
-		Weight::from_ref_time(17_155_000)
-			.saturating_add(Weight::from_proof_size(5103))
+		//  Measured:  `334`
+		//  Estimated: `5108`
+		// Minimum execution time: 22_329 nanoseconds.
+		Weight::from_ref_time(25_012_000)
+			.saturating_add(Weight::from_proof_size(5108))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:0)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	fn set_price() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `516`
-		//  Estimated: `8407`
-		// Minimum execution time: 22_777 nanoseconds.
-		Weight::from_ref_time(23_955_000)
-			.saturating_add(Weight::from_proof_size(8407))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		//  Measured:  `517`
+		//  Estimated: `11053`
+		// Minimum execution time: 39_026 nanoseconds.
+		Weight::from_ref_time(43_115_000)
+			.saturating_add(Weight::from_proof_size(11053))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:1 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:2)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
-	fn buy_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `934`
-		//  Estimated: `16129`
-		// Minimum execution time: 61_131 nanoseconds.
-		Weight::from_ref_time(62_791_000)
-			.saturating_add(Weight::from_proof_size(16129))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+	/// The range of component `r` is `[0, 5]`.
+	fn buy_item(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `936 + r * (77 ±0)`
+		//  Estimated: `26675 + r * (2603 ±0)`
+		// Minimum execution time: 114_475 nanoseconds.
+		Weight::from_ref_time(136_966_058)
+			.saturating_add(Weight::from_proof_size(26675))
+			// Standard Error: 436_150
+			.saturating_add(Weight::from_ref_time(39_452_360).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(2603).saturating_mul(r.into()))
+	}
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:1 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:7 w:7)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 5]`.
+	fn buy_item_priced_in_asset(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1432 + r * (77 ±0)`
+		//  Estimated: `34515 + r * (5180 ±0)`
+		// Minimum execution time: 144_152 nanoseconds.
+		Weight::from_ref_time(164_835_095)
+			.saturating_add(Weight::from_proof_size(34515))
+			// Standard Error: 1_276_019
+			.saturating_add(Weight::from_ref_time(66_426_026).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(5180).saturating_mul(r.into()))
 	}
 	/// The range of component `n` is `[0, 10]`.
 	fn pay_tips(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 1_952 nanoseconds.
-		Weight::from_ref_time(3_975_700)
+		// Minimum execution time: 2_654 nanoseconds.
+		Weight::from_ref_time(5_270_165)
 			.saturating_add(Weight::from_proof_size(0))
-			// Standard Error: 11_254
-			.saturating_add(Weight::from_ref_time(3_501_698).saturating_mul(n.into()))
+			// Standard Error: 87_690
+			.saturating_add(Weight::from_ref_time(5_967_645).saturating_mul(n.into()))
 	}
 	/// Storage: Nfts Item (r:2 w:0)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn create_swap() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `524`
-		//  Estimated: `6672`
-		// Minimum execution time: 20_327 nanoseconds.
-		Weight::from_ref_time(21_714_000)
-			.saturating_add(Weight::from_proof_size(6672))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		//  Measured:  `554`
+		//  Estimated: `11866`
+		// Minimum execution time: 34_133 nanoseconds.
+		Weight::from_ref_time(46_518_000)
+			.saturating_add(Weight::from_proof_size(11866))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts PendingSwapOf (r:1 w:1)
@@ -724,8 +853,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `511`
 		//  Estimated: `5882`
-		// Minimum execution time: 20_668 nanoseconds.
-		Weight::from_ref_time(21_416_000)
+		// Minimum execution time: 28_538 nanoseconds.
+		Weight::from_ref_time(32_432_000)
 			.saturating_add(Weight::from_proof_size(5882))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
@@ -734,32 +863,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:1 w:2)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:2 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:2 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:2 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:4)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:2)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
-	fn claim_swap() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1097`
-		//  Estimated: `21970`
-		// Minimum execution time: 88_006 nanoseconds.
-		Weight::from_ref_time(90_390_000)
-			.saturating_add(Weight::from_proof_size(21970))
-			.saturating_add(T::DbWeight::get().reads(8_u64))
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 5]`.
+	fn claim_swap(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1118 + r * (90 ±0)`
+		//  Estimated: `37675 + r * (2603 ±0)`
+		// Minimum execution time: 158_919 nanoseconds.
+		Weight::from_ref_time(233_819_102)
+			.saturating_add(Weight::from_proof_size(37675))
+			// Standard Error: 1_854_833
+			.saturating_add(Weight::from_ref_time(30_394_736).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(2603).saturating_mul(r.into()))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:1)
@@ -775,13 +918,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `n` is `[0, 10]`.
 	fn mint_pre_signed(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `596`
-		//  Estimated: `16180 + n * (2921 ±0)`
-		// Minimum execution time: 124_967 nanoseconds.
-		Weight::from_ref_time(131_602_642)
-			.saturating_add(Weight::from_proof_size(16180))
-			// Standard Error: 36_480
-			.saturating_add(Weight::from_ref_time(25_811_394).saturating_mul(n.into()))
+		//  Measured:  `597`
+		//  Estimated: `16185 + n * (2921 ±0)`
+		// Minimum execution time: 162_795 nanoseconds.
+		Weight::from_ref_time(208_640_255)
+			.saturating_add(Weight::from_proof_size(16185))
+			// Standard Error: 334_985
+			.saturating_add(Weight::from_ref_time(41_706_094).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
@@ -795,7 +938,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Attribute (r:10 w:10)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
@@ -803,19 +946,275 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `n` is `[0, 10]`.
 	fn set_attributes_pre_signed(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `716`
-		//  Estimated: `14198 + n * (2921 ±0)`
-		// Minimum execution time: 84_153 nanoseconds.
-		Weight::from_ref_time(96_401_623)
-			.saturating_add(Weight::from_proof_size(14198))
-			// Standard Error: 70_244
-			.saturating_add(Weight::from_ref_time(26_866_222).saturating_mul(n.into()))
+		//  Measured:  `717`
+		//  Estimated: `14203 + n * (2921 ±0)`
+		// Minimum execution time: 101_028 nanoseconds.
+		Weight::from_ref_time(145_563_474)
+			.saturating_add(Weight::from_proof_size(14203))
+			// Standard Error: 343_232
+			.saturating_add(Weight::from_ref_time(44_699_085).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
 	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	fn set_collection_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `334`
+		//  Estimated: `5108`
+		// Minimum execution time: 23_991 nanoseconds.
+		Weight::from_ref_time(27_380_000)
+			.saturating_add(Weight::from_proof_size(5108))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	fn set_item_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `445`
+		//  Estimated: `5078`
+		// Minimum execution time: 27_886 nanoseconds.
+		Weight::from_ref_time(31_121_000)
+			.saturating_add(Weight::from_proof_size(5078))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionEndings (r:1 w:1)
+	/// Proof: Nfts AuctionEndings (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `517`
+		//  Estimated: `16144`
+		// Minimum execution time: 46_978 nanoseconds.
+		Weight::from_ref_time(54_260_000)
+			.saturating_add(Weight::from_proof_size(16144))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionEndings (r:1 w:1)
+	/// Proof: Nfts AuctionEndings (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `871`
+		//  Estimated: `10415`
+		// Minimum execution time: 64_281 nanoseconds.
+		Weight::from_ref_time(83_086_000)
+			.saturating_add(Weight::from_proof_size(10415))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 5]`.
+	fn dutch_bid(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1038 + r * (96 ±0)`
+		//  Estimated: `24106 + r * (2603 ±0)`
+		// Minimum execution time: 140_492 nanoseconds.
+		Weight::from_ref_time(185_736_223)
+			.saturating_add(Weight::from_proof_size(24106))
+			// Standard Error: 1_186_934
+			.saturating_add(Weight::from_ref_time(63_797_926).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(2603).saturating_mul(r.into()))
+	}
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 5]`.
+	fn settle_auction(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1090 + r * (96 ±0)`
+		//  Estimated: `24106 + r * (2603 ±0)`
+		// Minimum execution time: 138_921 nanoseconds.
+		Weight::from_ref_time(201_680_008)
+			.saturating_add(Weight::from_proof_size(24106))
+			// Standard Error: 675_173
+			.saturating_add(Weight::from_ref_time(33_914_384).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(2603).saturating_mul(r.into()))
+	}
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `2641`
+		// Minimum execution time: 20_697 nanoseconds.
+		Weight::from_ref_time(37_125_000)
+			.saturating_add(Weight::from_proof_size(2641))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:2 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:3 w:1)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:2 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts ChildrenOf (r:11 w:1)
+	/// Proof: Nfts ChildrenOf (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn nest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2565`
+		//  Estimated: `55754`
+		// Minimum execution time: 162_917 nanoseconds.
+		Weight::from_ref_time(187_871_000)
+			.saturating_add(Weight::from_proof_size(55754))
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Nfts ParentOf (r:4 w:1)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:2 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:2 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts ChildrenOf (r:10 w:1)
+	/// Proof: Nfts ChildrenOf (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn unnest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2603`
+		//  Estimated: `55677`
+		// Minimum execution time: 155_087 nanoseconds.
+		Weight::from_ref_time(168_585_000)
+			.saturating_add(Weight::from_proof_size(55677))
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn force_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `825`
+		//  Estimated: `18734`
+		// Minimum execution time: 77_563 nanoseconds.
+		Weight::from_ref_time(85_746_000)
+			.saturating_add(Weight::from_proof_size(18734))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -827,15 +1226,15 @@ impl WeightInfo for () {
 	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:1)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `214`
 		//  Estimated: `3054`
-		// Minimum execution time: 33_769 nanoseconds.
-		Weight::from_ref_time(36_031_000)
+		// Minimum execution time: 47_917 nanoseconds.
+		Weight::from_ref_time(54_165_000)
 			.saturating_add(Weight::from_proof_size(3054))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
@@ -847,35 +1246,43 @@ impl WeightInfo for () {
 	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:1)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn force_create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `3054`
-		// Minimum execution time: 21_767 nanoseconds.
-		Weight::from_ref_time(22_565_000)
+		// Minimum execution time: 30_115 nanoseconds.
+		Weight::from_ref_time(36_372_000)
 			.saturating_add(Weight::from_proof_size(3054))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ChildrenOf (r:1 w:0)
+	/// Proof: Nfts ChildrenOf (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1001 w:1000)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1001 w:1000)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts Attribute (r:1001 w:1000)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1000 w:1000)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionMetadataOf (r:0 w:1)
 	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:0 w:1000)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1000)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:1)
@@ -885,26 +1292,26 @@ impl WeightInfo for () {
 	/// The range of component `a` is `[0, 1000]`.
 	fn destroy(_n: u32, m: u32, a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `172781 + m * (127 ±0) + a * (402 ±0)`
-		//  Estimated: `3347427 + m * (2615 ±0) + a * (2921 ±0)`
-		// Minimum execution time: 26_973_627 nanoseconds.
-		Weight::from_ref_time(19_692_361_714)
-			.saturating_add(Weight::from_proof_size(3347427))
-			// Standard Error: 17_036
-			.saturating_add(Weight::from_ref_time(7_797_219).saturating_mul(m.into()))
-			// Standard Error: 17_036
-			.saturating_add(Weight::from_ref_time(9_504_128).saturating_mul(a.into()))
-			.saturating_add(RocksDbWeight::get().reads(1004_u64))
+		//  Measured:  `172851 + m * (127 ±0) + a * (402 ±0)`
+		//  Estimated: `5880735 + m * (2615 ±0) + a * (2921 ±0)`
+		// Minimum execution time: 38_411_907 nanoseconds.
+		Weight::from_ref_time(67_832_682_921)
+			.saturating_add(Weight::from_proof_size(5880735))
+			// Standard Error: 1_159_676
+			.saturating_add(Weight::from_ref_time(4_483_996).saturating_mul(m.into()))
+			// Standard Error: 1_159_676
+			.saturating_add(Weight::from_ref_time(11_323_903).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(2008_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
-			.saturating_add(RocksDbWeight::get().writes(3005_u64))
+			.saturating_add(RocksDbWeight::get().writes(3006_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_proof_size(2615).saturating_mul(m.into()))
 			.saturating_add(Weight::from_proof_size(2921).saturating_mul(a.into()))
 	}
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
@@ -917,14 +1324,42 @@ impl WeightInfo for () {
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `448`
-		//  Estimated: `13506`
-		// Minimum execution time: 44_837 nanoseconds.
-		Weight::from_ref_time(46_794_000)
-			.saturating_add(Weight::from_proof_size(13506))
+		//  Measured:  `449`
+		//  Estimated: `13511`
+		// Minimum execution time: 63_141 nanoseconds.
+		Weight::from_ref_time(74_449_000)
+			.saturating_add(Weight::from_proof_size(13511))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn mint_priced_in_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1130`
+		//  Estimated: `23954`
+		// Minimum execution time: 116_032 nanoseconds.
+		Weight::from_ref_time(132_678_000)
+			.saturating_add(Weight::from_proof_size(23954))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1 w:1)
@@ -932,21 +1367,23 @@ impl WeightInfo for () {
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:1)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	fn force_mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `448`
-		//  Estimated: `13506`
-		// Minimum execution time: 43_976 nanoseconds.
-		Weight::from_ref_time(44_831_000)
-			.saturating_add(Weight::from_proof_size(13506))
+		//  Measured:  `449`
+		//  Estimated: `13511`
+		// Minimum execution time: 62_178 nanoseconds.
+		Weight::from_ref_time(85_511_000)
+			.saturating_add(Weight::from_proof_size(13511))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: Nfts ChildrenOf (r:11 w:1)
+	/// Proof: Nfts ChildrenOf (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:1)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
@@ -955,30 +1392,42 @@ impl WeightInfo for () {
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:1)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1)
 	/// Proof: Nfts ItemAttributesApprovalsOf (max_values: None, max_size: Some(681), added: 3156, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `647`
-		//  Estimated: `13573`
-		// Minimum execution time: 48_233 nanoseconds.
-		Weight::from_ref_time(50_113_000)
-			.saturating_add(Weight::from_proof_size(13573))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+		//  Measured:  `2451`
+		//  Estimated: `49890`
+		// Minimum execution time: 157_187 nanoseconds.
+		Weight::from_ref_time(169_833_000)
+			.saturating_add(Weight::from_proof_size(49890))
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1 w:1)
@@ -990,35 +1439,35 @@ impl WeightInfo for () {
 	/// Storage: Nfts Account (r:0 w:2)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `882`
-		//  Estimated: `16109`
-		// Minimum execution time: 55_452 nanoseconds.
-		Weight::from_ref_time(57_642_000)
-			.saturating_add(Weight::from_proof_size(16109))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+		//  Measured:  `883`
+		//  Estimated: `21278`
+		// Minimum execution time: 83_359 nanoseconds.
+		Weight::from_ref_time(92_347_000)
+			.saturating_add(Weight::from_proof_size(21278))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:5000 w:5000)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 5000]`.
 	fn redeposit(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `756 + i * (140 ±0)`
-		//  Estimated: `5103 + i * (3336 ±0)`
-		// Minimum execution time: 15_598 nanoseconds.
-		Weight::from_ref_time(15_926_000)
-			.saturating_add(Weight::from_proof_size(5103))
-			// Standard Error: 13_692
-			.saturating_add(Weight::from_ref_time(14_040_741).saturating_mul(i.into()))
+		//  Measured:  `757 + i * (140 ±0)`
+		//  Estimated: `5108 + i * (3336 ±0)`
+		// Minimum execution time: 19_757 nanoseconds.
+		Weight::from_ref_time(21_024_000)
+			.saturating_add(Weight::from_proof_size(5108))
+			// Standard Error: 54_333
+			.saturating_add(Weight::from_ref_time(23_189_446).saturating_mul(i.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(i.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(i.into())))
@@ -1026,16 +1475,18 @@ impl WeightInfo for () {
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:1)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	fn lock_item_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `401`
-		//  Estimated: `5067`
-		// Minimum execution time: 19_686 nanoseconds.
-		Weight::from_ref_time(20_404_000)
-			.saturating_add(Weight::from_proof_size(5067))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Estimated: `7590`
+		// Minimum execution time: 29_076 nanoseconds.
+		Weight::from_ref_time(39_733_000)
+			.saturating_add(Weight::from_proof_size(7590))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
@@ -1046,24 +1497,26 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `401`
 		//  Estimated: `5067`
-		// Minimum execution time: 19_172 nanoseconds.
-		Weight::from_ref_time(20_151_000)
+		// Minimum execution time: 26_228 nanoseconds.
+		Weight::from_ref_time(30_717_000)
 			.saturating_add(Weight::from_proof_size(5067))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	fn lock_collection() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `289`
-		//  Estimated: `5092`
-		// Minimum execution time: 17_063 nanoseconds.
-		Weight::from_ref_time(17_482_000)
-			.saturating_add(Weight::from_proof_size(5092))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Measured:  `330`
+		//  Estimated: `7620`
+		// Minimum execution time: 27_511 nanoseconds.
+		Weight::from_ref_time(33_125_000)
+			.saturating_add(Weight::from_proof_size(7620))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts OwnershipAcceptance (r:1 w:1)
@@ -1076,24 +1529,24 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `381`
 		//  Estimated: `5082`
-		// Minimum execution time: 21_974 nanoseconds.
-		Weight::from_ref_time(22_770_000)
+		// Minimum execution time: 29_918 nanoseconds.
+		Weight::from_ref_time(34_606_000)
 			.saturating_add(Weight::from_proof_size(5082))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:0 w:4)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:4)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	fn set_team() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `362`
-		//  Estimated: `2555`
-		// Minimum execution time: 24_341 nanoseconds.
-		Weight::from_ref_time(25_059_000)
-			.saturating_add(Weight::from_proof_size(2555))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Estimated: `5099`
+		// Minimum execution time: 33_185 nanoseconds.
+		Weight::from_ref_time(37_942_000)
+			.saturating_add(Weight::from_proof_size(5099))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
@@ -1104,8 +1557,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `304`
 		//  Estimated: `2555`
-		// Minimum execution time: 16_897 nanoseconds.
-		Weight::from_ref_time(17_560_000)
+		// Minimum execution time: 22_875 nanoseconds.
+		Weight::from_ref_time(26_351_000)
 			.saturating_add(Weight::from_proof_size(2555))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
@@ -1113,13 +1566,13 @@ impl WeightInfo for () {
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	fn force_collection_config() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `242`
 		//  Estimated: `2555`
-		// Minimum execution time: 13_239 nanoseconds.
-		Weight::from_ref_time(13_963_000)
+		// Minimum execution time: 17_454 nanoseconds.
+		Weight::from_ref_time(19_721_000)
 			.saturating_add(Weight::from_proof_size(2555))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
@@ -1132,8 +1585,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `445`
 		//  Estimated: `5078`
-		// Minimum execution time: 17_187 nanoseconds.
-		Weight::from_ref_time(17_942_000)
+		// Minimum execution time: 22_997 nanoseconds.
+		Weight::from_ref_time(26_577_000)
 			.saturating_add(Weight::from_proof_size(5078))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
@@ -1141,18 +1594,18 @@ impl WeightInfo for () {
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Attribute (r:1 w:1)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
 	fn set_attribute() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `474`
-		//  Estimated: `10547`
-		// Minimum execution time: 40_925 nanoseconds.
-		Weight::from_ref_time(42_733_000)
-			.saturating_add(Weight::from_proof_size(10547))
+		//  Measured:  `475`
+		//  Estimated: `10552`
+		// Minimum execution time: 60_806 nanoseconds.
+		Weight::from_ref_time(69_011_000)
+			.saturating_add(Weight::from_proof_size(10552))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -1164,8 +1617,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `337`
 		//  Estimated: `5476`
-		// Minimum execution time: 24_486 nanoseconds.
-		Weight::from_ref_time(25_409_000)
+		// Minimum execution time: 36_965 nanoseconds.
+		Weight::from_ref_time(42_383_000)
 			.saturating_add(Weight::from_proof_size(5476))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
@@ -1180,8 +1633,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `916`
 		//  Estimated: `7999`
-		// Minimum execution time: 36_643 nanoseconds.
-		Weight::from_ref_time(37_805_000)
+		// Minimum execution time: 50_564 nanoseconds.
+		Weight::from_ref_time(55_615_000)
 			.saturating_add(Weight::from_proof_size(7999))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
@@ -1194,8 +1647,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `379`
 		//  Estimated: `6492`
-		// Minimum execution time: 16_798 nanoseconds.
-		Weight::from_ref_time(17_326_000)
+		// Minimum execution time: 22_635 nanoseconds.
+		Weight::from_ref_time(24_924_000)
 			.saturating_add(Weight::from_proof_size(6492))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
@@ -1213,11 +1666,11 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `899 + n * (396 ±0)`
 		//  Estimated: `12016 + n * (2921 ±0)`
-		// Minimum execution time: 25_524 nanoseconds.
-		Weight::from_ref_time(26_107_000)
+		// Minimum execution time: 34_072 nanoseconds.
+		Weight::from_ref_time(38_996_000)
 			.saturating_add(Weight::from_proof_size(12016))
-			// Standard Error: 5_460
-			.saturating_add(Weight::from_ref_time(9_030_830).saturating_mul(n.into()))
+			// Standard Error: 25_250
+			.saturating_add(Weight::from_ref_time(11_451_053).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
@@ -1229,16 +1682,16 @@ impl WeightInfo for () {
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	fn set_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `474`
-		//  Estimated: `10241`
-		// Minimum execution time: 34_400 nanoseconds.
-		Weight::from_ref_time(35_469_000)
-			.saturating_add(Weight::from_proof_size(10241))
+		//  Measured:  `475`
+		//  Estimated: `10246`
+		// Minimum execution time: 48_728 nanoseconds.
+		Weight::from_ref_time(55_192_000)
+			.saturating_add(Weight::from_proof_size(10246))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -1252,57 +1705,57 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `609`
 		//  Estimated: `7693`
-		// Minimum execution time: 31_560 nanoseconds.
-		Weight::from_ref_time(33_081_000)
+		// Minimum execution time: 46_453 nanoseconds.
+		Weight::from_ref_time(50_390_000)
 			.saturating_add(Weight::from_proof_size(7693))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionMetadataOf (r:1 w:1)
 	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
 	fn set_collection_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `333`
-		//  Estimated: `7665`
-		// Minimum execution time: 28_821 nanoseconds.
-		Weight::from_ref_time(30_010_000)
-			.saturating_add(Weight::from_proof_size(7665))
+		//  Measured:  `334`
+		//  Estimated: `7670`
+		// Minimum execution time: 42_079 nanoseconds.
+		Weight::from_ref_time(68_136_000)
+			.saturating_add(Weight::from_proof_size(7670))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionMetadataOf (r:1 w:1)
 	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
 	fn clear_collection_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `476`
-		//  Estimated: `7665`
-		// Minimum execution time: 27_608 nanoseconds.
-		Weight::from_ref_time(28_766_000)
-			.saturating_add(Weight::from_proof_size(7665))
+		//  Measured:  `477`
+		//  Estimated: `7670`
+		// Minimum execution time: 37_603 nanoseconds.
+		Weight::from_ref_time(42_885_000)
+			.saturating_add(Weight::from_proof_size(7670))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	fn approve_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `466`
-		//  Estimated: `8428`
-		// Minimum execution time: 23_987 nanoseconds.
-		Weight::from_ref_time(24_819_000)
-			.saturating_add(Weight::from_proof_size(8428))
+		//  Measured:  `467`
+		//  Estimated: `8433`
+		// Minimum execution time: 34_299 nanoseconds.
+		Weight::from_ref_time(44_718_000)
+			.saturating_add(Weight::from_proof_size(8433))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1314,8 +1767,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `474`
 		//  Estimated: `5880`
-		// Minimum execution time: 21_254 nanoseconds.
-		Weight::from_ref_time(21_826_000)
+		// Minimum execution time: 28_303 nanoseconds.
+		Weight::from_ref_time(34_769_000)
 			.saturating_add(Weight::from_proof_size(5880))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
@@ -1328,8 +1781,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `474`
 		//  Estimated: `5880`
-		// Minimum execution time: 20_272 nanoseconds.
-		Weight::from_ref_time(20_922_000)
+		// Minimum execution time: 28_664 nanoseconds.
+		Weight::from_ref_time(31_941_000)
 			.saturating_add(Weight::from_proof_size(5880))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
@@ -1340,107 +1793,171 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `2527`
-		// Minimum execution time: 14_287 nanoseconds.
-		Weight::from_ref_time(14_960_000)
+		// Minimum execution time: 19_307 nanoseconds.
+		Weight::from_ref_time(22_456_000)
 			.saturating_add(Weight::from_proof_size(2527))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts CollectionConfigOf (r:1 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	fn set_collection_max_supply() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `333`
-		//  Estimated: `5103`
-		// Minimum execution time: 17_948 nanoseconds.
-		Weight::from_ref_time(18_780_000)
-			.saturating_add(Weight::from_proof_size(5103))
+		//  Measured:  `334`
+		//  Estimated: `5108`
+		// Minimum execution time: 42_543 nanoseconds.
+		Weight::from_ref_time(60_011_000)
+			.saturating_add(Weight::from_proof_size(5108))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:1)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	fn update_mint_settings() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `333`
-		//  Estimated: `5103`
-		// Minimum execution time: 16_616 nanoseconds.
-		Weight::from_ref_time(17_155_000)
-			.saturating_add(Weight::from_proof_size(5103))
+		//  Measured:  `334`
+		//  Estimated: `5108`
+		// Minimum execution time: 22_329 nanoseconds.
+		Weight::from_ref_time(25_012_000)
+			.saturating_add(Weight::from_proof_size(5108))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:0)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	fn set_price() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `516`
-		//  Estimated: `8407`
-		// Minimum execution time: 22_777 nanoseconds.
-		Weight::from_ref_time(23_955_000)
-			.saturating_add(Weight::from_proof_size(8407))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		//  Measured:  `517`
+		//  Estimated: `11053`
+		// Minimum execution time: 39_026 nanoseconds.
+		Weight::from_ref_time(43_115_000)
+			.saturating_add(Weight::from_proof_size(11053))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:1 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:2)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
-	fn buy_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `934`
-		//  Estimated: `16129`
-		// Minimum execution time: 61_131 nanoseconds.
-		Weight::from_ref_time(62_791_000)
-			.saturating_add(Weight::from_proof_size(16129))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	/// The range of component `r` is `[0, 5]`.
+	fn buy_item(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `936 + r * (77 ±0)`
+		//  Estimated: `26675 + r * (2603 ±0)`
+		// Minimum execution time: 114_475 nanoseconds.
+		Weight::from_ref_time(136_966_058)
+			.saturating_add(Weight::from_proof_size(26675))
+			// Standard Error: 436_150
+			.saturating_add(Weight::from_ref_time(39_452_360).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(2603).saturating_mul(r.into()))
+	}
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:1 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:7 w:7)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 5]`.
+	fn buy_item_priced_in_asset(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1432 + r * (77 ±0)`
+		//  Estimated: `34515 + r * (5180 ±0)`
+		// Minimum execution time: 144_152 nanoseconds.
+		Weight::from_ref_time(164_835_095)
+			.saturating_add(Weight::from_proof_size(34515))
+			// Standard Error: 1_276_019
+			.saturating_add(Weight::from_ref_time(66_426_026).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(5180).saturating_mul(r.into()))
 	}
 	/// The range of component `n` is `[0, 10]`.
 	fn pay_tips(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 1_952 nanoseconds.
-		Weight::from_ref_time(3_975_700)
+		// Minimum execution time: 2_654 nanoseconds.
+		Weight::from_ref_time(5_270_165)
 			.saturating_add(Weight::from_proof_size(0))
-			// Standard Error: 11_254
-			.saturating_add(Weight::from_ref_time(3_501_698).saturating_mul(n.into()))
+			// Standard Error: 87_690
+			.saturating_add(Weight::from_ref_time(5_967_645).saturating_mul(n.into()))
 	}
 	/// Storage: Nfts Item (r:2 w:0)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn create_swap() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `524`
-		//  Estimated: `6672`
-		// Minimum execution time: 20_327 nanoseconds.
-		Weight::from_ref_time(21_714_000)
-			.saturating_add(Weight::from_proof_size(6672))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Measured:  `554`
+		//  Estimated: `11866`
+		// Minimum execution time: 34_133 nanoseconds.
+		Weight::from_ref_time(46_518_000)
+			.saturating_add(Weight::from_proof_size(11866))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts PendingSwapOf (r:1 w:1)
@@ -1451,8 +1968,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `511`
 		//  Estimated: `5882`
-		// Minimum execution time: 20_668 nanoseconds.
-		Weight::from_ref_time(21_416_000)
+		// Minimum execution time: 28_538 nanoseconds.
+		Weight::from_ref_time(32_432_000)
 			.saturating_add(Weight::from_proof_size(5882))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
@@ -1461,32 +1978,46 @@ impl WeightInfo for () {
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:1 w:2)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:2 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:2 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:2 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:4)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:2)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
-	fn claim_swap() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1097`
-		//  Estimated: `21970`
-		// Minimum execution time: 88_006 nanoseconds.
-		Weight::from_ref_time(90_390_000)
-			.saturating_add(Weight::from_proof_size(21970))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 5]`.
+	fn claim_swap(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1118 + r * (90 ±0)`
+		//  Estimated: `37675 + r * (2603 ±0)`
+		// Minimum execution time: 158_919 nanoseconds.
+		Weight::from_ref_time(233_819_102)
+			.saturating_add(Weight::from_proof_size(37675))
+			// Standard Error: 1_854_833
+			.saturating_add(Weight::from_ref_time(30_394_736).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(2603).saturating_mul(r.into()))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1 w:1)
@@ -1502,13 +2033,13 @@ impl WeightInfo for () {
 	/// The range of component `n` is `[0, 10]`.
 	fn mint_pre_signed(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `596`
-		//  Estimated: `16180 + n * (2921 ±0)`
-		// Minimum execution time: 124_967 nanoseconds.
-		Weight::from_ref_time(131_602_642)
-			.saturating_add(Weight::from_proof_size(16180))
-			// Standard Error: 36_480
-			.saturating_add(Weight::from_ref_time(25_811_394).saturating_mul(n.into()))
+		//  Measured:  `597`
+		//  Estimated: `16185 + n * (2921 ±0)`
+		// Minimum execution time: 162_795 nanoseconds.
+		Weight::from_ref_time(208_640_255)
+			.saturating_add(Weight::from_proof_size(16185))
+			// Standard Error: 334_985
+			.saturating_add(Weight::from_ref_time(41_706_094).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
@@ -1522,7 +2053,7 @@ impl WeightInfo for () {
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: Nfts Attribute (r:10 w:10)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
@@ -1530,17 +2061,273 @@ impl WeightInfo for () {
 	/// The range of component `n` is `[0, 10]`.
 	fn set_attributes_pre_signed(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `716`
-		//  Estimated: `14198 + n * (2921 ±0)`
-		// Minimum execution time: 84_153 nanoseconds.
-		Weight::from_ref_time(96_401_623)
-			.saturating_add(Weight::from_proof_size(14198))
-			// Standard Error: 70_244
-			.saturating_add(Weight::from_ref_time(26_866_222).saturating_mul(n.into()))
+		//  Measured:  `717`
+		//  Estimated: `14203 + n * (2921 ±0)`
+		// Minimum execution time: 101_028 nanoseconds.
+		Weight::from_ref_time(145_563_474)
+			.saturating_add(Weight::from_proof_size(14203))
+			// Standard Error: 343_232
+			.saturating_add(Weight::from_ref_time(44_699_085).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_proof_size(2921).saturating_mul(n.into()))
 	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	fn set_collection_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `334`
+		//  Estimated: `5108`
+		// Minimum execution time: 23_991 nanoseconds.
+		Weight::from_ref_time(27_380_000)
+			.saturating_add(Weight::from_proof_size(5108))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:0 w:1)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	fn set_item_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `445`
+		//  Estimated: `5078`
+		// Minimum execution time: 27_886 nanoseconds.
+		Weight::from_ref_time(31_121_000)
+			.saturating_add(Weight::from_proof_size(5078))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionEndings (r:1 w:1)
+	/// Proof: Nfts AuctionEndings (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `517`
+		//  Estimated: `16144`
+		// Minimum execution time: 46_978 nanoseconds.
+		Weight::from_ref_time(54_260_000)
+			.saturating_add(Weight::from_proof_size(16144))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionEndings (r:1 w:1)
+	/// Proof: Nfts AuctionEndings (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `871`
+		//  Estimated: `10415`
+		// Minimum execution time: 64_281 nanoseconds.
+		Weight::from_ref_time(83_086_000)
+			.saturating_add(Weight::from_proof_size(10415))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 5]`.
+	fn dutch_bid(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1038 + r * (96 ±0)`
+		//  Estimated: `24106 + r * (2603 ±0)`
+		// Minimum execution time: 140_492 nanoseconds.
+		Weight::from_ref_time(185_736_223)
+			.saturating_add(Weight::from_proof_size(24106))
+			// Standard Error: 1_186_934
+			.saturating_add(Weight::from_ref_time(63_797_926).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(2603).saturating_mul(r.into()))
+	}
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts ItemRoyaltiesOf (max_values: None, max_size: Some(221), added: 2696, mode: MaxEncodedLen)
+	/// Storage: System Account (r:6 w:6)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoyaltiesOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoyaltiesOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 5]`.
+	fn settle_auction(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1090 + r * (96 ±0)`
+		//  Estimated: `24106 + r * (2603 ±0)`
+		// Minimum execution time: 138_921 nanoseconds.
+		Weight::from_ref_time(201_680_008)
+			.saturating_add(Weight::from_proof_size(24106))
+			// Standard Error: 675_173
+			.saturating_add(Weight::from_ref_time(33_914_384).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(2603).saturating_mul(r.into()))
+	}
+	/// Storage: Nfts AuctionOf (r:1 w:1)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `2641`
+		// Minimum execution time: 20_697 nanoseconds.
+		Weight::from_ref_time(37_125_000)
+			.saturating_add(Weight::from_proof_size(2641))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:2 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts ParentOf (r:3 w:1)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:2 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts ChildrenOf (r:11 w:1)
+	/// Proof: Nfts ChildrenOf (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn nest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2565`
+		//  Estimated: `55754`
+		// Minimum execution time: 162_917 nanoseconds.
+		Weight::from_ref_time(187_871_000)
+			.saturating_add(Weight::from_proof_size(55754))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Nfts ParentOf (r:4 w:1)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:2 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:2 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts ChildrenOf (r:10 w:1)
+	/// Proof: Nfts ChildrenOf (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn unnest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2603`
+		//  Estimated: `55677`
+		// Minimum execution time: 155_087 nanoseconds.
+		Weight::from_ref_time(168_585_000)
+			.saturating_add(Weight::from_proof_size(55677))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Nfts ParentOf (r:1 w:0)
+	/// Proof: Nfts ParentOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Nfts AuctionOf (r:1 w:0)
+	/// Proof: Nfts AuctionOf (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn force_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `825`
+		//  Estimated: `18734`
+		// Minimum execution time: 77_563 nanoseconds.
+		Weight::from_ref_time(85_746_000)
+			.saturating_add(Weight::from_proof_size(18734))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
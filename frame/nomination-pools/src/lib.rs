@@ -1368,7 +1368,6 @@ pub mod pallet {
 		pub max_members: Option<u32>,
 	}
 
-	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
 			Self {
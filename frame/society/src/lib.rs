@@ -650,7 +650,6 @@ pub mod pallet {
 		pub max_members: u32,
 	}
 
-	#[cfg(feature = "std")]
 	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
 		fn default() -> Self {
 			Self {
@@ -18,7 +18,7 @@
 use crate::construct_runtime::Pallet;
 use inflector::Inflector;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote, ToTokens};
+use quote::{format_ident, quote};
 use std::str::FromStr;
 use syn::Ident;
 
@@ -30,13 +30,13 @@ pub fn expand_outer_config(
 	let mut types = TokenStream::new();
 	let mut fields = TokenStream::new();
 	let mut build_storage_calls = TokenStream::new();
+	let mut genesis_build_calls = TokenStream::new();
 	let mut query_genesis_config_part_macros = Vec::new();
 
 	for decl in pallet_decls {
 		if let Some(pallet_entry) = decl.find_part("Config") {
 			let path = &decl.path;
 			let pallet_name = &decl.name;
-			let path_str = path.into_token_stream().to_string();
 			let config = format_ident!("{}Config", pallet_name);
 			let field_name =
 				&Ident::new(&pallet_name.to_string().to_snake_case(), decl.name.span());
@@ -54,10 +54,10 @@ pub fn expand_outer_config(
 			fields.extend(quote!(#attr pub #field_name: #config,));
 			build_storage_calls
 				.extend(expand_config_build_storage_call(scrate, attr, runtime, decl, field_name));
+			genesis_build_calls
+				.extend(expand_config_genesis_build_call(scrate, attr, runtime, decl, field_name));
 			query_genesis_config_part_macros.push(quote! {
 				#path::__substrate_genesis_config_check::is_genesis_config_defined!(#pallet_name);
-				#[cfg(feature = "std")]
-				#path::__substrate_genesis_config_check::is_std_enabled_for_genesis!(#pallet_name, #path_str);
 			});
 		}
 	}
@@ -67,9 +67,7 @@ pub fn expand_outer_config(
 
 		#types
 
-		#[cfg(any(feature = "std", test))]
 		use #scrate::serde as __genesis_config_serde_import__;
-		#[cfg(any(feature = "std", test))]
 		#[derive(#scrate::serde::Serialize, #scrate::serde::Deserialize, Default)]
 		#[serde(rename_all = "camelCase")]
 		#[serde(deny_unknown_fields)]
@@ -93,6 +91,14 @@ pub fn expand_outer_config(
 				Ok(())
 			}
 		}
+
+		impl #scrate::traits::BuildGenesisConfig for GenesisConfig {
+			fn build(&self) {
+				#genesis_build_calls
+
+				<AllPalletsWithSystem as #scrate::traits::OnGenesis>::on_genesis();
+			}
+		}
 	}
 }
 
@@ -108,17 +114,14 @@ fn expand_config_types(
 	match (decl.instance.as_ref(), part_is_generic) {
 		(Some(inst), true) => quote! {
 			#attr
-			#[cfg(any(feature = "std", test))]
 			pub type #config = #path::GenesisConfig<#runtime, #path::#inst>;
 		},
 		(None, true) => quote! {
 			#attr
-			#[cfg(any(feature = "std", test))]
 			pub type #config = #path::GenesisConfig<#runtime>;
 		},
 		(_, false) => quote! {
 			#attr
-			#[cfg(any(feature = "std", test))]
 			pub type #config = #path::GenesisConfig;
 		},
 	}
@@ -144,3 +147,23 @@ fn expand_config_build_storage_call(
 			<#runtime, #instance>::build_module_genesis_storage(&self.#field_name, storage)?;
 	}
 }
+
+fn expand_config_genesis_build_call(
+	scrate: &TokenStream,
+	attr: &TokenStream,
+	runtime: &Ident,
+	decl: &Pallet,
+	field_name: &Ident,
+) -> TokenStream {
+	let path = &decl.path;
+	let instance = if let Some(inst) = decl.instance.as_ref() {
+		quote!(#path::#inst)
+	} else {
+		quote!(#path::__InherentHiddenInstance)
+	};
+
+	quote! {
+		#attr
+		#scrate::traits::GenesisBuild::<#runtime, #instance>::build(&self.#field_name);
+	}
+}
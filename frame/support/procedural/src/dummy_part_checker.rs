@@ -24,8 +24,6 @@ pub fn generate_dummy_part_checker(input: TokenStream) -> TokenStream {
 		pub mod __substrate_genesis_config_check {
 			#[doc(hidden)]
 			pub use #no_op_macro_ident as is_genesis_config_defined;
-			#[doc(hidden)]
-			pub use #no_op_macro_ident as is_std_enabled_for_genesis;
 		}
 
 		#[doc(hidden)]
@@ -24,55 +24,38 @@ use syn::{spanned::Spanned, Ident};
 pub fn expand_genesis_config(def: &mut Def) -> proc_macro2::TokenStream {
 	let count = COUNTER.with(|counter| counter.borrow_mut().inc());
 
-	let (genesis_config, def_macro_ident, std_macro_ident) =
-		if let Some(genesis_config) = &def.genesis_config {
-			let def_macro_ident = Ident::new(
-				&format!("__is_genesis_config_defined_{}", count),
-				genesis_config.genesis_config.span(),
-			);
-
-			let std_macro_ident = Ident::new(
-				&format!("__is_std_macro_defined_for_genesis_{}", count),
-				genesis_config.genesis_config.span(),
-			);
-
-			(genesis_config, def_macro_ident, std_macro_ident)
-		} else {
-			let def_macro_ident =
-				Ident::new(&format!("__is_genesis_config_defined_{}", count), def.item.span());
-
-			let std_macro_ident =
-				Ident::new(&format!("__is_std_enabled_for_genesis_{}", count), def.item.span());
-
-			return quote::quote! {
+	let (genesis_config, def_macro_ident) = if let Some(genesis_config) = &def.genesis_config {
+		let def_macro_ident = Ident::new(
+			&format!("__is_genesis_config_defined_{}", count),
+			genesis_config.genesis_config.span(),
+		);
+
+		(genesis_config, def_macro_ident)
+	} else {
+		let def_macro_ident =
+			Ident::new(&format!("__is_genesis_config_defined_{}", count), def.item.span());
+
+		return quote::quote! {
+			#[doc(hidden)]
+			pub mod __substrate_genesis_config_check {
+				#[macro_export]
 				#[doc(hidden)]
-				pub mod __substrate_genesis_config_check {
-					#[macro_export]
-					#[doc(hidden)]
-					macro_rules! #def_macro_ident {
-						($pallet_name:ident) => {
-							compile_error!(concat!(
-								"`",
-								stringify!($pallet_name),
-								"` does not have #[pallet::genesis_config] defined, perhaps you should \
-								remove `Config` from construct_runtime?",
-							));
-						}
-					}
-
-					#[macro_export]
-					#[doc(hidden)]
-					macro_rules! #std_macro_ident {
-						($pallet_name:ident, $pallet_path:expr) => {};
+				macro_rules! #def_macro_ident {
+					($pallet_name:ident) => {
+						compile_error!(concat!(
+							"`",
+							stringify!($pallet_name),
+							"` does not have #[pallet::genesis_config] defined, perhaps you should \
+							remove `Config` from construct_runtime?",
+						));
 					}
-
-					#[doc(hidden)]
-					pub use #def_macro_ident as is_genesis_config_defined;
-					#[doc(hidden)]
-					pub use #std_macro_ident as is_std_enabled_for_genesis;
 				}
+
+				#[doc(hidden)]
+				pub use #def_macro_ident as is_genesis_config_defined;
 			}
-		};
+		}
+	};
 
 	let frame_support = &def.frame_support;
 
@@ -81,6 +64,8 @@ pub fn expand_genesis_config(def: &mut Def) -> proc_macro2::TokenStream {
 
 	let serde_crate = format!("{}::serde", frame_support);
 
+	let (serialize_bound, deserialize_bound) = serde_bounds(genesis_config_item, &serde_crate);
+
 	match genesis_config_item {
 		syn::Item::Enum(syn::ItemEnum { attrs, .. }) |
 		syn::Item::Struct(syn::ItemStruct { attrs, .. }) |
@@ -94,14 +79,13 @@ pub fn expand_genesis_config(def: &mut Def) -> proc_macro2::TokenStream {
 					"]
 				));
 			}
-			attrs.push(syn::parse_quote!( #[cfg(feature = "std")] ));
 			attrs.push(syn::parse_quote!(
 				#[derive(#frame_support::Serialize, #frame_support::Deserialize)]
 			));
 			attrs.push(syn::parse_quote!( #[serde(rename_all = "camelCase")] ));
 			attrs.push(syn::parse_quote!( #[serde(deny_unknown_fields)] ));
-			attrs.push(syn::parse_quote!( #[serde(bound(serialize = ""))] ));
-			attrs.push(syn::parse_quote!( #[serde(bound(deserialize = ""))] ));
+			attrs.push(syn::parse_quote!( #[serde(bound(serialize = #serialize_bound))] ));
+			attrs.push(syn::parse_quote!( #[serde(bound(deserialize = #deserialize_bound))] ));
 			attrs.push(syn::parse_quote!( #[serde(crate = #serde_crate)] ));
 		},
 		_ => unreachable!("Checked by genesis_config parser"),
@@ -116,32 +100,73 @@ pub fn expand_genesis_config(def: &mut Def) -> proc_macro2::TokenStream {
 				($pallet_name:ident) => {};
 			}
 
-			#[cfg(not(feature = "std"))]
-			#[macro_export]
 			#[doc(hidden)]
-			macro_rules! #std_macro_ident {
-				($pallet_name:ident, $pallet_path:expr) => {
-					compile_error!(concat!(
-						"`",
-						stringify!($pallet_name),
-						"` does not have the std feature enabled, this will cause the `",
-						$pallet_path,
-						"::GenesisConfig` type to be undefined."
-					));
-				};
-			}
+			pub use #def_macro_ident as is_genesis_config_defined;
+		}
+	}
+}
 
-			#[cfg(feature = "std")]
-			#[macro_export]
-			#[doc(hidden)]
-			macro_rules! #std_macro_ident {
-				($pallet_name:ident, $pallet_path:expr) => {};
-			}
+/// Build the serde `bound` strings for the genesis config.
+///
+/// Without `std` the pallet types are not required to be serializable, so instead of serde's
+/// inferred bounds (or no bounds at all) every serialized field type must implement
+/// `Serialize`/`Deserialize` for the derived impls to exist.
+fn serde_bounds(item: &syn::Item, serde_crate: &str) -> (String, String) {
+	let fields: Vec<&syn::Field> = match item {
+		syn::Item::Struct(item) => item.fields.iter().collect(),
+		syn::Item::Enum(item) => item.variants.iter().flat_map(|v| v.fields.iter()).collect(),
+		_ => Vec::new(),
+	};
+
+	let mut serialize = Vec::new();
+	let mut deserialize = Vec::new();
+	for field in fields {
+		let (skip_serializing, skip_deserializing) = serde_skips(&field.attrs);
+		let ty = &field.ty;
+		let ty = quote::quote!(#ty).to_string();
+		if !skip_serializing && !serialize.contains(&ty) {
+			serialize.push(ty.clone());
+		}
+		if !skip_deserializing && !deserialize.contains(&ty) {
+			deserialize.push(ty);
+		}
+	}
 
-			#[doc(hidden)]
-			pub use #def_macro_ident as is_genesis_config_defined;
-			#[doc(hidden)]
-			pub use #std_macro_ident as is_std_enabled_for_genesis;
+	(
+		serialize
+			.iter()
+			.map(|ty| format!("{}: {}::Serialize", ty, serde_crate))
+			.collect::<Vec<_>>()
+			.join(", "),
+		deserialize
+			.iter()
+			.map(|ty| format!("{}: {}::de::DeserializeOwned", ty, serde_crate))
+			.collect::<Vec<_>>()
+			.join(", "),
+	)
+}
+
+/// Whether the field is skipped (or serialized by a custom function) when serializing and when
+/// deserializing, in which case no bound on its type is needed.
+fn serde_skips(attrs: &[syn::Attribute]) -> (bool, bool) {
+	let (mut ser, mut de) = (false, false);
+	for attr in attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+		let Ok(syn::Meta::List(list)) = attr.parse_meta() else { continue };
+		for nested in list.nested {
+			let path = match nested {
+				syn::NestedMeta::Meta(syn::Meta::Path(path)) => path,
+				syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) => nv.path,
+				_ => continue,
+			};
+			if path.is_ident("skip") || path.is_ident("with") {
+				ser = true;
+				de = true;
+			} else if path.is_ident("skip_serializing") || path.is_ident("serialize_with") {
+				ser = true;
+			} else if path.is_ident("skip_deserializing") || path.is_ident("deserialize_with") {
+				de = true;
+			}
 		}
 	}
+	(ser, de)
 }
@@ -171,6 +171,15 @@ fn impl_build_storage(
 				self.assimilate_storage::<#fn_traitinstance> (storage)
 			}
 		}
+
+		#[cfg(feature = "std")]
+		impl #build_storage_impl #scrate::traits::GenesisBuild<#runtime_generic, #inherent_instance>
+			for GenesisConfig #genesis_struct #where_clause
+		{
+			fn build(&self) {
+				#( #builder_blocks )*
+			}
+		}
 	}
 }
 
@@ -0,0 +1,96 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helper functions for implementing [`sp_genesis_builder::GenesisBuilder`] for runtimes.
+//!
+//! Provides common logic. For more info refer to [`sp_genesis_builder::GenesisBuilder`].
+//!
+//! A runtime forwards the API to these helpers, passing the `GenesisConfig` generated by
+//! `construct_runtime!`:
+//!
+//! ```ignore
+//! impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
+//! 	fn create_default_config() -> Vec<u8> {
+//! 		frame_support::genesis_builder_helper::create_default_config::<GenesisConfig>()
+//! 	}
+//!
+//! 	fn build_config(config: Vec<u8>) -> sp_genesis_builder::Result {
+//! 		frame_support::genesis_builder_helper::build_config::<GenesisConfig>(config)
+//! 	}
+//! }
+//! ```
+
+use crate::traits::BuildGenesisConfig;
+use sp_runtime::format_runtime_string;
+use sp_std::vec::Vec;
+
+/// Get the default `GenesisConfig` as a JSON blob. For more info refer to
+/// [`sp_genesis_builder::GenesisBuilder::create_default_config`]
+pub fn create_default_config<GC: BuildGenesisConfig + serde::Serialize>() -> Vec<u8> {
+	serde_json::to_vec(&GC::default()).expect("serialization to json is expected to work. qed.")
+}
+
+/// Build `GenesisConfig` from a JSON blob not using any defaults and store it in the storage. For
+/// more info refer to [`sp_genesis_builder::GenesisBuilder::build_config`].
+pub fn build_config<GC: BuildGenesisConfig + serde::de::DeserializeOwned>(
+	json: Vec<u8>,
+) -> sp_genesis_builder::Result {
+	let gc = serde_json::from_slice::<GC>(&json)
+		.map_err(|e| format_runtime_string!("Invalid JSON blob: {}", e))?;
+	<GC as BuildGenesisConfig>::build(&gc);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+
+	#[derive(Default, serde::Serialize, serde::Deserialize)]
+	#[serde(rename_all = "camelCase", deny_unknown_fields)]
+	struct TestGenesisConfig {
+		some_value: u32,
+	}
+
+	impl BuildGenesisConfig for TestGenesisConfig {
+		fn build(&self) {
+			sp_io::storage::set(b"some_value", &self.some_value.encode());
+		}
+	}
+
+	#[test]
+	fn default_config_is_json() {
+		assert_eq!(create_default_config::<TestGenesisConfig>(), br#"{"someValue":0}"#.to_vec());
+	}
+
+	#[test]
+	fn build_config_writes_storage() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			build_config::<TestGenesisConfig>(br#"{"someValue":42}"#.to_vec()).unwrap();
+
+			assert_eq!(sp_io::storage::get(b"some_value"), Some(42u32.encode().into()));
+		});
+	}
+
+	#[test]
+	fn build_config_rejects_invalid_json() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(build_config::<TestGenesisConfig>(br#"{"otherValue":42}"#.to_vec()).is_err());
+			assert_eq!(sp_io::storage::get(b"some_value"), None);
+		});
+	}
+}
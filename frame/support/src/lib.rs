@@ -48,7 +48,6 @@ pub use once_cell;
 pub use paste;
 #[doc(hidden)]
 pub use scale_info;
-#[cfg(feature = "std")]
 pub use serde;
 pub use sp_core::Void;
 #[doc(hidden)]
@@ -74,6 +73,7 @@ mod hash;
 pub mod storage;
 #[macro_use]
 pub mod event;
+pub mod genesis_builder_helper;
 pub mod inherent;
 #[macro_use]
 pub mod error;
@@ -819,7 +819,6 @@ macro_rules! assert_error_encoded_size {
 	} => {};
 }
 
-#[cfg(feature = "std")]
 #[doc(hidden)]
 pub use serde::{Deserialize, Serialize};
 
@@ -1446,8 +1445,6 @@ pub mod tests {
 
 /// Prelude to be used alongside pallet macro, for ease of use.
 pub mod pallet_prelude {
-	#[cfg(feature = "std")]
-	pub use crate::traits::GenesisBuild;
 	pub use crate::{
 		dispatch::{
 			DispatchClass, DispatchError, DispatchResult, DispatchResultWithPostInfo, Parameter,
@@ -1464,8 +1461,8 @@ pub mod pallet_prelude {
 			},
 		},
 		traits::{
-			ConstU32, EnsureOrigin, Get, GetDefault, GetStorageVersion, Hooks, IsType,
-			PalletInfoAccess, StorageInfoTrait, StorageVersion, TypedGet,
+			ConstU32, EnsureOrigin, GenesisBuild, Get, GetDefault, GetStorageVersion, Hooks,
+			IsType, PalletInfoAccess, StorageInfoTrait, StorageVersion, TypedGet,
 		},
 		Blake2_128, Blake2_128Concat, Blake2_256, CloneNoBound, DebugNoBound, EqNoBound, Identity,
 		PartialEqNoBound, RuntimeDebug, RuntimeDebugNoBound, Twox128, Twox256, Twox64Concat,
@@ -2159,6 +2156,11 @@ pub mod pallet_prelude {
 /// [`#[pallet::genesis_build]`](#genesis-build-palletgenesis_build-optional). The type
 /// generics are constrained to be either none, or `T` or `T: Config`.
 ///
+/// The genesis config is also compiled without `std`, so the runtime can build it through
+/// [`genesis_builder_helper`]. Its `Default` implementation must not be gated on `std` and the
+/// types of all serialized fields must implement `serde::Serialize` and `serde::Deserialize`
+/// in `no_std` as well.
+///
 /// E.g:
 ///
 /// ```ignore
@@ -80,11 +80,9 @@ pub use metadata::{
 };
 
 mod hooks;
-#[cfg(feature = "std")]
-pub use hooks::GenesisBuild;
 pub use hooks::{
-	Hooks, IntegrityTest, OnFinalize, OnGenesis, OnIdle, OnInitialize, OnRuntimeUpgrade,
-	OnTimestampSet,
+	BuildGenesisConfig, GenesisBuild, Hooks, IntegrityTest, OnFinalize, OnGenesis, OnIdle,
+	OnInitialize, OnRuntimeUpgrade, OnTimestampSet,
 };
 
 pub mod schedule;
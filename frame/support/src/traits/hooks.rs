@@ -325,13 +325,13 @@ pub trait Hooks<BlockNumber> {
 
 /// A trait to define the build function of a genesis config, T and I are placeholder for pallet
 /// trait and pallet instance.
-#[cfg(feature = "std")]
 pub trait GenesisBuild<T, I = ()>: Default + sp_runtime::traits::MaybeSerializeDeserialize {
 	/// The build function is called within an externalities allowing storage APIs.
 	/// Thus one can write to storage using regular pallet storages.
 	fn build(&self);
 
 	/// Build the storage using `build` inside default storage.
+	#[cfg(feature = "std")]
 	fn build_storage(&self) -> Result<sp_runtime::Storage, String> {
 		let mut storage = Default::default();
 		self.assimilate_storage(&mut storage)?;
@@ -339,6 +339,7 @@ pub trait GenesisBuild<T, I = ()>: Default + sp_runtime::traits::MaybeSerializeD
 	}
 
 	/// Assimilate the storage for this module into pre-existing overlays.
+	#[cfg(feature = "std")]
 	fn assimilate_storage(&self, storage: &mut sp_runtime::Storage) -> Result<(), String> {
 		sp_state_machine::BasicExternalities::execute_with_storage(storage, || {
 			self.build();
@@ -347,6 +348,16 @@ pub trait GenesisBuild<T, I = ()>: Default + sp_runtime::traits::MaybeSerializeD
 	}
 }
 
+/// A trait to build the whole runtime genesis config in the current externalities.
+///
+/// Implemented by the `GenesisConfig` generated by `construct_runtime!`. Unlike
+/// [`sp_runtime::BuildStorage`] this is available without `std`, which is what allows the
+/// runtime to build its genesis state through [`sp_genesis_builder::GenesisBuilder`].
+pub trait BuildGenesisConfig: Default + sp_runtime::traits::MaybeSerializeDeserialize {
+	/// Write the genesis state of all pallets into the storage.
+	fn build(&self);
+}
+
 /// A trait which is called when the timestamp is set in the runtime.
 #[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
 #[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
@@ -224,6 +224,15 @@ impl PartialOrd<u16> for StorageVersion {
 	}
 }
 
+/// Adds to the version, saturating at `u16::MAX`.
+impl sp_std::ops::Add<u16> for StorageVersion {
+	type Output = StorageVersion;
+
+	fn add(self, rhs: u16) -> Self::Output {
+		Self::new(self.0.saturating_add(rhs))
+	}
+}
+
 /// Provides information about the storage version of a pallet.
 ///
 /// It differentiates between current and on-chain storage version. Both should be only out of sync
@@ -289,4 +298,11 @@ mod tests {
 		assert!(version > StorageVersion::new(1));
 		assert!(version < StorageVersion::new(5));
 	}
+
+	#[test]
+	fn check_storage_version_add() {
+		assert_eq!(StorageVersion::new(1) + 1, StorageVersion::new(2));
+		assert_eq!(StorageVersion::new(9) + 3, StorageVersion::new(12));
+		assert_eq!(StorageVersion::new(u16::MAX) + 1, StorageVersion::new(u16::MAX));
+	}
 }
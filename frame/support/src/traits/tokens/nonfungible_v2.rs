@@ -27,7 +27,7 @@
 use super::nonfungibles_v2 as nonfungibles;
 use crate::{dispatch::DispatchResult, traits::Get};
 use codec::{Decode, Encode};
-use sp_runtime::TokenError;
+use sp_runtime::{Permill, TokenError};
 use sp_std::prelude::*;
 
 /// Trait for providing an interface to a read-only NFT-like item.
@@ -98,6 +98,14 @@ pub trait Inspect<AccountId> {
 	fn can_transfer(_item: &Self::ItemId) -> bool {
 		true
 	}
+
+	/// Returns the royalties paid out of the price when `item` is sold, as the recipients and
+	/// their shares of the price.
+	///
+	/// Default implementation is that no royalties are paid.
+	fn royalties(_item: &Self::ItemId) -> Vec<(AccountId, Permill)> {
+		Vec::new()
+	}
 }
 
 /// Interface for enumerating items in existence or owned by a given account over a collection
@@ -224,6 +232,9 @@ impl<
 	fn can_transfer(item: &Self::ItemId) -> bool {
 		<F as nonfungibles::Inspect<AccountId>>::can_transfer(&A::get(), item)
 	}
+	fn royalties(item: &Self::ItemId) -> Vec<(AccountId, Permill)> {
+		<F as nonfungibles::Inspect<AccountId>>::royalties(&A::get(), item)
+	}
 }
 
 impl<
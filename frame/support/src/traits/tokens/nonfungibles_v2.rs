@@ -29,7 +29,7 @@
 
 use crate::dispatch::{DispatchError, DispatchResult};
 use codec::{Decode, Encode};
-use sp_runtime::TokenError;
+use sp_runtime::{Permill, TokenError};
 use sp_std::prelude::*;
 
 /// Trait for providing an interface to many read-only NFT-like sets of items.
@@ -150,6 +150,17 @@ pub trait Inspect<AccountId> {
 	fn can_transfer(_collection: &Self::CollectionId, _item: &Self::ItemId) -> bool {
 		true
 	}
+
+	/// Returns the royalties paid out of the price when `item` of `collection` is sold, as the
+	/// recipients and their shares of the price.
+	///
+	/// Default implementation is that no royalties are paid.
+	fn royalties(
+		_collection: &Self::CollectionId,
+		_item: &Self::ItemId,
+	) -> Vec<(AccountId, Permill)> {
+		Vec::new()
+	}
 }
 
 /// Interface for enumerating items in existence or owned by a given account over many collections
@@ -167,6 +167,11 @@ where
 		Self { tip, asset_id }
 	}
 
+	/// Returns the tip as being chosen by the transaction sender.
+	pub fn tip(&self) -> BalanceOf<T> {
+		self.tip
+	}
+
 	/// Fee withdrawal logic that dispatches to either `OnChargeAssetTransaction` or
 	/// `OnChargeTransaction`.
 	fn withdraw_fee(
@@ -20,10 +20,16 @@
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "serde")]
+extern crate alloc;
+
+#[doc(hidden)]
+#[cfg(any(feature = "full_crypto", feature = "serde"))]
+pub use sp_core::crypto::Ss58Codec;
 pub use sp_core::crypto::{key_types, CryptoTypeId, KeyTypeId};
 #[doc(hidden)]
 #[cfg(feature = "full_crypto")]
-pub use sp_core::crypto::{DeriveJunction, Pair, SecretStringError, Ss58Codec};
+pub use sp_core::crypto::{DeriveJunction, Pair, SecretStringError};
 #[doc(hidden)]
 pub use sp_core::{
 	self,
@@ -34,12 +40,15 @@ pub use sp_core::{
 	RuntimeDebug,
 };
 
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+pub use alloc::{format, string::String};
 #[doc(hidden)]
 pub use codec;
 #[doc(hidden)]
 pub use scale_info;
 #[doc(hidden)]
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 pub use serde;
 #[doc(hidden)]
 pub use sp_std::{ops::Deref, vec::Vec};
@@ -291,6 +300,7 @@ macro_rules! app_crypto_public_not_full_crypto {
 macro_rules! app_crypto_public_common {
 	($public:ty, $sig:ty, $key_type:expr, $crypto_type:expr) => {
 		$crate::app_crypto_public_common_if_std!();
+		$crate::app_crypto_public_common_if_serde!();
 
 		impl AsRef<[u8]> for Public {
 			fn as_ref(&self) -> &[u8] {
@@ -394,9 +404,27 @@ macro_rules! app_crypto_public_common_if_std {
 				write!(f, "{}", self.0.to_ss58check())
 			}
 		}
+	};
+}
 
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! app_crypto_public_common_if_std {
+	() => {
+		impl $crate::Derive for Public {}
+	};
+}
+
+/// Implements `Serialize` and `Deserialize` for the public key type if `feature = "serde"` is
+/// enabled.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! app_crypto_public_common_if_serde {
+	() => {
 		impl $crate::serde::Serialize for Public {
-			fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+			fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
 			where
 				S: $crate::serde::Serializer,
 			{
@@ -406,25 +434,25 @@ macro_rules! app_crypto_public_common_if_std {
 		}
 
 		impl<'de> $crate::serde::Deserialize<'de> for Public {
-			fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+			fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
 			where
 				D: $crate::serde::Deserializer<'de>,
 			{
 				use $crate::Ss58Codec;
-				Public::from_ss58check(&String::deserialize(deserializer)?)
-					.map_err(|e| $crate::serde::de::Error::custom(format!("{:?}", e)))
+				Public::from_ss58check(
+					&<$crate::String as $crate::serde::Deserialize>::deserialize(deserializer)?,
+				)
+				.map_err(|e| $crate::serde::de::Error::custom($crate::format!("{:?}", e)))
 			}
 		}
 	};
 }
 
-#[cfg(not(feature = "std"))]
+#[cfg(not(feature = "serde"))]
 #[doc(hidden)]
 #[macro_export]
-macro_rules! app_crypto_public_common_if_std {
-	() => {
-		impl $crate::Derive for Public {}
-	};
+macro_rules! app_crypto_public_common_if_serde {
+	() => {};
 }
 
 /// Declares Signature type which is functionally equivalent to `$sig`, but is new
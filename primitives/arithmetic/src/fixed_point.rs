@@ -32,7 +32,9 @@ use sp_std::{
 	prelude::*,
 };
 
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "serde")]
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Integer types that can be used to interact with `FixedPointNumber` implementations.
@@ -948,27 +950,29 @@ macro_rules! implement_fixed {
 
 		// Manual impl `Serialize` as serde_json does not support i128.
 		// TODO: remove impl if issue https://github.com/serde-rs/json/issues/548 fixed.
-		#[cfg(feature = "std")]
+		#[cfg(feature = "serde")]
 		impl Serialize for $name {
 			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 			where
 				S: Serializer,
 			{
-				serializer.serialize_str(&self.to_string())
+				serializer.serialize_str(&self.0.to_string())
 			}
 		}
 
 		// Manual impl `Deserialize` as serde_json does not support i128.
 		// TODO: remove impl if issue https://github.com/serde-rs/json/issues/548 fixed.
-		#[cfg(feature = "std")]
+		#[cfg(feature = "serde")]
 		impl<'de> Deserialize<'de> for $name {
 			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 			where
 				D: Deserializer<'de>,
 			{
-				use sp_std::str::FromStr;
 				let s = String::deserialize(deserializer)?;
-				$name::from_str(&s).map_err(de::Error::custom)
+				let inner: <Self as FixedPointNumber>::Inner = s.parse().map_err(|_| {
+					de::Error::custom("invalid string input for fixed point number")
+				})?;
+				Ok(Self::from_inner(inner))
 			}
 		}
 
@@ -209,6 +209,19 @@ impl Error {
 		Error::Execution(e)
 	}
 
+	/// Returns the error of the code executor if the execution failed with an error of type `E`.
+	///
+	/// Looks through runtime api errors wrapping the execution error.
+	pub fn execution_error<E: 'static>(&self) -> Option<&E> {
+		match self {
+			// Call `as_any` on the boxed error itself, not on the `Box`.
+			Error::Execution(e) => (**e).as_any().downcast_ref(),
+			Error::RuntimeApiError(ApiError::Application(e)) =>
+				e.downcast_ref::<Error>().and_then(|e| e.execution_error()),
+			_ => None,
+		}
+	}
+
 	/// Construct from a state db error.
 	// Can not be done directly, since that would make cargo run out of stack if
 	// `sc-state-db` is lib is added as dependency.
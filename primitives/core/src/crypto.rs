@@ -22,8 +22,8 @@
 #[cfg(feature = "std")]
 use crate::hexdisplay::HexDisplay;
 use crate::{ed25519, sr25519};
-#[cfg(feature = "std")]
-use base58::{FromBase58, ToBase58};
+#[cfg(feature = "serde")]
+use alloc::{format, string::String, vec};
 use codec::{Decode, Encode, MaxEncodedLen};
 #[cfg(feature = "std")]
 use rand::{rngs::OsRng, RngCore};
@@ -42,7 +42,7 @@ use sp_std::{hash::Hash, str, vec::Vec};
 /// Trait to zeroize a memory buffer.
 pub use zeroize::Zeroize;
 
-#[cfg(feature = "full_crypto")]
+#[cfg(any(feature = "full_crypto", feature = "serde"))]
 pub use ss58_registry::{from_known_address_format, Ss58AddressFormat, Ss58AddressFormatRegistry};
 
 /// The root phrase for our publicly known keys.
@@ -207,7 +207,7 @@ impl<T: AsRef<str>> From<T> for DeriveJunction {
 #[cfg_attr(not(feature = "std"), derive(Debug))]
 #[derive(Clone, Copy, Eq, PartialEq)]
 #[allow(missing_docs)]
-#[cfg(feature = "full_crypto")]
+#[cfg(any(feature = "full_crypto", feature = "serde"))]
 pub enum PublicError {
 	#[cfg_attr(feature = "std", error("Base 58 requirement is violated"))]
 	BadBase58,
@@ -246,7 +246,7 @@ impl sp_std::fmt::Debug for PublicError {
 ///
 /// See <https://docs.substrate.io/v3/advanced/ss58/>
 /// for information on the codec.
-#[cfg(feature = "full_crypto")]
+#[cfg(any(feature = "full_crypto", feature = "serde"))]
 pub trait Ss58Codec: Sized + AsMut<[u8]> + AsRef<[u8]> + ByteArray {
 	/// A format filterer, can be used to ensure that `from_ss58check` family only decode for
 	/// allowed identifiers. By default just refuses the two reserved identifiers.
@@ -255,7 +255,7 @@ pub trait Ss58Codec: Sized + AsMut<[u8]> + AsRef<[u8]> + ByteArray {
 	}
 
 	/// Some if the string is a properly encoded SS58Check address.
-	#[cfg(feature = "std")]
+	#[cfg(feature = "serde")]
 	fn from_ss58check(s: &str) -> Result<Self, PublicError> {
 		Self::from_ss58check_with_version(s).and_then(|(r, v)| match v {
 			v if !v.is_custom() => Ok(r),
@@ -265,12 +265,12 @@ pub trait Ss58Codec: Sized + AsMut<[u8]> + AsRef<[u8]> + ByteArray {
 	}
 
 	/// Some if the string is a properly encoded SS58Check address.
-	#[cfg(feature = "std")]
+	#[cfg(feature = "serde")]
 	fn from_ss58check_with_version(s: &str) -> Result<(Self, Ss58AddressFormat), PublicError> {
 		const CHECKSUM_LEN: usize = 2;
 		let body_len = Self::LEN;
 
-		let data = s.from_base58().map_err(|_| PublicError::BadBase58)?;
+		let data = bs58::decode(s).into_vec().map_err(|_| PublicError::BadBase58)?;
 		if data.len() < 2 {
 			return Err(PublicError::BadLength)
 		}
@@ -320,7 +320,7 @@ pub trait Ss58Codec: Sized + AsMut<[u8]> + AsRef<[u8]> + ByteArray {
 	}
 
 	/// Return the ss58-check string for this key.
-	#[cfg(feature = "std")]
+	#[cfg(feature = "serde")]
 	fn to_ss58check_with_version(&self, version: Ss58AddressFormat) -> String {
 		// We mask out the upper two bits of the ident - SS58 Prefix currently only supports 14-bits
 		let ident: u16 = u16::from(version) & 0b0011_1111_1111_1111;
@@ -339,11 +339,11 @@ pub trait Ss58Codec: Sized + AsMut<[u8]> + AsRef<[u8]> + ByteArray {
 		v.extend(self.as_ref());
 		let r = ss58hash(&v);
 		v.extend(&r[0..2]);
-		v.to_base58()
+		bs58::encode(v).into_string()
 	}
 
 	/// Return the ss58-check string for this key.
-	#[cfg(feature = "std")]
+	#[cfg(feature = "serde")]
 	fn to_ss58check(&self) -> String {
 		self.to_ss58check_with_version(default_ss58_version())
 	}
@@ -367,10 +367,10 @@ pub trait Derive: Sized {
 	}
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 const PREFIX: &[u8] = b"SS58PRE";
 
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 fn ss58hash(data: &[u8]) -> Vec<u8> {
 	use blake2::{Blake2b512, Digest};
 
@@ -381,15 +381,15 @@ fn ss58hash(data: &[u8]) -> Vec<u8> {
 }
 
 /// Default prefix number
-#[cfg(feature = "std")]
-static DEFAULT_VERSION: core::sync::atomic::AtomicU16 = std::sync::atomic::AtomicU16::new(
+#[cfg(feature = "serde")]
+static DEFAULT_VERSION: core::sync::atomic::AtomicU16 = core::sync::atomic::AtomicU16::new(
 	from_known_address_format(Ss58AddressFormatRegistry::SubstrateAccount),
 );
 
 /// Returns default SS58 format used by the current active process.
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 pub fn default_ss58_version() -> Ss58AddressFormat {
-	DEFAULT_VERSION.load(std::sync::atomic::Ordering::Relaxed).into()
+	DEFAULT_VERSION.load(core::sync::atomic::Ordering::Relaxed).into()
 }
 
 /// Returns either the input address format or the default.
@@ -457,6 +457,9 @@ impl<T: Sized + AsMut<[u8]> + AsRef<[u8]> + Public + Derive> Ss58Codec for T {
 	}
 }
 
+#[cfg(all(not(feature = "std"), feature = "serde"))]
+impl<T: Sized + AsMut<[u8]> + AsRef<[u8]> + Public + Derive> Ss58Codec for T {}
+
 /// Trait used for types that are really just a fixed-length array.
 pub trait ByteArray: AsRef<[u8]> + AsMut<[u8]> + for<'a> TryFrom<&'a [u8], Error = ()> {
 	/// The "length" of the values of this type, which is always the same.
@@ -509,7 +512,7 @@ impl ByteArray for AccountId32 {
 	const LEN: usize = 32;
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 impl Ss58Codec for AccountId32 {}
 
 impl AsRef<[u8]> for AccountId32 {
@@ -593,7 +596,7 @@ impl sp_std::fmt::Debug for AccountId32 {
 	}
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 impl serde::Serialize for AccountId32 {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
@@ -603,7 +606,7 @@ impl serde::Serialize for AccountId32 {
 	}
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for AccountId32 {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
@@ -20,6 +20,9 @@
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "serde")]
+extern crate alloc;
+
 /// Initialize a key-value collection from array.
 ///
 /// Creates a vector of given pairs and calls `collect` on the iterator from it.
@@ -34,16 +37,16 @@ macro_rules! map {
 #[doc(hidden)]
 pub use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 pub use serde;
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use sp_runtime_interface::pass_by::{PassByEnum, PassByInner};
 use sp_std::{ops::Deref, prelude::*};
 
 pub use sp_debug_derive::RuntimeDebug;
 
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 pub use impl_serde::serialize as bytes;
 
 #[cfg(feature = "full_crypto")]
@@ -203,7 +206,7 @@ impl sp_std::ops::Deref for OpaqueMetadata {
 	PassByInner,
 	TypeInfo,
 )]
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OpaquePeerId(pub Vec<u8>);
 
 impl OpaquePeerId {
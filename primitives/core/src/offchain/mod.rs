@@ -182,6 +182,53 @@ impl TryFrom<u32> for HttpRequestStatus {
 	}
 }
 
+/// Opaque type for offchain WebSocket connections.
+#[derive(
+	Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, Encode, Decode, PassByInner,
+)]
+#[cfg_attr(feature = "std", derive(Hash))]
+pub struct WebSocketId(pub u16);
+
+impl From<WebSocketId> for u32 {
+	fn from(c: WebSocketId) -> Self {
+		c.0 as u32
+	}
+}
+
+/// An error enum returned by the WebSocket methods.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, PassByEnum)]
+#[repr(C)]
+pub enum WebSocketError {
+	/// The requested action couldn't been completed within a deadline.
+	DeadlineReached = 1_isize,
+	/// There was an IO Error while processing the connection, or the remote rejected it.
+	///
+	/// The connection is then considered destroyed.
+	IoError = 2_isize,
+	/// The ID of the connection is invalid in this context.
+	Invalid = 3_isize,
+}
+
+impl TryFrom<u32> for WebSocketError {
+	type Error = ();
+
+	fn try_from(error: u32) -> Result<Self, Self::Error> {
+		match error {
+			e if e == WebSocketError::DeadlineReached as u8 as u32 =>
+				Ok(WebSocketError::DeadlineReached),
+			e if e == WebSocketError::IoError as u8 as u32 => Ok(WebSocketError::IoError),
+			e if e == WebSocketError::Invalid as u8 as u32 => Ok(WebSocketError::Invalid),
+			_ => Err(()),
+		}
+	}
+}
+
+impl From<WebSocketError> for u32 {
+	fn from(c: WebSocketError) -> Self {
+		c as u8 as u32
+	}
+}
+
 /// A blob to hold information about the local node's network state
 /// without committing to its format.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, PassByCodec, TypeInfo)]
@@ -278,6 +325,8 @@ bitflags::bitflags! {
 		const NODE_AUTHORIZATION = 0b0000_1000_0000;
 		/// Access time related functionality
 		const TIME = 0b0001_0000_0000;
+		/// External WebSocket connections.
+		const WEBSOCKET = 0b0010_0000_0000;
 	}
 }
 
@@ -421,6 +470,95 @@ pub trait Externalities: Send {
 		deadline: Option<Timestamp>,
 	) -> Result<usize, HttpError>;
 
+	/// Read the next chunk of the response body, as it was received from the server.
+	///
+	/// Unlike [`Self::http_response_read_body`], the chunk boundaries of the response are
+	/// preserved, which makes it possible to process streamed responses (e.g. server-sent events)
+	/// message by message. Dispatches the request if it hasn't been done yet.
+	///
+	/// Returns `Ok(None)` once the response body was read completely, the request is then
+	/// considered destroyed.
+	/// Passing `None` as a deadline blocks forever.
+	///
+	/// Returns an error if:
+	/// - The request identifier is invalid.
+	/// - The deadline is reached.
+	/// - An I/O error has happened, for example the remote has closed our request. The request is
+	///   then considered invalid.
+	///
+	/// The default implementation doesn't support streamed responses and treats every request
+	/// as invalid.
+	fn http_response_read_chunk(
+		&mut self,
+		_request_id: HttpRequestId,
+		_deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, HttpError> {
+		Err(HttpError::Invalid)
+	}
+
+	/// Open a WebSocket connection to the given `ws://` or `wss://` URI.
+	///
+	/// Blocks until the opening handshake has completed.
+	/// Passing `None` as a deadline blocks forever.
+	///
+	/// Returns an error if:
+	/// - The URI is invalid or no new connection identifier could be allocated.
+	/// - The deadline is reached.
+	/// - The connection failed or the remote rejected the handshake.
+	///
+	/// The default implementation doesn't support WebSockets and fails to open any connection.
+	fn ws_open(
+		&mut self,
+		_uri: &str,
+		_deadline: Option<Timestamp>,
+	) -> Result<WebSocketId, WebSocketError> {
+		Err(WebSocketError::IoError)
+	}
+
+	/// Send a message over the WebSocket connection.
+	///
+	/// The message is sent as a binary message if `binary` is set and as a text message
+	/// otherwise, in which case it must be valid UTF-8.
+	/// Passing `None` as a deadline blocks forever.
+	///
+	/// Returns an error if:
+	/// - The connection identifier is invalid, or the message isn't valid UTF-8.
+	/// - The deadline is reached.
+	/// - An I/O error has happened, for example the remote has closed the connection. The
+	///   connection is then considered destroyed.
+	fn ws_send(
+		&mut self,
+		_id: WebSocketId,
+		_message: &[u8],
+		_binary: bool,
+		_deadline: Option<Timestamp>,
+	) -> Result<(), WebSocketError> {
+		Err(WebSocketError::Invalid)
+	}
+
+	/// Receive the next message from the WebSocket connection.
+	///
+	/// Returns `Ok(None)` if the remote has closed the connection, the connection is then
+	/// considered destroyed.
+	/// Passing `None` as a deadline blocks forever.
+	///
+	/// Returns an error if:
+	/// - The connection identifier is invalid.
+	/// - The deadline is reached.
+	/// - An I/O error has happened. The connection is then considered destroyed.
+	fn ws_receive(
+		&mut self,
+		_id: WebSocketId,
+		_deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, WebSocketError> {
+		Err(WebSocketError::Invalid)
+	}
+
+	/// Close the WebSocket connection.
+	///
+	/// The identifier is invalid afterwards. Closing an unknown connection does nothing.
+	fn ws_close(&mut self, _id: WebSocketId) {}
+
 	/// Set the authorized nodes from runtime.
 	///
 	/// In a permissioned network, the connections between nodes need to reach a
@@ -503,6 +641,44 @@ impl<T: Externalities + ?Sized> Externalities for Box<T> {
 		(&mut **self).http_response_read_body(request_id, buffer, deadline)
 	}
 
+	fn http_response_read_chunk(
+		&mut self,
+		request_id: HttpRequestId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, HttpError> {
+		(&mut **self).http_response_read_chunk(request_id, deadline)
+	}
+
+	fn ws_open(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WebSocketId, WebSocketError> {
+		(&mut **self).ws_open(uri, deadline)
+	}
+
+	fn ws_send(
+		&mut self,
+		id: WebSocketId,
+		message: &[u8],
+		binary: bool,
+		deadline: Option<Timestamp>,
+	) -> Result<(), WebSocketError> {
+		(&mut **self).ws_send(id, message, binary, deadline)
+	}
+
+	fn ws_receive(
+		&mut self,
+		id: WebSocketId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, WebSocketError> {
+		(&mut **self).ws_receive(id, deadline)
+	}
+
+	fn ws_close(&mut self, id: WebSocketId) {
+		(&mut **self).ws_close(id)
+	}
+
 	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
 		(&mut **self).set_authorized_nodes(nodes, authorized_only)
 	}
@@ -610,6 +786,49 @@ impl<T: Externalities> Externalities for LimitedExternalities<T> {
 		self.externalities.http_response_read_body(request_id, buffer, deadline)
 	}
 
+	fn http_response_read_chunk(
+		&mut self,
+		request_id: HttpRequestId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, HttpError> {
+		self.check(Capabilities::HTTP, "http_response_read_chunk");
+		self.externalities.http_response_read_chunk(request_id, deadline)
+	}
+
+	fn ws_open(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WebSocketId, WebSocketError> {
+		self.check(Capabilities::WEBSOCKET, "ws_open");
+		self.externalities.ws_open(uri, deadline)
+	}
+
+	fn ws_send(
+		&mut self,
+		id: WebSocketId,
+		message: &[u8],
+		binary: bool,
+		deadline: Option<Timestamp>,
+	) -> Result<(), WebSocketError> {
+		self.check(Capabilities::WEBSOCKET, "ws_send");
+		self.externalities.ws_send(id, message, binary, deadline)
+	}
+
+	fn ws_receive(
+		&mut self,
+		id: WebSocketId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, WebSocketError> {
+		self.check(Capabilities::WEBSOCKET, "ws_receive");
+		self.externalities.ws_receive(id, deadline)
+	}
+
+	fn ws_close(&mut self, id: WebSocketId) {
+		self.check(Capabilities::WEBSOCKET, "ws_close");
+		self.externalities.ws_close(id)
+	}
+
 	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
 		self.check(Capabilities::NODE_AUTHORIZATION, "set_authorized_nodes");
 		self.externalities.set_authorized_nodes(nodes, authorized_only)
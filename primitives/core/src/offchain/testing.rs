@@ -19,17 +19,24 @@
 //!
 //! Namely all ExecutionExtensions that allow mocking
 //! the extra APIs.
+//!
+//! HTTP requests can either be expected one by one with [`OffchainState::expect_request`] or
+//! replayed from an [`HttpFixture`]. Fixtures are recorded from the requests of real offchain
+//! workers by `sc_offchain::HttpRecorder`.
 
 use crate::{
 	offchain::{
 		self, storage::InMemOffchainStorage, HttpError, HttpRequestId as RequestId,
 		HttpRequestStatus as RequestStatus, OffchainOverlayedChange, OffchainStorage,
-		OpaqueNetworkState, StorageKind, Timestamp, TransactionPool,
+		OpaqueNetworkState, StorageKind, Timestamp, TransactionPool, WebSocketError, WebSocketId,
 	},
 	OpaquePeerId,
 };
+use serde::{Deserialize, Serialize};
 use std::{
 	collections::{BTreeMap, VecDeque},
+	fs, io,
+	path::Path,
 	sync::Arc,
 };
 
@@ -56,6 +63,74 @@ pub struct PendingRequest {
 	pub read: usize,
 	/// Response headers
 	pub response_headers: Vec<(String, String)>,
+	/// Response status code, `200` if not given.
+	pub response_status: Option<u16>,
+}
+
+/// Pending WebSocket connection.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PendingWebSocket {
+	/// URI
+	pub uri: String,
+	/// Messages sent by the offchain worker, along with whether they were binary.
+	pub sent: Vec<(Vec<u8>, bool)>,
+	/// Messages to be received by the offchain worker.
+	///
+	/// The connection is closed by the remote once all messages were received.
+	pub incoming: VecDeque<Vec<u8>>,
+	/// Has the connection been closed by the offchain worker.
+	pub closed: bool,
+}
+
+/// An HTTP request along with its response, as recorded by `sc_offchain::HttpRecorder`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+	/// HTTP method
+	pub method: String,
+	/// URI
+	pub uri: String,
+	/// Request headers
+	pub headers: Vec<(String, String)>,
+	/// Request body
+	#[serde(with = "impl_serde::serialize")]
+	pub body: Vec<u8>,
+	/// Response status code
+	pub response_status: u16,
+	/// Response headers
+	pub response_headers: Vec<(String, String)>,
+	/// Response body
+	#[serde(with = "impl_serde::serialize")]
+	pub response_body: Vec<u8>,
+}
+
+impl RecordedRequest {
+	/// Returns whether this is a recording of the given request.
+	///
+	/// Requests are matched by method, URI and body.
+	pub fn matches(&self, request: &PendingRequest) -> bool {
+		self.method == request.method && self.uri == request.uri && self.body == request.body
+	}
+}
+
+/// A list of recorded HTTP requests that can be replayed by [`TestOffchainExt`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpFixture {
+	/// The recorded requests in the order they were completed.
+	pub requests: Vec<RecordedRequest>,
+}
+
+impl HttpFixture {
+	/// Load a fixture from the JSON file at `path`.
+	pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+		let file = fs::File::open(path)?;
+		serde_json::from_reader(file).map_err(Into::into)
+	}
+
+	/// Store the fixture as JSON at `path`.
+	pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+		let file = fs::File::create(path)?;
+		serde_json::to_writer_pretty(file, self).map_err(Into::into)
+	}
 }
 
 /// Sharable "persistent" offchain storage for test.
@@ -126,6 +201,12 @@ pub struct OffchainState {
 	pub requests: BTreeMap<RequestId, PendingRequest>,
 	// Queue of requests that the test is expected to perform (in order).
 	expected_requests: VecDeque<PendingRequest>,
+	// Recorded requests that answer requests which were not expected explicitly.
+	replayed_requests: Vec<RecordedRequest>,
+	/// A list of opened WebSocket connections.
+	pub websockets: BTreeMap<WebSocketId, PendingWebSocket>,
+	// Queue of WebSocket connections that the test is expected to open (in order).
+	expected_websockets: VecDeque<PendingWebSocket>,
 	/// Persistent local storage
 	pub persistent_storage: TestPersistentOffchainDB,
 	/// Local storage
@@ -161,10 +242,35 @@ impl OffchainState {
 		if let Some(mut req) = self.expected_requests.pop_back() {
 			let response = req.response.take().expect("Response checked when added.");
 			let headers = std::mem::take(&mut req.response_headers);
+			let status = req.response_status.take();
 			self.fulfill_pending_request(id, req, response, headers);
+			if let Some(req) = self.requests.get_mut(&RequestId(id)) {
+				req.response_status = status;
+			}
+		} else if !self.replayed_requests.is_empty() {
+			self.fulfill_replayed(id);
 		}
 	}
 
+	fn fulfill_replayed(&mut self, id: u16) {
+		let req = self
+			.requests
+			.get_mut(&RequestId(id))
+			.unwrap_or_else(|| panic!("Missing pending request: {:?}.", id));
+		let position =
+			self.replayed_requests.iter().position(|r| r.matches(req)).unwrap_or_else(|| {
+				panic!(
+					"No recorded response for request: {:?}.\n\nRecorded: {:?}",
+					req, self.replayed_requests
+				)
+			});
+
+		let recorded = self.replayed_requests.remove(position);
+		req.response = Some(recorded.response_body);
+		req.response_headers = recorded.response_headers;
+		req.response_status = Some(recorded.response_status);
+	}
+
 	/// Add expected HTTP request.
 	///
 	/// This method can be used to initialize expected HTTP requests and their responses
@@ -178,6 +284,24 @@ impl OffchainState {
 		}
 		self.expected_requests.push_front(expected);
 	}
+
+	/// Add expected WebSocket connection.
+	///
+	/// Connections are expected to be opened in the insertion order. The `incoming` messages
+	/// of the connection are received by the caller, the messages it sends are collected in
+	/// [`Self::websockets`].
+	pub fn expect_websocket(&mut self, expected: PendingWebSocket) {
+		self.expected_websockets.push_front(expected);
+	}
+
+	/// Answer HTTP requests with the responses recorded in the given `fixture`.
+	///
+	/// Requests that were not added with [`Self::expect_request`] are matched against the
+	/// recorded requests by method, URI and body, in any order. Every recorded request answers a
+	/// single request.
+	pub fn replay(&mut self, fixture: HttpFixture) {
+		self.replayed_requests.extend(fixture.requests);
+	}
 }
 
 impl Drop for OffchainState {
@@ -186,6 +310,9 @@ impl Drop for OffchainState {
 		if !self.expected_requests.is_empty() && !std::thread::panicking() {
 			panic!("Unfulfilled expected requests: {:?}", self.expected_requests);
 		}
+		if !self.expected_websockets.is_empty() && !std::thread::panicking() {
+			panic!("Unfulfilled expected WebSocket connections: {:?}", self.expected_websockets);
+		}
 	}
 }
 
@@ -304,7 +431,7 @@ impl offchain::Externalities for TestOffchainExt {
 					panic!("No `response` provided for request with id: {:?}", id)
 				},
 				None => RequestStatus::Invalid,
-				_ => RequestStatus::Finished(200),
+				Some(req) => RequestStatus::Finished(req.response_status.unwrap_or(200)),
 			})
 			.collect()
 	}
@@ -350,6 +477,91 @@ impl offchain::Externalities for TestOffchainExt {
 		}
 	}
 
+	fn http_response_read_chunk(
+		&mut self,
+		request_id: RequestId,
+		_deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, HttpError> {
+		let mut state = self.0.write();
+		if let Some(req) = state.requests.get_mut(&request_id) {
+			let response = req
+				.response
+				.as_ref()
+				.unwrap_or_else(|| panic!("No response provided for request: {:?}", request_id));
+
+			if req.read >= response.len() {
+				// Remove the pending request as per spec.
+				state.requests.remove(&request_id);
+				Ok(None)
+			} else {
+				let chunk = response[req.read..].to_vec();
+				req.read = response.len();
+				Ok(Some(chunk))
+			}
+		} else {
+			Err(HttpError::Invalid)
+		}
+	}
+
+	fn ws_open(
+		&mut self,
+		uri: &str,
+		_deadline: Option<Timestamp>,
+	) -> Result<WebSocketId, WebSocketError> {
+		let mut state = self.0.write();
+		let expected = state
+			.expected_websockets
+			.pop_back()
+			.unwrap_or_else(|| panic!("Unexpected WebSocket connection to {}", uri));
+		assert_eq!(expected.uri, uri);
+
+		let id = WebSocketId(state.websockets.len() as u16);
+		state.websockets.insert(id, expected);
+		Ok(id)
+	}
+
+	fn ws_send(
+		&mut self,
+		id: WebSocketId,
+		message: &[u8],
+		binary: bool,
+		_deadline: Option<Timestamp>,
+	) -> Result<(), WebSocketError> {
+		let mut state = self.0.write();
+		match state.websockets.get_mut(&id) {
+			Some(ws) if !ws.closed => {
+				if !binary && std::str::from_utf8(message).is_err() {
+					return Err(WebSocketError::Invalid)
+				}
+				ws.sent.push((message.to_vec(), binary));
+				Ok(())
+			},
+			_ => Err(WebSocketError::Invalid),
+		}
+	}
+
+	fn ws_receive(
+		&mut self,
+		id: WebSocketId,
+		_deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, WebSocketError> {
+		let mut state = self.0.write();
+		match state.websockets.get_mut(&id) {
+			Some(ws) if !ws.closed => {
+				let message = ws.incoming.pop_front();
+				ws.closed = message.is_none();
+				Ok(message)
+			},
+			_ => Err(WebSocketError::Invalid),
+		}
+	}
+
+	fn ws_close(&mut self, id: WebSocketId) {
+		if let Some(ws) = self.0.write().websockets.get_mut(&id) {
+			ws.closed = true;
+		}
+	}
+
 	fn set_authorized_nodes(&mut self, _nodes: Vec<OpaquePeerId>, _authorized_only: bool) {
 		unimplemented!()
 	}
@@ -431,3 +643,93 @@ impl TransactionPool for TestTransactionPoolExt {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::offchain::Externalities;
+
+	fn get(ext: &mut impl Externalities, uri: &str) -> (RequestStatus, Vec<u8>) {
+		let id = ext.http_request_start("GET", uri, &[]).unwrap();
+		ext.http_request_add_header(id, "Accept", "application/json").unwrap();
+		ext.http_request_write_body(id, &[], None).unwrap();
+		let status = ext.http_response_wait(&[id], None)[0];
+		ext.http_response_headers(id);
+
+		let mut body = Vec::new();
+		let mut buffer = [0u8; 4];
+		loop {
+			match ext.http_response_read_body(id, &mut buffer, None).unwrap() {
+				0 => return (status, body),
+				read => body.extend(&buffer[..read]),
+			}
+		}
+	}
+
+	#[test]
+	fn fixtures_can_be_replayed() {
+		let fixture = HttpFixture {
+			requests: vec![RecordedRequest {
+				method: "GET".into(),
+				uri: "https://example.com/price".into(),
+				headers: vec![("Accept".into(), "application/json".into())],
+				body: vec![],
+				response_status: 201,
+				response_headers: vec![("Content-Type".into(), "application/json".into())],
+				response_body: br#"{"USD": 1}"#.to_vec(),
+			}],
+		};
+
+		let json = serde_json::to_string(&fixture).unwrap();
+		let fixture: HttpFixture = serde_json::from_str(&json).unwrap();
+
+		let (mut offchain, state) = TestOffchainExt::new();
+		state.write().replay(fixture);
+		assert_eq!(
+			get(&mut offchain, "https://example.com/price"),
+			(RequestStatus::Finished(201), br#"{"USD": 1}"#.to_vec()),
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "No recorded response for request")]
+	fn replay_panics_on_unknown_requests() {
+		let (mut offchain, state) = TestOffchainExt::new();
+		state.write().replay(HttpFixture {
+			requests: vec![RecordedRequest {
+				method: "GET".into(),
+				uri: "https://example.com/price".into(),
+				response_status: 200,
+				..Default::default()
+			}],
+		});
+
+		get(&mut offchain, "https://example.com/other");
+	}
+
+	#[test]
+	fn read_chunk_of_unknown_request_is_invalid() {
+		let (mut offchain, _state) = TestOffchainExt::new();
+
+		assert_eq!(offchain.http_response_read_chunk(RequestId(0), None), Err(HttpError::Invalid));
+	}
+
+	#[test]
+	fn expected_websocket_exchanges_messages() {
+		let (mut offchain, state) = TestOffchainExt::new();
+		state.write().expect_websocket(PendingWebSocket {
+			uri: "wss://example.com/feed".into(),
+			incoming: vec![b"price: 1".to_vec(), b"price: 2".to_vec()].into(),
+			..Default::default()
+		});
+
+		let id = offchain.ws_open("wss://example.com/feed", None).unwrap();
+		offchain.ws_send(id, b"subscribe", false, None).unwrap();
+		assert_eq!(offchain.ws_receive(id, None), Ok(Some(b"price: 1".to_vec())));
+		assert_eq!(offchain.ws_receive(id, None), Ok(Some(b"price: 2".to_vec())));
+		assert_eq!(offchain.ws_receive(id, None), Ok(None));
+		assert_eq!(offchain.ws_receive(id, None), Err(WebSocketError::Invalid));
+
+		assert_eq!(state.read().websockets[&id].sent, vec![(b"subscribe".to_vec(), false)]);
+	}
+}
@@ -115,6 +115,22 @@ pub trait Database<H: Clone + AsRef<[u8]>>: Send + Sync {
 	///
 	/// Not all database implementations use a prefix for keys, so this function may be a noop.
 	fn sanitize_key(&self, _key: &mut Vec<u8>) {}
+
+	/// Call `f` for every key-value pair stored in `col`, in ascending key order, until `f`
+	/// returns `false`.
+	///
+	/// Not all database implementations are able to iterate a column in key order, in which case
+	/// an error is returned.
+	fn iter_column_while(
+		&self,
+		_col: ColumnId,
+		_f: &mut dyn FnMut(&[u8], &[u8]) -> bool,
+	) -> error::Result<()> {
+		Err(error::DatabaseError(Box::new(std::io::Error::new(
+			std::io::ErrorKind::Unsupported,
+			"Ordered column iteration is not supported by this database",
+		))))
+	}
 }
 
 impl<H> std::fmt::Debug for dyn Database<H> {
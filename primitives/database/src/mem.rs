@@ -73,6 +73,24 @@ where
 		let s = self.0.read();
 		s.get(&col).and_then(|c| c.get(key).map(|(_, v)| v.clone()))
 	}
+
+	fn iter_column_while(
+		&self,
+		col: ColumnId,
+		f: &mut dyn FnMut(&[u8], &[u8]) -> bool,
+	) -> error::Result<()> {
+		let s = self.0.read();
+		if let Some(column) = s.get(&col) {
+			let mut entries: Vec<_> = column.iter().collect();
+			entries.sort_by(|a, b| a.0.cmp(b.0));
+			for (key, (_, value)) in entries {
+				if !f(key, value) {
+					break
+				}
+			}
+		}
+		Ok(())
+	}
 }
 
 impl MemDb {
@@ -0,0 +1,50 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Substrate genesis config builder.
+//!
+//! This crate contains a runtime API that allows the node to build the genesis storage of a
+//! runtime without knowing the runtime's native `GenesisConfig` type. The config is exchanged
+//! as JSON, so a single node binary can build chain specs for several runtimes.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// The result type alias, used in build methods. `Err` contains formatted error message.
+pub type Result = core::result::Result<(), sp_runtime::RuntimeString>;
+
+sp_api::decl_runtime_apis! {
+	/// API to interact with `GenesisConfig` for the runtime.
+	pub trait GenesisBuilder {
+		/// Creates the default `GenesisConfig` and returns it as a JSON blob.
+		///
+		/// This function instantiates the default `GenesisConfig` struct for the runtime and
+		/// serializes it into a JSON blob. It returns a `Vec<u8>` containing the JSON
+		/// representation of the default `GenesisConfig`.
+		fn create_default_config() -> sp_std::vec::Vec<u8>;
+
+		/// Build `GenesisConfig` from a JSON blob not using any defaults and store it in the
+		/// storage.
+		///
+		/// This function deserializes the full `GenesisConfig` from the given JSON blob and puts
+		/// it into the storage. If the provided JSON blob is incorrect or incomplete or the
+		/// deserialization fails, an error is returned.
+		///
+		/// Please note that the provided JSON blob must contain all the keys of the
+		/// `GenesisConfig`, the defaults are not used.
+		fn build_config(json: sp_std::vec::Vec<u8>) -> Result;
+	}
+}
@@ -50,6 +50,7 @@ use sp_core::{
 	ecdsa, ed25519,
 	offchain::{
 		HttpError, HttpRequestId, HttpRequestStatus, OpaqueNetworkState, StorageKind, Timestamp,
+		WebSocketError, WebSocketId,
 	},
 	sr25519,
 	storage::StateVersion,
@@ -1419,6 +1420,70 @@ pub trait Offchain {
 			.expect("set_authorized_nodes can be called only in the offchain worker context")
 			.set_authorized_nodes(nodes, authorized_only)
 	}
+
+	/// Read the next chunk of the response body, as it was received from the server.
+	///
+	/// Returns `None` if the response has been fully consumed, the `request_id` is then invalid.
+	/// Passing `None` as a deadline blocks forever.
+	fn http_response_read_chunk(
+		&mut self,
+		request_id: HttpRequestId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, HttpError> {
+		self.extension::<OffchainWorkerExt>()
+			.expect("http_response_read_chunk can be called only in the offchain worker context")
+			.http_response_read_chunk(request_id, deadline)
+	}
+
+	/// Open a WebSocket connection to the given `ws://` or `wss://` URI.
+	///
+	/// Only available if the node has enabled offchain WebSockets.
+	/// Passing `None` as a deadline blocks forever.
+	fn ws_open(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WebSocketId, WebSocketError> {
+		self.extension::<OffchainWorkerExt>()
+			.expect("ws_open can be called only in the offchain worker context")
+			.ws_open(uri, deadline)
+	}
+
+	/// Send a binary or text message over a WebSocket connection.
+	///
+	/// Passing `None` as a deadline blocks forever.
+	fn ws_send(
+		&mut self,
+		id: WebSocketId,
+		message: &[u8],
+		binary: bool,
+		deadline: Option<Timestamp>,
+	) -> Result<(), WebSocketError> {
+		self.extension::<OffchainWorkerExt>()
+			.expect("ws_send can be called only in the offchain worker context")
+			.ws_send(id, message, binary, deadline)
+	}
+
+	/// Receive the next message from a WebSocket connection.
+	///
+	/// Returns `None` if the remote has closed the connection, the `id` is then invalid.
+	/// Passing `None` as a deadline blocks forever.
+	fn ws_receive(
+		&mut self,
+		id: WebSocketId,
+		deadline: Option<Timestamp>,
+	) -> Result<Option<Vec<u8>>, WebSocketError> {
+		self.extension::<OffchainWorkerExt>()
+			.expect("ws_receive can be called only in the offchain worker context")
+			.ws_receive(id, deadline)
+	}
+
+	/// Close a WebSocket connection.
+	fn ws_close(&mut self, id: WebSocketId) {
+		self.extension::<OffchainWorkerExt>()
+			.expect("ws_close can be called only in the offchain worker context")
+			.ws_close(id)
+	}
 }
 
 /// Wasm only interface that provides functions for calling into the allocator.
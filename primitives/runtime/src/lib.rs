@@ -24,7 +24,7 @@
 pub use codec;
 #[doc(hidden)]
 pub use scale_info;
-#[cfg(feature = "std")]
+#[cfg(feature = "serde")]
 #[doc(hidden)]
 pub use serde;
 #[doc(hidden)]
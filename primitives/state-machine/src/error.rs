@@ -16,14 +16,21 @@
 // limitations under the License.
 
 /// State Machine Errors
-use sp_std::fmt;
+use sp_std::{any::Any, fmt};
 
 /// State Machine Error bound.
 ///
 /// This should reflect Wasm error type bound for future compatibility.
-pub trait Error: 'static + fmt::Debug + fmt::Display + Send + Sync {}
+pub trait Error: 'static + fmt::Debug + fmt::Display + Send + Sync {
+	/// The error as [`Any`], to get back the concrete type of a boxed error.
+	fn as_any(&self) -> &dyn Any;
+}
 
-impl<T: 'static + fmt::Debug + fmt::Display + Send + Sync> Error for T {}
+impl<T: 'static + fmt::Debug + fmt::Display + Send + Sync> Error for T {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
 
 /// Externalities Error.
 ///
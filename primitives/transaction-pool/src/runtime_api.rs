@@ -17,11 +17,27 @@
 
 //! Tagged Transaction Queue Runtime API.
 
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::Block as BlockT,
 	transaction_validity::{TransactionSource, TransactionValidity},
 };
 
+/// Who pays for a transaction and how much, as reported by the runtime.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct TransactionInfo {
+	/// Encoded account that signed the transaction.
+	///
+	/// Runtimes must resolve the address of the signer first, so that an account is reported the
+	/// same way however a transaction addresses it.
+	pub sender: sp_std::vec::Vec<u8>,
+	/// Fee the sender pays for the transaction, including the tip.
+	pub fee: u128,
+	/// Reference time weight of the transaction.
+	pub weight: u64,
+}
+
 sp_api::decl_runtime_apis! {
 	/// The `TaggedTransactionQueue` api trait for interfering with the transaction queue.
 	#[api_version(3)]
@@ -51,5 +67,18 @@ sp_api::decl_runtime_apis! {
 			tx: <Block as BlockT>::Extrinsic,
 			block_hash: Block::Hash,
 		) -> TransactionValidity;
+
+		/// Validate the transaction and return its sender and fee.
+		///
+		/// Same as `validate_transaction`, the [`TransactionInfo`] lets the transaction pool apply
+		/// per-sender limits and evict the transactions paying the least per unit of weight first.
+		/// It is `None` for transactions without a sender, e.g. unsigned ones, and for
+		/// transactions whose sender address can't be resolved.
+		#[api_version(4)]
+		fn validate_transaction_with_info(
+			source: TransactionSource,
+			tx: <Block as BlockT>::Extrinsic,
+			block_hash: Block::Hash,
+		) -> (TransactionValidity, Option<TransactionInfo>);
 	}
 }
@@ -50,8 +50,9 @@ use trie_db::proof::{generate_proof, verify_proof};
 pub use trie_db::{
 	nibble_ops,
 	node::{NodePlan, ValuePlan},
-	CError, DBValue, Query, Recorder, Trie, TrieCache, TrieConfiguration, TrieDBIterator,
-	TrieDBKeyIterator, TrieDBRawIterator, TrieLayout, TrieMut, TrieRecorder,
+	trie_visit, CError, DBValue, Query, Recorder, Trie, TrieCache, TrieConfiguration,
+	TrieDBIterator, TrieDBKeyIterator, TrieDBRawIterator, TrieLayout, TrieMut, TrieRecorder,
+	TrieRoot,
 };
 /// The Substrate format implementation of `TrieStream`.
 pub use trie_stream::TrieStream;
@@ -24,6 +24,7 @@ mod overhead;
 mod pallet;
 mod shared;
 mod storage;
+mod storage_profile;
 
 pub use block::BlockCmd;
 pub use extrinsic::{ExtrinsicBuilder, ExtrinsicCmd, ExtrinsicFactory};
@@ -32,6 +33,7 @@ pub use overhead::OverheadCmd;
 pub use pallet::PalletCmd;
 pub use sc_service::BasePath;
 pub use storage::StorageCmd;
+pub use storage_profile::{ReportFormat, StorageProfileCmd};
 
 use sc_cli::{CliConfiguration, DatabaseParams, ImportParams, PruningParams, Result, SharedParams};
 
@@ -42,6 +44,7 @@ use sc_cli::{CliConfiguration, DatabaseParams, ImportParams, PruningParams, Resu
 pub enum BenchmarkCmd {
 	Pallet(PalletCmd),
 	Storage(StorageCmd),
+	StorageProfile(StorageProfileCmd),
 	Overhead(OverheadCmd),
 	Block(BlockCmd),
 	Machine(MachineCmd),
@@ -58,6 +61,7 @@ macro_rules! unwrap_cmd {
 		match $self {
 			BenchmarkCmd::Pallet($cmd) => $code,
 			BenchmarkCmd::Storage($cmd) => $code,
+			BenchmarkCmd::StorageProfile($cmd) => $code,
 			BenchmarkCmd::Overhead($cmd) => $code,
 			BenchmarkCmd::Block($cmd) => $code,
 			BenchmarkCmd::Machine($cmd) => $code,
@@ -0,0 +1,216 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`StorageProfileCmd`] as entry point for the CLI to profile the storage accesses
+//! of historic blocks.
+
+use sc_cli::{CliConfiguration, Error, ImportParams, Result, SharedParams};
+use sc_client_api::BlockBackend;
+use sc_client_db::{StorageProfile, StorageProfiler};
+use sp_api::{Core, Metadata, ProvideRuntimeApi};
+use sp_blockchain::{Error::RuntimeApiError, HeaderBackend};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, Header as HeaderT},
+	DigestItem,
+};
+
+use clap::{Parser, ValueEnum};
+use log::info;
+use std::{path::PathBuf, sync::Arc};
+
+use super::report::{BlockProfile, ProfileEntry, ProfileReport, StorageNames};
+
+/// Log target for printing the storage profile.
+const LOG_TARGET: &'static str = "benchmark::storage-profile";
+
+/// The format of a storage profile report.
+#[derive(ValueEnum, Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReportFormat {
+	/// One CSV row per storage item and block.
+	Csv,
+	/// A JSON document with the profiles of all blocks.
+	Json,
+}
+
+/// Profile the storage accesses of historic blocks.
+///
+/// Re-executes the given blocks and attributes every storage access to the pallet and storage
+/// item it belongs to. The names are resolved from the metadata of the runtime. Reports the
+/// number of reads, writes, accessed bytes and keys, repeat reads and trie depth for every block
+/// and for all blocks combined.
+///
+/// $ substrate benchmark storage-profile --from 1 --to 100 --dev -d /tmp/my-dev
+///   --pruning archive --out profile.csv
+///
+/// Profiling is slow, since the trie depth of every read key is measured by recording a read
+/// proof for it.
+#[derive(Debug, Parser)]
+pub struct StorageProfileCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+
+	/// Number of the first block to profile.
+	#[arg(long)]
+	pub from: u32,
+
+	/// Number of the last block to profile.
+	#[arg(long)]
+	pub to: u32,
+
+	/// File to write the report to.
+	#[arg(long)]
+	pub out: PathBuf,
+
+	/// Format of the report.
+	#[arg(long, value_enum, ignore_case = true, default_value_t = ReportFormat::Csv)]
+	pub format: ReportFormat,
+
+	/// Number of storage items with the most accessed bytes to log.
+	#[arg(long, default_value_t = 10)]
+	pub top: usize,
+
+	/// Enable the Trie cache.
+	///
+	/// This should only be used for performance analysis and not for final results.
+	#[arg(long)]
+	pub enable_trie_cache: bool,
+}
+
+impl StorageProfileCmd {
+	/// Profile the storage accesses of historic blocks and write the report to the output file.
+	pub fn run<Block, C>(&self, client: Arc<C>, profiler: Arc<StorageProfiler>) -> Result<()>
+	where
+		Block: BlockT,
+		C: ProvideRuntimeApi<Block> + BlockBackend<Block> + HeaderBackend<Block>,
+		C::Api: Core<Block> + Metadata<Block>,
+	{
+		if self.from == 0 {
+			return Err("Cannot profile the genesis block".into())
+		}
+
+		let mut names = StorageNames::default();
+		let mut spec_version = None;
+		let mut total = StorageProfile::default();
+		let mut report = ProfileReport::default();
+
+		for number in self.from..=self.to {
+			let hash = client.expect_block_hash_from_id(&BlockId::Number(number.into()))?;
+			let block = client.block(hash)?.ok_or(format!("Block {} not found", number))?.block;
+			let block = unsealed(block);
+			let parent_hash = *block.header().parent_hash();
+
+			let runtime_api = client.runtime_api();
+			let version = runtime_api
+				.version(parent_hash)
+				.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+			if spec_version != Some(version.spec_version) {
+				let metadata = runtime_api
+					.metadata(parent_hash)
+					.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+				names.extend_from_metadata(&metadata)?;
+				spec_version = Some(version.spec_version);
+			}
+
+			let profile = profile_block(&*client, &profiler, block, parent_hash)?;
+			total.merge(&profile);
+			report.blocks.push(BlockProfile {
+				number,
+				hash: format!("{:?}", hash),
+				entries: ProfileEntry::from_profile(&profile, &names),
+			});
+		}
+		report.total = ProfileEntry::from_profile(&total, &names);
+
+		for entry in report.total.iter().take(self.top) {
+			info!(
+				target: LOG_TARGET,
+				"{}::{}: {} reads ({} bytes), {} writes ({} bytes), {} keys",
+				entry.pallet,
+				entry.item,
+				entry.reads,
+				entry.bytes_read,
+				entry.writes,
+				entry.bytes_written,
+				entry.keys,
+			);
+		}
+
+		let rendered = match self.format {
+			ReportFormat::Csv => report.to_csv(),
+			ReportFormat::Json =>
+				report.to_json().map_err(|e| format!("Error rendering the report: {}", e))?,
+		};
+		std::fs::write(&self.out, rendered)?;
+		info!(target: LOG_TARGET, "Wrote the storage profile to {:?}", self.out);
+
+		Ok(())
+	}
+}
+
+/// Execute the block with profiling enabled and return its profile.
+fn profile_block<Block, C>(
+	client: &C,
+	profiler: &StorageProfiler,
+	block: Block,
+	parent_hash: Block::Hash,
+) -> Result<StorageProfile>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: Core<Block>,
+{
+	// Discard the accesses of states that were not created for the block execution.
+	profiler.take();
+	profiler.enable();
+	let result = client.runtime_api().execute_block(parent_hash, block);
+	profiler.disable();
+
+	result.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+	Ok(profiler.take())
+}
+
+/// Removes the consensus seal from the block.
+fn unsealed<Block: BlockT>(block: Block) -> Block {
+	let (mut header, exts) = block.deconstruct();
+	header.digest_mut().logs.retain(|item| !matches!(item, DigestItem::Seal(_, _)));
+	Block::new(header, exts)
+}
+
+// Boilerplate
+impl CliConfiguration for StorageProfileCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+
+	fn trie_cache_maximum_size(&self) -> Result<Option<usize>> {
+		if self.enable_trie_cache {
+			Ok(self.import_params().map(|x| x.trie_cache_maximum_size()).unwrap_or_default())
+		} else {
+			Ok(None)
+		}
+	}
+}
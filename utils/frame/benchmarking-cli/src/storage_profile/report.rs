@@ -0,0 +1,285 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resolves storage profiles to pallet and storage item names and renders them.
+
+use codec::Decode;
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use sc_client_db::StorageProfile;
+use sp_core::{hashing::twox_128, storage::well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX};
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Names of the storage items of a runtime, by their key prefix.
+#[derive(Debug, Default)]
+pub struct StorageNames {
+	pallets: HashMap<[u8; 16], String>,
+	items: HashMap<[u8; 32], String>,
+}
+
+impl StorageNames {
+	/// Add the storage items of the given SCALE encoded runtime metadata.
+	///
+	/// Names of previously added runtimes are kept, so that prefixes of storage items which were
+	/// removed by a runtime upgrade can still be resolved.
+	pub fn extend_from_metadata(&mut self, mut metadata: &[u8]) -> Result<(), String> {
+		let metadata = RuntimeMetadataPrefixed::decode(&mut metadata)
+			.map_err(|e| format!("Error decoding runtime metadata: {}", e))?;
+		let RuntimeMetadata::V14(metadata) = metadata.1 else {
+			return Err("Only metadata V14 is supported".into())
+		};
+
+		for storage in metadata.pallets.into_iter().filter_map(|pallet| pallet.storage) {
+			let pallet = twox_128(storage.prefix.as_bytes());
+			self.pallets.insert(pallet, storage.prefix);
+			for entry in storage.entries {
+				let mut prefix = [0u8; 32];
+				prefix[..16].copy_from_slice(&pallet);
+				prefix[16..].copy_from_slice(&twox_128(entry.name.as_bytes()));
+				self.items.insert(prefix, entry.name);
+			}
+		}
+		Ok(())
+	}
+
+	/// The pallet and storage item name of a prefix.
+	///
+	/// Well known keys are reported as items of the `:well_known` pseudo pallet, child tries as
+	/// items of the `:child_storage` pseudo pallet named by their hex encoded storage key. Unknown
+	/// parts of a prefix are hex encoded.
+	pub fn resolve(&self, prefix: &[u8]) -> (String, String) {
+		if let Some(storage_key) = prefix.strip_prefix(DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+			return (":child_storage".into(), array_bytes::bytes2hex("0x", storage_key))
+		}
+		if prefix.starts_with(b":") {
+			return (":well_known".into(), String::from_utf8_lossy(prefix).into_owned())
+		}
+
+		let pallet = prefix.get(..16).and_then(|pallet| {
+			self.pallets.get(<&[u8; 16]>::try_from(pallet).expect("16 bytes; qed"))
+		});
+		let item = <&[u8; 32]>::try_from(prefix).ok().and_then(|item| self.items.get(item));
+		match (pallet, item) {
+			(Some(pallet), Some(item)) => (pallet.clone(), item.clone()),
+			(Some(pallet), None) => (pallet.clone(), array_bytes::bytes2hex("0x", &prefix[16..])),
+			_ => ("unknown".into(), array_bytes::bytes2hex("0x", prefix)),
+		}
+	}
+}
+
+/// The storage accesses of a single storage item.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProfileEntry {
+	/// Name of the pallet.
+	pub pallet: String,
+	/// Name of the storage item.
+	pub item: String,
+	/// Hex encoded key prefix of the storage item.
+	pub prefix: String,
+	/// Number of reads.
+	pub reads: u64,
+	/// Number of reads of keys that were already read before in the same block.
+	pub repeat_reads: u64,
+	/// Number of bytes read.
+	pub bytes_read: u64,
+	/// Number of distinct keys written.
+	pub writes: u64,
+	/// Number of bytes written.
+	pub bytes_written: u64,
+	/// Number of distinct keys accessed.
+	pub keys: u64,
+	/// Average number of trie nodes on the path to a read key.
+	pub average_trie_depth: f64,
+	/// Maximum number of trie nodes on the path to a read key.
+	pub max_trie_depth: u32,
+}
+
+impl ProfileEntry {
+	/// The entries of a profile, ordered by the number of bytes accessed.
+	pub fn from_profile(profile: &StorageProfile, names: &StorageNames) -> Vec<Self> {
+		let mut entries = profile
+			.prefixes
+			.iter()
+			.map(|(prefix, usage)| {
+				let (pallet, item) = names.resolve(prefix);
+				ProfileEntry {
+					pallet,
+					item,
+					prefix: array_bytes::bytes2hex("0x", prefix),
+					reads: usage.reads,
+					repeat_reads: usage.repeat_reads,
+					bytes_read: usage.bytes_read,
+					writes: usage.writes,
+					bytes_written: usage.bytes_written,
+					keys: usage.keys,
+					average_trie_depth: usage.average_trie_depth(),
+					max_trie_depth: usage.max_trie_depth,
+				}
+			})
+			.collect::<Vec<_>>();
+		entries.sort_by_key(|e| std::cmp::Reverse(e.bytes_read + e.bytes_written));
+		entries
+	}
+}
+
+/// The storage profile of a single block.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BlockProfile {
+	/// Number of the block.
+	pub number: u32,
+	/// Hex encoded hash of the block.
+	pub hash: String,
+	/// The accessed storage items.
+	pub entries: Vec<ProfileEntry>,
+}
+
+/// The storage profile of a range of blocks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ProfileReport {
+	/// The profiles of the individual blocks.
+	pub blocks: Vec<BlockProfile>,
+	/// The profile of all blocks combined.
+	///
+	/// The number of distinct keys is summed up over the blocks.
+	pub total: Vec<ProfileEntry>,
+}
+
+impl ProfileReport {
+	/// Render the report as JSON.
+	pub fn to_json(&self) -> serde_json::Result<String> {
+		serde_json::to_string_pretty(self)
+	}
+
+	/// Render the report as CSV.
+	///
+	/// Every row is one storage item of a block, the rows of the combined profile have `total`
+	/// as block number.
+	pub fn to_csv(&self) -> String {
+		let mut csv = String::from(
+			"block,hash,pallet,item,prefix,reads,repeat_reads,bytes_read,writes,bytes_written,keys,\
+			 average_trie_depth,max_trie_depth\n",
+		);
+		let rows = self
+			.blocks
+			.iter()
+			.flat_map(|block| {
+				block.entries.iter().map(|e| (block.number.to_string(), block.hash.as_str(), e))
+			})
+			.chain(self.total.iter().map(|e| ("total".to_string(), "", e)));
+		for (block, hash, e) in rows {
+			csv.push_str(&format!(
+				"{},{},{},{},{},{},{},{},{},{},{},{:.2},{}\n",
+				block,
+				hash,
+				csv_field(&e.pallet),
+				csv_field(&e.item),
+				e.prefix,
+				e.reads,
+				e.repeat_reads,
+				e.bytes_read,
+				e.writes,
+				e.bytes_written,
+				e.keys,
+				e.average_trie_depth,
+				e.max_trie_depth,
+			));
+		}
+		csv
+	}
+}
+
+/// Quote a CSV field if needed.
+fn csv_field(field: &str) -> String {
+	if field.contains([',', '"', '\n']) {
+		format!("\"{}\"", field.replace('"', "\"\""))
+	} else {
+		field.into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_client_db::PrefixUsage;
+
+	fn names() -> StorageNames {
+		let pallet = twox_128(b"System");
+		let mut names = StorageNames::default();
+		names.pallets.insert(pallet, "System".into());
+		names.items.insert(
+			[&pallet[..], &twox_128(b"Account")[..]].concat().try_into().unwrap(),
+			"Account".into(),
+		);
+		names
+	}
+
+	#[test]
+	fn prefixes_are_resolved_to_names() {
+		let names = names();
+		let system = twox_128(b"System");
+		let account = [&system[..], &twox_128(b"Account")[..]].concat();
+		let events = [&system[..], &twox_128(b"Events")[..]].concat();
+
+		assert_eq!(names.resolve(&account), ("System".into(), "Account".into()));
+		assert_eq!(
+			names.resolve(&events),
+			("System".into(), array_bytes::bytes2hex("0x", twox_128(b"Events")))
+		);
+		assert_eq!(names.resolve(b":code"), (":well_known".into(), ":code".into()));
+		assert_eq!(
+			names.resolve(b":child_storage:default:\x01\x02"),
+			(":child_storage".into(), "0x0102".into())
+		);
+		assert_eq!(names.resolve(&[1u8; 32]).0, "unknown");
+	}
+
+	#[test]
+	fn report_renders_entries_ordered_by_bytes() {
+		let names = names();
+		let account = [&twox_128(b"System")[..], &twox_128(b"Account")[..]].concat();
+		let mut profile = StorageProfile::default();
+		profile.prefixes.insert(
+			account,
+			PrefixUsage { reads: 2, bytes_read: 10, keys: 2, trie_depth: 6, ..Default::default() },
+		);
+		profile.prefixes.insert(
+			b":code".to_vec(),
+			PrefixUsage { reads: 1, bytes_read: 100, ..Default::default() },
+		);
+
+		let entries = ProfileEntry::from_profile(&profile, &names);
+		assert_eq!(entries[0].item, ":code");
+		assert_eq!(entries[1].item, "Account");
+		assert_eq!(entries[1].average_trie_depth, 3.0);
+
+		let report = ProfileReport {
+			blocks: vec![BlockProfile { number: 1, hash: "0x01".into(), entries: entries.clone() }],
+			total: entries,
+		};
+		let csv = report.to_csv();
+		let lines = csv.lines().collect::<Vec<_>>();
+		assert_eq!(lines.len(), 5);
+		assert!(lines[2].starts_with("1,0x01,System,Account,"));
+		assert!(lines[4].starts_with("total,,System,Account,"));
+		assert!(lines[4].ends_with(",2,0,10,0,0,2,3.00,0"));
+
+		let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+		assert_eq!(json["blocks"][0]["entries"][1]["pallet"], "System");
+		assert_eq!(json["total"][0]["bytes_read"], 100);
+	}
+}